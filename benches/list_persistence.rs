@@ -0,0 +1,103 @@
+//! List copy benchmarks
+//!
+//! Run with: `cargo bench --features profiling --bench list_persistence`
+//!
+//! A list's backing graph keeps its nodes in a persistent map, so a copy
+//! shares node storage with the list it came from. These benchmarks time a
+//! functional update (copy, then change the copy) and plain list reads, which
+//! pay for the shared storage. Building an ordinary graph uses owned storage
+//! and is included to check it does not.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use graphoid::execution::Executor;
+use graphoid::values::{Graph, GraphType, List, Value};
+
+const SIZES: [usize; 2] = [100, 1000];
+
+fn numbers(n: usize) -> List {
+    List::from_vec((0..n).map(|i| Value::number(i as f64)).collect())
+}
+
+fn bench_copy_then_append(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_copy_then_append");
+    for n in SIZES {
+        let list = numbers(n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &list, |b, list| {
+            b.iter(|| {
+                let mut copy = list.clone();
+                copy.append(Value::number(-1.0)).unwrap();
+                black_box(copy)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_copy_then_set(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_copy_then_set");
+    for n in SIZES {
+        let list = numbers(n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &list, |b, list| {
+            b.iter(|| {
+                let mut copy = list.clone();
+                copy.set(n / 2, Value::number(-1.0)).unwrap();
+                black_box(copy)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_read_every_element(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_read_every_element");
+    for n in SIZES {
+        let list = numbers(n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &list, |b, list| {
+            b.iter(|| (0..list.len()).filter_map(|i| list.get(i)).count())
+        });
+    }
+    group.finish();
+}
+
+fn bench_graph_build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("graph_add_nodes");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| {
+                let mut graph = Graph::new(GraphType::Directed);
+                for i in 0..n {
+                    graph.add_node(format!("n{}", i), Value::number(i as f64)).unwrap();
+                }
+                black_box(graph)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_functional_append_loop(c: &mut Criterion) {
+    let source = r#"
+        items = []
+        i = 0
+        while i < 500 {
+            items = items.append(i)
+            i = i + 1
+        }
+    "#;
+    c.bench_function("script_functional_append_500", |b| {
+        b.iter(|| {
+            let mut executor = Executor::new();
+            executor.execute_source(black_box(source)).unwrap();
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_copy_then_append,
+    bench_copy_then_set,
+    bench_read_every_element,
+    bench_graph_build,
+    bench_functional_append_loop
+);
+criterion_main!(benches);
@@ -0,0 +1,94 @@
+//! Symbol interning benchmarks
+//!
+//! Run with: `cargo bench --features profiling --bench symbol_interning`
+//!
+//! Interned `Symbol`s are compared against plain `String`s, the representation
+//! symbols had before interning, for the operations symbols see most:
+//! equality, hashing and cloning. A symbol-heavy script is timed end to end.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use graphoid::execution::Executor;
+use graphoid::values::Symbol;
+use std::collections::HashSet;
+
+const NAMES: [&str; 8] = [
+    "status", "pending", "active", "archived", "weighted", "directed", "no_cycles", "connected",
+];
+
+fn bench_equality(c: &mut Criterion) {
+    let symbols: Vec<Symbol> = NAMES.iter().map(|name| Symbol::intern(name)).collect();
+    let other_symbols: Vec<Symbol> = NAMES.iter().map(|name| Symbol::intern(name)).collect();
+    let strings: Vec<String> = NAMES.iter().map(|name| name.to_string()).collect();
+    let other_strings: Vec<String> = NAMES.iter().map(|name| name.to_string()).collect();
+
+    let mut group = c.benchmark_group("symbol_equality");
+    group.bench_function("interned", |b| {
+        b.iter(|| {
+            symbols.iter()
+                .flat_map(|a| other_symbols.iter().map(move |b| a == b))
+                .filter(|&same| same)
+                .count()
+        })
+    });
+    group.bench_function("string", |b| {
+        b.iter(|| {
+            strings.iter()
+                .flat_map(|a| other_strings.iter().map(move |b| a == b))
+                .filter(|&same| same)
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn bench_hashing(c: &mut Criterion) {
+    let symbols: Vec<Symbol> = NAMES.iter().map(|name| Symbol::intern(name)).collect();
+    let strings: Vec<String> = NAMES.iter().map(|name| name.to_string()).collect();
+
+    let mut group = c.benchmark_group("symbol_set_lookup");
+    group.bench_function("interned", |b| {
+        let set: HashSet<Symbol> = symbols.iter().cloned().collect();
+        b.iter(|| symbols.iter().filter(|symbol| set.contains(*symbol)).count())
+    });
+    group.bench_function("string", |b| {
+        let set: HashSet<String> = strings.iter().cloned().collect();
+        b.iter(|| strings.iter().filter(|string| set.contains(*string)).count())
+    });
+    group.finish();
+}
+
+fn bench_cloning(c: &mut Criterion) {
+    let symbols: Vec<Symbol> = NAMES.iter().map(|name| Symbol::intern(name)).collect();
+    let strings: Vec<String> = NAMES.iter().map(|name| name.to_string()).collect();
+
+    let mut group = c.benchmark_group("symbol_clone");
+    group.bench_function("interned", |b| b.iter(|| black_box(symbols.clone())));
+    group.bench_function("string", |b| b.iter(|| black_box(strings.clone())));
+    group.finish();
+}
+
+fn bench_symbol_heavy_script(c: &mut Criterion) {
+    let source = r#"
+        states = [:pending, :active, :archived]
+        active = 0
+        tags = []
+        i = 0
+        while i < 300 {
+            state = states[i % 3]
+            if state == :active {
+                active = active + 1
+            }
+            tags = [state, :seen, :checked]
+            i = i + 1
+        }
+    "#;
+    c.bench_function("symbol_heavy_script", |b| {
+        b.iter(|| {
+            let mut executor = Executor::new();
+            executor.execute_source(black_box(source)).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_equality, bench_hashing, bench_cloning, bench_symbol_heavy_script);
+criterion_main!(benches);
@@ -23,6 +23,13 @@ pub enum Stmt {
         is_private: bool,  // Phase 10: priv keyword support
         position: SourcePosition,
     },
+    /// `const NAME = value`: a binding that can be shadowed but never reassigned
+    ConstDecl {
+        name: String,
+        value: Expr,
+        is_private: bool,
+        position: SourcePosition,
+    },
     Assignment {
         target: AssignmentTarget,
         value: Expr,
@@ -53,6 +60,9 @@ pub enum Stmt {
     },
     For {
         variable: String,
+        /// Further names in `for a, b in pairs`: each item is unpacked
+        /// across `variable` followed by these. Empty for a plain loop.
+        unpack: Vec<String>,
         iterable: Expr,
         body: Vec<Stmt>,
         position: SourcePosition,
@@ -125,6 +135,19 @@ pub enum Stmt {
         body: Vec<Stmt>,
         position: SourcePosition,
     },
+    /// Cleanup block run when the enclosing function or top-level scope exits
+    Defer {
+        body: Vec<Stmt>,
+        position: SourcePosition,
+    },
+    /// Runtime check: assert condition, "message"
+    /// `source` is the condition's text, reported when no message is given
+    Assert {
+        condition: Expr,
+        message: Option<Expr>,
+        source: String,
+        position: SourcePosition,
+    },
 }
 
 /// A property declaration inside a graph body: name: value
@@ -222,6 +245,12 @@ pub enum Expr {
         args: Vec<Argument>,
         position: SourcePosition,
     },
+    /// Super property access: super.name (no parentheses)
+    /// Reads the parent graph's property, or calls its zero-argument getter
+    SuperPropertyAccess {
+        property: String,
+        position: SourcePosition,
+    },
     /// Property access: object.property (no parentheses)
     /// Used for data node access on graphs and key access on hashes
     PropertyAccess {
@@ -248,7 +277,7 @@ pub enum Expr {
         position: SourcePosition,
     },
     Map {
-        entries: Vec<(String, Expr)>,
+        entries: Vec<(MapKey, Expr)>,
         position: SourcePosition,
     },
     Graph {
@@ -273,6 +302,11 @@ pub enum Expr {
         expr: Box<Expr>,  // The graph instantiation expression
         position: SourcePosition,
     },
+    /// `...expr` inside a list literal: the elements of the list `expr` evaluates to
+    Spread {
+        expr: Box<Expr>,
+        position: SourcePosition,
+    },
     Match {
         value: Box<Expr>,
         arms: Vec<MatchArm>,
@@ -286,6 +320,17 @@ pub enum Expr {
     },
 }
 
+/// Key of a map literal entry
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapKey {
+    /// String or identifier key ("name": v, name: v)
+    Static(String),
+    /// Computed key ([expr]: v), evaluated when the map is built
+    Computed(Expr),
+    /// `...expr` entry: every entry of the map the entry's value evaluates to
+    Spread,
+}
+
 /// A single arm in a match expression
 #[derive(Debug, Clone, PartialEq)]
 pub struct MatchArm {
@@ -330,8 +375,10 @@ impl Expr {
             Expr::Raise { position, .. } => position,
             Expr::Match { position, .. } => position,
             Expr::SuperMethodCall { position, .. } => position,
+            Expr::SuperPropertyAccess { position, .. } => position,
             Expr::Instantiate { position, .. } => position,
             Expr::SpawnActor { position, .. } => position,
+            Expr::Spread { position, .. } => position,
         }
     }
 }
@@ -554,7 +601,7 @@ fn collect_from_stmt(stmt: &Stmt, properties: &std::collections::HashSet<&String
             // Also collect reads from the value expression
             collect_from_expr(value, properties, refs);
         }
-        Stmt::VariableDecl { value, .. } => {
+        Stmt::VariableDecl { value, .. } | Stmt::ConstDecl { value, .. } => {
             collect_from_expr(value, properties, refs);
         }
         Stmt::Return { value, .. } => {
@@ -588,11 +635,17 @@ fn collect_from_stmt(stmt: &Stmt, properties: &std::collections::HashSet<&String
                 collect_from_stmt(s, properties, refs);
             }
         }
-        Stmt::Spawn { body, .. } | Stmt::PrivBlock { body, .. } => {
+        Stmt::Spawn { body, .. } | Stmt::PrivBlock { body, .. } | Stmt::Defer { body, .. } => {
             for s in body {
                 collect_from_stmt(s, properties, refs);
             }
         }
+        Stmt::Assert { condition, message, .. } => {
+            collect_from_expr(condition, properties, refs);
+            if let Some(expr) = message {
+                collect_from_expr(expr, properties, refs);
+            }
+        }
         _ => {}
     }
 }
@@ -633,7 +686,7 @@ fn collect_from_expr(expr: &Expr, properties: &std::collections::HashSet<&String
             collect_from_expr(object, properties, refs);
             collect_from_expr(index, properties, refs);
         }
-        Expr::PropertyAccess { object, .. } => {
+        Expr::PropertyAccess { object, .. } | Expr::Spread { expr: object, .. } => {
             collect_from_expr(object, properties, refs);
         }
         Expr::List { elements, .. } => {
@@ -642,13 +695,21 @@ fn collect_from_expr(expr: &Expr, properties: &std::collections::HashSet<&String
             }
         }
         Expr::Map { entries, .. } => {
-            for (_, v) in entries {
+            for (k, v) in entries {
+                if let MapKey::Computed(key_expr) = k {
+                    collect_from_expr(key_expr, properties, refs);
+                }
                 collect_from_expr(v, properties, refs);
             }
         }
         Expr::Lambda { body, .. } => {
             collect_from_expr(body, properties, refs);
         }
+        Expr::Block { statements, .. } => {
+            for s in statements {
+                collect_from_stmt(s, properties, refs);
+            }
+        }
         Expr::Conditional { condition, then_expr, else_expr, .. } => {
             collect_from_expr(condition, properties, refs);
             collect_from_expr(then_expr, properties, refs);
@@ -52,9 +52,11 @@ pub enum GraphoidError {
     #[error("Graph rule violated: {rule} - {message}")]
     RuleViolation { rule: String, message: String },
 
-    #[error("Module not found: '{module}' at {position}")]
+    #[error("Module not found: '{module}' (tried {}) at {position}", tried.join(", "))]
     ModuleNotFound {
         module: String,
+        /// Every path checked, in the order they were tried
+        tried: Vec<String>,
         position: SourcePosition,
     },
 
@@ -81,6 +83,14 @@ pub enum GraphoidError {
 
     #[error("Return control flow")]
     ReturnControl { value: crate::values::Value },
+
+    /// Fatal abort raised by `abort()`. `catch` clauses never handle it;
+    /// `finally` and `defer` blocks still run while it unwinds.
+    #[error("Aborted: {message}")]
+    Abort {
+        message: String,
+        stack_trace: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -168,6 +178,7 @@ impl GraphoidError {
             GraphoidError::ConfigError { .. } => "ConfigError".to_string(),
             GraphoidError::LoopControl { .. } => "LoopControl".to_string(),
             GraphoidError::ReturnControl { .. } => "ReturnControl".to_string(),
+            GraphoidError::Abort { .. } => "Abort".to_string(),
         }
     }
 
@@ -186,7 +197,57 @@ impl GraphoidError {
             GraphoidError::LoopControl { .. } => SourcePosition::unknown(),
             GraphoidError::ReturnControl { .. } => SourcePosition::unknown(),
             GraphoidError::ConfigError { .. } => SourcePosition::unknown(),
+            GraphoidError::Abort { .. } => SourcePosition::unknown(),
+        }
+    }
+
+    /// The error type a `catch` clause matches against, and the message
+    /// without its display prefix. Runtime errors raised with a typed message
+    /// such as `"ValueError: bad input"` report that type.
+    pub fn type_and_message(&self) -> (String, String) {
+        match self {
+            GraphoidError::IOError { message, .. } => return ("IOError".to_string(), message.clone()),
+            GraphoidError::ModuleNotFound { module, tried, .. } => return (
+                "ModuleNotFound".to_string(),
+                format!("Module not found: '{}' (tried {})", module, tried.join(", ")),
+            ),
+            _ => {}
+        }
+        let error_message = self.to_string();
+        let prefixes = [
+            "Runtime error: ", "Type error: ", "Syntax error: ",
+            "IO error: ", "Rule violation: ",
+            "Circular dependency: ", "Configuration error: ",
+        ];
+        let mut inner_message = error_message.as_str();
+        for prefix in &prefixes {
+            if let Some(stripped) = inner_message.strip_prefix(prefix) {
+                inner_message = stripped;
+                break;
+            }
+        }
+
+        if let Some(colon_pos) = inner_message.find(':') {
+            let potential_type = &inner_message[..colon_pos];
+            if matches!(potential_type, "ValueError" | "TypeError" | "IOError" | "NetworkError" | "ParseError" | "RuntimeError" | "FileError" | "NetError" | "AssertionError" | "TimeoutError") {
+                return (potential_type.to_string(), inner_message[(colon_pos + 1)..].trim().to_string());
+            }
         }
+        (self.error_type(), inner_message.to_string())
+    }
+
+    /// Machine-readable form used by `configure { output: :json }`:
+    /// `{"error": {"type": ..., "message": ..., "position": ...}}`, with a
+    /// null position when the error has none.
+    pub fn to_json(&self) -> serde_json::Value {
+        let (error_type, message) = self.type_and_message();
+        let position = self.position();
+        let position = if position.line == 0 {
+            serde_json::Value::Null
+        } else {
+            serde_json::json!({ "line": position.line, "column": position.column, "file": position.file })
+        };
+        serde_json::json!({ "error": { "type": error_type, "message": message, "position": position } })
     }
 }
 
@@ -216,9 +277,10 @@ impl Clone for GraphoidError {
                     message: message.clone(),
                 }
             }
-            GraphoidError::ModuleNotFound { module, position } => {
+            GraphoidError::ModuleNotFound { module, tried, position } => {
                 GraphoidError::ModuleNotFound {
                     module: module.clone(),
+                    tried: tried.clone(),
                     position: position.clone(),
                 }
             }
@@ -247,6 +309,10 @@ impl Clone for GraphoidError {
             }
             GraphoidError::LoopControl { control } => GraphoidError::LoopControl { control: *control },
             GraphoidError::ReturnControl { value } => GraphoidError::ReturnControl { value: value.clone() },
+            GraphoidError::Abort { message, stack_trace } => GraphoidError::Abort {
+                message: message.clone(),
+                stack_trace: stack_trace.clone(),
+            },
         }
     }
 }
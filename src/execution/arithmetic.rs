@@ -10,6 +10,48 @@ use crate::execution::config::PrecisionMode;
 use crate::execution::Executor;
 use crate::values::{BigNum, List, Value, ValueKind};
 
+/// How the operands of `+` or an ordering comparison (`<`, `<=`, `>`, `>=`)
+/// are brought to a common type before the operator runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Coercion {
+    /// Two numbers, used as they are
+    Numbers,
+    /// Two bignums, or a number and a bignum with the number widened to a
+    /// temporary Float128 (the number itself is not changed)
+    BigNumbers,
+    /// `+` with a string on either side: both operands are displayed as text
+    /// and joined
+    Concat,
+    /// Two strings, ordered by their characters
+    Strings,
+    /// No rule applies and the operator raises a TypeError
+    Incompatible,
+}
+
+/// The single source of the coercion rules for `+` and the ordering
+/// comparisons. `eval_add()` and the comparison helpers follow it, and the
+/// `can_add?()` / `can_compare?()` builtins report it, so a pair of operands
+/// either works for both or for neither. Other operators are not covered.
+pub(crate) fn coerce(op: &BinaryOp, left: &Value, right: &Value) -> Coercion {
+    let comparison = matches!(
+        op,
+        BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual
+    );
+    if !comparison && !matches!(op, BinaryOp::Add) {
+        return Coercion::Incompatible;
+    }
+    match (&left.kind, &right.kind) {
+        // Arithmetic between numbers wins over text
+        (ValueKind::Number(_), ValueKind::Number(_)) => Coercion::Numbers,
+        (ValueKind::Number(_) | ValueKind::BigNumber(_), ValueKind::Number(_) | ValueKind::BigNumber(_)) => {
+            Coercion::BigNumbers
+        }
+        (ValueKind::String(_), ValueKind::String(_)) if comparison => Coercion::Strings,
+        (ValueKind::String(_), _) | (_, ValueKind::String(_)) if !comparison => Coercion::Concat,
+        _ => Coercion::Incompatible,
+    }
+}
+
 impl Executor {
     // =========================================================================
     // BigNum Helper Methods
@@ -190,6 +232,11 @@ impl Executor {
             ))),
         };
 
+        // Inside `precision N { }`, arithmetic results are rounded to N places
+        if let ValueKind::Number(n) = result.kind {
+            result.kind = ValueKind::Number(self.apply_block_precision(n));
+        }
+
         // Propagate taint from operands to arithmetic result
         if tainted {
             result.tainted = true;
@@ -198,8 +245,40 @@ impl Executor {
         Ok(result)
     }
 
+    /// Round `n` to the decimal places of the innermost `precision` block.
+    /// `precision 0` (which is what `precision :int` means) truncates toward
+    /// zero instead, so `/` divides like `//`. Outside any block `n` is
+    /// returned unchanged.
+    pub(crate) fn apply_block_precision(&self, n: f64) -> f64 {
+        match self.precision_stack.last() {
+            None => n,
+            Some(None) | Some(Some(0)) => n.trunc(),
+            Some(Some(places)) => {
+                let scale = 10f64.powi((*places).min(308) as i32);
+                let rounded = (n * scale).round() / scale;
+                if rounded.is_finite() { rounded } else { n }
+            }
+        }
+    }
+
     // Arithmetic helpers
     pub(crate) fn eval_add(&self, left: Value, right: Value) -> Result<Value> {
+        match coerce(&BinaryOp::Add, &left, &right) {
+            Coercion::Concat => {
+                // Either operand is a string: display both and concatenate
+                let left_str = left.to_string_value();
+                let right_str = right.to_string_value();
+                return Ok(Value::string(format!("{}{}", left_str, right_str)));
+            }
+            Coercion::Incompatible | Coercion::Strings => {
+                return Err(GraphoidError::type_error(
+                    "number or string",
+                    &format!("{} and {}", left.type_name(), right.type_name()),
+                ));
+            }
+            Coercion::Numbers | Coercion::BigNumbers => {}
+        }
+
         match (&left.kind, &right.kind) {
             // BigNumber + BigNumber
             (ValueKind::BigNumber(l), ValueKind::BigNumber(r)) => {
@@ -312,14 +391,6 @@ impl Executor {
                 }
             }
 
-            // String concatenation
-            (ValueKind::String(_), _) | (_, ValueKind::String(_)) => {
-                // If either operand is a string, convert both to strings and concatenate
-                let left_str = left.to_string_value();
-                let right_str = right.to_string_value();
-                Ok(Value::string(format!("{}{}", left_str, right_str)))
-            }
-
             // Phase 1B: Mixed num/bignum operations - auto-cast num to bignum (TEMPORARY)
             // CRITICAL: This creates a TEMPORARY bignum copy for the operation.
             // The original num variable is NOT mutated!
@@ -828,6 +899,15 @@ impl Executor {
 
     pub(crate) fn eval_modulo(&mut self, left: Value, right: Value) -> Result<Value> {
         match (&left.kind, &right.kind) {
+            // String formatting: "%s scored %.1f" % [name, score]
+            (ValueKind::String(template), _) => {
+                let args = match &right.kind {
+                    ValueKind::List(list) => list.to_vec(),
+                    _ => vec![right.clone()],
+                };
+                format_percent(template, &args).map(Value::string)
+            }
+
             // BigNumber % BigNumber
             (ValueKind::BigNumber(l), ValueKind::BigNumber(r)) => {
                 match (l, r) {
@@ -1535,120 +1615,179 @@ impl Executor {
 
     // Comparison helpers
     pub(crate) fn eval_less(&self, left: Value, right: Value) -> Result<Value> {
-        match (&left.kind, &right.kind) {
-            (ValueKind::Number(l), ValueKind::Number(r)) => Ok(Value::boolean(l < r)),
-            (ValueKind::String(l), ValueKind::String(r)) => Ok(Value::boolean(l < r)),
-
-            // BigNumber < BigNumber - compare by value
-            (ValueKind::BigNumber(l), ValueKind::BigNumber(r)) => {
-                // Convert both to f64 for comparison (may lose precision for very large numbers)
-                let lv = self.bignum_to_f64(l)?;
-                let rv = self.bignum_to_f64(r)?;
-                Ok(Value::boolean(lv < rv))
-            }
-
-            // Phase 1B: Mixed num/bignum comparison - compare by value, not type
-            (ValueKind::Number(n), ValueKind::BigNumber(bn)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(*n < bn_f64))
-            }
-            (ValueKind::BigNumber(bn), ValueKind::Number(n)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(bn_f64 < *n))
-            }
-
-            (_l, _r) => Err(GraphoidError::type_error(
-                "number, string, or bignum",
-                &format!("{} and {}", left.type_name(), right.type_name()),
-            )),
-        }
+        self.eval_ordering(&BinaryOp::Less, left, right)
     }
 
     pub(crate) fn eval_less_equal(&self, left: Value, right: Value) -> Result<Value> {
-        match (&left.kind, &right.kind) {
-            (ValueKind::Number(l), ValueKind::Number(r)) => Ok(Value::boolean(l <= r)),
-            (ValueKind::String(l), ValueKind::String(r)) => Ok(Value::boolean(l <= r)),
+        self.eval_ordering(&BinaryOp::LessEqual, left, right)
+    }
 
-            // BigNumber <= BigNumber - compare by value
-            (ValueKind::BigNumber(l), ValueKind::BigNumber(r)) => {
-                let lv = self.bignum_to_f64(l)?;
-                let rv = self.bignum_to_f64(r)?;
-                Ok(Value::boolean(lv <= rv))
-            }
+    pub(crate) fn eval_greater(&self, left: Value, right: Value) -> Result<Value> {
+        self.eval_ordering(&BinaryOp::Greater, left, right)
+    }
 
-            // Phase 1B: Mixed num/bignum comparison
-            (ValueKind::Number(n), ValueKind::BigNumber(bn)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(*n <= bn_f64))
-            }
-            (ValueKind::BigNumber(bn), ValueKind::Number(n)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(bn_f64 <= *n))
-            }
+    pub(crate) fn eval_greater_equal(&self, left: Value, right: Value) -> Result<Value> {
+        self.eval_ordering(&BinaryOp::GreaterEqual, left, right)
+    }
 
-            (_l, _r) => Err(GraphoidError::type_error(
+    /// `<`, `<=`, `>` or `>=` after coercing the operands (see `coerce()`).
+    /// Bignums are compared by value as f64, which may lose precision for
+    /// very large numbers. Any comparison involving NaN is false.
+    fn eval_ordering(&self, op: &BinaryOp, left: Value, right: Value) -> Result<Value> {
+        let ordering = match (coerce(op, &left, &right), &left.kind, &right.kind) {
+            (Coercion::Numbers, ValueKind::Number(l), ValueKind::Number(r)) => l.partial_cmp(r),
+            (Coercion::Strings, ValueKind::String(l), ValueKind::String(r)) => Some(l.cmp(r)),
+            (Coercion::BigNumbers, _, _) => {
+                let lv = self.compare_operand_f64(&left)?;
+                let rv = self.compare_operand_f64(&right)?;
+                lv.partial_cmp(&rv)
+            }
+            _ => return Err(GraphoidError::type_error(
                 "number, string, or bignum",
                 &format!("{} and {}", left.type_name(), right.type_name()),
             )),
+        };
+
+        use std::cmp::Ordering;
+        Ok(Value::boolean(match (op, ordering) {
+            (_, None) => false,
+            (BinaryOp::Less, Some(o)) => o == Ordering::Less,
+            (BinaryOp::LessEqual, Some(o)) => o != Ordering::Greater,
+            (BinaryOp::Greater, Some(o)) => o == Ordering::Greater,
+            (_, Some(o)) => o != Ordering::Less,
+        }))
+    }
+
+    fn compare_operand_f64(&self, value: &Value) -> Result<f64> {
+        match &value.kind {
+            ValueKind::Number(n) => Ok(*n),
+            ValueKind::BigNumber(bn) => self.bignum_to_f64(bn),
+            _ => Err(GraphoidError::type_error("number or bignum", value.type_name())),
         }
     }
 
-    pub(crate) fn eval_greater(&self, left: Value, right: Value) -> Result<Value> {
-        match (&left.kind, &right.kind) {
-            (ValueKind::Number(l), ValueKind::Number(r)) => Ok(Value::boolean(l > r)),
-            (ValueKind::String(l), ValueKind::String(r)) => Ok(Value::boolean(l > r)),
+}
 
-            // BigNumber > BigNumber - compare by value
-            (ValueKind::BigNumber(l), ValueKind::BigNumber(r)) => {
-                let lv = self.bignum_to_f64(l)?;
-                let rv = self.bignum_to_f64(r)?;
-                Ok(Value::boolean(lv > rv))
+/// Expand a printf-style template for the `%` operator.
+///
+/// Supports `%s`, `%d` and `%f` with an optional `-` (left-align) or `0`
+/// (zero-pad) flag, width and precision, e.g. `%-8s`, `%05d`, `%.2f`.
+/// `%%` is a literal percent sign.
+fn format_percent(template: &str, args: &[Value]) -> Result<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    let mut next_arg = 0;
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            out.push('%');
+            continue;
+        }
+
+        let mut left_align = false;
+        let mut zero_pad = false;
+        while let Some(&flag) = chars.peek() {
+            match flag {
+                '-' => left_align = true,
+                '0' => zero_pad = true,
+                _ => break,
             }
+            chars.next();
+        }
+        let width = take_digits(&mut chars).unwrap_or(0);
+        let precision = if chars.peek() == Some(&'.') {
+            chars.next();
+            Some(take_digits(&mut chars).unwrap_or(0))
+        } else {
+            None
+        };
 
-            // Phase 1B: Mixed num/bignum comparison
-            (ValueKind::Number(n), ValueKind::BigNumber(bn)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(*n > bn_f64))
+        let conversion = chars.next().ok_or_else(|| {
+            GraphoidError::runtime("ValueError: incomplete format specifier at end of string".to_string())
+        })?;
+        let arg = args.get(next_arg).ok_or_else(|| {
+            GraphoidError::runtime(format!(
+                "ValueError: not enough arguments for format string (got {})",
+                args.len()
+            ))
+        })?;
+        next_arg += 1;
+
+        let text = match conversion {
+            's' => {
+                let text = arg.to_string_value();
+                match precision {
+                    Some(p) => text.chars().take(p).collect(),
+                    None => text,
+                }
+            }
+            'd' => {
+                let n = format_number_arg(arg, conversion)?;
+                let n = n.trunc() as i64;
+                if zero_pad && !left_align {
+                    format!("{:0width$}", n, width = width)
+                } else {
+                    n.to_string()
+                }
+            }
+            'f' => {
+                let n = format_number_arg(arg, conversion)?;
+                let precision = precision.unwrap_or(6);
+                if zero_pad && !left_align {
+                    format!("{:0width$.precision$}", n, width = width, precision = precision)
+                } else {
+                    format!("{:.precision$}", n, precision = precision)
+                }
             }
-            (ValueKind::BigNumber(bn), ValueKind::Number(n)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(bn_f64 > *n))
+            other => {
+                return Err(GraphoidError::runtime(format!(
+                    "ValueError: unsupported format character '{}'", other
+                )));
             }
+        };
 
-            (_l, _r) => Err(GraphoidError::type_error(
-                "number, string, or bignum",
-                &format!("{} and {}", left.type_name(), right.type_name()),
-            )),
+        if left_align {
+            out.push_str(&format!("{:<width$}", text, width = width));
+        } else {
+            out.push_str(&format!("{:>width$}", text, width = width));
         }
     }
 
-    pub(crate) fn eval_greater_equal(&self, left: Value, right: Value) -> Result<Value> {
-        match (&left.kind, &right.kind) {
-            (ValueKind::Number(l), ValueKind::Number(r)) => Ok(Value::boolean(l >= r)),
-            (ValueKind::String(l), ValueKind::String(r)) => Ok(Value::boolean(l >= r)),
-
-            // BigNumber >= BigNumber - compare by value
-            (ValueKind::BigNumber(l), ValueKind::BigNumber(r)) => {
-                let lv = self.bignum_to_f64(l)?;
-                let rv = self.bignum_to_f64(r)?;
-                Ok(Value::boolean(lv >= rv))
-            }
-
-            // Phase 1B: Mixed num/bignum comparison
-            (ValueKind::Number(n), ValueKind::BigNumber(bn)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(*n >= bn_f64))
-            }
-            (ValueKind::BigNumber(bn), ValueKind::Number(n)) => {
-                let bn_f64 = self.bignum_to_f64(bn)?;
-                Ok(Value::boolean(bn_f64 >= *n))
-            }
+    if next_arg < args.len() {
+        return Err(GraphoidError::runtime(format!(
+            "ValueError: too many arguments for format string (expected {}, got {})",
+            next_arg,
+            args.len()
+        )));
+    }
+    Ok(out)
+}
 
-            (_l, _r) => Err(GraphoidError::type_error(
-                "number, string, or bignum",
-                &format!("{} and {}", left.type_name(), right.type_name()),
-            )),
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<usize> {
+    let mut digits = String::new();
+    while let Some(&d) = chars.peek() {
+        if !d.is_ascii_digit() {
+            break;
         }
+        digits.push(d);
+        chars.next();
     }
+    digits.parse().ok()
+}
 
+fn format_number_arg(arg: &Value, conversion: char) -> Result<f64> {
+    match &arg.kind {
+        ValueKind::Number(n) => Ok(*n),
+        ValueKind::BigNumber(bn) => Ok(bn.to_f64()),
+        _ => Err(GraphoidError::runtime(format!(
+            "TypeError: %{} requires a number, got {}",
+            conversion,
+            arg.type_name()
+        ))),
+    }
 }
@@ -19,6 +19,7 @@ pub struct Config {
 
     // Numeric precision
     pub decimal_places: Option<usize>,  // None = no rounding
+    pub display_precision: Option<usize>,  // Decimal places floats print with; None = shortest form
 
     // Type system
     pub strict_types: bool,
@@ -49,6 +50,18 @@ pub struct Config {
 
     // Bit width for wrapping arithmetic (Phase 13 - :32bit directive)
     pub bit_width: BitWidth,  // Controls wrapping behavior for arithmetic/bitwise ops
+
+    // Assertions
+    pub assertions: bool,  // false = `assert` statements are skipped without evaluating
+
+    // Tracing
+    pub trace: bool,  // true = print statements and function entry/exit as they run
+
+    // Module resolution
+    pub module_paths: Vec<String>,  // searched by import/load after the current file's directory
+
+    // How the REPL and `gr eval` report results and errors
+    pub output_format: OutputFormat,
 }
 
 /// Error handling mode
@@ -88,6 +101,13 @@ pub enum PrecisionMode {
     High,      // i64/u64 or f128 (BigInt used internally for overflow, never user-visible)
 }
 
+/// Result format for the REPL and `gr eval`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,  // Human-readable values and error messages (default)
+    Json,  // One JSON envelope per result or error, for tools
+}
+
 /// Bit width for integer wrapping (Phase 13)
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BitWidth {
@@ -103,7 +123,8 @@ impl Default for Config {
             type_coercion: TypeCoercionMode::Strict,
             none_handling: NoneHandlingMode::Propagate,
             decimal_places: None,
-            strict_types: true,
+            display_precision: None,
+            strict_types: false,
             edge_validation: true,
             strict_edge_rules: true,
             none_conversions: true,
@@ -115,6 +136,10 @@ impl Default for Config {
             precision_mode: PrecisionMode::Standard,  // Default to f64 precision
             integer_mode: false,  // Default to preserving floats
             bit_width: BitWidth::Bits64,  // Default to 64-bit (no wrapping)
+            assertions: true,
+            trace: false,
+            module_paths: Vec::new(),
+            output_format: OutputFormat::Text,
         }
     }
 }
@@ -145,6 +170,7 @@ impl ConfigStack {
 
     /// Push a new configuration onto the stack
     pub fn push(&mut self, config: Config) {
+        crate::values::set_display_precision(config.display_precision);
         self.stack.push(config);
     }
 
@@ -152,7 +178,9 @@ impl ConfigStack {
     /// Returns None if trying to pop the base config (stack size 1)
     pub fn pop(&mut self) -> Option<Config> {
         if self.stack.len() > 1 {
-            self.stack.pop()
+            let popped = self.stack.pop();
+            crate::values::set_display_precision(self.current().display_precision);
+            popped
         } else {
             None  // Never pop the base config
         }
@@ -184,6 +212,9 @@ impl ConfigStack {
                     })?;
                     new_config.decimal_places = Some(num as usize);
                 }
+                "display_precision" => {
+                    new_config.display_precision = Some(parse_display_precision(&value)?);
+                }
                 "skip_none" => {
                     new_config.skip_none = value.is_truthy();
                 }
@@ -208,9 +239,21 @@ impl ConfigStack {
                 "allow_overrides" => {
                     new_config.allow_overrides = Some(value.is_truthy());
                 }
+                "assertions" => {
+                    new_config.assertions = value.is_truthy();
+                }
+                "trace" => {
+                    new_config.trace = value.is_truthy();
+                }
                 "precision" => {
                     new_config.precision_mode = parse_precision_mode(&value)?;
                 }
+                "module_paths" => {
+                    new_config.module_paths = parse_module_paths(&value)?;
+                }
+                "output" => {
+                    new_config.output_format = parse_output_format(&value)?;
+                }
                 _ => {
                     // Check if the value is a standalone directive (symbol)
                     if let ValueKind::Symbol(sym) = &value.kind {
@@ -374,3 +417,45 @@ fn parse_precision_mode(value: &Value) -> Result<PrecisionMode> {
         }),
     }
 }
+
+fn parse_display_precision(value: &Value) -> Result<usize> {
+    match &value.kind {
+        ValueKind::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(*n as usize),
+        ValueKind::Number(n) => Err(GraphoidError::ConfigError {
+            message: format!("display_precision must be a non-negative whole number, got {}", n),
+        }),
+        _ => Err(GraphoidError::ConfigError {
+            message: format!("display_precision must be a number, got {}", value.type_name()),
+        }),
+    }
+}
+
+fn parse_output_format(value: &Value) -> Result<OutputFormat> {
+    match &value.kind {
+        ValueKind::Symbol(s) => match s.as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(GraphoidError::ConfigError {
+                message: format!("Invalid output: :{}, expected :text or :json", s),
+            }),
+        },
+        _ => Err(GraphoidError::ConfigError {
+            message: format!("output must be a symbol, got {}", value.type_name()),
+        }),
+    }
+}
+
+fn parse_module_paths(value: &Value) -> Result<Vec<String>> {
+    let invalid = || GraphoidError::ConfigError {
+        message: format!("module_paths must be a list of strings, got {}", value.type_name()),
+    };
+    match &value.kind {
+        ValueKind::List(list) => list.to_vec().iter()
+            .map(|item| match &item.kind {
+                ValueKind::String(path) => Ok(path.clone()),
+                _ => Err(invalid()),
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,182 @@
+//! Iteration helpers: `range`, `enumerate`, `zip` and `line_stream`
+//!
+//! When one of them is the iterable of a `for` loop the executor pulls items
+//! from a `LoopItems` source one at a time, so `for i, x in enumerate(big_list)`
+//! never builds the list of pairs and `for line in line_stream(path)` never
+//! holds more than one line of the file. Called anywhere else `range`,
+//! `enumerate` and `zip` return lists; `line_stream` is an error there, since
+//! a list of every line is exactly what it exists to avoid.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::error::{GraphoidError, Result};
+use crate::values::{List, Value, ValueKind};
+
+/// Names handled by this module. User functions with the same name win.
+pub(crate) const ITERATION_BUILTINS: &[&str] = &["range", "enumerate", "zip", "line_stream"];
+
+/// A list or string whose items can be read by position.
+pub(crate) enum Indexed {
+    List(Box<List>),
+    Chars(Vec<char>),
+}
+
+impl Indexed {
+    fn from_value(name: &str, value: &Value) -> Result<Self> {
+        match &value.kind {
+            ValueKind::List(list) => Ok(Indexed::List(Box::new(list.clone()))),
+            ValueKind::String(s) => Ok(Indexed::Chars(s.chars().collect())),
+            _ => Err(GraphoidError::runtime(format!(
+                "TypeError: {}() expects a list or string, got {}", name, value.type_name()
+            ))),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Indexed::List(list) => list.len(),
+            Indexed::Chars(chars) => chars.len(),
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<Value> {
+        match self {
+            Indexed::List(list) => list.get(index).cloned(),
+            Indexed::Chars(chars) => chars.get(index).map(|c| Value::string(c.to_string())),
+        }
+    }
+}
+
+/// A lazy sequence of loop items produced by `range`, `enumerate`, `zip` or
+/// `line_stream`.
+pub(crate) enum LoopItems {
+    Range { next: f64, stop: f64, step: f64 },
+    Enumerate { source: Indexed, index: usize },
+    Zip { sources: Vec<Indexed>, index: usize },
+    /// Lines of an open file; the file closes when the source is dropped,
+    /// including when a loop breaks early.
+    Lines { path: String, reader: BufReader<File>, line: usize },
+}
+
+impl LoopItems {
+    /// Build the source for `name(args)`, or `None` if `name` is not an
+    /// iteration helper.
+    pub fn from_call(name: &str, args: &[Value]) -> Result<Option<Self>> {
+        let items = match name {
+            "range" => {
+                let numbers = args.iter()
+                    .map(|arg| match &arg.kind {
+                        ValueKind::Number(n) => Ok(*n),
+                        _ => Err(GraphoidError::runtime(format!(
+                            "TypeError: range() expects numbers, got {}", arg.type_name()
+                        ))),
+                    })
+                    .collect::<Result<Vec<f64>>>()?;
+                let (start, stop, step) = match numbers[..] {
+                    [stop] => (0.0, stop, 1.0),
+                    [start, stop] => (start, stop, 1.0),
+                    [start, stop, step] => (start, stop, step),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "range() takes 1 to 3 arguments (start, stop, step), got {}", args.len()
+                    ))),
+                };
+                if step == 0.0 {
+                    return Err(GraphoidError::runtime("ValueError: range() step cannot be zero".to_string()));
+                }
+                LoopItems::Range { next: start, stop, step }
+            }
+            "enumerate" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "enumerate() takes exactly 1 argument, got {}", args.len()
+                    )));
+                }
+                LoopItems::Enumerate { source: Indexed::from_value(name, &args[0])?, index: 0 }
+            }
+            "zip" => {
+                if args.is_empty() {
+                    return Err(GraphoidError::runtime("zip() requires at least 1 argument".to_string()));
+                }
+                let sources = args.iter()
+                    .map(|arg| Indexed::from_value(name, arg))
+                    .collect::<Result<Vec<_>>>()?;
+                LoopItems::Zip { sources, index: 0 }
+            }
+            "line_stream" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "line_stream() takes exactly 1 argument, got {}", args.len()
+                    )));
+                }
+                let path = match &args[0].kind {
+                    ValueKind::String(path) => path.clone(),
+                    _ => return Err(GraphoidError::type_error("string path", args[0].type_name())),
+                };
+                let file = File::open(&path).map_err(|e| GraphoidError::runtime(format!(
+                    "IOError: line_stream() could not open '{}': {}", path, e
+                )))?;
+                LoopItems::Lines { path, reader: BufReader::new(file), line: 0 }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(items))
+    }
+
+    /// Collect every remaining item into a list value.
+    pub fn into_list(mut self) -> Result<Value> {
+        let mut items = Vec::new();
+        while let Some(item) = self.try_next()? {
+            items.push(item);
+        }
+        Ok(Value::list(List::from_vec(items)))
+    }
+
+    /// The next item, or `None` once the source is exhausted. Only
+    /// `line_stream` can fail, when reading the file errors part way through;
+    /// the error reports the last line read successfully.
+    pub fn try_next(&mut self) -> Result<Option<Value>> {
+        match self {
+            LoopItems::Range { next, stop, step } => {
+                let done = if *step > 0.0 { *next >= *stop } else { *next <= *stop };
+                if done {
+                    return Ok(None);
+                }
+                let value = *next;
+                *next += *step;
+                Ok(Some(Value::number(value)))
+            }
+            LoopItems::Enumerate { source, index } => {
+                let Some(value) = source.get(*index) else { return Ok(None) };
+                let pair = vec![Value::number(*index as f64), value];
+                *index += 1;
+                Ok(Some(Value::list(List::from_vec(pair))))
+            }
+            LoopItems::Zip { sources, index } => {
+                if sources.iter().any(|source| *index >= source.len()) {
+                    return Ok(None);
+                }
+                let tuple = sources.iter().filter_map(|source| source.get(*index)).collect();
+                *index += 1;
+                Ok(Some(Value::list(List::from_vec(tuple))))
+            }
+            LoopItems::Lines { path, reader, line } => {
+                let mut text = String::new();
+                let read = reader.read_line(&mut text).map_err(|e| GraphoidError::runtime(format!(
+                    "IOError: reading '{}' failed after line {}: {}", path, line, e
+                )))?;
+                if read == 0 {
+                    return Ok(None);
+                }
+                *line += 1;
+                if text.ends_with('\n') {
+                    text.pop();
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+                Ok(Some(Value::string(text)))
+            }
+        }
+    }
+}
@@ -98,6 +98,70 @@ impl Executor {
         Ok(matching_edges)
     }
 
+    /// Evaluates static methods on the graph type (e.g., graph.from_edge_list).
+    pub(crate) fn eval_graph_static_method(&mut self, method: &str, args: &[Value]) -> Result<Value> {
+        match method {
+            "from_edge_list" => {
+                // graph.from_edge_list(edges, directed: true, multigraph: false)
+                if args.is_empty() || args.len() > 3 {
+                    return Err(GraphoidError::runtime(format!(
+                        "graph.from_edge_list() expects 1-3 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let entries = match &args[0].kind {
+                    ValueKind::List(list) => list.to_vec(),
+                    _ => return Err(GraphoidError::type_error("list", args[0].type_name())),
+                };
+                let mut flags = [true, false];
+                for (flag, arg) in flags.iter_mut().zip(&args[1..]) {
+                    *flag = match &arg.kind {
+                        ValueKind::Boolean(b) => *b,
+                        _ => return Err(GraphoidError::type_error("bool", arg.type_name())),
+                    };
+                }
+                let [directed, multigraph] = flags;
+
+                let mut edges = Vec::with_capacity(entries.len());
+                for (index, entry) in entries.iter().enumerate() {
+                    let malformed = || GraphoidError::runtime(format!(
+                        "ValueError: from_edge_list() entry {} must be [from, to] or [from, to, weight], got {}",
+                        index, entry.to_string_value()
+                    ));
+                    let items = match &entry.kind {
+                        ValueKind::List(list) => list.to_vec(),
+                        _ => return Err(malformed()),
+                    };
+                    if items.len() != 2 && items.len() != 3 {
+                        return Err(malformed());
+                    }
+                    let (from, to) = match (&items[0].kind, &items[1].kind) {
+                        (ValueKind::String(from), ValueKind::String(to)) => (from.clone(), to.clone()),
+                        _ => return Err(malformed()),
+                    };
+                    let weight = match items.get(2).map(|w| &w.kind) {
+                        None | Some(ValueKind::None) => None,
+                        Some(ValueKind::Number(w)) => Some(*w),
+                        Some(_) => return Err(malformed()),
+                    };
+                    edges.push((from, to, weight));
+                }
+
+                let graph_type = if directed {
+                    crate::values::GraphType::Directed
+                } else {
+                    crate::values::GraphType::Undirected
+                };
+                let graph = crate::values::Graph::from_edge_list(&edges, graph_type, multigraph)?;
+                Ok(Value::graph(graph))
+            }
+            _ => Err(GraphoidError::runtime(format!(
+                "graph does not have static method '{}'",
+                method
+            ))),
+        }
+    }
+
     /// Evaluates a method call on a graph.
     pub(crate) fn eval_graph_method(&mut self, mut graph: crate::values::Graph, method: &str, args: &[Value], object_expr: &Expr) -> Result<Value> {
         // Phase 20: Check for static methods first (called on class, not instances)
@@ -112,7 +176,9 @@ impl Executor {
         if !method_variants.is_empty() {
             // Phase 15: Private method check (convention-based: underscore prefix)
             // Methods starting with _ are private and can only be called from within the same graph's methods
-            if method.starts_with('_') {
+            // Special methods (`__add__`, `__to_string__`, ...) are public hooks used by operators
+            let is_special = method.len() > 4 && method.starts_with("__") && method.ends_with("__");
+            if method.starts_with('_') && !is_special {
                 // Check if we're inside a method of this graph
                 let graph_var_name = if let Expr::Variable { name, .. } = object_expr {
                     Some(name.clone())
@@ -235,12 +301,12 @@ impl Executor {
                 // Add the node
                 graph.add_node(node_id, node_value)?;
 
-                // Update graph in environment
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "set_node_type" => {
                 // Set the type of a node
@@ -270,18 +336,20 @@ impl Executor {
                 // Set the node type
                 graph.set_node_type(node_id, node_type)?;
 
-                // Update graph in environment
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "add_edge" => {
-                // Add an edge between two nodes
-                if args.len() < 2 || args.len() > 3 {
+                // Add an edge between two nodes:
+                //   add_edge(from, to, [edge_type], [weight], [properties])
+                // The optional arguments may be left out but keep their order
+                if args.len() < 2 || args.len() > 5 {
                     return Err(GraphoidError::runtime(format!(
-                        "add_edge() expects 2-3 arguments (from, to, [edge_type]), but got {}",
+                        "add_edge() expects 2-5 arguments (from, to, [edge_type], [weight], [properties]), but got {}",
                         args.len()
                     )));
                 }
@@ -302,29 +370,54 @@ impl Executor {
                     }
                 };
 
-                // Get optional edge type (default to "edge")
-                let edge_type = if args.len() == 3 {
-                    match &args[2].kind {
-                        ValueKind::String(s) => s.clone(),
-                        _other => {
-                            return Err(GraphoidError::type_error("string", args[2].type_name()));
-                        }
+                // Optional edge type (default to "edge"), weight and properties map
+                let mut rest = args[2..].iter().peekable();
+                let edge_type = match rest.peek().map(|arg| &arg.kind) {
+                    Some(ValueKind::String(s)) => {
+                        let edge_type = s.clone();
+                        rest.next();
+                        edge_type
                     }
-                } else {
-                    "edge".to_string()
+                    _ => "edge".to_string(),
                 };
+                let weight = match rest.peek().map(|arg| &arg.kind) {
+                    Some(ValueKind::Number(w)) => {
+                        let weight = *w;
+                        rest.next();
+                        Some(weight)
+                    }
+                    Some(ValueKind::None) => {
+                        rest.next();
+                        None
+                    }
+                    _ => None,
+                };
+                let properties = match rest.next() {
+                    None => std::collections::HashMap::new(),
+                    Some(Value { kind: ValueKind::Map(props), .. }) => props.to_hashmap(),
+                    Some(other) => {
+                        return Err(GraphoidError::runtime(format!(
+                            "TypeError: add_edge() expects [edge_type: string], [weight: num], [properties: map] after from and to, got {}",
+                            other.type_name()
+                        )));
+                    }
+                };
+                if let Some(extra) = rest.next() {
+                    return Err(GraphoidError::runtime(format!(
+                        "TypeError: add_edge() got an unexpected {} after the properties map",
+                        extra.type_name()
+                    )));
+                }
 
-                // Add the edge with empty properties and no weight
-                use std::collections::HashMap;
-                let properties = HashMap::new();
-                graph.add_edge(from, to, edge_type, None, properties)?;
+                // Rules see the weight and properties along with the edge
+                graph.add_edge(from, to, edge_type, weight, properties)?;
 
-                // Update graph in environment
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "remove_node" => {
                 // Remove a node from the graph
@@ -346,12 +439,12 @@ impl Executor {
                 // Remove the node
                 graph.remove_node(node_id, None)?;
 
-                // Update graph in environment
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "remove_edge" => {
                 // Remove an edge from the graph
@@ -381,12 +474,12 @@ impl Executor {
                 // Remove the edge
                 graph.remove_edge(from, to)?;
 
-                // Update graph in environment
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "with_ruleset" => {
                 // Apply a ruleset to the graph
@@ -399,7 +492,7 @@ impl Executor {
 
                 // Get the ruleset name from symbol argument
                 let ruleset_name = match &args[0].kind {
-                    ValueKind::Symbol(name) => name.clone(),
+                    ValueKind::Symbol(name) => name.to_string(),
                     _other => {
                         return Err(GraphoidError::runtime(format!(
                             "with_ruleset() expects a symbol argument, got {}",
@@ -433,6 +526,27 @@ impl Executor {
 
                 Ok(Value::boolean(graph.has_ruleset(ruleset_name)))
             }
+            "satisfies_ruleset?" => {
+                // Check the current graph against a ruleset without applying it
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "satisfies_ruleset?() expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+
+                let ruleset_name = match &args[0].kind {
+                    ValueKind::Symbol(name) => name.as_str(),
+                    _other => {
+                        return Err(GraphoidError::runtime(format!(
+                            "satisfies_ruleset?() expects a symbol argument, got {}",
+                            args[0].type_name()
+                        )));
+                    }
+                };
+
+                Ok(Value::boolean(graph.satisfies_ruleset(ruleset_name)?))
+            }
             "has_node" => {
                 // Check if a node exists in the graph
                 if args.len() != 1 {
@@ -478,6 +592,32 @@ impl Executor {
                 let has_path = graph.has_path(from, to);
                 Ok(Value::boolean(has_path))
             }
+            "path_exists" => {
+                // Yes/no reachability; unlike has_path(), unknown nodes are an error
+                if args.len() != 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "path_exists() expects 2 arguments (from, to), but got {}",
+                        args.len()
+                    )));
+                }
+                let mut ids = Vec::with_capacity(2);
+                for arg in args {
+                    let id = match &arg.kind {
+                        ValueKind::String(s) => s.as_str(),
+                        _ => return Err(GraphoidError::runtime(format!(
+                            "TypeError: path_exists() expects node ids as strings, got {}",
+                            arg.type_name()
+                        ))),
+                    };
+                    if !graph.has_node(id) {
+                        return Err(GraphoidError::runtime(format!(
+                            "ValueError: path_exists() node '{}' not found", id
+                        )));
+                    }
+                    ids.push(id);
+                }
+                Ok(Value::boolean(graph.has_path(ids[0], ids[1])))
+            }
             "shortest_path" => {
                 // Find the shortest path between two nodes
                 // shortest_path(from, to) - unweighted BFS
@@ -534,6 +674,46 @@ impl Executor {
                     None => Ok(Value::none()),
                 }
             }
+            "astar_path" => {
+                // astar_path(from, to, heuristic) - A* search where heuristic(id)
+                // estimates the remaining cost from id to `to`
+                if args.len() != 3 {
+                    return Err(GraphoidError::runtime(format!(
+                        "astar_path() expects 3 arguments (from, to, heuristic), but got {}",
+                        args.len()
+                    )));
+                }
+                let from = match &args[0].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::type_error("string", args[0].type_name())),
+                };
+                let to = match &args[1].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::type_error("string", args[1].type_name())),
+                };
+                let heuristic = match &args[2].kind {
+                    ValueKind::Function(func) => func.clone(),
+                    _ => return Err(GraphoidError::type_error("function", args[2].type_name())),
+                };
+
+                let path = graph.astar_path(from, to, |id| {
+                    let estimate = self.call_function(&heuristic, &[Value::string(id.to_string())])?;
+                    match estimate.kind {
+                        ValueKind::Number(n) => Ok(n),
+                        _ => Err(GraphoidError::runtime(format!(
+                            "TypeError: astar_path() heuristic must return a number, got {}",
+                            estimate.type_name()
+                        ))),
+                    }
+                })?;
+                match path {
+                    Some(nodes) => {
+                        let list: Vec<Value> = nodes.into_iter().map(Value::string).collect();
+                        Ok(Value::list(List::from_vec(list)))
+                    }
+                    None => Ok(Value::none()),
+                }
+            }
             "distance" => {
                 // Get shortest path distance between two nodes
                 if args.len() != 2 {
@@ -563,11 +743,45 @@ impl Executor {
                 let dist = graph.distance(from, to);
                 Ok(Value::number(dist as f64))
             }
+            "weight_of_path" => {
+                // weight_of_path(path) - total weight along a list of node ids,
+                // e.g. the result of shortest_path(); unweighted edges count 1
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "weight_of_path() expects 1 argument (path), but got {}",
+                        args.len()
+                    )));
+                }
+                let path = match &args[0].kind {
+                    ValueKind::List(list) => list.to_vec(),
+                    _ => return Err(GraphoidError::type_error("list", args[0].type_name())),
+                };
+                let ids = path.iter()
+                    .map(|id| match &id.kind {
+                        ValueKind::String(s) => Ok(s.clone()),
+                        _ => Err(GraphoidError::runtime(format!(
+                            "TypeError: weight_of_path() expects a list of node id strings, got a {} in the list",
+                            id.type_name()
+                        ))),
+                    })
+                    .collect::<Result<Vec<String>>>()?;
+                Ok(Value::number(graph.weight_of_path(&ids)?))
+            }
+            "total_weight" => {
+                // Sum of all edge weights; unweighted edges count 1
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "total_weight() takes no arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(Value::number(graph.total_weight()))
+            }
             "all_paths" => {
-                // Find all paths between two nodes up to max length
-                if args.len() != 3 {
+                // Find all simple paths between two nodes, optionally up to a max length
+                if args.len() != 2 && args.len() != 3 {
                     return Err(GraphoidError::runtime(format!(
-                        "all_paths() expects 3 arguments (from, to, max_length), but got {}",
+                        "all_paths() expects 2-3 arguments (from, to, max_length), but got {}",
                         args.len()
                     )));
                 }
@@ -588,10 +802,17 @@ impl Executor {
                     }
                 };
 
-                // Get max length
-                let max_len = match &args[2].kind {
-                    ValueKind::Number(n) => *n as usize,
-                    _other => {
+                // Get max length; a simple path never has more than n - 1 edges
+                let max_len = match args.get(2).map(|arg| &arg.kind) {
+                    None => graph.node_count().saturating_sub(1),
+                    Some(ValueKind::Number(n)) if *n >= 0.0 => *n as usize,
+                    Some(ValueKind::Number(n)) => {
+                        return Err(GraphoidError::runtime(format!(
+                            "ValueError: all_paths() max_length cannot be negative, got {}",
+                            n
+                        )));
+                    }
+                    Some(_) => {
                         return Err(GraphoidError::type_error("number", args[2].type_name()));
                     }
                 };
@@ -749,6 +970,37 @@ impl Executor {
                     None => Ok(Value::none()),
                 }
             }
+            "node" => {
+                // node(id) - live handle to the node: .value, .value = x,
+                // .property(key), .set_property(key, v), .neighbors().
+                // Taken from a variable, the handle follows that variable.
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "node() expects 1 argument (node_id), but got {}",
+                        args.len()
+                    )));
+                }
+                let node_id = match &args[0].kind {
+                    ValueKind::String(s) => s.clone(),
+                    _ => return Err(GraphoidError::type_error("string", args[0].type_name())),
+                };
+                if !graph.has_node(&node_id) {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: node() found no node '{}' in the graph",
+                        node_id
+                    )));
+                }
+                // The handle shares the receiver's storage rather than this copy
+                let shared = match self.graph_method_value_stack.last().map(|value| &value.kind) {
+                    Some(ValueKind::Graph(rc)) => rc.clone(),
+                    _ => std::rc::Rc::new(std::cell::RefCell::new(graph)),
+                };
+                let binding = match object_expr {
+                    Expr::Variable { name, .. } => Some(name.clone()),
+                    _ => None,
+                };
+                Ok(Value::node_handle(shared, node_id, binding))
+            }
             "nodes" => {
                 // Get node IDs as a list
                 // nodes()      - Data nodes only (default)
@@ -794,6 +1046,63 @@ impl Executor {
                 // including nodes, edges, rules, and methods
                 Ok(Value::graph(graph.clone()))
             }
+            "clone_structure" => {
+                // clone_structure()        - same nodes and edges, every value none
+                // clone_structure(default) - every value a fresh copy of default
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "clone_structure() expects 0-1 arguments (default value), but got {}",
+                        args.len()
+                    )));
+                }
+                let default_value = args.first().cloned().unwrap_or_else(Value::none);
+                Ok(Value::graph(graph.clone_structure(&default_value)))
+            }
+            "map_nodes" | "map_edges" => {
+                // map_nodes(block) - new graph, each node value replaced by block(value)
+                // map_edges(block) - new graph, each edge's property map replaced by
+                //   block(props); the block must return a map
+                // The receiver is left unchanged
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 1 argument (block), but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let func = match &args[0].kind {
+                    ValueKind::Function(func) => func.clone(),
+                    _ => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                };
+
+                let mapped = if method == "map_nodes" {
+                    graph.map_node_values(|id, value| {
+                        self.call_function(&func, std::slice::from_ref(value))
+                            .map_err(|e| predicate_error(e, &format!("map_nodes() block failed on node '{}'", id)))
+                    })?
+                } else {
+                    graph.map_edge_properties(|from, to, properties| {
+                        let context = format!("map_edges() block failed on edge '{}' -> '{}'", from, to);
+                        let mut props = crate::values::Hash::new();
+                        let mut keys: Vec<&String> = properties.keys().collect();
+                        keys.sort();
+                        for key in keys {
+                            props.insert(key.clone(), properties[key].clone())?;
+                        }
+                        let result = self.call_function(&func, &[Value::map(props)])
+                            .map_err(|e| predicate_error(e, &context))?;
+                        match &result.kind {
+                            ValueKind::Map(hash) => Ok(hash.to_hashmap()),
+                            _ => Err(GraphoidError::runtime(format!(
+                                "TypeError: {}: block must return a map, got {}",
+                                context,
+                                result.type_name()
+                            ))),
+                        }
+                    })?
+                };
+                Ok(Value::graph(mapped))
+            }
             // Phase 18: Type checking methods
             "type_of" => {
                 // Returns the type name of the graph (the variable name it was assigned to)
@@ -1081,17 +1390,172 @@ impl Executor {
                 Ok(Value::list(crate::values::List::from_vec(order_values)))
             }
             // Phase 3: Inheritance methods
-            "ancestors" => {
+            "ancestors" if args.is_empty() => {
                 // Get list of ancestor type names
-                if !args.is_empty() {
+                let ancestors = graph.ancestors();
+                let ancestor_values: Vec<Value> = ancestors.iter().map(|a| Value::string(a.clone())).collect();
+                Ok(Value::list(crate::values::List::from_vec(ancestor_values)))
+            }
+            "ancestors" | "descendants" => {
+                // ancestors(id) / descendants(id)  - transitive closure over incoming / outgoing edges
+                // ancestors(id, :include_self)     - also list id itself first
+                if args.is_empty() || args.len() > 2 {
                     return Err(GraphoidError::runtime(format!(
-                        "ancestors() expects no arguments, but got {}",
+                        "{}() expects 1-2 arguments (id, [:include_self]), but got {}",
+                        method,
                         args.len()
                     )));
                 }
-                let ancestors = graph.ancestors();
-                let ancestor_values: Vec<Value> = ancestors.iter().map(|a| Value::string(a.clone())).collect();
-                Ok(Value::list(crate::values::List::from_vec(ancestor_values)))
+                let id = match &args[0].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: {}() expects a node id string, got {}",
+                        method,
+                        args[0].type_name()
+                    ))),
+                };
+                let include_self = match args.get(1).map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "include_self" => true,
+                    Some(_) => return Err(GraphoidError::runtime(format!(
+                        "{}() optional argument must be :include_self",
+                        method
+                    ))),
+                };
+                if !graph.has_node(id) {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: {}() node '{}' not found", method, id
+                    )));
+                }
+
+                let related = if method == "ancestors" {
+                    graph.ancestors_of(id)
+                } else {
+                    graph.descendants_of(id)
+                };
+                let mut ids: Vec<Value> = Vec::with_capacity(related.len() + 1);
+                if include_self {
+                    ids.push(Value::string(id.to_string()));
+                }
+                ids.extend(related.into_iter().filter(|n| n != id).map(Value::string));
+                Ok(Value::list(crate::values::List::from_vec(ids)))
+            }
+            "reachable_from" => {
+                // reachable_from(start) - map of every node id to whether start reaches it
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "reachable_from() expects 1 argument (start), but got {}",
+                        args.len()
+                    )));
+                }
+                let start = match &args[0].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: reachable_from() expects a node id string, got {}",
+                        args[0].type_name()
+                    ))),
+                };
+                if !graph.has_node(start) {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: reachable_from() node '{}' not found", start
+                    )));
+                }
+                let mut mask = crate::values::Hash::new();
+                for (id, reached) in graph.reachable_from(start) {
+                    mask.insert(id, Value::boolean(reached))?;
+                }
+                Ok(Value::map(mask))
+            }
+            "bfs_tree" | "dfs_tree" => {
+                // bfs_tree(start) / dfs_tree(start) - spanning tree of the nodes
+                // reachable from start, as a new directed graph under :tree
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 1 argument (start), but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let start = match &args[0].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: {}() expects a node id string, got {}",
+                        method,
+                        args[0].type_name()
+                    ))),
+                };
+                if !graph.has_node(start) {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: {}() node '{}' not found", method, start
+                    )));
+                }
+                Ok(Value::graph(graph.traversal_tree(start, method == "dfs_tree")?))
+            }
+            "to_tree" => {
+                // to_tree(root)         - BFS tree of the nodes reachable from root
+                // to_tree(root, :forest) - also keep unreachable nodes, under a
+                //   synthetic forest root (also accepts forest: true)
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "to_tree() expects 1-2 arguments (root, [:forest]), but got {}",
+                        args.len()
+                    )));
+                }
+                let root = match &args[0].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: to_tree() expects a node id string, got {}",
+                        args[0].type_name()
+                    ))),
+                };
+                let forest = match args.get(1).map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "forest" => true,
+                    Some(ValueKind::Boolean(b)) => *b,
+                    Some(_) => return Err(GraphoidError::runtime(
+                        "to_tree() optional argument must be :forest".to_string()
+                    )),
+                };
+                Ok(Value::graph(graph.to_tree(root, forest)?))
+            }
+            "walk" => {
+                // walk(start, steps)            - uniform random walk over outgoing edges
+                // walk(start, steps, :weighted) - bias each step by edge weight
+                //   (also accepts weighted: true); reproducible under random.seed()
+                if args.len() < 2 || args.len() > 3 {
+                    return Err(GraphoidError::runtime(format!(
+                        "walk() expects 2-3 arguments (start, steps, [:weighted]), but got {}",
+                        args.len()
+                    )));
+                }
+                let start = match &args[0].kind {
+                    ValueKind::String(s) => s.as_str(),
+                    _ => return Err(GraphoidError::type_error("string", args[0].type_name())),
+                };
+                let steps = match &args[1].kind {
+                    ValueKind::Number(n) if *n >= 0.0 && n.fract() == 0.0 => *n as usize,
+                    ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: walk() steps must be a non-negative integer, got {}", n
+                    ))),
+                    _ => return Err(GraphoidError::type_error("number", args[1].type_name())),
+                };
+                let weighted = match args.get(2).map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "weighted" => true,
+                    Some(ValueKind::Boolean(b)) => *b,
+                    Some(_) => return Err(GraphoidError::runtime(
+                        "walk() optional argument must be :weighted".to_string()
+                    )),
+                };
+                if !graph.has_node(start) {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: walk() node '{}' not found", start
+                    )));
+                }
+
+                let walk = graph.random_walk(start, steps, weighted, crate::stdlib::RandomModule::next_f64);
+                let ids: Vec<Value> = walk.into_iter().map(Value::string).collect();
+                Ok(Value::list(crate::values::List::from_vec(ids)))
             }
             "edges" => {
                 // Get edges as a list of lists [from, to, edge_type]
@@ -1132,6 +1596,59 @@ impl Executor {
                 }).collect();
                 Ok(Value::list(crate::values::List::from_vec(edge_values)))
             }
+            "adjacency_list" => {
+                // adjacency_list()            - map of node id -> sorted out-neighbor ids
+                // adjacency_list(:weighted)   - neighbors as [id, weight] pairs
+                //   (also accepts weighted: true); unweighted edges pair with none
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "adjacency_list() expects 0-1 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let weighted = match args.first().map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "weighted" => true,
+                    Some(ValueKind::Boolean(b)) => *b,
+                    Some(_) => return Err(GraphoidError::runtime(
+                        "adjacency_list() optional argument must be :weighted".to_string()
+                    )),
+                };
+
+                let mut adjacency = crate::values::Hash::new();
+                for (id, neighbors) in graph.adjacency_list() {
+                    let entries: Vec<Value> = neighbors.into_iter().map(|(to, weight)| {
+                        if weighted {
+                            let weight = weight.map(Value::number).unwrap_or_else(Value::none);
+                            Value::list(crate::values::List::from_vec(vec![Value::string(to), weight]))
+                        } else {
+                            Value::string(to)
+                        }
+                    }).collect();
+                    adjacency.insert(id, Value::list(crate::values::List::from_vec(entries)))?;
+                }
+                Ok(Value::map(adjacency))
+            }
+            "to_edge_list" => {
+                // to_edge_list() - list of [from, to] pairs; when any edge is
+                // weighted every entry becomes [from, to, weight] (none if unweighted)
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "to_edge_list() takes no arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let edges = graph.to_edge_list();
+                let weighted = edges.iter().any(|(_, _, weight)| weight.is_some());
+                let entries: Vec<Value> = edges.into_iter().map(|(from, to, weight)| {
+                    let mut entry = vec![Value::string(from), Value::string(to)];
+                    if weighted {
+                        entry.push(weight.map(Value::number).unwrap_or_else(Value::none));
+                    }
+                    Value::list(crate::values::List::from_vec(entry))
+                }).collect();
+                Ok(Value::list(crate::values::List::from_vec(entries)))
+            }
             "extract" => {
                 // Extract subgraph using filter predicates
                 // Supports two syntaxes:
@@ -1330,6 +1847,74 @@ impl Executor {
                 let result = graph.add_subgraph(&other_graph, conflict_strategy)?;
                 Ok(Value::graph(result))
             }
+            "merge" => {
+                // Union with another graph, returning a new graph
+                // Arguments: (other_graph, resolve?) where resolve(mine, theirs)
+                // picks the value for node ids found in both (default: theirs)
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "merge() expects 1-2 arguments (other_graph, resolve), but got {}",
+                        args.len()
+                    )));
+                }
+
+                let other_graph = match &args[0].kind {
+                    ValueKind::Graph(g) => g.borrow().clone(),
+                    _ => return Err(GraphoidError::type_error("graph", args[0].type_name())),
+                };
+                let resolver = match args.get(1).map(|arg| &arg.kind) {
+                    Some(ValueKind::Function(func)) => Some(func.clone()),
+                    Some(_) => return Err(GraphoidError::type_error("function", args[1].type_name())),
+                    None => None,
+                };
+
+                let result = graph.merge_with(&other_graph, |_, mine, theirs| match &resolver {
+                    Some(func) => self.call_function(func, &[mine.clone(), theirs.clone()]),
+                    None => Ok(theirs.clone()),
+                })?;
+                Ok(Value::graph(result))
+            }
+            "relabel_nodes" => {
+                // Copy with node ids rewritten: (mapping, resolve?) where mapping
+                // is {old_id: new_id}. Nodes that end up sharing an id are merged
+                // with resolve(kept, incoming); without resolve that is an error.
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "relabel_nodes() expects 1-2 arguments (mapping, resolve), but got {}",
+                        args.len()
+                    )));
+                }
+
+                let mapping = match &args[0].kind {
+                    ValueKind::Map(hash) => hash.to_hashmap(),
+                    _ => return Err(GraphoidError::type_error("hash", args[0].type_name())),
+                };
+                let mut new_ids = std::collections::HashMap::new();
+                for (old_id, new_id) in mapping {
+                    match &new_id.kind {
+                        ValueKind::String(s) => new_ids.insert(old_id, s.clone()),
+                        _ => return Err(GraphoidError::runtime(format!(
+                            "TypeError: relabel_nodes() maps '{}' to a {}; new ids must be strings",
+                            old_id,
+                            new_id.type_name()
+                        ))),
+                    };
+                }
+                let resolver = match args.get(1).map(|arg| &arg.kind) {
+                    Some(ValueKind::Function(func)) => Some(func.clone()),
+                    Some(_) => return Err(GraphoidError::type_error("function", args[1].type_name())),
+                    None => None,
+                };
+
+                let result = graph.relabel_nodes_with(&new_ids, |id, kept, incoming| match &resolver {
+                    Some(func) => self.call_function(func, &[kept.clone(), incoming.clone()]),
+                    None => Err(GraphoidError::runtime(format!(
+                        "ValueError: relabel_nodes() maps more than one node to '{}'; pass a resolve function to merge them",
+                        id
+                    ))),
+                })?;
+                Ok(Value::graph(result))
+            }
             "node_count" => {
                 // Return the number of nodes in the graph
                 if !args.is_empty() {
@@ -1350,6 +1935,370 @@ impl Executor {
                 }
                 Ok(Value::number(graph.edge_count() as f64))
             }
+            "count_nodes" | "count_edges" => {
+                // count_nodes(block) - nodes whose value satisfies block(value)
+                // count_edges(block) - edges satisfying block([from, to, props]),
+                //   where props holds the edge's properties plus "type" and "weight"
+                // With no block, the totals from node_count() / edge_count()
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 0-1 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let func = match args.first().map(|arg| &arg.kind) {
+                    None => {
+                        let total = if method == "count_nodes" { graph.node_count() } else { graph.edge_count() };
+                        return Ok(Value::number(total as f64));
+                    }
+                    Some(ValueKind::Function(func)) => func.clone(),
+                    Some(_) => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                };
+
+                let mut count = 0;
+                if method == "count_nodes" {
+                    for id in graph.data_node_ids() {
+                        let value = graph.get_node(&id).cloned().unwrap_or_else(Value::none);
+                        let keep = self.call_function(&func, &[value])
+                            .map_err(|e| predicate_error(e, &format!("count_nodes() block failed on node '{}'", id)))?;
+                        if keep.is_truthy() {
+                            count += 1;
+                        }
+                    }
+                } else {
+                    for (from, to, edge_info) in graph.data_edge_infos() {
+                        let mut props = crate::values::Hash::new();
+                        let mut keys: Vec<&String> = edge_info.properties.keys().collect();
+                        keys.sort();
+                        for key in keys {
+                            props.insert(key.clone(), edge_info.properties[key].clone())?;
+                        }
+                        props.insert("type".to_string(), Value::string(edge_info.edge_type.clone()))?;
+                        props.insert("weight".to_string(), edge_info.weight.map(Value::number).unwrap_or_else(Value::none))?;
+                        let edge = Value::list(List::from_vec(vec![
+                            Value::string(from.to_string()),
+                            Value::string(to.to_string()),
+                            Value::map(props),
+                        ]));
+                        let keep = self.call_function(&func, &[edge])
+                            .map_err(|e| predicate_error(e, &format!("count_edges() block failed on edge '{}' -> '{}'", from, to)))?;
+                        if keep.is_truthy() {
+                            count += 1;
+                        }
+                    }
+                }
+                Ok(Value::number(count as f64))
+            }
+            "find_node" | "select_nodes" => {
+                // find_node(block)          - first node id (insertion order) whose value
+                //                             satisfies block(value), or none
+                // select_nodes(block)       - list of every matching node id
+                // find_node(block, :node)   - block receives {"id", "value", "properties"}
+                //   instead (also accepts node: true)
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 1-2 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let func = match &args[0].kind {
+                    ValueKind::Function(func) => func.clone(),
+                    _ => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                };
+                let whole_node = match args.get(1).map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "node" => true,
+                    Some(ValueKind::Boolean(b)) => *b,
+                    Some(_) => return Err(GraphoidError::runtime(format!(
+                        "{}() optional argument must be :node",
+                        method
+                    ))),
+                };
+
+                let mut matches = Vec::new();
+                for id in graph.data_node_ids() {
+                    let Some(node) = graph.nodes.get(&id) else { continue };
+                    let arg = if whole_node {
+                        let mut props = crate::values::Hash::new();
+                        let mut keys: Vec<&String> = node.properties.keys().collect();
+                        keys.sort();
+                        for key in keys {
+                            props.insert(key.clone(), node.properties[key].clone())?;
+                        }
+                        let mut entry = crate::values::Hash::new();
+                        entry.insert("id".to_string(), Value::string(id.clone()))?;
+                        entry.insert("value".to_string(), node.value.clone())?;
+                        entry.insert("properties".to_string(), Value::map(props))?;
+                        Value::map(entry)
+                    } else {
+                        node.value.clone()
+                    };
+                    let keep = self.call_function(&func, &[arg])
+                        .map_err(|e| predicate_error(e, &format!("{}() block failed on node '{}'", method, id)))?;
+                    if keep.is_truthy() {
+                        if method == "find_node" {
+                            return Ok(Value::string(id));
+                        }
+                        matches.push(Value::string(id));
+                    }
+                }
+                if method == "find_node" {
+                    Ok(Value::none())
+                } else {
+                    Ok(Value::list(List::from_vec(matches)))
+                }
+            }
+            "degree_histogram" => {
+                // Map of degree -> number of nodes with that degree
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "degree_histogram() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let histogram = graph.degree_histogram()
+                    .into_iter()
+                    .map(|(degree, count)| (degree.to_string(), Value::number(count as f64)))
+                    .collect();
+                Ok(Value::map(crate::values::Hash::from_hashmap(histogram)))
+            }
+            "density" => {
+                // Edges present over edges possible (directed vs undirected formula)
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "density() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(Value::number(graph.density()))
+            }
+            "average_degree" => {
+                // Mean degree across all nodes
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "average_degree() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(Value::number(graph.average_degree()))
+            }
+            "diameter" => {
+                // Longest shortest path in hops; none when the graph is disconnected
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "diameter() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(match graph.diameter() {
+                    Some(d) => Value::number(d as f64),
+                    None => Value::none(),
+                })
+            }
+            "degree_centrality" | "closeness_centrality" | "betweenness_centrality" => {
+                // Map of node id -> normalized centrality score
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let measure = method.trim_end_matches("_centrality");
+                centrality_map(&graph, measure, 0.85)
+            }
+            "pagerank" => {
+                // Map of node id -> PageRank, with an optional damping factor
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "pagerank() expects 0-1 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let damping = match args.first().map(|arg| &arg.kind) {
+                    None => 0.85,
+                    Some(ValueKind::Number(d)) if *d > 0.0 && *d < 1.0 => *d,
+                    Some(ValueKind::Number(d)) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: pagerank() damping must be between 0 and 1, got {}",
+                        d
+                    ))),
+                    Some(_) => return Err(GraphoidError::type_error("number", args[0].type_name())),
+                };
+                centrality_map(&graph, "pagerank", damping)
+            }
+            "centrality" => {
+                // Dispatch to one of the centrality measures by symbol
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "centrality() expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                let measure = match &args[0].kind {
+                    ValueKind::Symbol(name) => name.as_str(),
+                    _ => return Err(GraphoidError::type_error("symbol", args[0].type_name())),
+                };
+                centrality_map(&graph, measure, 0.85)
+            }
+            "connected?" | "weakly_connected?" => {
+                // Whether the graph is one connected component, ignoring edge
+                // direction. Empty and single-node graphs are connected.
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                Ok(Value::boolean(graph.is_connected()))
+            }
+            "has_cycle" => {
+                // True when find_cycles() would return at least one cycle
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "has_cycle() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(Value::boolean(graph.has_cycle()))
+            }
+            "find_cycles" => {
+                // List of cycles, each a list of node ids
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "find_cycles() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let cycles: Vec<Value> = graph.find_cycles().into_iter()
+                    .map(|cycle| Value::list(List::from_vec(cycle.into_iter().map(Value::string).collect())))
+                    .collect();
+                Ok(Value::list(List::from_vec(cycles)))
+            }
+            "girth" | "shortest_cycle" => {
+                // girth() - length of the shortest cycle; shortest_cycle() - its node ids.
+                // Both are none for acyclic graphs
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                Ok(match graph.shortest_cycle() {
+                    None => Value::none(),
+                    Some(cycle) if method == "girth" => Value::number(cycle.len() as f64),
+                    Some(cycle) => Value::list(List::from_vec(cycle.into_iter().map(Value::string).collect())),
+                })
+            }
+            "complement" => {
+                // New undirected graph joining exactly the node pairs not adjacent here
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "complement() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(Value::graph(graph.complement()?))
+            }
+            "topological_generations" => {
+                // Nodes grouped by dependency depth, each generation sorted by id
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "topological_generations() expects 0 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let generations = graph.topological_generations().ok_or_else(|| {
+                    GraphoidError::runtime(
+                        "ValueError: topological_generations() requires an acyclic graph".to_string()
+                    )
+                })?;
+                let generations: Vec<Value> = generations.into_iter()
+                    .map(|generation| Value::list(List::from_vec(generation.into_iter().map(Value::string).collect())))
+                    .collect();
+                Ok(Value::list(List::from_vec(generations)))
+            }
+            "is_tree" | "is_dag" | "is_bipartite" => {
+                // Shape checks on the data layer, independent of declared rulesets
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                Ok(Value::boolean(match method {
+                    "is_tree" => graph.is_tree(),
+                    "is_dag" => graph.is_dag(),
+                    _ => graph.is_bipartite(),
+                }))
+            }
+            "color" | "chromatic_number_estimate" => {
+                // Greedy largest-first vertex coloring (undirected graphs only)
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                if graph.graph_type != crate::values::graph::GraphType::Undirected {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: {}() requires an undirected graph", method
+                    )));
+                }
+                if method == "chromatic_number_estimate" {
+                    return Ok(Value::number(graph.chromatic_number_estimate() as f64));
+                }
+                let mut coloring = crate::values::Hash::new();
+                for (id, color) in graph.greedy_coloring() {
+                    coloring.insert(id, Value::number(color as f64))?;
+                }
+                Ok(Value::map(coloring))
+            }
+            "create_index" | "drop_index" | "has_index" => {
+                // Property indexes: create_index(:name), drop_index(:name), has_index(:name)
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 1 argument (property), but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let property = match &args[0].kind {
+                    ValueKind::Symbol(name) => name.to_string(),
+                    ValueKind::String(name) => name.clone(),
+                    _ => {
+                        return Err(GraphoidError::runtime(format!(
+                            "{}() expects a symbol or string property, got {}",
+                            method,
+                            args[0].type_name()
+                        )));
+                    }
+                };
+
+                if method == "has_index" {
+                    return Ok(Value::boolean(graph.has_index(&property)));
+                }
+                let dropped = method == "drop_index" && graph.drop_index(&property);
+                if method == "create_index" {
+                    graph.create_index(&property);
+                }
+
+                // Update graph in environment
+                if let Expr::Variable { name, .. } = object_expr {
+                    self.env.set(name, Value::graph(graph.clone()))?;
+                }
+
+                // create_index returns the graph for chaining; drop_index whether there was an index
+                if method == "drop_index" {
+                    return Ok(Value::boolean(dropped));
+                }
+                Ok(Value::graph(graph))
+            }
             "add_rule" => {
                 // Add a rule to the graph (scoped to data layer only)
                 // add_rule(:rule_name) or add_rule(:rule_name, param)
@@ -1393,12 +2342,12 @@ impl Executor {
                 use crate::graph::RuleInstance;
                 graph.add_rule(RuleInstance::new(rule_spec))?;
 
-                // Update graph in environment (mutation)
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "remove_rule" => {
                 // Remove a rule from the graph
@@ -1442,12 +2391,12 @@ impl Executor {
                 // Remove rule from graph
                 graph.remove_rule(&rule_spec);
 
-                // Update graph in environment (mutation)
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "add_method_constraint" => {
                 // Add a custom method constraint function
@@ -1496,12 +2445,12 @@ impl Executor {
                 use crate::graph::RuleInstance;
                 graph.add_rule(RuleInstance::new(rule_spec))?;
 
-                // Update graph in environment (mutation)
+                // Update graph in environment and return it for chaining
                 if let Expr::Variable { name, .. } = object_expr {
-                    self.env.set(name, Value::graph(graph))?;
+                    self.env.set(name, Value::graph(graph.clone()))?;
                 }
 
-                Ok(Value::none())
+                Ok(Value::graph(graph))
             }
             "has_rule" => {
                 // Check if graph has a specific rule (from either rulesets or ad hoc)
@@ -1631,6 +2580,30 @@ impl Executor {
 
                 Ok(Value::string(output))
             }
+            "show" => {
+                // Readable multi-line view: one line per node with its neighbors
+                // show()       - at most SHOW_NODE_LIMIT nodes, then "+N more"
+                // show(limit)  - at most `limit` nodes
+                // show(:all)   - every node
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "show() expects 0-1 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                let limit = match args.first().map(|arg| &arg.kind) {
+                    None => Some(crate::values::graph::SHOW_NODE_LIMIT),
+                    Some(ValueKind::Symbol(s)) if s == "all" => None,
+                    Some(ValueKind::Number(n)) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as usize),
+                    Some(ValueKind::Number(n)) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: show() limit must be a non-negative integer, got {}", n
+                    ))),
+                    Some(_) => return Err(GraphoidError::runtime(
+                        "show() optional argument must be a node limit or :all".to_string()
+                    )),
+                };
+                Ok(Value::string(graph.show(limit)))
+            }
             "to_dot" => {
                 // Export to Graphviz DOT format
                 // to_dot()       - Data layer only (default)
@@ -1693,6 +2666,16 @@ impl Executor {
 
                 Ok(Value::string(output))
             }
+            "to_svg" => {
+                // Self-contained SVG image of the data layer (circular layout)
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "to_svg() takes no arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                Ok(Value::string(graph.to_svg()))
+            }
             "to_ascii" => {
                 // ASCII tree visualization
                 // Works best for tree-like structures
@@ -1905,3 +2888,37 @@ impl Executor {
         }
     }
 }
+
+/// Centrality scores for `measure` as a map keyed by node id in sorted order.
+fn centrality_map(graph: &crate::values::Graph, measure: &str, damping: f64) -> Result<Value> {
+    let scores = match measure {
+        "degree" => graph.degree_centrality(),
+        "closeness" => graph.closeness_centrality(),
+        "betweenness" => graph.betweenness_centrality(),
+        "pagerank" => graph.pagerank(damping),
+        _ => return Err(GraphoidError::runtime(format!(
+            "ValueError: Unknown centrality measure ':{}'. Valid measures: :degree, :betweenness, :pagerank, :closeness",
+            measure
+        ))),
+    };
+    let mut ids: Vec<&String> = scores.keys().collect();
+    ids.sort();
+    let mut map = crate::values::Hash::new();
+    for id in ids {
+        map.insert(id.clone(), Value::number(scores[id]))?;
+    }
+    Ok(Value::map(map))
+}
+
+/// Add where a predicate block failed to its runtime error, keeping the error
+/// type in front (`ValueError: count_nodes() block failed on node 'a': ...`)
+/// so `catch` clauses still match it. Other errors pass through unchanged.
+fn predicate_error(err: GraphoidError, context: &str) -> GraphoidError {
+    match err {
+        GraphoidError::RuntimeError { .. } => {
+            let (error_type, message) = err.type_and_message();
+            GraphoidError::runtime(format!("{}: {}: {}", error_type, context, message))
+        }
+        other => other,
+    }
+}
@@ -7,7 +7,7 @@
 use crate::graph::{RuleSpec, RuleInstance};
 use crate::error::{GraphoidError, Result};
 use crate::execution::Executor;
-use crate::values::{Value, ValueKind, List};
+use crate::values::{Value, ValueKind, List, ValueSet};
 
 impl Executor {
     // =========================================================================
@@ -148,10 +148,13 @@ impl Executor {
                     .cloned()
                     .ok_or_else(|| GraphoidError::runtime("Cannot get last element of empty list".to_string()))
             }
-            "contains" => {
+            "contains" | "contains?" => {
+                // Compares with value equality, so floats must match exactly;
+                // use any?(block) for approximate matches
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime(format!(
-                        "Method 'contains' expects 1 argument, but got {}",
+                        "Method '{}' expects 1 argument, but got {}",
+                        method,
                         args.len()
                     )));
                 }
@@ -270,15 +273,59 @@ impl Executor {
                     }
                 };
 
-                // Execute the function for each element (for side effects)
-                for element in &elements {
-                    // Call the function with this element, ignore result
-                    let _ = self.call_function(func, &[element.clone()])?;
+                // Execute the function for each element (for side effects).
+                // A two-parameter block also gets the index; `break` stops early.
+                let with_index = func.parameters.len() >= 2;
+                for (index, element) in elements.iter().enumerate() {
+                    let block_args = if with_index {
+                        vec![element.clone(), Value::number(index as f64)]
+                    } else {
+                        vec![element.clone()]
+                    };
+                    if !self.call_each_block(func, &block_args)? {
+                        break;
+                    }
                 }
 
                 // Return the original list
                 Ok(Value::list(list.clone()))
             }
+            "each_slice" | "each_cons" => {
+                // each_slice(n, block) - block gets successive non-overlapping
+                //   slices of n; the last one may be shorter
+                // each_cons(n, block)  - block gets every run of n consecutive
+                //   elements; never called when the list is shorter than n
+                if args.len() != 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects 2 arguments (size, block), but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let size = match &args[0].kind {
+                    ValueKind::Number(n) if *n >= 1.0 && n.fract() == 0.0 => *n as usize,
+                    ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: {}() size must be a positive whole number, got {}", method, n
+                    ))),
+                    _ => return Err(GraphoidError::type_error("number", args[0].type_name())),
+                };
+                let func = match &args[1].kind {
+                    ValueKind::Function(f) => f,
+                    _ => return Err(GraphoidError::type_error("function", args[1].type_name())),
+                };
+
+                let groups: Vec<&[Value]> = if method == "each_slice" {
+                    elements.chunks(size).collect()
+                } else {
+                    elements.windows(size).collect()
+                };
+                for group in groups {
+                    let _ = self.call_function(func, &[Value::list(List::from_vec(group.to_vec()))])?;
+                }
+
+                // Return the original list for chaining
+                Ok(Value::list(list.clone()))
+            }
             "slice" => {
                 if args.len() < 2 || args.len() > 3 {
                     return Err(GraphoidError::runtime(format!(
@@ -506,6 +553,77 @@ impl Executor {
                 reversed.reverse();
                 Ok(Value::list(List::from_vec(reversed)))
             }
+            "rotate" => {
+                // rotate(n) - rotate left by n, or right when n is negative,
+                // wrapping modulo the length
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'rotate' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                let n = match &args[0].kind {
+                    ValueKind::Number(n) if n.fract() == 0.0 && n.is_finite() => *n,
+                    ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: rotate() expects a whole number, got {}", n
+                    ))),
+                    _ => return Err(GraphoidError::type_error("number", args[0].type_name())),
+                };
+
+                let mut rotated = elements.clone();
+                if !rotated.is_empty() {
+                    let shift = n.rem_euclid(rotated.len() as f64) as usize;
+                    rotated.rotate_left(shift);
+                }
+                Ok(Value::list(List::from_vec(rotated)))
+            }
+            "sample" => {
+                // sample()  - one random element
+                // sample(n) - n distinct elements (by position) in random order
+                // Draws follow rand.seed() when it has been called
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'sample' expects 0-1 arguments, but got {}",
+                        args.len()
+                    )));
+                }
+                if elements.is_empty() {
+                    return Err(GraphoidError::runtime(
+                        "ValueError: sample() cannot choose from an empty list".to_string()
+                    ));
+                }
+                let random_index = |bound: usize| {
+                    ((crate::stdlib::RandomModule::next_f64() * bound as f64) as usize).min(bound - 1)
+                };
+
+                let Some(count) = args.first() else {
+                    return Ok(elements[random_index(elements.len())].clone());
+                };
+                let count = match &count.kind {
+                    ValueKind::Number(n) if *n >= 0.0 && n.fract() == 0.0 => *n as usize,
+                    ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: sample() expects a non-negative whole number, got {}", n
+                    ))),
+                    _ => return Err(GraphoidError::type_error("number", count.type_name())),
+                };
+                if count > elements.len() {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: sample() count ({}) exceeds list length ({})",
+                        count,
+                        elements.len()
+                    )));
+                }
+
+                // Partial Fisher-Yates: the first `count` slots end up a random
+                // selection in random order
+                let mut pool = elements.clone();
+                for i in 0..count {
+                    let j = i + random_index(pool.len() - i);
+                    pool.swap(i, j);
+                }
+                pool.truncate(count);
+                Ok(Value::list(List::from_vec(pool)))
+            }
             "join" => {
                 // join(separator) - join list elements into a string
                 if args.len() != 1 {
@@ -520,33 +638,89 @@ impl Executor {
                     _ => return Err(GraphoidError::type_error("string", args[0].type_name())),
                 };
 
-                let string_elements: Vec<String> = elements
-                    .iter()
-                    .map(|e| e.to_string_value())
-                    .collect();
+                let mut string_elements: Vec<String> = Vec::with_capacity(elements.len());
+                for element in elements.iter() {
+                    string_elements.push(self.display_string(element)?);
+                }
 
                 Ok(Value::string(string_elements.join(&separator)))
             }
-            "uniq" => {
+            "uniq" | "unique" => {
                 if !args.is_empty() {
                     return Err(GraphoidError::runtime(format!(
-                        "Method 'uniq' expects 0 arguments, but got {}",
+                        "Method '{}' expects 0 arguments, but got {}",
+                        method,
                         args.len()
                     )));
                 }
 
                 // Remove duplicates (keep first occurrence)
-                let mut seen = std::collections::HashSet::new();
+                let mut seen = ValueSet::new();
+                let mut unique = Vec::new();
+                for elem in &elements {
+                    if seen.insert(elem.clone()) {
+                        unique.push(elem.clone());
+                    }
+                }
+                Ok(Value::list(List::from_vec(unique)))
+            }
+            "unique_by" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'unique_by' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                let func = match &args[0].kind {
+                    ValueKind::Function(f) => f,
+                    _ => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                };
+
+                // Keep the first element for each distinct key
+                let mut seen = ValueSet::new();
                 let mut unique = Vec::new();
                 for elem in &elements {
-                    // Create a simple hash key from the value
-                    let key = format!("{:?}", elem);
+                    let key = self.call_function(func, std::slice::from_ref(elem))?;
                     if seen.insert(key) {
                         unique.push(elem.clone());
                     }
                 }
                 Ok(Value::list(List::from_vec(unique)))
             }
+            "intersect" | "union" | "difference" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects 1 argument, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let other = match &args[0].kind {
+                    ValueKind::List(other) => other.to_vec(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: {}() expects a list, got {}",
+                        method,
+                        args[0].type_name()
+                    ))),
+                };
+
+                // Deduplicated, in order of first appearance in self (then other, for union)
+                let mut seen = ValueSet::new();
+                let result: Vec<Value> = if method == "union" {
+                    elements.iter().chain(other.iter())
+                        .filter(|elem| seen.insert((*elem).clone()))
+                        .cloned()
+                        .collect()
+                } else {
+                    let others: ValueSet = other.into_iter().collect();
+                    let keep_shared = method == "intersect";
+                    elements.iter()
+                        .filter(|elem| others.contains(elem) == keep_shared && seen.insert((*elem).clone()))
+                        .cloned()
+                        .collect()
+                };
+                Ok(Value::list(List::from_vec(result)))
+            }
             "reject" => {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime(format!(
@@ -600,6 +774,48 @@ impl Executor {
                     .collect();
                 Ok(Value::list(List::from_vec(compacted)))
             }
+            "flatten_deep" | "depth" => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                if method == "depth" {
+                    return Ok(Value::number(Self::list_depth(&elements) as f64));
+                }
+                Ok(Value::list(List::from_vec(Self::flatten_list_deep(&elements))))
+            }
+            "compact_map" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'compact_map' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+
+                // Map each element and keep only the non-none results
+                let mut results = Vec::new();
+                for element in &elements {
+                    let result = match &args[0].kind {
+                        ValueKind::Symbol(transform_name) => {
+                            self.apply_named_transformation(element, transform_name)?
+                        }
+                        ValueKind::Function(func) => self.call_function(func, std::slice::from_ref(element))?,
+                        _other => {
+                            return Err(GraphoidError::runtime(format!(
+                                "Method 'compact_map' expects function or symbol, got {}",
+                                args[0].type_name()
+                            )));
+                        }
+                    };
+                    if !matches!(&result.kind, ValueKind::None) {
+                        results.push(result);
+                    }
+                }
+                Ok(Value::list(List::from_vec(results)))
+            }
             "select" => {
                 // select is an alias for filter
                 if args.len() != 1 {
@@ -683,19 +899,16 @@ impl Executor {
                 new_list.prepend_raw(transformed_value)?;
                 Ok(Value::list(new_list))
             }
-            "insert" => {
+            "insert" | "with_inserted" => {
                 if args.len() != 2 {
                     return Err(GraphoidError::runtime(format!(
-                        "Method 'insert' expects 2 arguments (index, value), but got {}",
+                        "Method '{}' expects 2 arguments (index, value), but got {}",
+                        method,
                         args.len()
                     )));
                 }
-                let index = match &args[0].kind {
-                    ValueKind::Number(n) => *n as usize,
-                    _other => {
-                        return Err(GraphoidError::type_error("number", args[0].type_name()));
-                    }
-                };
+                // index == length appends
+                let index = Self::list_position(method, &args[0], list.len(), true)?;
                 let mut new_list = list.clone();
                 let transformed_value = self.apply_transformation_rules_with_context(args[1].clone(), &new_list.graph.rules)?;
                 new_list.insert_at_raw(index, transformed_value)?;
@@ -729,6 +942,29 @@ impl Executor {
                 new_list.remove_at_index(index)?;
                 Ok(Value::list(new_list))
             }
+            "remove_at" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'remove_at' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                // Like pop(), returns the element; remove_at!() also removes it.
+                let index = Self::list_position(method, &args[0], list.len(), false)?;
+                Ok(elements[index].clone())
+            }
+            "without_at" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'without_at' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                let index = Self::list_position(method, &args[0], list.len(), false)?;
+                let mut new_list = list.clone();
+                new_list.remove_at_index(index)?;
+                Ok(Value::list(new_list))
+            }
             "pop" => {
                 if !args.is_empty() {
                     return Err(GraphoidError::runtime(format!(
@@ -775,6 +1011,139 @@ impl Executor {
 
                 Ok(accumulator)
             }
+            "sum" | "average" => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects 0 arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                if method == "average" && elements.is_empty() {
+                    return Err(GraphoidError::runtime(
+                        "ValueError: average() of an empty list".to_string()
+                    ));
+                }
+
+                let mut total = 0.0;
+                for (index, element) in elements.iter().enumerate() {
+                    match &element.kind {
+                        ValueKind::Number(n) => total += n,
+                        _ => {
+                            return Err(GraphoidError::runtime(format!(
+                                "TypeError: {}() expects numbers, got {} at index {}",
+                                method,
+                                element.type_name(),
+                                index
+                            )));
+                        }
+                    }
+                }
+                if method == "average" {
+                    total /= elements.len() as f64;
+                }
+                Ok(Value::number(total))
+            }
+            "min" | "max" | "min_by" | "max_by" => {
+                let by_key = method.ends_with("_by");
+                let expected_args = if by_key { 1 } else { 0 };
+                if args.len() != expected_args {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects {} argument{}, but got {}",
+                        method,
+                        expected_args,
+                        if by_key { "" } else { "s" },
+                        args.len()
+                    )));
+                }
+                if elements.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: {}() of an empty list",
+                        method
+                    )));
+                }
+
+                // Elements are compared directly, or by the key the block returns
+                let keys = if by_key {
+                    let func = match &args[0].kind {
+                        ValueKind::Function(f) => f,
+                        _ => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                    };
+                    let mut keys = Vec::with_capacity(elements.len());
+                    for element in &elements {
+                        keys.push(self.call_function(func, std::slice::from_ref(element))?);
+                    }
+                    keys
+                } else {
+                    elements.clone()
+                };
+
+                // Only a strictly better key replaces the current pick, so ties
+                // go to the earlier element
+                let wanted = if method.starts_with("min") {
+                    std::cmp::Ordering::Less
+                } else {
+                    std::cmp::Ordering::Greater
+                };
+                let mut best = 0;
+                for index in 1..keys.len() {
+                    if self.compare_values(&keys[index], &keys[best])? == wanted {
+                        best = index;
+                    }
+                }
+                Ok(elements[best].clone())
+            }
+            "par_map" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'par_map' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                let func = match &args[0].kind {
+                    ValueKind::Function(f) => f,
+                    _ => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                };
+                let results = self.par_map_elements(func, &elements)?;
+                Ok(Value::list(List::from_vec(results)))
+            }
+            "find" | "find_index" => {
+                // find(block)       - first element the block accepts, or none
+                // find_index(block) - its index, or -1
+                // The block may also be a named predicate symbol like :even
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects 1 argument, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                for (index, element) in elements.iter().enumerate() {
+                    if self.list_element_matches(method, args.first(), element, index)? {
+                        return Ok(if method == "find" { element.clone() } else { Value::number(index as f64) });
+                    }
+                }
+                Ok(if method == "find" { Value::none() } else { Value::number(-1.0) })
+            }
+            "any?" | "all?" | "none?" => {
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' expects 0 or 1 argument, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+
+                // Stop at the first element that decides the answer:
+                // any? on a match, all?/none? on a mismatch / match
+                let stop_on = method != "all?";
+                for (index, element) in elements.iter().enumerate() {
+                    if self.list_element_matches(method, args.first(), element, index)? == stop_on {
+                        return Ok(Value::boolean(method == "any?"));
+                    }
+                }
+                Ok(Value::boolean(method != "any?"))
+            }
             _ => Err(GraphoidError::runtime(format!(
                 "List does not have method '{}'",
                 method
@@ -782,4 +1151,442 @@ impl Executor {
         }
     }
 
+    /// Map a function over elements on worker threads, keeping result order.
+    ///
+    /// Workers share nothing with this thread: each runs its own executor
+    /// under a copy of the active configuration, and receives plain-data
+    /// copies (`PlainValue`) of the elements, of the captured variables the
+    /// function reads, and of the named functions it calls. Output a worker
+    /// prints and errors it collects are handed back and replayed here in
+    /// element order. When that is not possible the map runs sequentially instead:
+    /// the function has no AST body (native and pattern-matching functions),
+    /// assigns to a captured variable, or reads a captured value or element
+    /// that is not plain data, such as a graph or a closure. A result that is
+    /// not plain data can't come back and is an error. If several elements
+    /// fail, the error for the earliest element is returned.
+    fn par_map_elements(&mut self, func: &crate::values::Function, elements: &[Value]) -> Result<Vec<Value>> {
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1).min(elements.len());
+        let plan = if workers < 2 { None } else { self.par_map_plan(func, elements) };
+        let Some(plan) = plan else {
+            let mut results = Vec::with_capacity(elements.len());
+            for element in elements {
+                results.push(self.call_function(func, std::slice::from_ref(element))?);
+            }
+            return Ok(results);
+        };
+
+        let chunk_size = plan.elements.len().div_ceil(workers);
+        let config = self.config_stack.current().clone();
+        let outcomes: Vec<WorkerOutcome> = std::thread::scope(|scope| {
+            let handles: Vec<_> = plan.elements.chunks(chunk_size).map(|chunk| {
+                let func = plan.func.clone();
+                let globals = plan.globals.clone();
+                let captured = plan.captured.clone();
+                let config = config.clone();
+                let chunk = chunk.to_vec();
+                scope.spawn(move || {
+                    let mut env = crate::execution::Environment::new();
+                    for (name, value) in captured {
+                        env.define(name, value.into_value());
+                    }
+                    let env = std::rc::Rc::new(std::cell::RefCell::new(env));
+                    let mut executor = Executor::new();
+                    executor.config_stack.push(config);
+                    executor.enable_output_capture();
+                    for (name, overloads) in globals {
+                        let overloads = overloads.into_iter().map(|f| f.into_function(&env)).collect();
+                        executor.global_functions.insert(name, overloads);
+                    }
+                    let func = func.into_function(&env);
+
+                    let mut results = Vec::with_capacity(chunk.len());
+                    let mut failure = None;
+                    for element in chunk {
+                        match executor.call_function(&func, &[element.into_value()]) {
+                            Ok(value) => match PlainValue::from_value(&value) {
+                                Some(plain) => results.push(plain),
+                                None => {
+                                    failure = Some(WorkerError::not_plain(&value));
+                                    break;
+                                }
+                            },
+                            Err(e) => {
+                                failure = Some(WorkerError::from_error(e));
+                                break;
+                            }
+                        }
+                    }
+                    WorkerOutcome {
+                        results: match failure {
+                            Some(e) => Err(e),
+                            None => Ok(results),
+                        },
+                        output: std::mem::take(&mut executor.output_buffer),
+                        collected: executor.error_collector.get_errors().iter()
+                            .map(CollectedWorkerError::from_collected)
+                            .collect(),
+                    }
+                })
+            }).collect();
+            handles.into_iter()
+                .map(|h| h.join().unwrap_or_else(|_| WorkerOutcome {
+                    results: Err(WorkerError::Raised {
+                        error_type: "RuntimeError".to_string(),
+                        message: "par_map worker panicked".to_string(),
+                    }),
+                    output: String::new(),
+                    collected: Vec::new(),
+                }))
+                .collect()
+        });
+
+        // Chunks are in order, so output and collected errors come back in
+        // the order a sequential map would produce them, and the first
+        // failing chunk holds the earliest error
+        let mut results = Vec::with_capacity(elements.len());
+        for outcome in outcomes {
+            for line in outcome.output.lines() {
+                self.write_output_line(line);
+            }
+            for collected in outcome.collected {
+                let (error, file, position) = collected.into_collected();
+                self.error_collector.collect(error, file, position);
+            }
+            match outcome.results {
+                Ok(values) => results.extend(values.into_iter().map(PlainValue::into_value)),
+                Err(e) => return Err(e.into_error()),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Everything `par_map` hands to its workers, or `None` when the map
+    /// must run on this thread (see `par_map_elements`). Named functions the
+    /// body calls are followed, so recursive helpers travel with it.
+    fn par_map_plan(&self, func: &crate::values::Function, elements: &[Value]) -> Option<ParMapPlan> {
+        if func.pattern_clauses.is_some() {
+            return None;
+        }
+        let body = self.function_ast_body(func)?;
+        let elements = elements.iter().map(PlainValue::from_value).collect::<Option<Vec<_>>>()?;
+
+        let bindings = func.env.borrow().get_all_bindings_recursive();
+        let mut names: Vec<String> = bindings.iter().map(|(name, _)| name.clone()).collect();
+        names.extend(self.global_functions.keys().cloned());
+
+        let mut globals = Vec::new();
+        let mut reads = std::collections::HashSet::new();
+        let mut pending = vec![body.clone()];
+        while let Some(stmts) = pending.pop() {
+            let refs = crate::ast::extract_property_references(&stmts, &names);
+            if !refs.writes.is_empty() {
+                return None;
+            }
+            for name in refs.reads {
+                let Some(overloads) = self.global_functions.get(&name) else {
+                    reads.insert(name);
+                    continue;
+                };
+                if globals.iter().any(|(seen, _)| *seen == name) {
+                    continue;
+                }
+                let mut portable = Vec::with_capacity(overloads.len());
+                for overload in overloads {
+                    if overload.body.is_empty() || overload.pattern_clauses.is_some() || overload.guard.is_some() {
+                        return None;
+                    }
+                    pending.push(overload.body.clone());
+                    portable.push(PortableFunction::new(overload, overload.body.clone()));
+                }
+                globals.push((name, portable));
+            }
+        }
+
+        let mut captured = Vec::new();
+        for (name, value) in &bindings {
+            if reads.contains(name) {
+                captured.push((name.clone(), PlainValue::from_value(value)?));
+            }
+        }
+        Some(ParMapPlan {
+            func: PortableFunction::new(func, body),
+            globals,
+            captured,
+            elements,
+        })
+    }
+
+    /// Every non-list value nested anywhere in `elements`, in order. Maps and
+    /// graphs are leaves. The walk is iterative so deep nesting can't overflow
+    /// the stack; lists hold copies, so one can never contain itself.
+    fn flatten_list_deep(elements: &[Value]) -> Vec<Value> {
+        let mut flat = Vec::new();
+        let mut pending = vec![Vec::from(elements).into_iter()];
+        while let Some(items) = pending.last_mut() {
+            match items.next() {
+                Some(Value { kind: ValueKind::List(inner), .. }) => pending.push(inner.to_vec().into_iter()),
+                Some(value) => flat.push(value),
+                None => {
+                    pending.pop();
+                }
+            }
+        }
+        flat
+    }
+
+    /// Maximum list nesting depth: 1 for a list with no list elements
+    /// (including an empty list), one more for each level of nested lists.
+    fn list_depth(elements: &[Value]) -> usize {
+        let mut deepest = 1;
+        let mut pending = vec![(elements.to_vec(), 1)];
+        while let Some((items, depth)) = pending.pop() {
+            deepest = deepest.max(depth);
+            for item in items {
+                if let ValueKind::List(inner) = &item.kind {
+                    pending.push((inner.to_vec(), depth + 1));
+                }
+            }
+        }
+        deepest
+    }
+
+    /// Resolve an `insert`/`remove_at` index argument to a position in a list
+    /// of length `len`. Negative indices count from the end; `allow_end`
+    /// permits `len` itself (insertion at the end).
+    fn list_position(method: &str, index: &Value, len: usize, allow_end: bool) -> Result<usize> {
+        let n = match &index.kind {
+            ValueKind::Number(n) => *n,
+            _ => return Err(GraphoidError::type_error("number", index.type_name())),
+        };
+        if n.fract() != 0.0 {
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: {}() index must be a whole number, got {}",
+                method, n
+            )));
+        }
+        let len = len as i64;
+        let position = if n < 0.0 { len + n as i64 } else { n as i64 };
+        let limit = if allow_end { len } else { len - 1 };
+        if position < 0 || position > limit {
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: {}() index {} out of range for list of length {}",
+                method, n, len
+            )));
+        }
+        Ok(position as usize)
+    }
+
+    /// Test one element for `any?`/`all?`/`none?`: the block result, a named
+    /// predicate, or the element's own truthiness when no block is given.
+    /// Block errors are reported with the element's index.
+    fn list_element_matches(&mut self, method: &str, block: Option<&Value>, element: &Value, index: usize) -> Result<bool> {
+        let Some(block) = block else {
+            return Ok(element.is_truthy());
+        };
+        match &block.kind {
+            ValueKind::Symbol(predicate_name) => self.apply_named_predicate(element, predicate_name),
+            ValueKind::Function(func) => match self.call_function(func, std::slice::from_ref(element)) {
+                Ok(result) => Ok(result.is_truthy()),
+                Err(GraphoidError::RuntimeError { message }) => Err(GraphoidError::runtime(format!(
+                    "{} (in '{}' block at element {})",
+                    message, method, index
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(GraphoidError::runtime(format!(
+                "Method '{}' expects function or symbol, got {}",
+                method,
+                block.type_name()
+            ))),
+        }
+    }
+
+}
+
+/// What `par_map` sends to each worker thread.
+struct ParMapPlan {
+    func: PortableFunction,
+    globals: Vec<(String, Vec<PortableFunction>)>,
+    captured: Vec<(String, PlainValue)>,
+    elements: Vec<PlainValue>,
+}
+
+/// A function without its environment, rebuilt on a worker around an
+/// environment of plain-data copies.
+#[derive(Clone)]
+struct PortableFunction {
+    name: Option<String>,
+    params: Vec<String>,
+    parameters: Vec<crate::ast::Parameter>,
+    body: Vec<crate::ast::Stmt>,
+    is_static: bool,
+}
+
+impl PortableFunction {
+    fn new(func: &crate::values::Function, body: Vec<crate::ast::Stmt>) -> Self {
+        PortableFunction {
+            name: func.name.clone(),
+            params: func.params.clone(),
+            parameters: func.parameters.clone(),
+            body,
+            is_static: func.is_static,
+        }
+    }
+
+    fn into_function(self, env: &std::rc::Rc<std::cell::RefCell<crate::execution::Environment>>) -> crate::values::Function {
+        crate::values::Function {
+            name: self.name,
+            params: self.params,
+            parameters: self.parameters,
+            body: self.body,
+            pattern_clauses: None,
+            env: env.clone(),
+            node_id: None,
+            is_setter: false,
+            is_static: self.is_static,
+            guard: None,
+            synthetic: None,
+        }
+    }
+}
+
+/// A value copied to or from a worker thread. Only plain data converts:
+/// nothing inside holds an `Rc`, so the copy is `Send` as it stands.
+#[derive(Clone)]
+struct PlainValue {
+    kind: PlainKind,
+    tainted: bool,
+    taint_source: Option<String>,
+}
+
+#[derive(Clone)]
+enum PlainKind {
+    Number(f64),
+    BigNumber(crate::values::BigNum),
+    String(String),
+    Boolean(bool),
+    None,
+    Symbol(crate::values::Symbol),
+    Time(f64),
+    List(Vec<PlainValue>),
+    Map(Vec<(PlainValue, PlainValue)>),
+}
+
+impl PlainValue {
+    /// `None` if the value holds anything shared by reference (graphs,
+    /// functions, channels, ...), at any depth.
+    fn from_value(value: &Value) -> Option<PlainValue> {
+        let kind = match &value.kind {
+            ValueKind::Number(n) => PlainKind::Number(*n),
+            ValueKind::BigNumber(n) => PlainKind::BigNumber(n.clone()),
+            ValueKind::String(s) => PlainKind::String(s.clone()),
+            ValueKind::Boolean(b) => PlainKind::Boolean(*b),
+            ValueKind::None => PlainKind::None,
+            ValueKind::Symbol(s) => PlainKind::Symbol(s.clone()),
+            ValueKind::Time(t) => PlainKind::Time(*t),
+            ValueKind::List(list) => PlainKind::List(
+                list.to_vec().iter().map(Self::from_value).collect::<Option<_>>()?,
+            ),
+            ValueKind::Map(hash) => PlainKind::Map(
+                hash.keys().iter()
+                    .map(|k| Some((Self::from_value(&hash.key_value(k))?, Self::from_value(hash.get(k)?)?)))
+                    .collect::<Option<_>>()?,
+            ),
+            _ => return None,
+        };
+        Some(PlainValue { kind, tainted: value.tainted, taint_source: value.taint_source.clone() })
+    }
+
+    fn into_value(self) -> Value {
+        let kind = match self.kind {
+            PlainKind::Number(n) => ValueKind::Number(n),
+            PlainKind::BigNumber(n) => ValueKind::BigNumber(n),
+            PlainKind::String(s) => ValueKind::String(s),
+            PlainKind::Boolean(b) => ValueKind::Boolean(b),
+            PlainKind::None => ValueKind::None,
+            PlainKind::Symbol(s) => ValueKind::Symbol(s),
+            PlainKind::Time(t) => ValueKind::Time(t),
+            PlainKind::List(items) => ValueKind::List(List::from_vec(items.into_iter().map(Self::into_value).collect())),
+            PlainKind::Map(entries) => {
+                let mut hash = crate::values::Hash::new();
+                for (key, value) in entries {
+                    let _ = hash.insert_key(key.into_value(), value.into_value());
+                }
+                ValueKind::Map(hash)
+            }
+        };
+        Value { kind, frozen: false, tainted: self.tainted, taint_source: self.taint_source }
+    }
+}
+
+/// What a `par_map` worker hands back: its results or the error that stopped
+/// it, plus the output it printed and the errors it collected along the way.
+struct WorkerOutcome {
+    results: std::result::Result<Vec<PlainValue>, WorkerError>,
+    output: String,
+    collected: Vec<CollectedWorkerError>,
+}
+
+/// An error collected on a worker in `:collect` mode, in a form that can
+/// cross back to this thread.
+struct CollectedWorkerError {
+    error: WorkerError,
+    file: Option<String>,
+    position: crate::error::SourcePosition,
+}
+
+impl CollectedWorkerError {
+    fn from_collected(collected: &crate::execution::error_collector::CollectedError) -> Self {
+        CollectedWorkerError {
+            error: WorkerError::from_error(collected.error.clone()),
+            file: collected.file.clone(),
+            position: collected.position.clone(),
+        }
+    }
+
+    fn into_collected(self) -> (GraphoidError, Option<String>, crate::error::SourcePosition) {
+        (self.error.into_error(), self.file, self.position)
+    }
+}
+
+/// An error raised on a worker, in a form that can cross back to this thread.
+enum WorkerError {
+    /// A `return` escaping the function, with its value
+    Return(PlainValue),
+    Abort { message: String, stack_trace: Vec<String> },
+    Raised { error_type: String, message: String },
+}
+
+impl WorkerError {
+    fn from_error(error: GraphoidError) -> Self {
+        match error {
+            GraphoidError::ReturnControl { value } => match PlainValue::from_value(&value) {
+                Some(value) => WorkerError::Return(value),
+                None => WorkerError::not_plain(&value),
+            },
+            GraphoidError::Abort { message, stack_trace } => WorkerError::Abort { message, stack_trace },
+            other => {
+                let (error_type, message) = other.type_and_message();
+                WorkerError::Raised { error_type, message }
+            }
+        }
+    }
+
+    fn not_plain(value: &Value) -> Self {
+        WorkerError::Raised {
+            error_type: "TypeError".to_string(),
+            message: format!("par_map results must be plain data, got {}", value.type_name()),
+        }
+    }
+
+    fn into_error(self) -> GraphoidError {
+        match self {
+            WorkerError::Return(value) => GraphoidError::ReturnControl { value: value.into_value() },
+            WorkerError::Abort { message, stack_trace } => GraphoidError::Abort { message, stack_trace },
+            WorkerError::Raised { error_type, message } if error_type == "RuntimeError" => GraphoidError::runtime(message),
+            WorkerError::Raised { error_type, message } => {
+                GraphoidError::runtime(format!("{}: {}", error_type, message))
+            }
+        }
+    }
 }
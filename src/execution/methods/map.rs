@@ -22,11 +22,7 @@ impl Executor {
                         "keys() takes no arguments".to_string()
                     ));
                 }
-                let keys: Vec<Value> = hash.keys()
-                    .iter()
-                    .map(|k| Value::string(k.clone()))
-                    .collect();
-                Ok(Value::list(List::from_vec(keys)))
+                Ok(Value::list(List::from_vec(hash.key_values())))
             }
             "values" => {
                 // Return list of all values
@@ -38,6 +34,15 @@ impl Executor {
                 let values: Vec<Value> = hash.values();
                 Ok(Value::list(List::from_vec(values)))
             }
+            "to_string_sorted" => {
+                // Display text with keys in sorted order (canonical snapshots)
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(
+                        "to_string_sorted() takes no arguments".to_string()
+                    ));
+                }
+                Ok(Value::string(hash.to_string_sorted()))
+            }
             "has_key" => {
                 // Check if key exists
                 if args.len() != 1 {
@@ -45,13 +50,51 @@ impl Executor {
                         "has_key() requires exactly one argument".to_string()
                     ));
                 }
-                let key = match &args[0].kind {
-                    ValueKind::String(s) => s,
-                    _ => return Err(GraphoidError::runtime(
-                        "has_key() requires a string argument".to_string()
+                Ok(Value::boolean(hash.contains(&args[0])))
+            }
+            "get_or_default" => {
+                // get_or_default(key, default) - value for key, or default (hash unchanged)
+                if args.len() != 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "get_or_default() expects 2 arguments (key, default), but got {}",
+                        args.len()
+                    )));
+                }
+                Hash::key_id(&args[0])?;
+                Ok(hash.get_key(&args[0]).cloned().unwrap_or_else(|| args[1].clone()))
+            }
+            "get_or_insert" | "get_or_insert_with" => {
+                // Inserting needs somewhere to write the hash back to
+                Err(GraphoidError::runtime(format!(
+                    "{}() modifies the hash; call it as {}!(...)",
+                    method, method
+                )))
+            }
+            "deep_merge" => {
+                // deep_merge(other)                - nested maps merged, lists replaced
+                // deep_merge(other, :concat_lists) - nested lists concatenated instead
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "deep_merge() expects 1-2 arguments (other, [:concat_lists]), but got {}",
+                        args.len()
+                    )));
+                }
+                let other = match &args[0].kind {
+                    ValueKind::Map(other) => other,
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: deep_merge() expects a map, got {}",
+                        args[0].type_name()
+                    ))),
+                };
+                let concat_lists = match args.get(1).map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "concat_lists" => true,
+                    Some(_) => return Err(GraphoidError::runtime(
+                        "deep_merge() optional argument must be :concat_lists".to_string()
                     )),
                 };
-                Ok(Value::boolean(hash.contains_key(key)))
+                let merged = Self::deep_merge_hashes(hash, other, concat_lists, &mut Vec::new())?;
+                Ok(Value::map(merged))
             }
             "size" | "len" | "length" => {
                 // Return number of entries
@@ -197,20 +240,98 @@ impl Executor {
                         args.len()
                     )));
                 }
-                let key = match &args[0].kind {
-                    ValueKind::String(s) => s.clone(),
-                    _other => {
-                        return Err(GraphoidError::runtime(format!(
-                            "remove() requires a string key, got {}",
+                // Clone hash and remove key
+                let mut new_hash = hash.clone();
+                let _ = new_hash.remove_key(&args[0])?;  // Ok if key doesn't exist
+                Ok(Value::map(new_hash))
+            }
+            "each" => {
+                // each(block) - block gets (key, value) per entry in insertion
+                // order; `break` stops early. Returns the hash.
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'each' expects 1 argument, but got {}",
+                        args.len()
+                    )));
+                }
+                let func = match &args[0].kind {
+                    ValueKind::Function(f) => f,
+                    _ => return Err(GraphoidError::type_error("function", args[0].type_name())),
+                };
+                for id in hash.keys() {
+                    let Some(value) = hash.get(&id) else { continue };
+                    if !self.call_each_block(func, &[hash.key_value(&id), value.clone()])? {
+                        break;
+                    }
+                }
+                Ok(Value::map(hash.clone()))
+            }
+            "transform_keys" | "invert" => {
+                // transform_keys(block[, :strict]) - keys replaced by block(key), stringified
+                // invert([:strict])                - values become keys, keys become values
+                // Colliding keys keep the last value (in the first key's position)
+                // unless :strict is given, which raises instead
+                let expected = if method == "invert" { "0-1" } else { "1-2" };
+                let min_args = if method == "invert" { 0 } else { 1 };
+                if args.len() < min_args || args.len() > min_args + 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects {} arguments, but got {}",
+                        method, expected, args.len()
+                    )));
+                }
+                let strict = match args.get(min_args).map(|arg| &arg.kind) {
+                    None => false,
+                    Some(ValueKind::Symbol(s)) if s == "strict" => true,
+                    Some(ValueKind::Boolean(b)) => *b,
+                    Some(_) => return Err(GraphoidError::runtime(format!(
+                        "{}() optional argument must be :strict", method
+                    ))),
+                };
+                let block = if method == "transform_keys" {
+                    match &args[0].kind {
+                        ValueKind::Function(func) => Some(func),
+                        _ => return Err(GraphoidError::runtime(format!(
+                            "TypeError: transform_keys() expects a function, got {}",
                             args[0].type_name()
-                        )));
+                        ))),
                     }
+                } else {
+                    None
                 };
 
-                // Clone hash and remove key
-                let mut new_hash = hash.clone();
-                let _ = new_hash.remove(&key);  // Ignore result - Ok if key doesn't exist
-                Ok(Value::map(new_hash))
+                let mut result = Hash::new();
+                let mut sources: std::collections::HashMap<String, Value> = std::collections::HashMap::new();
+                for id in hash.keys() {
+                    let Some(value) = hash.get(&id) else { continue };
+                    let key = hash.key_value(&id);
+                    // transform_keys() keys are always strings; invert() keeps
+                    // each value as the key, so it has to be a hashable one
+                    let (new_key, new_value) = match block {
+                        Some(func) => {
+                            let text = self.call_function(func, std::slice::from_ref(&key))?.to_string_value();
+                            (Value::string(text), value.clone())
+                        }
+                        None => (value.clone(), key.clone()),
+                    };
+                    let new_id = Hash::key_id(&new_key).map_err(|_| GraphoidError::runtime(format!(
+                        "TypeError: invert() cannot use the {} value of key {} as a key",
+                        value.type_name(),
+                        Self::quoted_key(&key)
+                    )))?;
+                    if let Some(previous) = sources.insert(new_id, key.clone()) {
+                        if strict {
+                            return Err(GraphoidError::runtime(format!(
+                                "ValueError: {}() maps keys {} and {} to the same key {}",
+                                method,
+                                Self::quoted_key(&previous),
+                                Self::quoted_key(&key),
+                                Self::quoted_key(&new_key)
+                            )));
+                        }
+                    }
+                    result.insert_key(new_key, new_value)?;
+                }
+                Ok(Value::map(result))
             }
             _ => {
                 // Check if this is property-style access (no arguments, method name matches a key)
@@ -227,4 +348,101 @@ impl Executor {
             }
         }
     }
+
+    /// Mutating `get_or_insert!(key, default)` / `get_or_insert_with!(key, block)`.
+    ///
+    /// Returns the value for `key` together with the updated hash when the key
+    /// was missing and had to be inserted. The block is only called on a miss.
+    pub(crate) fn eval_map_get_or_insert(
+        &mut self,
+        map_value: &Value,
+        method: &str,
+        args: &[Value],
+    ) -> Result<(Value, Option<Hash>)> {
+        let hash = match &map_value.kind {
+            ValueKind::Map(hash) => hash,
+            _ => return Err(GraphoidError::type_error("map", map_value.type_name())),
+        };
+        if args.len() != 2 {
+            let second = if method == "get_or_insert" { "default" } else { "block" };
+            return Err(GraphoidError::runtime(format!(
+                "{}() expects 2 arguments (key, {}), but got {}",
+                method, second, args.len()
+            )));
+        }
+        if map_value.is_frozen() {
+            return Err(GraphoidError::runtime(format!(
+                "{}() cannot modify a frozen hash", method
+            )));
+        }
+
+        Hash::key_id(&args[0])?;
+        if let Some(existing) = hash.get_key(&args[0]) {
+            return Ok((existing.clone(), None));
+        }
+
+        let value = if method == "get_or_insert_with" {
+            match &args[1].kind {
+                ValueKind::Function(func) => self.call_function(func, &[])?,
+                _ => return Err(GraphoidError::runtime(format!(
+                    "get_or_insert_with() expects a function as its second argument, got {}",
+                    args[1].type_name()
+                ))),
+            }
+        } else {
+            args[1].clone()
+        };
+
+        let mut updated = hash.clone();
+        updated.insert_key(args[0].clone(), value.clone())?;
+        Ok((value, Some(updated)))
+    }
+
+    /// Key text for error messages: strings quoted, other keys as their value
+    fn quoted_key(key: &Value) -> String {
+        match &key.kind {
+            ValueKind::String(s) => format!("\"{}\"", s),
+            _ => key.to_string_value(),
+        }
+    }
+
+    /// Recursively merge `other` into a copy of `base`.
+    ///
+    /// `ancestors` holds the maps currently being merged on the way down from
+    /// the top. A nested map that shares storage with one of them can only come
+    /// from a self-referential structure, so it is not descended into again:
+    /// the value from `other` replaces it, which keeps the recursion finite.
+    fn deep_merge_hashes<'a>(
+        base: &'a Hash,
+        other: &'a Hash,
+        concat_lists: bool,
+        ancestors: &mut Vec<&'a Hash>,
+    ) -> Result<Hash> {
+        ancestors.push(base);
+        let mut merged = Hash::new();
+        for key in base.keys() {
+            if let Some(value) = base.get(&key) {
+                merged.insert_key(base.key_value(&key), value.clone())?;
+            }
+        }
+        for key in other.keys() {
+            let Some(incoming) = other.get(&key) else { continue };
+            let value = match (base.get(&key).map(|v| &v.kind), &incoming.kind) {
+                (Some(ValueKind::Map(existing)), ValueKind::Map(nested))
+                    if !ancestors.iter().any(|a| a.graph.nodes.ptr_eq(&existing.graph.nodes)) =>
+                {
+                    Value::map(Self::deep_merge_hashes(existing, nested, concat_lists, ancestors)?)
+                }
+                (Some(ValueKind::List(existing)), ValueKind::List(nested)) if concat_lists => {
+                    let mut items = existing.to_vec();
+                    items.extend(nested.to_vec());
+                    Value::list(List::from_vec(items))
+                }
+                _ => incoming.clone(),
+            };
+            merged.insert_key(other.key_value(&key), value)?;
+        }
+        ancestors.pop();
+        Ok(merged)
+    }
 }
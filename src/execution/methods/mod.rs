@@ -7,3 +7,84 @@ pub mod graph;
 pub mod list;
 pub mod map;
 pub mod string;
+
+use crate::values::{Value, ValueKind};
+
+/// Methods every value answers to (see `try_universal_method`).
+pub(crate) const UNIVERSAL_METHODS: &[&str] = &[
+    "to_string", "to_num", "to_bool", "inspect", "freeze", "freeze_deep", "freeze_shallow", "is_frozen", "has_frozen",
+    "to_bignum", "is_bignum", "fits_in_num", "type", "type_name", "respond_to?", "methods",
+    "hash_code",
+];
+
+/// Built-in list methods (see `eval_list_method`).
+pub(crate) const LIST_METHODS: &[&str] = &[
+    "size", "length", "len", "first", "last", "contains", "contains?", "is_empty", "empty?", "present?", "map", "filter", "each",
+    "slice", "add_rule", "remove_rule", "sort", "reverse", "rotate", "join", "uniq", "unique", "unique_by",
+    "intersect", "union", "difference", "reject", "compact", "compact_map", "flatten_deep", "depth", "select", "append", "index_of", "sample",
+    "prepend", "insert", "with_inserted", "remove", "remove_at", "remove_at_index", "without_at", "pop", "clear", "reduce", "sum", "average",
+    "min", "max", "min_by", "max_by", "par_map", "any?", "all?", "none?", "find", "find_index", "each_slice", "each_cons",
+];
+
+/// Built-in map methods (see `eval_map_method`).
+pub(crate) const MAP_METHODS: &[&str] = &[
+    "keys", "values", "has_key", "get_or_default", "get_or_insert", "get_or_insert_with",
+    "deep_merge", "size", "len", "length", "is_empty", "empty?", "present?", "add_rule", "remove_rule", "remove",
+    "to_string_sorted", "transform_keys", "invert", "each",
+];
+
+/// Built-in string methods (see `eval_string_method`).
+pub(crate) const STRING_METHODS: &[&str] = &[
+    "length", "size", "len", "first", "last", "is_empty", "empty?", "present?", "slice", "map", "filter", "reject",
+    "each", "upper", "lower", "trim", "reverse", "substring", "split", "lines", "words", "starts_with",
+    "ends_with", "contains", "extract", "count", "find", "replace", "index_of", "last_index_of",
+    "char_code", "to_bytes", "to_symbol",
+];
+
+/// Built-in graph methods (see `eval_graph_method`).
+pub(crate) const GRAPH_METHODS: &[&str] = &[
+    "add_node", "set_node_type", "add_edge", "remove_node", "remove_edge", "with_ruleset",
+    "has_ruleset", "satisfies_ruleset?", "has_node", "has_path", "path_exists", "shortest_path", "astar_path", "distance",
+    "weight_of_path", "total_weight", "all_paths", "match", "get_node", "node", "nodes", "clone", "clone_structure", "map_nodes", "map_edges", "type_of", "template", "is_a",
+    "remove_method", "include", "responds_to", "method_reads", "method_writes",
+    "property_readers", "property_writers", "dependencies", "dependents", "dependency_order",
+    "ancestors", "descendants", "reachable_from", "bfs_tree", "dfs_tree", "to_tree", "walk", "edges", "adjacency_list", "to_edge_list", "extract", "delete", "add_subgraph", "merge", "relabel_nodes", "node_count",
+    "edge_count", "count_nodes", "count_edges", "find_node", "select_nodes", "empty?", "present?", "degree_histogram", "density", "average_degree", "diameter", "degree_centrality", "closeness_centrality", "betweenness_centrality", "pagerank", "centrality",
+    "has_cycle", "connected?", "weakly_connected?",
+    "find_cycles", "girth", "shortest_cycle", "complement", "topological_generations", "is_tree", "is_dag", "is_bipartite", "color", "chromatic_number_estimate",
+    "create_index", "drop_index", "has_index", "add_rule", "remove_rule",
+    "add_method_constraint", "has_rule", "rule", "visualize", "show", "to_dot", "to_svg", "to_ascii",
+    "equals",
+];
+
+/// Built-in number methods (see `dispatch_number_method`).
+pub(crate) const NUMBER_METHODS: &[&str] = &[
+    "abs", "floor", "ceil", "sqrt", "round", "up", "down", "log", "to_char", "to_str",
+];
+
+/// Built-in methods for the remaining value types with methods of their own.
+pub(crate) const FUNCTION_METHODS: &[&str] = &["arity", "partial"];
+pub(crate) const ERROR_METHODS: &[&str] = &[
+    "message", "file", "line", "column", "stack_trace", "stack", "full_chain", "cause", "caused_by",
+];
+pub(crate) const NODE_METHODS: &[&str] = &["id", "value", "property", "set_property", "neighbors"];
+pub(crate) const TIME_METHODS: &[&str] = &[
+    "to_str", "year", "month", "day", "hour", "minute", "second", "time_numbers",
+];
+
+/// Built-in method names for a value's type, not counting `UNIVERSAL_METHODS`
+/// or methods a graph defines itself.
+pub(crate) fn builtin_method_names(value: &Value) -> &'static [&'static str] {
+    match &value.kind {
+        ValueKind::List(_) => LIST_METHODS,
+        ValueKind::Map(_) => MAP_METHODS,
+        ValueKind::String(_) => STRING_METHODS,
+        ValueKind::Graph(_) => GRAPH_METHODS,
+        ValueKind::Number(_) => NUMBER_METHODS,
+        ValueKind::Function(_) => FUNCTION_METHODS,
+        ValueKind::Error(_) => ERROR_METHODS,
+        ValueKind::Time(_) => TIME_METHODS,
+        ValueKind::Node(_) => NODE_METHODS,
+        _ => &[],
+    }
+}
@@ -467,6 +467,29 @@ impl Executor {
 
                 Ok(Value::list(crate::values::List::from_vec(parts)))
             }
+            "lines" | "words" => {
+                // lines(): split on \n (a preceding \r is dropped), so "" -> [""]
+                // and a trailing newline leaves a final empty line, like split("\n")
+                // words(): split on runs of Unicode whitespace, so "" -> []
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "String method '{}' takes no arguments, but got {}",
+                        method,
+                        args.len()
+                    )));
+                }
+                let parts: Vec<Value> = if method == "lines" {
+                    s.split('\n')
+                        .map(|line| Value::string(line.strip_suffix('\r').unwrap_or(line).to_string()))
+                        .collect()
+                } else {
+                    s.split_whitespace()
+                        .map(|word| Value::string(word.to_string()))
+                        .collect()
+                };
+
+                Ok(Value::list(crate::values::List::from_vec(parts)))
+            }
             "starts_with" => {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime(format!(
@@ -730,9 +753,41 @@ impl Executor {
                 Ok(Value::string(s.replace(old.as_str(), new.as_str())))
             }
             "index_of" => {
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "String method 'index_of' expects 1-2 arguments (substring, start), but got {}",
+                        args.len()
+                    )));
+                }
+                let substring = match &args[0].kind {
+                    ValueKind::String(sub) => sub,
+                    _other => {
+                        return Err(GraphoidError::type_error("string", args[0].type_name()));
+                    }
+                };
+                let start = match args.get(1).map(|arg| &arg.kind) {
+                    None => 0,
+                    Some(ValueKind::Number(n)) => n.max(0.0) as usize,
+                    Some(_) => return Err(GraphoidError::type_error("number", args[1].type_name())),
+                };
+
+                // Indices count characters, not bytes, so multibyte text lines up with indexing
+                let start_byte = match s.char_indices().nth(start) {
+                    Some((byte_index, _)) => byte_index,
+                    None if start == s.chars().count() => s.len(),
+                    None => return Ok(Value::number(-1.0)),
+                };
+                match s[start_byte..].find(substring.as_str()) {
+                    Some(byte_index) => {
+                        Ok(Value::number(s[..start_byte + byte_index].chars().count() as f64))
+                    }
+                    None => Ok(Value::number(-1.0)),
+                }
+            }
+            "last_index_of" => {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime(format!(
-                        "String method 'index_of' expects 1 argument (substring), but got {}",
+                        "String method 'last_index_of' expects 1 argument (substring), but got {}",
                         args.len()
                     )));
                 }
@@ -743,8 +798,8 @@ impl Executor {
                     }
                 };
 
-                match s.find(substring.as_str()) {
-                    Some(index) => Ok(Value::number(index as f64)),
+                match s.rfind(substring.as_str()) {
+                    Some(byte_index) => Ok(Value::number(s[..byte_index].chars().count() as f64)),
                     None => Ok(Value::number(-1.0)),
                 }
             }
@@ -857,6 +912,23 @@ impl Executor {
 
                 Ok(Value::list(List::from_vec(bytes)))
             }
+            "to_symbol" => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "String method 'to_symbol' takes no arguments, but got {}",
+                        args.len()
+                    )));
+                }
+
+                // Same names the lexer accepts after ':'
+                if s.is_empty() || !s.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err(GraphoidError::runtime(format!(
+                        "ValueError: to_symbol() cannot make a symbol from {:?}; symbol names use only letters, digits and underscores",
+                        s
+                    )));
+                }
+                Ok(Value::symbol(s))
+            }
             _ => Err(GraphoidError::runtime(format!(
                 "String does not have method '{}'",
                 method
@@ -5,14 +5,16 @@
 pub mod config;
 pub mod error_collector;
 pub mod function_graph;
+pub(crate) mod iteration;
 pub mod module_manager;
 pub mod pattern_matcher;
+pub mod program_cache;
 
 // The arithmetic and methods modules provide impl blocks for Executor (= GraphExecutor).
 pub mod arithmetic;
 pub mod methods;
 
-pub use config::{Config, ConfigStack, ErrorMode, BoundsCheckingMode, TypeCoercionMode, NoneHandlingMode};
+pub use config::{Config, ConfigStack, ErrorMode, BoundsCheckingMode, TypeCoercionMode, NoneHandlingMode, OutputFormat};
 
 // Phase 15: NamespaceGraph is the environment, re-exported as Environment for API compatibility.
 pub use crate::namespace::NamespaceGraph as Environment;
@@ -195,116 +195,60 @@ impl ModuleManager {
         module_name: &str,
         from_file: Option<&Path>,
     ) -> Result<PathBuf> {
-        // 1. Relative paths (./file or ../file)
-        if module_name.starts_with("./") || module_name.starts_with("../") {
-            if let Some(from) = from_file {
-                let base = from.parent().ok_or_else(|| GraphoidError::ModuleNotFound {
-                    module: module_name.to_string(),
-                    position: SourcePosition::unknown(),
-                })?;
-
-                let path = base.join(module_name);
-
-                // Try exact path
-                if path.exists() && path.is_file() {
-                    return path.canonicalize().map_err(|e| {
-                        GraphoidError::IOError {
-                            message: format!("Failed to canonicalize path: {}", e),
-                            position: SourcePosition::unknown(),
-                        }
-                    });
-                }
-
-                // Try with .gr extension
-                let with_ext = if path.extension().is_none() {
-                    path.with_extension("gr")
-                } else {
-                    path
-                };
-
-                if with_ext.exists() {
-                    return with_ext.canonicalize().map_err(|e| {
-                        GraphoidError::IOError {
-                            message: format!("Failed to canonicalize path: {}", e),
-                            position: SourcePosition::unknown(),
-                        }
-                    });
-                }
-            }
+        self.resolve_module_path_in(module_name, from_file, &[])
+    }
 
-            return Err(GraphoidError::ModuleNotFound {
-                module: module_name.to_string(),
-                position: SourcePosition::unknown(),
-            });
+    /// Resolve a module name to a file path, looking in order:
+    ///
+    /// 1. `./file` and `../file`: only relative to the current file (or the
+    ///    working directory when there is none)
+    /// 2. The current file's directory
+    /// 3. `extra_paths` (from `configure { module_paths: [...] }`)
+    /// 4. The built-in search paths: `src`, `lib`, then the stdlib
+    ///
+    /// Each candidate is tried as given and, without an extension, with
+    /// `.gr`. If nothing matches, the `IOError` lists every file tried.
+    pub fn resolve_module_path_in(
+        &self,
+        module_name: &str,
+        from_file: Option<&Path>,
+        extra_paths: &[PathBuf],
+    ) -> Result<PathBuf> {
+        let current_dir = from_file.and_then(|from| from.parent());
+        let mut dirs: Vec<&Path> = Vec::new();
+        if Path::new(module_name).is_absolute() {
+            dirs.push(Path::new(""));
+        } else if module_name.starts_with("./") || module_name.starts_with("../") {
+            dirs.push(current_dir.unwrap_or(Path::new(".")));
+        } else {
+            dirs.extend(current_dir);
+            dirs.extend(extra_paths.iter().map(PathBuf::as_path));
+            dirs.extend(self.search_paths.iter().map(PathBuf::as_path));
         }
 
-        // 2. Try same directory as current file (for non-relative imports)
-        if let Some(from) = from_file {
-            if let Some(base) = from.parent() {
-                let candidate = base.join(module_name);
-
-                // Try direct path
-                if candidate.exists() && candidate.is_file() {
-                    return candidate.canonicalize().map_err(|e| {
-                        GraphoidError::IOError {
-                            message: format!("Failed to canonicalize path: {}", e),
-                            position: SourcePosition::unknown(),
-                        }
-                    });
-                }
-
-                // Try with .gr extension
-                let with_ext = if candidate.extension().is_none() {
-                    candidate.with_extension("gr")
-                } else {
-                    candidate
-                };
-
-                if with_ext.exists() {
-                    return with_ext.canonicalize().map_err(|e| {
+        let mut tried = Vec::new();
+        for dir in dirs {
+            let candidate = dir.join(module_name);
+            let mut options = vec![candidate.clone()];
+            if candidate.extension().is_none() {
+                options.push(candidate.with_extension("gr"));
+            }
+            for path in options {
+                if path.is_file() {
+                    return path.canonicalize().map_err(|e| {
                         GraphoidError::IOError {
                             message: format!("Failed to canonicalize path: {}", e),
                             position: SourcePosition::unknown(),
                         }
                     });
                 }
+                tried.push(path.display().to_string());
             }
         }
 
-        // 3. Project modules (search in search paths)
-        for search_path in &self.search_paths {
-            let candidate = search_path.join(module_name);
-
-            // Try direct path
-            if candidate.exists() && candidate.is_file() {
-                return candidate.canonicalize().map_err(|e| {
-                    GraphoidError::IOError {
-                        message: format!("Failed to canonicalize path: {}", e),
-                        position: SourcePosition::unknown(),
-                    }
-                });
-            }
-
-            // Try with .gr extension
-            let with_ext = if candidate.extension().is_none() {
-                candidate.with_extension("gr")
-            } else {
-                candidate
-            };
-
-            if with_ext.exists() {
-                return with_ext.canonicalize().map_err(|e| {
-                    GraphoidError::IOError {
-                        message: format!("Failed to canonicalize path: {}", e),
-                        position: SourcePosition::unknown(),
-                    }
-                });
-            }
-        }
-
-        // Not found
         Err(GraphoidError::ModuleNotFound {
             module: module_name.to_string(),
+            tried,
             position: SourcePosition::unknown(),
         })
     }
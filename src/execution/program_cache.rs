@@ -0,0 +1,80 @@
+//! Parsed program cache
+//!
+//! Files executed through `load` and `import` are lexed and parsed once per
+//! process. The parsed `Program` is cached by canonical path together with the
+//! file's modification time and size, so a spec suite that loads the same
+//! helpers from many files (or imports the same module from several isolated
+//! module executors) only pays the parse cost the first time. Editing a file
+//! invalidates its entry on the next lookup.
+//!
+//! The cache is thread-local: `Program` is shared through `Rc`, and each actor
+//! thread keeps its own cache.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use crate::ast::Program;
+use crate::error::{GraphoidError, Result};
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+
+struct CachedProgram {
+    modified: Option<SystemTime>,
+    len: u64,
+    program: Rc<Program>,
+}
+
+thread_local! {
+    static PROGRAMS: RefCell<HashMap<PathBuf, CachedProgram>> = RefCell::new(HashMap::new());
+}
+
+/// Lex and parse source text into a `Program`
+pub fn parse_source(source: &str) -> Result<Program> {
+    let tokens = Lexer::new(source).tokenize()
+        .map_err(|e| GraphoidError::runtime(format!("Lexer error: {}", e)))?;
+    Parser::new(tokens).parse()
+        .map_err(|e| GraphoidError::runtime(format!("Parser error: {}", e)))
+}
+
+/// Parse a file, reusing the cached `Program` if the file is unchanged
+/// since it was last parsed.
+pub fn parse_file(path: &Path) -> Result<Rc<Program>> {
+    let metadata = fs::metadata(path)?;
+    let modified = metadata.modified().ok();
+    let len = metadata.len();
+
+    let cached = PROGRAMS.with(|programs| {
+        programs.borrow().get(path).and_then(|entry| {
+            (entry.modified.is_some() && entry.modified == modified && entry.len == len)
+                .then(|| entry.program.clone())
+        })
+    });
+    if let Some(program) = cached {
+        return Ok(program);
+    }
+
+    let source = fs::read_to_string(path)?;
+    let program = Rc::new(parse_source(&source)?);
+    PROGRAMS.with(|programs| {
+        programs.borrow_mut().insert(path.to_path_buf(), CachedProgram {
+            modified,
+            len,
+            program: program.clone(),
+        });
+    });
+    Ok(program)
+}
+
+/// Number of programs currently cached on this thread
+pub fn cached_count() -> usize {
+    PROGRAMS.with(|programs| programs.borrow().len())
+}
+
+/// Drop every cached program on this thread
+pub fn clear() {
+    PROGRAMS.with(|programs| programs.borrow_mut().clear());
+}
@@ -101,6 +101,11 @@ impl AstToGraphConverter {
                 }
                 node
             }
+            Expr::SuperPropertyAccess { property, position } => {
+                let mut props = HashMap::new();
+                props.insert("property".to_string(), AstProperty::Str(property.clone()));
+                self.add_node(arena, AstNodeType::SuperPropertyAccessExpr, props, position.clone())
+            }
             Expr::PropertyAccess { object, property, position } => {
                 let mut props = HashMap::new();
                 props.insert("property".to_string(), AstProperty::Str(property.clone()));
@@ -123,6 +128,11 @@ impl AstToGraphConverter {
                 for (i, p) in params.iter().enumerate() {
                     props.insert(format!("param_{}", i), AstProperty::Str(p.clone()));
                 }
+                // Keep the body as AST so the lambda can run on worker threads (par_map)
+                props.insert("body_stmts".to_string(), AstProperty::Stmts(vec![Stmt::Return {
+                    value: Some((**body).clone()),
+                    position: position.clone(),
+                }]));
                 let node = self.add_node(arena, AstNodeType::LambdaExpr, props, position.clone());
                 // Lambda body gets its own arena for incremental re-parsing
                 let body_arena = self.graph.new_arena();
@@ -149,10 +159,25 @@ impl AstToGraphConverter {
             Expr::Map { entries, position } => {
                 let node = self.add_node(arena, AstNodeType::MapExpr, HashMap::new(), position.clone());
                 for (i, (key, value)) in entries.iter().enumerate() {
-                    // Each entry is a MapEntryNode with key property and ValueEdge
+                    // A `...expr` entry is a SpreadExpr node, as in list literals
+                    if let MapKey::Spread = key {
+                        let spread_node = self.add_node(arena, AstNodeType::SpreadExpr, HashMap::new(), value.position().clone());
+                        let operand_ref = self.convert_expr_in(value, arena);
+                        self.graph.add_edge(spread_node, ExecEdgeType::Operand, operand_ref);
+                        self.graph.add_edge(node, ExecEdgeType::Element(i as u32), spread_node);
+                        continue;
+                    }
+                    // Each entry is a MapEntryNode with a key property (or a KeyEdge
+                    // for computed keys) and a ValueEdge
                     let mut entry_props = HashMap::new();
-                    entry_props.insert("key".to_string(), AstProperty::Str(key.clone()));
+                    if let MapKey::Static(key) = key {
+                        entry_props.insert("key".to_string(), AstProperty::Str(key.clone()));
+                    }
                     let entry_node = self.add_node(arena, AstNodeType::MapEntryNode, entry_props, position.clone());
+                    if let MapKey::Computed(key_expr) = key {
+                        let key_ref = self.convert_expr_in(key_expr, arena);
+                        self.graph.add_edge(entry_node, ExecEdgeType::KeyEdge, key_ref);
+                    }
                     let val_ref = self.convert_expr_in(value, arena);
                     self.graph.add_edge(entry_node, ExecEdgeType::ValueEdge, val_ref);
                     self.graph.add_edge(node, ExecEdgeType::Element(i as u32), entry_node);
@@ -191,6 +216,12 @@ impl AstToGraphConverter {
                 self.graph.add_edge(node, ExecEdgeType::ValueEdge, err_ref);
                 node
             }
+            Expr::Spread { expr, position } => {
+                let node = self.add_node(arena, AstNodeType::SpreadExpr, HashMap::new(), position.clone());
+                let operand_ref = self.convert_expr_in(expr, arena);
+                self.graph.add_edge(node, ExecEdgeType::Operand, operand_ref);
+                node
+            }
             Expr::SpawnActor { expr, position } => {
                 let node = self.add_node(arena, AstNodeType::SpawnActorExpr, HashMap::new(), position.clone());
                 let expr_ref = self.convert_expr_in(expr, arena);
@@ -248,6 +279,16 @@ impl AstToGraphConverter {
                 self.graph.add_edge(node, ExecEdgeType::ValueEdge, val_ref);
                 node
             }
+            Stmt::ConstDecl { name, value, is_private, position } => {
+                let mut props = HashMap::new();
+                props.insert("name".to_string(), AstProperty::Str(name.clone()));
+                props.insert("is_private".to_string(), AstProperty::Bool(*is_private));
+                props.insert("is_const".to_string(), AstProperty::Bool(true));
+                let node = self.add_node(arena, AstNodeType::VarDeclStmt, props, position.clone());
+                let val_ref = self.convert_expr_in(value, arena);
+                self.graph.add_edge(node, ExecEdgeType::ValueEdge, val_ref);
+                node
+            }
             Stmt::Assignment { target, value, position } => {
                 let mut props = HashMap::new();
                 match target {
@@ -351,9 +392,12 @@ impl AstToGraphConverter {
                 self.graph.add_edge(node, ExecEdgeType::Body, body_node);
                 node
             }
-            Stmt::For { variable, iterable, body, position } => {
+            Stmt::For { variable, unpack, iterable, body, position } => {
                 let mut props = HashMap::new();
                 props.insert("variable".to_string(), AstProperty::Str(variable.clone()));
+                if !unpack.is_empty() {
+                    props.insert("unpack".to_string(), AstProperty::Str(unpack.join(",")));
+                }
                 let node = self.add_node(arena, AstNodeType::ForStmt, props, position.clone());
                 let iter_ref = self.convert_expr_in(iterable, arena);
                 self.graph.add_edge(node, ExecEdgeType::Iterable, iter_ref);
@@ -454,6 +498,12 @@ impl AstToGraphConverter {
                 }
                 node
             }
+            Stmt::Defer { body, position } => {
+                let node = self.add_node(arena, AstNodeType::DeferStmt, HashMap::new(), position.clone());
+                let body_node = self.convert_stmt_block(body, arena, position);
+                self.graph.add_edge(node, ExecEdgeType::Body, body_node);
+                node
+            }
             Stmt::GraphDecl { name, graph_type, parent, properties, methods, rules, config, position } => {
                 let mut props = HashMap::new();
                 props.insert("name".to_string(), AstProperty::Str(name.clone()));
@@ -513,6 +563,18 @@ impl AstToGraphConverter {
                 props.insert("body_stmts".to_string(), AstProperty::Stmts(body.clone()));
                 self.add_node(arena, AstNodeType::SpawnStmt, props, position.clone())
             }
+            Stmt::Assert { condition, message, source, position } => {
+                let mut props = HashMap::new();
+                props.insert("source".to_string(), AstProperty::Str(source.clone()));
+                let node = self.add_node(arena, AstNodeType::AssertStmt, props, position.clone());
+                let cond_ref = self.convert_expr_in(condition, arena);
+                self.graph.add_edge(node, ExecEdgeType::Condition, cond_ref);
+                if let Some(msg) = message {
+                    let msg_ref = self.convert_expr_in(msg, arena);
+                    self.graph.add_edge(node, ExecEdgeType::ValueEdge, msg_ref);
+                }
+                node
+            }
         }
     }
 
@@ -3,7 +3,7 @@
 //! Phase 16: Replaces the tree-walking interpreter with graph traversal.
 
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::cell::RefCell;
 use std::sync::Mutex;
@@ -22,9 +22,9 @@ use crate::error::{GraphoidError, SourcePosition, Result};
 use crate::execution::{Environment, ConfigStack, ErrorCollector};
 use crate::execution::function_graph::FunctionGraph;
 use crate::execution::module_manager::ModuleManager;
-use crate::values::{Value, ValueKind, Function};
-use crate::lexer::Lexer;
-use crate::parser::Parser;
+use crate::execution::program_cache;
+use crate::execution::iteration::{LoopItems, ITERATION_BUILTINS};
+use crate::values::{Value, ValueKind, Function, SyntheticKind};
 
 use super::ExecutionGraph;
 use super::arena::NodeRef;
@@ -51,6 +51,13 @@ pub struct GraphExecutor {
     pub(crate) graph_method_value_stack: Vec<Value>,
     pub(crate) suppress_self_property_assignment: usize,
     pub(crate) function_call_depth: usize,
+    /// The statement being executed, for `caller_location()`
+    current_statement: Option<NodeRef>,
+    /// The statement that made each active function or method call
+    call_sites: Vec<Option<NodeRef>>,
+    /// Blocks registered by `defer`, one frame per active function call.
+    /// The first frame belongs to the top-level scope.
+    defer_frames: Vec<Vec<DeferredBlock>>,
     /// The execution graph being traversed (set during execution)
     graph: Option<ExecutionGraph>,
     /// Maps function IDs to their body NodeRef (for graph-based function execution)
@@ -71,6 +78,16 @@ pub struct GraphExecutor {
     start_time: Instant,
     /// Phase 18.7: call stack snapshot at raise time (for error.stack())
     raise_stack: Option<Vec<String>>,
+    /// Source position of the failing statement at raise time (for error.line())
+    raise_position: Option<SourcePosition>,
+    /// Cause chained onto the raised error (for error.cause() once caught)
+    raise_cause: Option<crate::values::ErrorObject>,
+    /// Set while bench() measures a block, so memoize() wrappers recompute
+    bypass_memo_cache: bool,
+    /// Deadlines of the enclosing timeout() blocks, each paired with the
+    /// duration in seconds it enforces. Kept tightest-last so nested timeouts
+    /// never extend an outer one.
+    deadlines: Vec<(Instant, f64)>,
     /// Phase 19: IDs of actors spawned by this executor (for cleanup on drop)
     spawned_actor_ids: Vec<u64>,
     /// Phase 20c: counter for unique bridge node IDs
@@ -79,6 +96,8 @@ pub struct GraphExecutor {
     ffi_limits: crate::ffi::limits::FfiLimits,
     /// Phase 20c: FFI resource usage tracking
     ffi_usage: crate::ffi::limits::FfiUsage,
+    /// Arguments given after `--` on the `gr file.gr -- ...` command line (for args())
+    script_args: Vec<String>,
 }
 
 /// A pattern clause stored as graph references (for pattern-matching functions).
@@ -91,6 +110,13 @@ struct GraphPatternClause {
     body_ref: NodeRef,
 }
 
+/// A block registered by `defer`, with the variables it could see then.
+#[derive(Debug, Clone)]
+struct DeferredBlock {
+    body_ref: NodeRef,
+    bindings: Vec<(String, Value)>,
+}
+
 impl GraphExecutor {
     pub fn new() -> Self {
         let mut executor = GraphExecutor {
@@ -112,6 +138,9 @@ impl GraphExecutor {
             graph_method_value_stack: Vec::new(),
             suppress_self_property_assignment: 0,
             function_call_depth: 0,
+            current_statement: None,
+            call_sites: Vec::new(),
+            defer_frames: vec![Vec::new()],
             graph: None,
             graph_function_bodies: HashMap::new(),
             graph_pattern_clauses: HashMap::new(),
@@ -122,10 +151,15 @@ impl GraphExecutor {
             universe_graph: Rc::new(RefCell::new(Self::build_initial_universe_graph())),
             start_time: Instant::now(),
             raise_stack: None,
+            raise_position: None,
+            raise_cause: None,
+            bypass_memo_cache: false,
+            deadlines: Vec::new(),
             spawned_actor_ids: Vec::new(),
             bridge_ptr_counter: 0,
             ffi_limits: crate::ffi::limits::FfiLimits::default(),
             ffi_usage: crate::ffi::limits::FfiUsage::default(),
+            script_args: Vec::new(),
         };
         // Phase 18.7: Set __MODULE__ for top-level scripts
         executor.env.define("__MODULE__".to_string(), Value::string("__main__".to_string()));
@@ -193,7 +227,7 @@ impl GraphExecutor {
         // Error type hierarchy (subtypes of type:error)
         let error_types = [
             "RuntimeError", "ValueError", "TypeError",
-            "IOError", "NetworkError", "ParseError",
+            "IOError", "NetworkError", "ParseError", "AssertionError", "TimeoutError",
         ];
         for et in &error_types {
             let node_id = format!("error:{}", et);
@@ -288,11 +322,12 @@ impl GraphExecutor {
 
     /// Execute source code: lex → parse → convert → execute. Returns the final value.
     pub fn execute_source_value(&mut self, source: &str) -> Result<Value> {
-        let tokens = Lexer::new(source).tokenize()
-            .map_err(|e| GraphoidError::runtime(format!("Lexer error: {}", e)))?;
-        let program = Parser::new(tokens).parse()
-            .map_err(|e| GraphoidError::runtime(format!("Parser error: {}", e)))?;
+        let program = program_cache::parse_source(source)?;
+        self.execute_program(&program)
+    }
 
+    /// Execute an already-parsed program and return the last value.
+    pub fn execute_program(&mut self, program: &Program) -> Result<Value> {
         // Register and push a toplevel function for function graph tracking
         let toplevel_func = Function {
             name: Some("__toplevel__".to_string()),
@@ -305,15 +340,18 @@ impl GraphExecutor {
             is_setter: false,
             is_static: false,
             guard: None,
+            synthetic: None,
         };
         let toplevel_id = self.function_graph.borrow_mut().register_function(toplevel_func);
         self.function_graph.borrow_mut().push_call(toplevel_id, Vec::new());
 
         let mut converter = AstToGraphConverter::new();
-        let root = converter.convert_program(&program);
+        let root = converter.convert_program(program);
         let exec_graph = converter.into_graph();
 
+        self.defer_frames.push(Vec::new());
         let result = self.execute(exec_graph, root);
+        let result = self.pop_defer_frame(result);
 
         self.function_graph.borrow_mut().pop_call(Value::none());
 
@@ -345,11 +383,42 @@ impl GraphExecutor {
         self.env.get(name).ok()
     }
 
+    /// Evaluate source code for an embedding host: run every statement and,
+    /// if the last one is an expression, return its value. Bindings persist
+    /// across calls. Lexer and parser failures are returned as the
+    /// `SyntaxError` they raised. Blocks deferred at the top level wait for
+    /// `run_top_level_defers`, so a host decides when its program has ended.
+    pub fn eval_source(&mut self, source: &str) -> Result<Option<Value>> {
+        // An error left uncaught by an earlier call is not reported with this one
+        self.raise_stack = None;
+        self.raise_position = None;
+        self.raise_cause = None;
+        let tokens = crate::lexer::Lexer::new(source).tokenize()?;
+        let program = crate::parser::Parser::new(tokens).parse()?;
+        let mut last_value = None;
+        for stmt in &program.statements {
+            let value = self.eval_stmt(stmt)?;
+            last_value = match stmt {
+                Stmt::Expression { .. } => value,
+                _ => None,
+            };
+        }
+        Ok(last_value)
+    }
+
     /// Execute a single node by dispatching on its type.
     pub(crate) fn execute_node(&mut self, node_ref: NodeRef) -> Result<Value> {
         let node = self.get_node(node_ref)?;
         let node_type = node.node_type.clone();
 
+        if node_type.is_statement() {
+            if self.config_stack.current().trace {
+                let line = format!("{:?} at {}", node_type, node.position);
+                self.write_trace_line(&line);
+            }
+            self.current_statement = Some(node_ref);
+        }
+
         match node_type {
             // Literals
             AstNodeType::NumberLit => self.exec_number_lit(node_ref),
@@ -386,6 +455,7 @@ impl GraphExecutor {
 
             // Exceptions
             AstNodeType::TryStmt => self.exec_try(node_ref),
+            AstNodeType::DeferStmt => self.exec_defer(node_ref),
             AstNodeType::RaiseExpr => self.exec_raise(node_ref),
 
             // Control flow
@@ -400,6 +470,7 @@ impl GraphExecutor {
                 control: crate::error::LoopControlType::Continue,
             }),
             AstNodeType::ConditionalExpr => self.exec_conditional(node_ref),
+            AstNodeType::AssertStmt => self.exec_assert(node_ref),
 
             // Module system
             AstNodeType::ImportStmt => self.exec_import(node_ref),
@@ -420,6 +491,7 @@ impl GraphExecutor {
 
             // Super method calls
             AstNodeType::SuperMethodCallExpr => self.exec_super_method_call(node_ref),
+            AstNodeType::SuperPropertyAccessExpr => self.exec_super_property_access(node_ref),
 
             // Phase 17: Privacy block
             AstNodeType::PrivBlockStmt => self.exec_priv_block(node_ref),
@@ -429,6 +501,10 @@ impl GraphExecutor {
             // Phase 19.3: Actor spawn expression
             AstNodeType::SpawnActorExpr => self.exec_spawn_actor(node_ref),
 
+            AstNodeType::SpreadExpr => Err(GraphoidError::runtime(
+                "'...' can only be used inside list and map literals".to_string()
+            )),
+
             _ => Err(GraphoidError::runtime(format!(
                 "Unimplemented node type: {:?}", node_type
             ))),
@@ -461,6 +537,7 @@ impl GraphExecutor {
     /// graph properties accessed via implicit self.
     fn set_variable_or_self_property(&mut self, name: &str, value: Value) -> Result<()> {
         if self.env.exists(name) {
+            self.check_declared_type(name, &value)?;
             self.env.set(name, value)?;
         } else if self.suppress_self_property_assignment == 0 {
             if let Ok(self_value) = self.env.get("self") {
@@ -547,7 +624,8 @@ impl GraphExecutor {
     }
 
     fn exec_symbol_lit(&self, node_ref: NodeRef) -> Result<Value> {
-        match self.get_property(node_ref, "value") {
+        // Borrow the name so repeated evaluation only hits the symbol pool
+        match self.get_node(node_ref)?.properties.get("value") {
             Some(AstProperty::Str(s)) => Ok(Value::symbol(s)),
             _ => Err(GraphoidError::runtime("Missing symbol value".to_string())),
         }
@@ -587,6 +665,10 @@ impl GraphExecutor {
             }
         }
 
+        if let Some(builtin) = Self::builtin_function_value(&name) {
+            return Ok(builtin);
+        }
+
         // Special constants
         match name.as_str() {
             "true" => return Ok(Value::boolean(true)),
@@ -648,6 +730,30 @@ impl GraphExecutor {
 
     /// Delegates to arithmetic.rs for full implementations.
     fn eval_binary_op(&mut self, op: &BinaryOp, left: Value, right: Value) -> Result<Value> {
+        // Operator overloading: a graph on the left with a special method
+        // (e.g. `fn __add__(other)`) handles the operator itself.
+        if let Some(special) = Self::operator_method_name(op) {
+            if let Some(result) = self.try_operator_method(&left, special, std::slice::from_ref(&right))? {
+                return Ok(match op {
+                    BinaryOp::NotEqual => Value::boolean(!result.is_truthy()),
+                    _ => result,
+                });
+            }
+        }
+
+        // String concatenation displays graphs through `__to_string__`
+        let (left, right) = match (op, &left.kind, &right.kind) {
+            (BinaryOp::Add, ValueKind::String(_), ValueKind::Graph(_) | ValueKind::List(_) | ValueKind::Map(_)) => {
+                let display = self.display_string(&right)?;
+                (left, Value::string(display))
+            }
+            (BinaryOp::Add, ValueKind::Graph(_) | ValueKind::List(_) | ValueKind::Map(_), ValueKind::String(_)) => {
+                let display = self.display_string(&left)?;
+                (Value::string(display), right)
+            }
+            _ => (left, right),
+        };
+
         match op {
             BinaryOp::BitwiseAnd => self.eval_bitwise_and(left, right),
             BinaryOp::BitwiseOr => self.eval_bitwise_or(left, right),
@@ -670,6 +776,66 @@ impl GraphExecutor {
         }
     }
 
+    /// Special method name that overloads a binary operator on graph instances.
+    /// `!=` reuses `__eq__` and negates the result.
+    fn operator_method_name(op: &BinaryOp) -> Option<&'static str> {
+        match op {
+            BinaryOp::Add => Some("__add__"),
+            BinaryOp::Subtract => Some("__sub__"),
+            BinaryOp::Multiply => Some("__mul__"),
+            BinaryOp::Divide => Some("__div__"),
+            BinaryOp::Modulo => Some("__mod__"),
+            BinaryOp::Equal | BinaryOp::NotEqual => Some("__eq__"),
+            _ => None,
+        }
+    }
+
+    /// Invoke a special method (`__add__`, `__eq__`, `__index__`, ...) if `receiver`
+    /// is a graph that defines it. Returns `None` so callers fall back to default
+    /// behavior when the graph has no such method. Errors raised inside propagate.
+    fn try_operator_method(&mut self, receiver: &Value, method: &str, args: &[Value]) -> Result<Option<Value>> {
+        let has_method = match &receiver.kind {
+            ValueKind::Graph(g) => g.borrow().has_method(method),
+            _ => false,
+        };
+        if !has_method {
+            return Ok(None);
+        }
+        // A literal receiver expression keeps the call from writing `self` back to a variable
+        let receiver_expr = Expr::Literal { value: crate::ast::LiteralValue::None, position: SourcePosition::unknown() };
+        self.dispatch_method_inner(receiver.clone(), method, args.to_vec(), &receiver_expr).map(Some)
+    }
+
+    /// Display text for a value, honoring a graph's `__to_string__` method.
+    ///
+    /// Lists and maps are rendered in the same format as `to_string_value()`,
+    /// with any graphs they contain displayed through their own hooks.
+    pub(crate) fn display_string(&mut self, value: &Value) -> Result<String> {
+        match &value.kind {
+            ValueKind::Graph(_) => match self.try_operator_method(value, "__to_string__", &[])? {
+                Some(text) => Ok(text.to_string_value()),
+                None => Ok(value.to_string_value()),
+            },
+            ValueKind::List(list) => {
+                let mut parts = Vec::with_capacity(list.len());
+                for item in list.to_vec().iter() {
+                    parts.push(self.display_string(item)?);
+                }
+                Ok(format!("[{}]", parts.join(", ")))
+            }
+            ValueKind::Map(hash) => {
+                let mut parts = Vec::with_capacity(hash.len());
+                for key in hash.keys() {
+                    if let Some(item) = hash.get(&key) {
+                        parts.push(format!("{}: {}", hash.display_key(&key), self.display_string(item)?));
+                    }
+                }
+                Ok(format!("{{{}}}", parts.join(", ")))
+            }
+            _ => Ok(value.to_string_value()),
+        }
+    }
+
     // --- Unary expression ---
 
     fn exec_unary(&mut self, node_ref: NodeRef) -> Result<Value> {
@@ -724,24 +890,67 @@ impl GraphExecutor {
         let element_refs = self.get_ordered_edges(node_ref, "Element");
         let mut elements = Vec::new();
         for elem_ref in element_refs {
+            if let Some(spread) = self.exec_spread_operand(elem_ref)? {
+                match &spread.kind {
+                    ValueKind::List(list) => elements.extend(list.to_vec()),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: cannot spread {} into a list literal; expected a list",
+                        spread.type_name()
+                    ))),
+                }
+                continue;
+            }
             let val = self.execute_node(elem_ref)?;
             elements.push(val);
         }
         Ok(Value::list(crate::values::List::from_vec(elements)))
     }
 
+    /// For a `...expr` element of a list or map literal, the value of `expr`.
+    fn exec_spread_operand(&mut self, elem_ref: NodeRef) -> Result<Option<Value>> {
+        if self.get_node(elem_ref)?.node_type != AstNodeType::SpreadExpr {
+            return Ok(None);
+        }
+        let operand_ref = self.get_edge_target(elem_ref, &ExecEdgeType::Operand)
+            .ok_or_else(|| GraphoidError::runtime("Missing spread operand".to_string()))?;
+        self.execute_node(operand_ref).map(Some)
+    }
+
     // --- Map literal ---
 
     fn exec_map(&mut self, node_ref: NodeRef) -> Result<Value> {
         let entry_refs = self.get_ordered_edges(node_ref, "Element");
         let mut map = crate::values::Hash::new();
         for entry_ref in entry_refs {
-            let key = self.get_str_property(entry_ref, "key")
-                .ok_or_else(|| GraphoidError::runtime("Missing map key".to_string()))?;
+            // Spread entries are copied in order, so later entries override them
+            if let Some(spread) = self.exec_spread_operand(entry_ref)? {
+                let ValueKind::Map(source) = &spread.kind else {
+                    return Err(GraphoidError::runtime(format!(
+                        "TypeError: cannot spread {} into a map literal; expected a map",
+                        spread.type_name()
+                    )));
+                };
+                for id in source.keys() {
+                    if let Some(value) = source.get(&id) {
+                        map.insert_key(source.key_value(&id), value.clone())?;
+                    }
+                }
+                continue;
+            }
+            // A computed `[expr]` key keeps its value, so it is stored and
+            // looked up the same way as `m[expr] = value`
+            let key = match self.get_str_property(entry_ref, "key") {
+                Some(key) => Value::string(key),
+                None => {
+                    let key_ref = self.get_edge_target(entry_ref, &ExecEdgeType::KeyEdge)
+                        .ok_or_else(|| GraphoidError::runtime("Missing map key".to_string()))?;
+                    self.execute_node(key_ref)?
+                }
+            };
             let val_ref = self.get_edge_target(entry_ref, &ExecEdgeType::ValueEdge)
                 .ok_or_else(|| GraphoidError::runtime("Missing map value".to_string()))?;
             let val = self.execute_node(val_ref)?;
-            map.insert(key, val).map_err(|e| GraphoidError::runtime(format!("{}", e)))?;
+            map.insert_key(key, val).map_err(|e| GraphoidError::runtime(format!("{}", e)))?;
         }
         Ok(Value::map(map))
     }
@@ -775,7 +984,12 @@ impl GraphExecutor {
         let name = self.get_str_property(node_ref, "name")
             .ok_or_else(|| GraphoidError::runtime("Missing variable name".to_string()))?;
         let is_private = self.get_bool_property(node_ref, "is_private").unwrap_or(false);
+        let is_const = self.get_bool_property(node_ref, "is_const").unwrap_or(false);
         let type_base = self.get_str_property(node_ref, "type_base");
+        let type_constraint = self.get_str_property(node_ref, "type_constraint");
+        if self.env.is_const_in_current_scope(&name) {
+            return Err(GraphoidError::runtime(format!("cannot reassign constant {}", name)));
+        }
         let val_ref = self.get_edge_target(node_ref, &ExecEdgeType::ValueEdge)
             .ok_or_else(|| GraphoidError::runtime("Missing variable value".to_string()))?;
         let mut value = self.execute_node(val_ref)?;
@@ -798,10 +1012,68 @@ impl GraphExecutor {
             self.private_symbols.insert(name.clone());
         }
 
-        self.env.define(name, value.clone());
+        if is_const {
+            value.freeze();
+            self.env.define_const(name, value.clone());
+        } else if let Some(base_type) = type_base {
+            let annotation = crate::ast::TypeAnnotation { base_type, constraint: type_constraint };
+            if self.config_stack.current().strict_types {
+                Self::check_type_annotation(&name, &annotation, &value)?;
+            }
+            self.env.define_typed(name, value.clone(), annotation);
+        } else {
+            self.env.define(name, value.clone());
+        }
         Ok(value)
     }
 
+    /// Under `configure { strict_types: true }`, a variable declared with a
+    /// type annotation only accepts values of that type
+    fn check_declared_type(&self, name: &str, value: &Value) -> Result<()> {
+        if !self.config_stack.current().strict_types {
+            return Ok(());
+        }
+        match self.env.declared_type(name) {
+            Some(annotation) => Self::check_type_annotation(name, annotation, value),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks a value against a declared type: its `type_name()` must match the
+    /// base type, and for `list<T>` / `map<T>` every element must be a `T`
+    fn check_type_annotation(name: &str, annotation: &crate::ast::TypeAnnotation, value: &Value) -> Result<()> {
+        let declared = match &annotation.constraint {
+            Some(constraint) => format!("{}<{}>", annotation.base_type, constraint),
+            None => annotation.base_type.clone(),
+        };
+        let expected = match annotation.base_type.as_str() {
+            "data" => return Ok(()),
+            "hash" => "map",
+            "tree" => "graph",
+            other => other,
+        };
+        if value.type_name() != expected {
+            return Err(GraphoidError::runtime(format!(
+                "TypeError: cannot assign {} to '{}' declared as {}",
+                value.type_name(), name, declared
+            )));
+        }
+
+        let Some(constraint) = &annotation.constraint else { return Ok(()) };
+        let elements: Vec<Value> = match &value.kind {
+            ValueKind::List(list) => list.to_vec(),
+            ValueKind::Map(map) => map.values(),
+            _ => return Ok(()),
+        };
+        match elements.iter().find(|element| element.type_name() != constraint) {
+            Some(element) => Err(GraphoidError::runtime(format!(
+                "TypeError: cannot store {} in '{}' declared as {}",
+                element.type_name(), name, declared
+            ))),
+            None => Ok(()),
+        }
+    }
+
     /// Truncates numeric values when integer_mode is active
     fn truncate_if_integer_mode(&self, value: Value) -> Value {
         if !self.config_stack.current().integer_mode {
@@ -937,6 +1209,7 @@ impl GraphExecutor {
                     self.private_symbols.insert(name.clone());
                 }
                 if self.env.exists(&name) {
+                    self.check_declared_type(&name, &value)?;
                     self.env.set(&name, value.clone())?;
                 } else {
                     // Implicit self: check if `self` is a graph with this property
@@ -984,8 +1257,8 @@ impl GraphExecutor {
                         let idx = *n as usize;
                         items.set(idx, value.clone())?;
                     }
-                    (ValueKind::Map(ref mut m), ValueKind::String(key)) => {
-                        m.insert(key.clone(), value.clone())?;
+                    (ValueKind::Map(ref mut m), _) => {
+                        m.insert_key(index.clone(), value.clone())?;
                     }
                     (ValueKind::Graph(ref g), ValueKind::String(key)) => {
                         g.borrow_mut().add_node(key.clone(), value.clone())?;
@@ -997,6 +1270,7 @@ impl GraphExecutor {
 
                 // Write back the modified object
                 if let Some(name) = obj_name {
+                    self.check_declared_type(&name, &object)?;
                     self.env.set(&name, object)?;
                 }
             }
@@ -1029,6 +1303,17 @@ impl GraphExecutor {
                         // Graph is Rc<RefCell> so no writeback needed
                         return Ok(value);
                     }
+                    ValueKind::Node(ref handle) if prop_name == "value" => {
+                        // Writes through to the graph the handle refers to now
+                        let graph = self.node_handle_graph(handle);
+                        if !graph.borrow().has_node(&handle.id) {
+                            return Err(GraphoidError::runtime(format!(
+                                "ValueError: node '{}' is no longer in the graph", handle.id
+                            )));
+                        }
+                        graph.borrow_mut().add_node(handle.id.clone(), value.clone())?;
+                        return Ok(value);
+                    }
                     _ => {
                         return Err(GraphoidError::runtime(format!(
                             "Cannot set property on type '{}'", object.type_name()
@@ -1038,6 +1323,7 @@ impl GraphExecutor {
 
                 // Write back the modified object
                 if let Some(name) = obj_name {
+                    self.check_declared_type(&name, &object)?;
                     self.env.set(&name, object)?;
                 }
             }
@@ -1087,6 +1373,7 @@ impl GraphExecutor {
             .ok_or_else(|| GraphoidError::runtime("Missing while body".to_string()))?;
 
         loop {
+            self.check_deadline()?;
             let cond_val = self.execute_node(cond_ref)?;
             if !cond_val.is_truthy() {
                 break;
@@ -1112,36 +1399,31 @@ impl GraphExecutor {
     fn exec_for(&mut self, node_ref: NodeRef) -> Result<Value> {
         let var_name = self.get_str_property(node_ref, "variable")
             .ok_or_else(|| GraphoidError::runtime("Missing for loop variable".to_string()))?;
+        let unpack: Vec<String> = self.get_str_property(node_ref, "unpack")
+            .map(|names| names.split(',').map(String::from).collect())
+            .unwrap_or_default();
         let iter_ref = self.get_edge_target(node_ref, &ExecEdgeType::Iterable)
             .ok_or_else(|| GraphoidError::runtime("Missing for loop iterable".to_string()))?;
         let body_ref = self.get_edge_target(node_ref, &ExecEdgeType::Body)
             .ok_or_else(|| GraphoidError::runtime("Missing for loop body".to_string()))?;
 
+        // range(), enumerate(), zip() and line_stream() hand out items one at a time
+        if let Some(mut items) = self.loop_items_for_call(iter_ref)? {
+            while let Some(value) = items.try_next()? {
+                if !self.run_loop_body(&var_name, &unpack, value, body_ref)? {
+                    break;
+                }
+            }
+            return Ok(Value::none());
+        }
+
         let iterable_value = self.execute_node(iter_ref)?;
 
         // Phase 19.2: Channel iteration — lazy receive loop until channel closed
         if let ValueKind::Channel(ref channel) = iterable_value.kind {
-            loop {
-                match channel.receive() {
-                    Some(sendable_val) => {
-                        let value = sendable_val.0;
-                        if self.env.exists(&var_name) {
-                            self.env.set(&var_name, value)?;
-                        } else {
-                            self.env.define(var_name.clone(), value);
-                        }
-                        match self.execute_node(body_ref) {
-                            Ok(_) => {}
-                            Err(GraphoidError::LoopControl { control }) => {
-                                match control {
-                                    crate::error::LoopControlType::Break => break,
-                                    crate::error::LoopControlType::Continue => continue,
-                                }
-                            }
-                            Err(e) => return Err(e),
-                        }
-                    }
-                    None => break, // channel closed + empty
+            while let Some(sendable_val) = channel.receive() {
+                if !self.run_loop_body(&var_name, &unpack, sendable_val.0, body_ref)? {
+                    break;
                 }
             }
             return Ok(Value::none());
@@ -1162,27 +1444,86 @@ impl GraphExecutor {
         };
 
         for value in values {
-            // Bind loop variable
-            if self.env.exists(&var_name) {
-                self.env.set(&var_name, value)?;
+            if !self.run_loop_body(&var_name, &unpack, value, body_ref)? {
+                break;
+            }
+        }
+
+        Ok(Value::none())
+    }
+
+    /// Bind the loop variable(s) to `value` and run one pass of the body.
+    /// Returns false when the body hit `break`.
+    fn run_loop_body(&mut self, var_name: &str, unpack: &[String], value: Value, body_ref: NodeRef) -> Result<bool> {
+        self.check_deadline()?;
+        let mut bindings = vec![(var_name.to_string(), value)];
+        if !unpack.is_empty() {
+            let (_, item) = bindings.pop().unwrap();
+            let parts = match &item.kind {
+                ValueKind::List(list) => list.to_vec(),
+                _ => return Err(GraphoidError::runtime(format!(
+                    "TypeError: for loop with {} variables needs list items, got {}",
+                    unpack.len() + 1, item.type_name()
+                ))),
+            };
+            if parts.len() != unpack.len() + 1 {
+                return Err(GraphoidError::runtime(format!(
+                    "ValueError: for loop expected {} values to unpack, got {}",
+                    unpack.len() + 1, parts.len()
+                )));
+            }
+            let names = std::iter::once(var_name.to_string()).chain(unpack.iter().cloned());
+            bindings = names.zip(parts).collect();
+        }
+
+        for (name, value) in bindings {
+            if self.env.exists(&name) {
+                self.env.set(&name, value)?;
             } else {
-                self.env.define(var_name.clone(), value);
+                self.env.define(name, value);
             }
+        }
 
-            // Execute body
-            match self.execute_node(body_ref) {
-                Ok(_) => {}
-                Err(GraphoidError::LoopControl { control }) => {
-                    match control {
-                        crate::error::LoopControlType::Break => break,
-                        crate::error::LoopControlType::Continue => continue,
-                    }
-                }
-                Err(e) => return Err(e),
+        match self.execute_node(body_ref) {
+            Ok(_) => Ok(true),
+            Err(GraphoidError::LoopControl { control }) => {
+                Ok(!matches!(control, crate::error::LoopControlType::Break))
             }
+            Err(e) => Err(e),
         }
+    }
 
-        Ok(Value::none())
+    /// If `iter_ref` is a call to `range`, `enumerate`, `zip` or `line_stream`
+    /// that is not shadowed by a user function, evaluate its arguments and
+    /// return a lazy item source for the loop.
+    fn loop_items_for_call(&mut self, iter_ref: NodeRef) -> Result<Option<LoopItems>> {
+        if self.get_node(iter_ref)?.node_type != AstNodeType::CallExpr {
+            return Ok(None);
+        }
+        let Some(callee_ref) = self.get_edge_target(iter_ref, &ExecEdgeType::Callee) else {
+            return Ok(None);
+        };
+        let callee = self.get_node(callee_ref)?;
+        let name = match callee.properties.get("name") {
+            Some(AstProperty::Str(name)) if callee.node_type == AstNodeType::Identifier => name.clone(),
+            _ => return Ok(None),
+        };
+        if !ITERATION_BUILTINS.contains(&name.as_str())
+            || self.env.get(&name).is_ok()
+            || self.global_functions.contains_key(&name)
+        {
+            return Ok(None);
+        }
+
+        let arg_refs = self.get_ordered_edges(iter_ref, "Argument");
+        let mut args = Vec::with_capacity(arg_refs.len());
+        for arg_ref in arg_refs {
+            if self.get_node(arg_ref)?.properties.contains_key("arg_name") {
+                return Ok(None);
+            }
+            args.push(self.execute_node(arg_ref)?);
+        }
+        LoopItems::from_call(&name, &args)
     }
 
     // --- Return statement ---
@@ -1300,6 +1641,7 @@ impl GraphExecutor {
             is_setter: false,
             is_static,
             guard: None,
+            synthetic: None,
         };
 
         // Register in function graph for tracking
@@ -1523,6 +1865,19 @@ impl GraphExecutor {
                         }
                         // No matching arity found - fall through to error or other handling
                     }
+
+                    // range(), enumerate() and zip() unless a user function shadows them.
+                    // line_stream() exists to avoid reading the whole file, so it has no list form.
+                    if func_name == "line_stream" {
+                        return Err(GraphoidError::runtime(
+                            "line_stream() can only be used as a for loop iterable \
+                             (for line in line_stream(path) { ... }); use io.read_lines(path) for a list of lines"
+                                .to_string(),
+                        ));
+                    }
+                    if let Some(items) = LoopItems::from_call(&func_name, &arg_values)? {
+                        return items.into_list();
+                    }
                 }
             }
         }
@@ -1608,7 +1963,7 @@ impl GraphExecutor {
                             "type" => { edge_type = Some(val.to_string_value()); }
                             "direction" => {
                                 if let ValueKind::Symbol(s) = &val.kind {
-                                    direction = s.clone();
+                                    direction = s.to_string();
                                 } else {
                                     return Err(GraphoidError::runtime(format!(
                                         "edge() direction must be a symbol (:outgoing, :incoming, or :both), got {}",
@@ -1660,7 +2015,7 @@ impl GraphExecutor {
                             }
                             "direction" => {
                                 if let ValueKind::Symbol(s) = &val.kind {
-                                    direction = s.clone();
+                                    direction = s.to_string();
                                 } else {
                                     return Err(GraphoidError::runtime(format!(
                                         "path() direction must be a symbol, got {}", val.type_name()
@@ -1812,16 +2167,37 @@ impl GraphExecutor {
     fn try_builtin(&mut self, name: &str, args: &[Value]) -> Result<Option<Value>> {
         match name {
             "print" => {
-                let output: Vec<String> = args.iter().map(|a| a.to_string()).collect();
-                let text = output.join(" ");
-                if self.output_capture_enabled {
-                    self.output_buffer.push_str(&text);
-                    self.output_buffer.push('\n');
-                } else {
-                    println!("{}", text);
+                let mut output: Vec<String> = Vec::with_capacity(args.len());
+                for arg in args {
+                    output.push(self.display_string(arg)?);
                 }
+                let text = output.join(" ");
+                self.write_output_line(&text);
                 Ok(Some(Value::none()))
             }
+            "can_add?" | "can_compare?" => {
+                // Whether `a + b` / `a < b` would run, by the same rules the operators use
+                if args.len() != 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "{}() expects 2 arguments, but got {}",
+                        name,
+                        args.len()
+                    )));
+                }
+                let (op, special) = if name == "can_add?" {
+                    (BinaryOp::Add, Some("__add__"))
+                } else {
+                    (BinaryOp::Less, None)
+                };
+                let overloaded = special.is_some_and(|method| match &args[0].kind {
+                    ValueKind::Graph(g) => g.borrow().has_method(method),
+                    _ => false,
+                });
+                let coercion = crate::execution::arithmetic::coerce(&op, &args[0], &args[1]);
+                Ok(Some(Value::boolean(
+                    overloaded || coercion != crate::execution::arithmetic::Coercion::Incompatible
+                )))
+            }
             "length" => {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime("length() requires exactly 1 argument".to_string()));
@@ -1838,7 +2214,7 @@ impl GraphExecutor {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime("string() requires exactly 1 argument".to_string()));
                 }
-                Ok(Some(Value::string(args[0].to_string())))
+                Ok(Some(Value::string(self.display_string(&args[0])?)))
             }
             "num" => {
                 if args.len() != 1 {
@@ -1871,8 +2247,73 @@ impl GraphExecutor {
                     _ => Err(GraphoidError::type_error("number or string", args[0].type_name())),
                 }
             }
+            // Lenient parse: anything that isn't a number comes back as none
+            "to_num" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "to_num() takes exactly 1 argument, got {}", args.len()
+                    )));
+                }
+                let parsed = match &args[0].kind {
+                    ValueKind::Number(n) => Some(*n),
+                    ValueKind::BigNumber(bn) => Some(bn.to_f64()),
+                    ValueKind::String(s) => s.trim().parse::<f64>().ok(),
+                    ValueKind::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+                    _ => None,
+                };
+                Ok(Some(parsed.map(Value::number).unwrap_or_else(Value::none)))
+            }
+            // Strict parse: truncates toward zero, raises on bad input
+            "to_int" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "to_int() takes exactly 1 argument, got {}", args.len()
+                    )));
+                }
+                let n = match &args[0].kind {
+                    ValueKind::Number(n) => *n,
+                    ValueKind::BigNumber(bn) => bn.to_f64(),
+                    ValueKind::String(s) => s.trim().parse::<f64>()
+                        .ok()
+                        .filter(|n| n.is_finite())
+                        .ok_or_else(|| GraphoidError::runtime(format!(
+                            "ValueError: cannot convert '{}' to int", s
+                        )))?,
+                    ValueKind::Boolean(b) => if *b { 1.0 } else { 0.0 },
+                    _ => return Err(GraphoidError::type_error("number, string or boolean", args[0].type_name())),
+                };
+                Ok(Some(Value::number(n.trunc())))
+            }
+            // Base-N integer parsing: parse_num("ff", 16) => 255
+            "parse_num" => {
+                if args.is_empty() || args.len() > 2 {
+                    return Err(GraphoidError::runtime(format!(
+                        "parse_num() takes 1 or 2 arguments (text, base), got {}", args.len()
+                    )));
+                }
+                let text = match &args[0].kind {
+                    ValueKind::String(s) => s.trim(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "TypeError: parse_num() expects a string, got {}", args[0].type_name()
+                    ))),
+                };
+                let base = match args.get(1).map(|arg| &arg.kind) {
+                    None => 10,
+                    Some(ValueKind::Number(b)) if b.fract() == 0.0 && (2.0..=36.0).contains(b) => *b as u32,
+                    Some(_) => return Err(GraphoidError::runtime(format!(
+                        "ValueError: parse_num() base must be an integer from 2 to 36, got {}",
+                        args[1].to_string_value()
+                    ))),
+                };
+                let n = i64::from_str_radix(text, base).map_err(|_| {
+                    GraphoidError::runtime(format!(
+                        "ValueError: cannot parse '{}' as a base-{} integer", text, base
+                    ))
+                })?;
+                Ok(Some(Value::number(n as f64)))
+            }
             // Error type constructors
-            "RuntimeError" | "ValueError" | "TypeError" | "IOError" | "NetworkError" | "ParseError" => {
+            "RuntimeError" | "ValueError" | "TypeError" | "IOError" | "NetworkError" | "ParseError" | "AssertionError" | "TimeoutError" => {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime(format!(
                         "{} constructor expects 1 argument (message), got {}",
@@ -1888,6 +2329,18 @@ impl GraphExecutor {
                 );
                 Ok(Some(Value::error(error_obj)))
             }
+            // Fatal abort: unwinds past every catch, running finally blocks
+            "abort" => {
+                if args.len() > 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "abort() expects 0-1 arguments (message), got {}", args.len()
+                    )));
+                }
+                let message = args.first()
+                    .map(|arg| arg.to_string_value())
+                    .unwrap_or_else(|| "abort() called".to_string());
+                Err(GraphoidError::Abort { message, stack_trace: self.call_stack.clone() })
+            }
             "get_errors" => {
                 if !args.is_empty() {
                     return Err(GraphoidError::runtime(format!(
@@ -1962,12 +2415,76 @@ impl GraphExecutor {
                 };
                 Ok(Some(Value::channel(crate::values::Channel::new(capacity))))
             }
+            // env(name) - value of an environment variable, or none if unset
+            // env()     - map of every environment variable
+            // Read-only: there is no way to set variables from a script.
+            // A user-defined `env` takes precedence.
+            "env" if !self.env.exists("env") && !self.global_functions.contains_key("env") => {
+                match args {
+                    [] => {
+                        let mut vars: Vec<(String, String)> = std::env::vars_os()
+                            .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+                            .collect();
+                        vars.sort();
+                        let mut map = crate::values::Hash::new();
+                        for (key, value) in vars {
+                            map.insert(key, Value::string(value))?;
+                        }
+                        Ok(Some(Value::map(map)))
+                    }
+                    [name] => match &name.kind {
+                        ValueKind::String(name) => Ok(Some(
+                            std::env::var(name).map(Value::string).unwrap_or_else(|_| Value::none())
+                        )),
+                        _ => Err(GraphoidError::runtime(format!(
+                            "TypeError: env() variable name must be a string, got {}", name.type_name()
+                        ))),
+                    },
+                    _ => Err(GraphoidError::runtime(format!(
+                        "env() is read-only and takes 0-1 arguments (variable name), got {}", args.len()
+                    ))),
+                }
+            }
+            // current_precision() - decimal places of the innermost `precision`
+            // block (0 for `precision :int`), or none outside any block
+            "current_precision" if !self.env.exists("current_precision") && !self.global_functions.contains_key("current_precision") => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "current_precision() takes no arguments, but got {}", args.len()
+                    )));
+                }
+                Ok(Some(match self.precision_stack.last() {
+                    Some(places) => Value::number(places.unwrap_or(0) as f64),
+                    None => Value::none(),
+                }))
+            }
+            // caller_location() - {file, line, column} of the statement that
+            // called the current function (of this statement at the top level)
+            "caller_location" if !self.env.exists("caller_location") && !self.global_functions.contains_key("caller_location") => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "caller_location() takes no arguments, but got {}", args.len()
+                    )));
+                }
+                Ok(Some(self.caller_location()?))
+            }
+            // args() - arguments given after `--` on the command line
+            "args" if !self.env.exists("args") && !self.global_functions.contains_key("args") => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "args() takes no arguments, but got {}", args.len()
+                    )));
+                }
+                let values = self.script_args.iter().cloned().map(Value::string).collect();
+                Ok(Some(Value::list(crate::values::List::from_vec(values))))
+            }
             _ => Ok(None), // Not a builtin
         }
     }
 
     /// Call a graph-based function (one whose body is stored as a NodeRef).
     fn call_graph_function(&mut self, func: Function, args: Vec<Value>) -> Result<Value> {
+        self.check_deadline()?;
         // If function has no graph ID or no graph body, fall back to AST-based execution
         let func_id = match func.node_id.as_ref() {
             Some(id) => id.clone(),
@@ -1975,7 +2492,7 @@ impl GraphExecutor {
                 if !func.body.is_empty() {
                     return self.call_ast_function(&func, args);
                 }
-                return Err(GraphoidError::runtime("Function has no graph ID and no AST body".to_string()));
+                return self.call_synthetic_function(&func, args);
             }
         };
         let body_ref_opt = self.graph_function_bodies.get(&func_id).copied();
@@ -1993,8 +2510,13 @@ impl GraphExecutor {
             return self.call_pattern_matching_function(&func, &args, &clauses);
         }
 
+        if Self::is_curryable_call(&func, &args) {
+            return Ok(Value::function(Self::make_partial(&func, args)));
+        }
+
         // Build call environment from captured env
         let mut call_env = (*func.env.borrow()).clone();
+        call_env.inherit_constants();
 
         // Check for too many arguments (only if no variadic param)
         let has_variadic = func.parameters.iter().any(|p| p.is_variadic);
@@ -2062,7 +2584,7 @@ impl GraphExecutor {
 
         // Swap environments
         let saved_env = std::mem::replace(&mut self.env, call_env);
-        self.function_call_depth += 1;
+        self.enter_function(&func_name, &args);
 
         // Execute body
         // Named functions: only explicit `return` produces a value
@@ -2081,7 +2603,7 @@ impl GraphExecutor {
         };
 
         // Restore environment
-        self.function_call_depth -= 1;
+        let result = self.exit_function(&func_name, result);
         self.call_stack.pop();
         // Record exception propagation edge if function exited with error
         if result.is_err() {
@@ -2100,8 +2622,14 @@ impl GraphExecutor {
 
     /// Fallback: execute an AST-bodied function (for stdlib .gr modules loaded at runtime).
     fn call_ast_function(&mut self, func: &Function, args: Vec<Value>) -> Result<Value> {
+        self.check_deadline()?;
+        if Self::is_curryable_call(func, &args) {
+            return Ok(Value::function(Self::make_partial(func, args)));
+        }
+
         // Build call environment
         let mut call_env = (*func.env.borrow()).clone();
+        call_env.inherit_constants();
 
         // Bind parameters
         let has_variadic = func.parameters.iter().any(|p| p.is_variadic);
@@ -2128,7 +2656,7 @@ impl GraphExecutor {
         // Track call
         let func_name = func.name.as_deref().unwrap_or("<anonymous>").to_string();
         self.call_stack.push(func_name.clone());
-        self.function_call_depth += 1;
+        self.enter_function(&func_name, &args);
 
         // Swap environments
         let saved_env = std::mem::replace(&mut self.env, call_env);
@@ -2141,21 +2669,394 @@ impl GraphExecutor {
         let mut converter = AstToGraphConverter::new();
         let root = converter.convert_program(&program);
         let body_graph = converter.into_graph();
-        let result = self.execute(body_graph, root);
+        let result = match self.execute(body_graph, root) {
+            Ok(val) => Ok(val),
+            Err(GraphoidError::ReturnControl { value }) => Ok(value),
+            Err(e) => Err(e),
+        };
 
         // Restore environment
-        self.function_call_depth -= 1;
+        let result = self.exit_function(&func_name, result);
         self.call_stack.pop();
         let call_env_after = std::mem::replace(&mut self.env, saved_env);
         *func.env.borrow_mut() = call_env_after;
 
-        match result {
-            Ok(val) => Ok(val),
-            Err(GraphoidError::ReturnControl { value }) => Ok(value),
-            Err(e) => Err(e),
+        result
+    }
+
+    /// Implicit currying applies only when every parameter is required, so calls
+    /// that rely on defaults or variadics keep their usual meaning. Use
+    /// `f.partial(...)` for those.
+    fn is_curryable_call(func: &Function, args: &[Value]) -> bool {
+        !args.is_empty()
+            && args.len() < func.parameters.len()
+            && func.parameters.iter().all(|p| p.default_value.is_none() && !p.is_variadic)
+    }
+
+    /// Build a function that calls `target` with `bound` prepended to its arguments.
+    /// The target keeps its own closure environment; the partial only captures the
+    /// target and the bound values.
+    fn make_partial(target: &Function, bound: Vec<Value>) -> Function {
+        let fixed = bound.len().min(target.parameters.iter().filter(|p| !p.is_variadic).count());
+        let parameters: Vec<Parameter> = target.parameters.iter().skip(fixed).cloned().collect();
+        Self::synthetic_function(target.name.clone(), parameters, SyntheticKind::Partial {
+            target: Box::new(Value::function(target.clone())),
+            bound,
+        })
+    }
+
+    /// Functions produced by the executor itself (partials, compositions, builtin
+    /// function values) have no body. Their `SyntheticKind` describes what a call
+    /// does; see `call_synthetic_function`.
+    fn synthetic_function(name: Option<String>, parameters: Vec<Parameter>, kind: SyntheticKind) -> Function {
+        Function {
+            name,
+            params: parameters.iter().map(|p| p.name.clone()).collect(),
+            parameters,
+            body: vec![],
+            pattern_clauses: None,
+            env: Rc::new(RefCell::new(Environment::new())),
+            node_id: None,
+            is_setter: false,
+            is_static: false,
+            guard: None,
+            synthetic: Some(kind),
+        }
+    }
+
+    /// Builtin functions that are first-class values (`xs.map(identity)`).
+    /// Resolved only when the name is not bound, so user definitions shadow them.
+    fn builtin_function_value(name: &str) -> Option<Value> {
+        let param = |name: &str, is_variadic: bool| Parameter {
+            name: name.to_string(),
+            default_value: None,
+            is_variadic,
+        };
+        let parameters = match name {
+            "identity" => vec![param("value", false)],
+            "constant" => vec![param("value", false)],
+            "compose" => vec![param("functions", true)],
+            "memoize" => vec![param("function", false)],
+            "bench" => vec![
+                param("name", false),
+                param("iterations", false),
+                param("block", false),
+                param("options", true),
+            ],
+            "timeout" => vec![param("seconds", false), param("block", false)],
+            _ => return None,
+        };
+        Some(Value::function(Self::synthetic_function(
+            Some(name.to_string()),
+            parameters,
+            SyntheticKind::Builtin(name.to_string()),
+        )))
+    }
+
+    /// Call a body-less function built by `synthetic_function`.
+    fn call_synthetic_function(&mut self, func: &Function, args: Vec<Value>) -> Result<Value> {
+        let builtin = match &func.synthetic {
+            Some(SyntheticKind::Builtin(name)) => name.clone(),
+            Some(SyntheticKind::Memoized { target, cache }) => {
+                if self.bypass_memo_cache {
+                    return self.call_value(target, args);
+                }
+                // Arguments that can't be map keys (functions, channels, ...)
+                // can't be cached either, so such calls always run
+                let Some(key) = Self::memo_key(&args) else {
+                    return self.call_memoized_target(func, target, args);
+                };
+                if let Some(cached) = cache.borrow().get(&key) {
+                    return Ok(cached.clone());
+                }
+                let result = self.call_memoized_target(func, target, args)?;
+                cache.borrow_mut().insert(key, result.clone());
+                return Ok(result);
+            }
+            Some(SyntheticKind::Partial { target, bound }) => {
+                let mut full_args = bound.clone();
+                full_args.extend(args);
+                return self.call_value(target, full_args);
+            }
+            Some(SyntheticKind::Composed(functions)) => {
+                // Right-to-left: the last function receives the call's arguments,
+                // each earlier one receives the previous result.
+                // Too few arguments curry the whole composition, not just the
+                // innermost function. Each outer function is then called with
+                // exactly one value; one that needs more is an arity error rather
+                // than a partial passed along the chain.
+                if Self::is_curryable_call(func, &args) {
+                    return Ok(Value::function(Self::make_partial(func, args)));
+                }
+                let mut remaining = functions.iter().rev();
+                let mut result = match remaining.next() {
+                    Some(innermost) => self.call_value(innermost, args)?,
+                    None => return Ok(args.into_iter().next().unwrap_or_else(Value::none)),
+                };
+                for f in remaining {
+                    if let ValueKind::Function(outer) = &f.kind {
+                        if Self::is_curryable_call(outer, std::slice::from_ref(&result)) {
+                            return Err(GraphoidError::runtime(format!(
+                                "Function '{}' expects {} argument(s), got 1 from the function composed after it",
+                                outer.name.as_deref().unwrap_or("<anonymous>"),
+                                outer.parameters.len()
+                            )));
+                        }
+                    }
+                    result = self.call_value(f, vec![result])?;
+                }
+                return Ok(result);
+            }
+            Some(SyntheticKind::Constant(value)) => return Ok((**value).clone()),
+            None => return Err(GraphoidError::runtime("Function has no graph ID and no AST body".to_string())),
+        };
+
+        match builtin.as_str() {
+            "identity" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "identity() expects 1 argument, but got {}", args.len()
+                    )));
+                }
+                Ok(args.into_iter().next().unwrap_or_else(Value::none))
+            }
+            "constant" => {
+                if args.len() != 1 {
+                    return Err(GraphoidError::runtime(format!(
+                        "constant() expects 1 argument, but got {}", args.len()
+                    )));
+                }
+                let value = args.into_iter().next().unwrap_or_else(Value::none);
+                Ok(Value::function(Self::synthetic_function(
+                    Some("constant".to_string()),
+                    vec![],
+                    SyntheticKind::Constant(Box::new(value)),
+                )))
+            }
+            "memoize" => {
+                let target = match args.as_slice() {
+                    [target] => target.clone(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "memoize() expects 1 argument, but got {}", args.len()
+                    ))),
+                };
+                let (name, parameters) = match &target.kind {
+                    ValueKind::Function(f) => (f.name.clone(), f.parameters.clone()),
+                    ValueKind::NativeFunction(_) => (None, vec![Parameter { name: "args".to_string(), default_value: None, is_variadic: true }]),
+                    _ => return Err(GraphoidError::type_error("function", target.type_name())),
+                };
+                // The cache and the recursion hook (`call_memoized_target`) both
+                // belong to the wrapper; the wrapped function is not changed.
+                Ok(Value::function(Self::synthetic_function(
+                    name,
+                    parameters,
+                    SyntheticKind::Memoized {
+                        target: Box::new(target),
+                        cache: Rc::new(RefCell::new(HashMap::new())),
+                    },
+                )))
+            }
+            "bench" => self.run_bench(args),
+            "timeout" => self.run_timeout(args),
+            "compose" => {
+                for f in &args {
+                    if !matches!(f.kind, ValueKind::Function(_) | ValueKind::NativeFunction(_)) {
+                        return Err(GraphoidError::type_error("function", f.type_name()));
+                    }
+                }
+                // The composition takes whatever the innermost function takes
+                let parameters = match args.last().map(|f| &f.kind) {
+                    Some(ValueKind::Function(innermost)) => innermost.parameters.clone(),
+                    _ => vec![Parameter { name: "value".to_string(), default_value: None, is_variadic: false }],
+                };
+                Ok(Value::function(Self::synthetic_function(
+                    Some("compose".to_string()),
+                    parameters,
+                    SyntheticKind::Composed(args),
+                )))
+            }
+            other => Err(GraphoidError::runtime(format!("Unknown builtin function '{}'", other))),
+        }
+    }
+
+    /// Cache key for a memoized call: the argument list's map key
+    /// (`Hash::key_id`), so memoized arguments match exactly when they would
+    /// find the same map entry. `None` when an argument can't be a map key.
+    fn memo_key(args: &[Value]) -> Option<String> {
+        use crate::values::Hash;
+        if args.iter().any(|arg| Hash::key_id(arg).is_err()) {
+            return None;
+        }
+        let args = Value::list(crate::values::List::from_vec(args.to_vec()));
+        Hash::key_id(&args).ok()
+    }
+
+    /// Call the function a memoized wrapper caches. A named function finds
+    /// itself through its captured environment, so for the length of the call
+    /// its name there refers to the wrapper: `fib = memoize(fib)` then caches
+    /// the inner `fib(n - 1)` calls too. The previous binding is restored
+    /// afterwards, so the wrapped function itself is left unchanged.
+    fn call_memoized_target(&mut self, wrapper: &Function, target: &Value, args: Vec<Value>) -> Result<Value> {
+        let (env, name) = match &target.kind {
+            ValueKind::Function(Function { name: Some(name), env, .. }) => (env.clone(), name.clone()),
+            _ => return self.call_value(target, args),
+        };
+        let previous = env.borrow().get_in_current_scope(&name);
+        env.borrow_mut().define(name.clone(), Value::function(wrapper.clone()));
+        let result = self.call_value(target, args);
+        match previous {
+            Some(value) => env.borrow_mut().define(name, value),
+            None => {
+                env.borrow_mut().remove_variable(&name);
+            }
+        }
+        result
+    }
+
+    /// Call a function value (user-defined or native) with positional arguments.
+    fn call_value(&mut self, callee: &Value, args: Vec<Value>) -> Result<Value> {
+        match &callee.kind {
+            ValueKind::Function(func) => self.call_graph_function(func.clone(), args),
+            ValueKind::NativeFunction(native_func) => native_func(&args),
+            _ => Err(GraphoidError::type_error("function", callee.type_name())),
+        }
+    }
+
+    /// `bench(name, iterations, block)`: run `block` a few times to warm up,
+    /// then time `iterations` runs. Prints a one-line report and returns a map
+    /// of timings in milliseconds. memoize() caches are bypassed while timing
+    /// unless `:cached` is passed.
+    fn run_bench(&mut self, args: Vec<Value>) -> Result<Value> {
+        const WARMUP_RUNS: usize = 3;
+
+        let (name, iterations, block, options) = match args.as_slice() {
+            [name, iterations, block, options @ ..] => (name, iterations, block, options),
+            _ => return Err(GraphoidError::runtime(format!(
+                "bench() expects a name, an iteration count and a block, but got {} arguments", args.len()
+            ))),
+        };
+        let name = match &name.kind {
+            ValueKind::String(s) => s.clone(),
+            _ => return Err(GraphoidError::runtime(format!(
+                "TypeError: bench() name must be a string, got {}", name.type_name()
+            ))),
+        };
+        let iterations = match iterations.kind {
+            ValueKind::Number(n) if n >= 1.0 && n.fract() == 0.0 => n as usize,
+            ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                "ValueError: bench() iterations must be a positive whole number, got {}", n
+            ))),
+            _ => return Err(GraphoidError::runtime(format!(
+                "TypeError: bench() iterations must be a number, got {}", iterations.type_name()
+            ))),
+        };
+        if !matches!(block.kind, ValueKind::Function(_) | ValueKind::NativeFunction(_)) {
+            return Err(GraphoidError::runtime(format!(
+                "TypeError: bench() block must be a function, got {}", block.type_name()
+            )));
+        }
+        let mut keep_caches = false;
+        for option in options {
+            match &option.kind {
+                ValueKind::Symbol(s) if s == "cached" => keep_caches = true,
+                _ => return Err(GraphoidError::runtime(format!(
+                    "ValueError: bench() option must be :cached, got {}", option.to_string_value()
+                ))),
+            }
+        }
+
+        let saved_bypass = self.bypass_memo_cache;
+        self.bypass_memo_cache = !keep_caches;
+        let timings: Result<Vec<f64>> = (|| {
+            for _ in 0..WARMUP_RUNS.min(iterations) {
+                self.call_value(block, vec![])?;
+            }
+            let mut timings = Vec::with_capacity(iterations);
+            for _ in 0..iterations {
+                let started = Instant::now();
+                self.call_value(block, vec![])?;
+                timings.push(started.elapsed().as_secs_f64() * 1000.0);
+            }
+            Ok(timings)
+        })();
+        self.bypass_memo_cache = saved_bypass;
+        let timings = timings?;
+
+        let total: f64 = timings.iter().sum();
+        let mean = total / iterations as f64;
+        let min = timings.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = timings.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        self.write_output_line(&format!(
+            "bench {}: {} iterations, mean {:.3} ms (min {:.3} ms, max {:.3} ms, total {:.3} ms)",
+            name, iterations, mean, min, max, total
+        ));
+
+        let mut report = crate::values::Hash::new();
+        report.insert("name".to_string(), Value::string(name))?;
+        report.insert("iterations".to_string(), Value::number(iterations as f64))?;
+        report.insert("total_ms".to_string(), Value::number(total))?;
+        report.insert("mean_ms".to_string(), Value::number(mean))?;
+        report.insert("min_ms".to_string(), Value::number(min))?;
+        report.insert("max_ms".to_string(), Value::number(max))?;
+        Ok(Value::map(report))
+    }
+
+    /// `timeout(seconds, block)`: run `block`, raising a `TimeoutError` if it
+    /// takes longer than `seconds`. The interpreter cannot be preempted, so the
+    /// deadline is checked at loop iterations and function calls, and once more
+    /// when the block returns. Nested timeouts honor the tightest deadline.
+    fn run_timeout(&mut self, args: Vec<Value>) -> Result<Value> {
+        let (seconds, block) = match args.as_slice() {
+            [seconds, block] => (seconds, block),
+            _ => return Err(GraphoidError::runtime(format!(
+                "timeout() expects a duration in seconds and a block, but got {} arguments", args.len()
+            ))),
+        };
+        let seconds = match seconds.kind {
+            ValueKind::Number(n) if n > 0.0 && n.is_finite() => n,
+            ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                "ValueError: timeout() seconds must be a positive number, got {}", n
+            ))),
+            _ => return Err(GraphoidError::runtime(format!(
+                "TypeError: timeout() seconds must be a number, got {}", seconds.type_name()
+            ))),
+        };
+        if !matches!(block.kind, ValueKind::Function(_) | ValueKind::NativeFunction(_)) {
+            return Err(GraphoidError::runtime(format!(
+                "TypeError: timeout() block must be a function, got {}", block.type_name()
+            )));
+        }
+
+        let deadline = Instant::now() + std::time::Duration::from_secs_f64(seconds);
+        let entry = match self.deadlines.last() {
+            Some(&(outer, outer_seconds)) if outer <= deadline => (outer, outer_seconds),
+            _ => (deadline, seconds),
+        };
+        self.deadlines.push(entry);
+        let result = self.call_value(block, vec![]);
+        self.deadlines.pop();
+        let value = result?;
+
+        // A block can overrun without reaching a check point (one long native call)
+        if Instant::now() >= entry.0 {
+            return Err(Self::timeout_error(entry.1));
+        }
+        Ok(value)
+    }
+
+    /// Fail with a `TimeoutError` once the tightest enclosing `timeout()`
+    /// deadline has passed.
+    fn check_deadline(&self) -> Result<()> {
+        match self.deadlines.last() {
+            Some(&(deadline, seconds)) if Instant::now() >= deadline => Err(Self::timeout_error(seconds)),
+            _ => Ok(()),
         }
     }
 
+    fn timeout_error(seconds: f64) -> GraphoidError {
+        GraphoidError::runtime(format!("TimeoutError: block exceeded timeout of {} seconds", seconds))
+    }
+
     /// Execute a pattern-matching function by matching args against clauses.
     fn call_pattern_matching_function(
         &mut self,
@@ -2173,6 +3074,7 @@ impl GraphExecutor {
 
         // Set up call environment with parameter bound
         let mut call_env = (*func.env.borrow()).clone();
+        call_env.inherit_constants();
         if let Some(param) = func.parameters.first() {
             call_env.define(param.name.clone(), arg.clone());
         }
@@ -2258,7 +3160,8 @@ impl GraphExecutor {
             }
 
             let saved_env = std::mem::replace(&mut self.env, body_env);
-            self.function_call_depth += 1;
+            let func_name = func.name.as_deref().unwrap_or("<anonymous>");
+            self.enter_function(func_name, args);
 
             let result = match self.execute_node(clause.body_ref) {
                 Ok(val) => Ok(val),
@@ -2266,7 +3169,7 @@ impl GraphExecutor {
                 Err(e) => Err(e),
             };
 
-            self.function_call_depth -= 1;
+            let result = self.exit_function(func_name, result);
             let body_env_after = std::mem::replace(&mut self.env, saved_env);
             *func.env.borrow_mut() = body_env_after;
 
@@ -2280,6 +3183,7 @@ impl GraphExecutor {
     /// Call a graph-based function with named arguments.
     /// Named args are reordered to match parameter positions; supports variadic and defaults.
     fn call_graph_function_named(&mut self, func: Function, args: Vec<Value>, arg_names: Vec<Option<String>>) -> Result<Value> {
+        self.check_deadline()?;
         let func_id = func.node_id.as_ref()
             .ok_or_else(|| GraphoidError::runtime("Function has no graph ID".to_string()))?;
         let body_ref = *self.graph_function_bodies.get(func_id)
@@ -2338,6 +3242,7 @@ impl GraphExecutor {
 
         // Build call environment
         let mut call_env = (*func.env.borrow()).clone();
+        call_env.inherit_constants();
 
         // Bind non-variadic params (with defaults for unfilled slots)
         let mut nv_idx = 0;
@@ -2363,7 +3268,8 @@ impl GraphExecutor {
 
         // Swap environments and execute (same as call_graph_function)
         let saved_env = std::mem::replace(&mut self.env, call_env);
-        self.function_call_depth += 1;
+        let func_name = func.name.as_deref().unwrap_or("<anonymous>");
+        self.enter_function(func_name, &args);
 
         let is_lambda = func.name.is_none();
         let result = match self.execute_node(body_ref) {
@@ -2378,7 +3284,7 @@ impl GraphExecutor {
             Err(e) => Err(e),
         };
 
-        self.function_call_depth -= 1;
+        let result = self.exit_function(func_name, result);
         let call_env_after = std::mem::replace(&mut self.env, saved_env);
         *func.env.borrow_mut() = call_env_after;
 
@@ -2417,6 +3323,8 @@ impl GraphExecutor {
         let func_id = format!("__graph_lambda_{}", self.next_func_id);
         self.next_func_id += 1;
         self.graph_function_bodies.insert(func_id.clone(), body_ref);
+        // The lambda node keeps the AST body; see `function_ast_body`
+        self.graph_function_bodies.insert(format!("{}__lambda", func_id), node_ref);
 
         // Capture current environment
         let env = Rc::new(RefCell::new(self.env.clone()));
@@ -2432,11 +3340,27 @@ impl GraphExecutor {
             is_setter: false,
             is_static: false,
             guard: None,
+            synthetic: None,
         };
 
         Ok(Value::function(func))
     }
 
+    /// The AST body of a function, for running it on another thread.
+    /// Lambdas run from the execution graph and carry no AST body, so
+    /// theirs is read from the lambda node only when it is asked for.
+    pub(crate) fn function_ast_body(&self, func: &Function) -> Option<Vec<Stmt>> {
+        if !func.body.is_empty() {
+            return Some(func.body.clone());
+        }
+        let lambda_ref = func.node_id.as_ref()
+            .and_then(|id| self.graph_function_bodies.get(&format!("{}__lambda", id)))?;
+        match self.get_property(*lambda_ref, "body_stmts")? {
+            AstProperty::Stmts(stmts) => Some(stmts),
+            _ => None,
+        }
+    }
+
     // --- Method call ---
 
     fn exec_method_call(&mut self, node_ref: NodeRef) -> Result<Value> {
@@ -2459,12 +3383,13 @@ impl GraphExecutor {
             Expr::Literal { value: crate::ast::LiteralValue::None, position: SourcePosition::unknown() }
         };
 
-        // Check for static method calls on built-in type identifiers (time, list, string)
+        // Check for static method calls on built-in type identifiers (time, list, string, graph)
         if let Some(ref name) = obj_var_name {
             let static_dispatch = match name.as_str() {
                 "time" if !self.env.exists("time") => Some("time"),
                 "list" => Some("list"),
                 "string" => Some("string"),
+                "graph" => Some("graph"),
                 "reflect" if !self.env.exists("reflect") => Some("reflect"),
                 "runtime" if !self.env.exists("runtime") => Some("runtime"),
                 "modules" if !self.env.exists("modules") => Some("modules"),
@@ -2490,6 +3415,7 @@ impl GraphExecutor {
                     "time" => self.eval_time_static_method(&method_name, &args),
                     "list" => self.eval_list_static_method(&method_name, &args),
                     "string" => self.eval_string_static_method(&method_name, &args),
+                    "graph" => self.eval_graph_static_method(&method_name, &args),
                     "reflect" => self.eval_reflect_static_method(&method_name, &args),
                     "runtime" => self.eval_runtime_static_method(&method_name, &args),
                     "modules" => self.eval_modules_static_method(&method_name, &args),
@@ -2559,7 +3485,34 @@ impl GraphExecutor {
                 }
             }
 
+            // remove_at! returns the removed element, like pop!
+            if base_method == "remove_at" && matches!(object.kind, ValueKind::List(_)) {
+                let removed = self.dispatch_method_inner(object.clone(), "remove_at", args.clone(), object_expr)?;
+                let remaining = self.dispatch_method_inner(object, "without_at", args, object_expr)?;
+                self.set_variable_or_self_property(&var_name, remaining)?;
+                return Ok(removed);
+            }
+
+            // get_or_insert!/get_or_insert_with! return the value, not the hash
+            if matches!(base_method, "get_or_insert" | "get_or_insert_with")
+                && matches!(object.kind, ValueKind::Map(_))
+            {
+                let (value, updated) = self.eval_map_get_or_insert(&object, base_method, &args)?;
+                if let Some(updated) = updated {
+                    self.set_variable_or_self_property(&var_name, Value::map(updated))?;
+                }
+                return Ok(value);
+            }
+
+            // Collection mutators hand back the updated receiver so calls can
+            // be chained; other `!` forms (strings, freeze!) return none
+            let returns_receiver = base_method != "freeze"
+                && matches!(object.kind, ValueKind::List(_) | ValueKind::Map(_) | ValueKind::Graph(_));
             let result = self.dispatch_method_inner(object, base_method, args, object_expr)?;
+            if returns_receiver {
+                self.set_variable_or_self_property(&var_name, result.clone())?;
+                return Ok(result);
+            }
             self.set_variable_or_self_property(&var_name, result)?;
             return Ok(Value::none());
         }
@@ -2568,6 +3521,13 @@ impl GraphExecutor {
     }
 
     fn dispatch_method_inner(&mut self, object: Value, method: &str, args: Vec<Value>, object_expr: &Expr) -> Result<Value> {
+        // A graph's `__to_string__` takes precedence over the universal to_string()
+        if method == "to_string" && args.is_empty() {
+            if let Some(text) = self.try_operator_method(&object, "__to_string__", &[])? {
+                return Ok(Value::string(text.to_string_value()));
+            }
+        }
+
         // Universal methods that work on all types (skip for modules — module members take priority)
         if !matches!(&object.kind, ValueKind::Module(_)) {
             if let Some(result) = self.try_universal_method(&object, method, &args)? {
@@ -2599,6 +3559,7 @@ impl GraphExecutor {
             }
             ValueKind::Number(_) => self.dispatch_number_method(&object, method, &args),
             ValueKind::Error(ref err) => self.eval_error_method(err, method, &args),
+            ValueKind::Function(ref func) => self.eval_function_method(func, method, &args),
             ValueKind::Time(timestamp) => self.eval_time_method(*timestamp, method, &args),
             ValueKind::Channel(ref ch) => self.eval_channel_method(ch, method, &args),
             ValueKind::Actor(ref actor_ref) => self.eval_actor_method(actor_ref, method, &args),
@@ -2609,6 +3570,7 @@ impl GraphExecutor {
             ValueKind::PatternNode(ref pn) => self.eval_pattern_node_method(pn, method, &args),
             ValueKind::PatternEdge(ref pe) => self.eval_pattern_edge_method(pe, method, &args),
             ValueKind::PatternPath(ref pp) => self.eval_pattern_path_method(pp, method, &args),
+            ValueKind::Node(ref handle) => self.eval_node_handle_method(handle, method, &args),
             ValueKind::PatternMatchResults(ref results) => {
                 let results_clone = results.clone();
                 self.eval_pattern_match_results_method(&results_clone, method, &args)
@@ -2687,7 +3649,36 @@ impl GraphExecutor {
                 }
             }
             _ => Err(GraphoidError::runtime(format!(
-                "Cannot call method '{}' on type '{}'", method, object.type_name()
+                "Cannot call method '{}' on type '{}'", method, object.type_name()
+            ))),
+        }
+    }
+
+    /// Function value methods: partial(), arity().
+    fn eval_function_method(&self, func: &Function, method: &str, args: &[Value]) -> Result<Value> {
+        match method {
+            "arity" => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'arity' takes no arguments, but got {}", args.len()
+                    )));
+                }
+                Ok(Value::number(func.parameters.len() as f64))
+            }
+            "partial" => {
+                let has_variadic = func.parameters.iter().any(|p| p.is_variadic);
+                if !has_variadic && args.len() > func.parameters.len() {
+                    return Err(GraphoidError::runtime(format!(
+                        "partial() got {} argument(s) but '{}' takes {}",
+                        args.len(),
+                        func.name.as_deref().unwrap_or("<anonymous>"),
+                        func.parameters.len()
+                    )));
+                }
+                Ok(Value::function(Self::make_partial(func, args.to_vec())))
+            }
+            _ => Err(GraphoidError::runtime(format!(
+                "Function does not have method '{}'", method
             ))),
         }
     }
@@ -2767,6 +3758,24 @@ impl GraphExecutor {
         }
     }
 
+    /// Error properties (`e.type`, `e.message`, ...), read straight from the
+    /// ErrorObject fields. Unlike the `stack_trace()` method, which formats a
+    /// printable trace, the `stack_trace` property is the raw list of frames.
+    fn eval_error_property(&self, err: &crate::values::ErrorObject, property: &str) -> Result<Value> {
+        match property {
+            "type" | "message" | "file" | "line" | "column" | "cause" => {
+                self.eval_error_method(err, property, &[])
+            }
+            "stack_trace" => {
+                let frames = err.stack_trace.iter().map(|frame| Value::string(frame.clone())).collect();
+                Ok(Value::list(crate::values::List::from_vec(frames)))
+            }
+            _ => Err(GraphoidError::runtime(format!(
+                "Error does not have property '{}'", property
+            ))),
+        }
+    }
+
     /// Time instance methods
     fn eval_time_method(&self, timestamp: f64, method: &str, args: &[Value]) -> Result<Value> {
         use chrono::{Utc, TimeZone, Datelike, Timelike};
@@ -4181,6 +5190,86 @@ impl GraphExecutor {
         }
     }
 
+    /// The graph a node handle refers to now: the graph held by the variable
+    /// the handle was taken from, or the graph it was taken from when that
+    /// variable is not in scope.
+    fn node_handle_graph(&self, handle: &crate::values::NodeHandle) -> Rc<RefCell<crate::values::Graph>> {
+        if let Some(name) = &handle.binding {
+            if let Ok(Value { kind: ValueKind::Graph(graph), .. }) = self.env.get(name) {
+                return graph;
+            }
+        }
+        handle.graph.clone()
+    }
+
+    fn eval_node_handle_method(&self, handle: &crate::values::NodeHandle, method: &str, args: &[Value]) -> Result<Value> {
+        let expect_args = |count: usize| {
+            if args.len() == count {
+                Ok(())
+            } else {
+                Err(GraphoidError::runtime(format!(
+                    "node.{}() expects {} argument(s), but got {}", method, count, args.len()
+                )))
+            }
+        };
+        let missing = || GraphoidError::runtime(format!(
+            "ValueError: node '{}' is no longer in the graph", handle.id
+        ));
+        let shared = self.node_handle_graph(handle);
+        let handle_to = |id: String| Value::node_handle(shared.clone(), id, handle.binding.clone());
+        match method {
+            "id" => {
+                expect_args(0)?;
+                Ok(Value::string(handle.id.clone()))
+            }
+            "value" => {
+                expect_args(0)?;
+                shared.borrow().get_node(&handle.id).cloned().ok_or_else(missing)
+            }
+            "property" => {
+                expect_args(1)?;
+                let key = match &args[0].kind {
+                    ValueKind::String(s) => s,
+                    _ => return Err(GraphoidError::type_error("string", args[0].type_name())),
+                };
+                let graph = shared.borrow();
+                let node = graph.nodes.get(&handle.id).ok_or_else(missing)?;
+                Ok(crate::values::Graph::node_property(node, key).cloned().unwrap_or_else(Value::none))
+            }
+            "set_property" => {
+                // Returns the handle so calls can be chained
+                expect_args(2)?;
+                let key = match &args[0].kind {
+                    ValueKind::String(s) => s.clone(),
+                    _ => return Err(GraphoidError::type_error("string", args[0].type_name())),
+                };
+                let mut graph = shared.borrow_mut();
+                if graph.is_frozen() {
+                    return Err(GraphoidError::runtime("Cannot modify frozen graph".to_string()));
+                }
+                let mut properties = graph.nodes.get(&handle.id).ok_or_else(missing)?.properties.clone();
+                properties.insert(key, args[1].clone());
+                graph.set_node_properties(&handle.id, properties)?;
+                Ok(handle_to(handle.id.clone()))
+            }
+            "neighbors" => {
+                // Handles for the node's successors, in the order the edges were added
+                expect_args(0)?;
+                let graph = shared.borrow();
+                if !graph.has_node(&handle.id) {
+                    return Err(missing());
+                }
+                let neighbors: Vec<Value> = graph.neighbors(&handle.id)
+                    .into_iter()
+                    .filter(|id| !id.starts_with("__"))
+                    .map(&handle_to)
+                    .collect();
+                Ok(Value::list(crate::values::List::from_vec(neighbors)))
+            }
+            _ => Err(GraphoidError::runtime(format!("node does not have method '{}'", method))),
+        }
+    }
+
     fn eval_pattern_match_results_method(&mut self, results: &crate::values::PatternMatchResults, method: &str, args: &[Value]) -> Result<Value> {
         match method {
             "len" | "count" | "size" => {
@@ -4191,10 +5280,27 @@ impl GraphExecutor {
                 }
                 Ok(Value::number(results.len() as f64))
             }
+            "where" if args.len() == 3 => {
+                // where("var", :property, value) - property equality, uses the graph's index if any
+                let variable = match &args[0].kind {
+                    ValueKind::String(s) => s.clone(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "PatternMatchResults.where() expects a variable name string, got {}", args[0].type_name()
+                    ))),
+                };
+                let property = match &args[1].kind {
+                    ValueKind::Symbol(s) => s.to_string(),
+                    ValueKind::String(s) => s.clone(),
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "PatternMatchResults.where() expects a symbol or string property, got {}", args[1].type_name()
+                    ))),
+                };
+                Ok(Value::pattern_match_results(results.where_node_property_equals(&variable, &property, &args[2])))
+            }
             "where" => {
                 if args.len() != 1 {
                     return Err(GraphoidError::runtime(format!(
-                        "PatternMatchResults.where() expects 1 argument (predicate function), got {}", args.len()
+                        "PatternMatchResults.where() expects 1 argument (predicate function) or 3 (variable, property, value), got {}", args.len()
                     )));
                 }
                 let func = match &args[0].kind {
@@ -4275,16 +5381,99 @@ impl GraphExecutor {
         }
     }
 
+    /// Public method names `value` can be called with: methods a graph defines
+    /// (including inherited and static ones), built-ins for its type and the
+    /// universal methods. Private `_name` methods are left out.
+    fn callable_method_names(value: &Value) -> Vec<String> {
+        use crate::execution::methods::{builtin_method_names, UNIVERSAL_METHODS};
+
+        let mut names: Vec<String> = Vec::new();
+        if let ValueKind::Graph(ref g) = value.kind {
+            let graph = g.borrow();
+            let mut current = Some(&*graph);
+            while let Some(level) = current {
+                for name in level.method_names() {
+                    let name = name.strip_prefix("__static__").map(str::to_string).unwrap_or(name);
+                    if !name.starts_with('_') {
+                        names.push(name);
+                    }
+                }
+                current = level.parent.as_deref();
+            }
+        }
+        names.extend(builtin_method_names(value).iter().map(|name| name.to_string()));
+        names.extend(UNIVERSAL_METHODS.iter().map(|name| name.to_string()));
+        let mut seen = HashSet::new();
+        names.retain(|name| seen.insert(name.clone()));
+        names
+    }
+
     /// Universal methods that work on any type (to_string, to_num, to_bool, etc.)
-    fn try_universal_method(&self, value: &Value, method: &str, args: &[Value]) -> Result<Option<Value>> {
+    fn try_universal_method(&mut self, value: &Value, method: &str, args: &[Value]) -> Result<Option<Value>> {
         match method {
+            "respond_to?" | "methods" => {
+                // A graph's own method of the same name wins
+                if let ValueKind::Graph(ref g) = value.kind {
+                    if g.borrow().has_method(method) {
+                        return Ok(None);
+                    }
+                }
+                let mut names = Self::callable_method_names(value);
+                if method == "methods" {
+                    if !args.is_empty() {
+                        return Err(GraphoidError::runtime(format!(
+                            "Method 'methods' takes no arguments, but got {}", args.len()
+                        )));
+                    }
+                    names.sort();
+                    let symbols = names.into_iter().map(Value::symbol).collect();
+                    return Ok(Some(Value::list(crate::values::List::from_vec(symbols))));
+                }
+                let name = match args {
+                    [arg] => match &arg.kind {
+                        ValueKind::String(name) => name.as_str(),
+                        ValueKind::Symbol(name) => name.as_str(),
+                        _ => return Err(GraphoidError::type_error("method name string or symbol", arg.type_name())),
+                    },
+                    _ => return Err(GraphoidError::runtime(format!(
+                        "Method 'respond_to?' expects 1 argument (method name), but got {}", args.len()
+                    ))),
+                };
+                // `sort!` and friends are the mutating form of a built-in
+                let base = name.strip_suffix('!').unwrap_or(name);
+                Ok(Some(Value::boolean(names.iter().any(|known| known == name || known == base))))
+            }
+            "empty?" | "present?" => {
+                // Shared by every container type; anything else is a type error
+                if let ValueKind::Graph(ref g) = value.kind {
+                    if g.borrow().has_method(method) {
+                        return Ok(None);
+                    }
+                }
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method '{}' takes no arguments, but got {}", method, args.len()
+                    )));
+                }
+                let empty = value.is_empty_container()
+                    .ok_or_else(|| GraphoidError::type_error("string, list, map, or graph", value.type_name()))?;
+                Ok(Some(Value::boolean(if method == "empty?" { empty } else { !empty })))
+            }
             "to_string" => {
                 if !args.is_empty() {
                     return Err(GraphoidError::runtime(format!(
                         "Method 'to_string' takes no arguments, but got {}", args.len()
                     )));
                 }
-                Ok(Some(self.value_to_string_impl(value)))
+                Ok(Some(self.value_to_string_impl(value)?))
+            }
+            "hash_code" => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'hash_code' takes no arguments, but got {}", args.len()
+                    )));
+                }
+                Ok(Some(Value::number(value.hash_code())))
             }
             "to_num" => {
                 if !args.is_empty() {
@@ -4318,11 +5507,24 @@ impl GraphExecutor {
                 }
                 Ok(Some(Value::boolean(value.is_truthy())))
             }
-            "freeze" => {
+            "inspect" => {
+                if !args.is_empty() {
+                    return Err(GraphoidError::runtime(format!(
+                        "Method 'inspect' takes no arguments, but got {}", args.len()
+                    )));
+                }
+                Ok(Some(Value::string(value.inspect())))
+            }
+            "freeze" | "freeze_deep" => {
                 let mut frozen_copy = value.clone();
                 frozen_copy.freeze();
                 Ok(Some(frozen_copy))
             }
+            "freeze_shallow" => {
+                let mut frozen_copy = value.clone();
+                frozen_copy.freeze_shallow();
+                Ok(Some(frozen_copy))
+            }
             "is_frozen" => {
                 Ok(Some(Value::boolean(value.is_frozen())))
             }
@@ -4410,34 +5612,45 @@ impl GraphExecutor {
     }
 
     /// Convert a value to its string representation.
-    fn value_to_string_impl(&self, value: &Value) -> Value {
-        match &value.kind {
+    fn value_to_string_impl(&mut self, value: &Value) -> Result<Value> {
+        Ok(match &value.kind {
             ValueKind::String(s) => Value::string(s.clone()),
-            ValueKind::Number(n) => Value::string(n.to_string()),
+            ValueKind::Number(_) => Value::string(value.to_string_value()),
             ValueKind::Boolean(b) => Value::string(if *b { "true".to_string() } else { "false".to_string() }),
             ValueKind::None => Value::string(String::new()),
+            // The bare name, so to_symbol() round-trips
+            ValueKind::Symbol(name) => Value::string(name.as_str().to_string()),
             ValueKind::List(list) => {
                 let items = list.to_vec();
-                let elements: Vec<String> = items.iter().map(|v| match &v.kind {
-                    ValueKind::String(s) => format!("\"{}\"", s),
-                    ValueKind::Number(n) => {
-                        if n.fract() == 0.0 { format!("{:.0}", n) } else { n.to_string() }
-                    }
-                    ValueKind::Boolean(b) => b.to_string(),
-                    ValueKind::None => "none".to_string(),
-                    _ => v.type_name().to_string(),
-                }).collect();
+                let mut elements: Vec<String> = Vec::with_capacity(items.len());
+                for v in items.iter() {
+                    elements.push(match &v.kind {
+                        ValueKind::String(s) => format!("\"{}\"", s),
+                        ValueKind::Number(_) => v.to_string_value(),
+                        ValueKind::Boolean(b) => b.to_string(),
+                        ValueKind::None => "none".to_string(),
+                        _ => match self.try_operator_method(v, "__to_string__", &[])? {
+                            Some(text) => text.to_string_value(),
+                            None => v.type_name().to_string(),
+                        },
+                    });
+                }
                 Value::string(format!("[{}]", elements.join(", ")))
             }
             ValueKind::Map(m) => {
-                let entries: Vec<String> = m.keys().iter().map(|k| {
-                    let v = m.get(k).map(|v| v.to_string()).unwrap_or_default();
-                    format!("\"{}\": {}", k, v)
-                }).collect();
+                let mut entries: Vec<String> = Vec::with_capacity(m.len());
+                for k in m.keys() {
+                    let v = match m.get(&k) {
+                        Some(v @ Value { kind: ValueKind::Graph(_), .. }) => self.display_string(v)?,
+                        Some(v) => v.to_string(),
+                        None => String::new(),
+                    };
+                    entries.push(format!("{}: {}", m.display_key(&k), v));
+                }
                 Value::string(format!("{{{}}}", entries.join(", ")))
             }
             _ => Value::string(value.to_string()),
-        }
+        })
     }
 
     fn dispatch_number_method(&self, num: &Value, method: &str, args: &[Value]) -> Result<Value> {
@@ -4553,6 +5766,10 @@ impl GraphExecutor {
         let object = self.execute_node(obj_ref)?;
         let index = self.execute_node(idx_ref)?;
 
+        if let Some(result) = self.try_operator_method(&object, "__index__", std::slice::from_ref(&index))? {
+            return Ok(result);
+        }
+
         match (&object.kind, &index.kind) {
             (ValueKind::List(items), ValueKind::Number(n)) => {
                 let idx = *n as i64;
@@ -4578,8 +5795,9 @@ impl GraphExecutor {
                     }
                 }
             }
-            (ValueKind::Map(m), ValueKind::String(key)) => {
-                match m.get(key).cloned() {
+            (ValueKind::Map(m), _) => {
+                let key = index.to_string_value();
+                match m.get(&crate::values::Hash::key_id(&index)?).cloned() {
                     Some(v) => Ok(v),
                     None => {
                         use crate::execution::ErrorMode;
@@ -4663,6 +5881,10 @@ impl GraphExecutor {
             ValueKind::PatternPath(ref pp) => {
                 self.eval_pattern_path_method(pp, &property, &[])
             }
+            ValueKind::Node(ref handle) => {
+                self.eval_node_handle_method(handle, &property, &[])
+            }
+            ValueKind::Error(ref err) => self.eval_error_property(err, &property),
             _ => Err(GraphoidError::runtime(format!(
                 "Cannot access property '{}' on type '{}'", property, object.type_name()
             ))),
@@ -4681,9 +5903,11 @@ impl GraphExecutor {
         let result = match self.execute_node(body_ref) {
             Ok(val) => Ok(val),
             Err(e) => {
-                // Check for control flow errors that should not be caught
+                // Check for control flow errors (and fatal aborts) that should not be caught
                 match &e {
-                    GraphoidError::LoopControl { .. } | GraphoidError::ReturnControl { .. } => {
+                    GraphoidError::LoopControl { .. }
+                    | GraphoidError::ReturnControl { .. }
+                    | GraphoidError::Abort { .. } => {
                         // Run finally if present, then re-propagate
                         if let Some(fin_ref) = finally_ref {
                             let _ = self.execute_node(fin_ref);
@@ -4695,30 +5919,7 @@ impl GraphExecutor {
 
                 // Extract the actual error type from the error message
                 // User-raised errors are wrapped like: "Runtime error: ValueError: message"
-                let error_message = e.to_string();
-                let prefixes = [
-                    "Runtime error: ", "Type error: ", "Syntax error: ",
-                    "IO error: ", "Rule violation: ", "Module not found: ",
-                    "Circular dependency: ", "Configuration error: ",
-                ];
-                let mut inner_message = error_message.as_str();
-                for prefix in &prefixes {
-                    if let Some(stripped) = inner_message.strip_prefix(prefix) {
-                        inner_message = stripped;
-                        break;
-                    }
-                }
-
-                let (error_type_name, actual_message) = if let Some(colon_pos) = inner_message.find(':') {
-                    let potential_type = &inner_message[..colon_pos];
-                    if matches!(potential_type, "ValueError" | "TypeError" | "IOError" | "NetworkError" | "ParseError" | "RuntimeError" | "FileError" | "NetError") {
-                        (potential_type.to_string(), inner_message[(colon_pos + 1)..].trim().to_string())
-                    } else {
-                        (e.error_type(), inner_message.to_string())
-                    }
-                } else {
-                    (e.error_type(), inner_message.to_string())
-                };
+                let (error_type_name, actual_message) = e.type_and_message();
 
                 // Try each catch handler
                 let mut caught = false;
@@ -4749,15 +5950,23 @@ impl GraphExecutor {
                         // Phase 18.7: Consume raise-time stack snapshot (clear even if no variable binding)
                         let stack = self.raise_stack.take()
                             .unwrap_or_else(|| self.call_stack.clone());
+                        let raised_at = self.raise_position.take();
+                        let cause = self.raise_cause.take();
 
                         // Bind error to variable if specified
                         if let Some(var_name) = variable {
-                            let error_obj = crate::values::ErrorObject::with_stack_trace(
+                            let (line, column) = raised_at.as_ref()
+                                .map(|p| (p.line, p.column))
+                                .unwrap_or((0, 0));
+                            let mut error_obj = crate::values::ErrorObject::with_stack_trace(
                                 error_type_name.clone(),
                                 actual_message.clone(),
                                 self.current_file.as_ref().map(|p| p.to_string_lossy().to_string()),
-                                0, 0, stack,
+                                line, column, stack,
                             );
+                            if let Some(cause) = cause {
+                                error_obj = error_obj.with_cause(cause);
+                            }
                             self.env.define(var_name, Value::error(error_obj));
                         }
                         if let Some(body_ref) = catch_body_ref {
@@ -4791,6 +6000,22 @@ impl GraphExecutor {
         result
     }
 
+    // --- Defer ---
+
+    /// Register the block to run when the enclosing function or top-level
+    /// scope exits. Nothing runs now, but the variables in scope are
+    /// snapshotted so the block later reads them as they are at this point
+    /// (a loop variable, say, rather than its last value).
+    fn exec_defer(&mut self, node_ref: NodeRef) -> Result<Value> {
+        let body_ref = self.get_edge_target(node_ref, &ExecEdgeType::Body)
+            .ok_or_else(|| GraphoidError::runtime("Missing defer body".to_string()))?;
+        let bindings = self.env.get_all_bindings_recursive();
+        if let Some(frame) = self.defer_frames.last_mut() {
+            frame.push(DeferredBlock { body_ref, bindings });
+        }
+        Ok(Value::none())
+    }
+
     // --- Raise ---
 
     fn exec_raise(&mut self, node_ref: NodeRef) -> Result<Value> {
@@ -4812,6 +6037,11 @@ impl GraphExecutor {
 
         // Phase 18.7: Capture call stack at raise time for error.stack()
         self.raise_stack = Some(self.call_stack.clone());
+        self.raise_position = Some(position.clone());
+        self.raise_cause = match &value.kind {
+            ValueKind::Error(err_obj) => err_obj.cause.as_deref().cloned(),
+            _ => None,
+        };
 
         let graphoid_error = GraphoidError::runtime(message);
 
@@ -4828,6 +6058,37 @@ impl GraphExecutor {
         }
     }
 
+    // --- Assert ---
+
+    fn exec_assert(&mut self, node_ref: NodeRef) -> Result<Value> {
+        // Disabled assertions skip the condition entirely, side effects included
+        if !self.config_stack.current().assertions {
+            return Ok(Value::none());
+        }
+
+        let cond_ref = self.get_edge_target(node_ref, &ExecEdgeType::Condition)
+            .ok_or_else(|| GraphoidError::runtime("Missing assert condition".to_string()))?;
+
+        if self.execute_node(cond_ref)?.is_truthy() {
+            return Ok(Value::none());
+        }
+
+        let node = self.get_node(node_ref)?;
+        let position = node.position.clone();
+        let source = node.get_str("source").unwrap_or_default();
+
+        let message = match self.get_edge_target(node_ref, &ExecEdgeType::ValueEdge) {
+            Some(msg_ref) => self.execute_node(msg_ref)?.to_string_value(),
+            None => format!("Assertion failed: {}", source),
+        };
+
+        self.raise_stack = Some(self.call_stack.clone());
+        self.raise_position = Some(position);
+        self.raise_cause = None;
+
+        Err(GraphoidError::runtime(format!("AssertionError: {}", message)))
+    }
+
     // --- Conditional expression (ternary: value if condition else other) ---
 
     fn exec_conditional(&mut self, node_ref: NodeRef) -> Result<Value> {
@@ -4862,6 +6123,18 @@ impl GraphExecutor {
         self.call_graph_function(func.clone(), arg_values.to_vec())
     }
 
+    /// Call the block of an `each`-style method, treating `break` and `continue`
+    /// inside it like a loop body. Returns false once the block breaks.
+    pub(crate) fn call_each_block(&mut self, func: &Function, arg_values: &[Value]) -> Result<bool> {
+        match self.call_function(func, arg_values) {
+            Ok(_) => Ok(true),
+            Err(GraphoidError::LoopControl { control }) => {
+                Ok(!matches!(control, crate::error::LoopControlType::Break))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     // =========================================================================
     // Module system
     // =========================================================================
@@ -5437,7 +6710,7 @@ impl GraphExecutor {
             // Read strategy and max_restarts from graph properties
             let strategy = graph.get_node(&Graph::property_node_id("strategy"))
                 .and_then(|n| match &n.kind {
-                    ValueKind::Symbol(s) => Some(s.clone()),
+                    ValueKind::Symbol(s) => Some(s.to_string()),
                     _ => None,
                 })
                 .unwrap_or_else(|| "one_for_one".to_string());
@@ -5912,7 +7185,6 @@ impl GraphExecutor {
     }
 
     fn load_module(&mut self, module_path: &str, _alias: Option<&String>) -> Result<Value> {
-        use std::fs;
         use crate::execution::module_manager::Module;
 
         // Check for native modules first
@@ -5947,11 +7219,10 @@ impl GraphExecutor {
         }
 
         // Resolve the module path
-        let resolved_path = if let Some(ref current) = self.current_file {
-            self.module_manager.resolve_module_path(module_path, Some(current))?
-        } else {
-            self.module_manager.resolve_module_path(module_path, None)?
-        };
+        let module_dirs = self.module_search_dirs();
+        let resolved_path = self.module_manager.resolve_module_path_in(
+            module_path, self.current_file.as_deref(), &module_dirs,
+        )?;
 
         // Check if already loaded (cached)
         if let Some(module) = self.module_manager.get_module(&resolved_path.to_string_lossy().to_string()) {
@@ -5963,10 +7234,14 @@ impl GraphExecutor {
         self.module_manager.begin_loading(resolved_path.clone())?;
 
         // Read and execute module source in isolated environment
-        let source = fs::read_to_string(&resolved_path)?;
+        let program = program_cache::parse_file(&resolved_path)?;
         let module_env = Environment::new();
         let mut module_executor = GraphExecutor::with_env(module_env);
         module_executor.set_current_file(Some(resolved_path.clone()));
+        // Nested imports keep searching the importer's module_paths
+        module_executor.config_stack.current_mut().module_paths = module_dirs.iter()
+            .map(|dir| dir.to_string_lossy().to_string())
+            .collect();
 
         // Pass magic variables
         for (name, value) in self.env.get_all_bindings() {
@@ -5981,8 +7256,9 @@ impl GraphExecutor {
 
         // Set module executor's func ID counter to avoid collisions with parent
         module_executor.next_func_id = self.next_func_id;
+        module_executor.script_args = self.script_args.clone();
 
-        module_executor.execute_source(&source)?;
+        module_executor.execute_program(&program)?;
 
         // Update parent's func ID counter to account for IDs used by module
         self.next_func_id = module_executor.next_func_id;
@@ -6089,26 +7365,32 @@ impl GraphExecutor {
         Ok(Value::module(module))
     }
 
-    fn execute_load(&mut self, path_str: &str) -> Result<()> {
-        use std::fs;
-
-        // For load(), first try the path relative to current working directory.
-        // This is important for spec_runner which loads files like "tests/gspec/foo_spec.gr"
-        // from the project root, even though spec_runner.gr itself is in stdlib.
-        let cwd_path = PathBuf::from(path_str);
-        let resolved_path = if cwd_path.exists() && cwd_path.is_file() {
-            cwd_path.canonicalize().map_err(|e| GraphoidError::IOError {
-                message: format!("Failed to canonicalize path: {}", e),
-                position: crate::error::SourcePosition::unknown(),
-            })?
-        } else if let Some(ref current) = self.current_file {
-            self.module_manager.resolve_module_path(path_str, Some(current))?
-        } else {
-            self.module_manager.resolve_module_path(path_str, None)?
-        };
+    /// Directories from `configure { module_paths: [...] }`. Relative entries
+    /// are taken relative to the current file's directory, or the working
+    /// directory when no file is running.
+    fn module_search_dirs(&self) -> Vec<PathBuf> {
+        let base = self.current_file.as_deref()
+            .and_then(|file| file.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        self.config_stack.current().module_paths.iter()
+            .map(|dir| base.join(dir))
+            .collect()
+    }
 
-        let source = fs::read_to_string(&resolved_path)?;
-        self.execute_source(&source)?;
+    fn execute_load(&mut self, path_str: &str) -> Result<()> {
+        // For load(), the working directory is searched right after the current
+        // file's directory. This is important for spec_runner which loads files like
+        // "tests/gspec/foo_spec.gr" from the project root, even though spec_runner.gr
+        // itself is in stdlib.
+        let mut load_dirs = vec![PathBuf::from(".")];
+        load_dirs.extend(self.module_search_dirs());
+        let resolved_path = self.module_manager.resolve_module_path_in(
+            path_str, self.current_file.as_deref(), &load_dirs,
+        )?;
+
+        let program = program_cache::parse_file(&resolved_path)?;
+        self.execute_program(&program)?;
         Ok(())
     }
 
@@ -6181,6 +7463,7 @@ impl GraphExecutor {
         match graph_type_str.as_deref() {
             Some("dag") => { graph.rulesets.push("dag".to_string()); }
             Some("tree") => { graph.rulesets.push("tree".to_string()); }
+            Some("multigraph") => { graph.multigraph = true; }
             _ => {}
         }
 
@@ -6219,6 +7502,7 @@ impl GraphExecutor {
             body_ref: NodeRef,
             param_names: Vec<String>,
             parameters: Vec<Parameter>,
+            default_refs: Vec<(usize, NodeRef)>,
             guard_ref: Option<NodeRef>,
             body_stmts: Vec<crate::ast::Stmt>,  // Phase 19.3: AST body for actor threads
         }
@@ -6235,12 +7519,17 @@ impl GraphExecutor {
             if let Some(body_ref) = self.get_edge_target(*method_ref, &ExecEdgeType::Body) {
                 let mut param_names = Vec::new();
                 let mut parameters = Vec::new();
+                let mut default_refs = Vec::new();
                 let param_refs = self.get_ordered_edges_cloned(*method_ref, "Parameter");
-                for param_ref in &param_refs {
+                for (i, param_ref) in param_refs.iter().enumerate() {
                     let pn = self.get_node(*param_ref)?;
                     let pname = pn.get_str("name").unwrap_or_default();
                     let is_variadic = pn.get_bool("is_variadic").unwrap_or(false);
-                    let has_default = self.get_edge_target(*param_ref, &ExecEdgeType::DefaultValue).is_some();
+                    let default_ref = self.get_edge_target(*param_ref, &ExecEdgeType::DefaultValue);
+                    let has_default = default_ref.is_some();
+                    if let Some(default_ref) = default_ref {
+                        default_refs.push((i, default_ref));
+                    }
                     param_names.push(pname.clone());
                     parameters.push(Parameter {
                         name: pname,
@@ -6258,7 +7547,7 @@ impl GraphExecutor {
                     Some(AstProperty::Stmts(stmts)) => stmts.clone(),
                     _ => Vec::new(),
                 };
-                method_infos.push(MethodInfo { method_name, is_static, is_private, body_ref, param_names, parameters, guard_ref, body_stmts });
+                method_infos.push(MethodInfo { method_name, is_static, is_private, body_ref, param_names, parameters, default_refs, guard_ref, body_stmts });
             }
         }
         // Now register methods (mutable borrow is safe here)
@@ -6279,6 +7568,9 @@ impl GraphExecutor {
 
                 let func_id = format!("__graph_method_{}_{}_{}", name, registered_name, variant_idx);
                 self.store_function_body(func_id.clone(), mi.body_ref);
+                for (i, default_ref) in &mi.default_refs {
+                    self.store_function_body(format!("{}__default_{}", func_id, i), *default_ref);
+                }
 
                 // Store guard NodeRef for dispatch-time evaluation
                 if let Some(guard_ref) = mi.guard_ref {
@@ -6297,6 +7589,7 @@ impl GraphExecutor {
                     is_setter: false,
                     is_static: mi.is_static,
                     guard: None,  // Guard evaluated at dispatch time via graph_method_guards
+                    synthetic: None,
                 };
 
                 graph.attach_method(registered_name, func);
@@ -6435,13 +7728,16 @@ impl GraphExecutor {
             if s.starts_with(':') { s[1..].to_string() } else { s.clone() }
         });
         let graph_type = match type_str_normalized.as_deref() {
-            Some("directed") | Some("dag") => GraphType::Directed,
+            Some("directed") | Some("dag") | Some("multigraph") | None => GraphType::Directed,
             Some("undirected") => GraphType::Undirected,
             Some("tree") => GraphType::Directed, // Trees are directed graphs with constraints
-            _ => GraphType::Directed,
+            Some(other) => return Err(GraphoidError::runtime(format!(
+                "ValueError: {}", crate::values::graph::unknown_graph_type_message(other)
+            ))),
         };
 
         let mut graph = Graph::new(graph_type);
+        graph.multigraph = type_str_normalized.as_deref() == Some("multigraph");
 
         // Apply ruleset if specified
         // Also normalize ruleset string (strip leading colon)
@@ -6677,42 +7973,78 @@ impl GraphExecutor {
             args.push(self.execute_node(arg_ref)?);
         }
 
-        // Get the current self graph and look up the method on its parent
-        // super.method() calls the parent's method but with `self` bound to the child
-        let self_value = self.env.get("self")?;
-        if let ValueKind::Graph(ref graph_rc) = self_value.kind {
-            // Clone what we need before releasing borrows
-            let child_graph = graph_rc.borrow().clone();
-            let func_to_call: Option<Function> = {
-                if let Some(ref parent_box) = child_graph.parent {
-                    let parent = parent_box.as_ref();
-                    let method_node_id = format!("__methods__/{}", method_name);
-                    if let Some(method_val) = parent.get_node(&method_node_id) {
-                        if let ValueKind::Function(func) = &method_val.kind {
-                            Some(func.clone())
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    return Err(GraphoidError::runtime("No parent graph available for super call".to_string()));
-                }
-            };
+        let (child_graph, parent) = self.super_target()?;
+        match self.parent_method(&parent, &method_name) {
+            Some(func) => self.call_super_method(&child_graph, parent, &func, &args),
+            None => Err(GraphoidError::runtime(format!("No method '{}' on parent graph", method_name))),
+        }
+    }
 
-            if let Some(func) = func_to_call {
-                // Call parent's method with `self` bound to child graph
-                // This ensures properties like `speed` access the child's values
-                let self_expr = Expr::Variable {
-                    name: "self".to_string(),
-                    position: SourcePosition::unknown(),
-                };
-                return self.call_graph_method_impl(&child_graph, &func, &args, &self_expr, false);
+    /// `super.name`: the parent's property if it has one, otherwise the
+    /// parent's zero-argument getter called with `self` bound to the child.
+    fn exec_super_property_access(&mut self, node_ref: NodeRef) -> Result<Value> {
+        let node = self.get_node(node_ref)?;
+        let property = node.get_str("property").unwrap_or_default();
+
+        let (child_graph, parent) = self.super_target()?;
+        if let Some(value) = parent.get_node(&crate::values::Graph::property_node_id(&property)) {
+            return Ok(value.clone());
+        }
+        match self.parent_method(&parent, &property) {
+            Some(func) if func.params.is_empty() => {
+                self.call_super_method(&child_graph, parent, &func, &[])
+            }
+            Some(_) => Err(GraphoidError::runtime(format!(
+                "Parent method '{}' takes arguments; call it as super.{}(...)", property, property
+            ))),
+            None => Err(GraphoidError::runtime(format!("No property '{}' on parent graph", property))),
+        }
+    }
+
+    /// The current `self` graph and the parent that `super` refers to.
+    ///
+    /// The parent is taken from the innermost method context rather than from
+    /// `self`, so a parent method that itself uses `super` walks one more level
+    /// up instead of resolving back to its own class.
+    fn super_target(&self) -> Result<(crate::values::Graph, crate::values::Graph)> {
+        let outside = || GraphoidError::runtime("super can only be used within a graph method".to_string());
+        let self_value = self.env.get("self").map_err(|_| outside())?;
+        let child_graph = match &self_value.kind {
+            ValueKind::Graph(graph_rc) => graph_rc.borrow().clone(),
+            _ => return Err(outside()),
+        };
+        let level = self.super_context_stack.last().unwrap_or(&child_graph);
+        match &level.parent {
+            Some(parent) => {
+                let parent = parent.as_ref().clone();
+                Ok((child_graph, parent))
             }
-            return Err(GraphoidError::runtime(format!("No method '{}' on parent graph", method_name)));
+            None => Err(GraphoidError::runtime("No parent graph available for super call".to_string())),
         }
-        Err(GraphoidError::runtime("super can only be used within a graph method".to_string()))
+    }
+
+    /// The parent's unguarded variant of a method. Overriding an inherited
+    /// method appends a variant, so the last one is the parent's own.
+    fn parent_method(&self, parent: &crate::values::Graph, name: &str) -> Option<Function> {
+        parent.get_method_variants(name).into_iter()
+            .filter(|func| {
+                func.guard.is_none()
+                    && !func.node_id.as_ref().is_some_and(|id| self.graph_method_guards.contains_key(id))
+            })
+            .last()
+    }
+
+    /// Call a parent's method with `self` bound to the child graph, so
+    /// properties like `speed` still read the child's values.
+    fn call_super_method(&mut self, child_graph: &crate::values::Graph, parent: crate::values::Graph, func: &Function, args: &[Value]) -> Result<Value> {
+        let self_expr = Expr::Variable {
+            name: "self".to_string(),
+            position: SourcePosition::unknown(),
+        };
+        self.super_context_stack.push(parent);
+        let result = self.call_graph_method_impl(child_graph, func, args, &self_expr, false);
+        self.super_context_stack.pop();
+        result
     }
 
     // =========================================================================
@@ -6762,6 +8094,7 @@ impl GraphExecutor {
             is_setter: false,
             is_static: false,
             guard: None,
+            synthetic: None,
         }
     }
 
@@ -6800,6 +8133,7 @@ impl GraphExecutor {
             is_setter: true,
             is_static: false,
             guard: None,
+            synthetic: None,
         }
     }
 
@@ -6835,11 +8169,229 @@ impl GraphExecutor {
         self.current_file = path;
     }
 
+    /// Set the command-line arguments returned by `args()`.
+    pub fn set_script_args(&mut self, args: Vec<String>) {
+        self.script_args = args;
+    }
+
+    /// Cause chained onto the most recently raised error that has not been
+    /// caught, if any. Used to print "Caused by:" sections for uncaught errors.
+    pub fn uncaught_error_cause(&self) -> Option<&crate::values::ErrorObject> {
+        self.raise_cause.as_ref()
+    }
+
+    /// Where the most recently raised error that has not been caught was
+    /// raised, if it came from `raise` or a failed `assert`.
+    pub fn uncaught_error_position(&self) -> Option<&SourcePosition> {
+        self.raise_position.as_ref()
+    }
+
     /// Enable output capture (for testing).
     pub fn enable_output_capture(&mut self) {
         self.output_capture_enabled = true;
     }
 
+    /// Write a line of program output: to the capture buffer when capture is
+    /// enabled, otherwise to stdout.
+    pub(crate) fn write_output_line(&mut self, text: &str) {
+        if self.output_capture_enabled {
+            self.output_buffer.push_str(text);
+            self.output_buffer.push('\n');
+        } else {
+            println!("{}", text);
+        }
+    }
+
+    /// Write an execution trace line, indented by function call depth.
+    fn write_trace_line(&mut self, text: &str) {
+        let indent = "  ".repeat(self.function_call_depth);
+        self.write_output_line(&format!("[trace] {}{}", indent, text));
+    }
+
+    /// `inspect()` form of a value for trace output, cut short if long.
+    fn trace_summary(value: &Value) -> String {
+        const MAX_CHARS: usize = 60;
+        let text = value.inspect();
+        if text.chars().count() <= MAX_CHARS {
+            text
+        } else {
+            format!("{}...", text.chars().take(MAX_CHARS).collect::<String>())
+        }
+    }
+
+    /// Trace a function call's arguments when `configure { trace: true }` is active.
+    fn trace_call_enter(&mut self, func_name: &str, args: &[Value]) {
+        if self.config_stack.current().trace {
+            let args: Vec<String> = args.iter().map(Self::trace_summary).collect();
+            self.write_trace_line(&format!("-> {}({})", func_name, args.join(", ")));
+        }
+    }
+
+    /// Trace a function call's result when `configure { trace: true }` is active.
+    fn trace_call_exit(&mut self, func_name: &str, result: &Result<Value>) {
+        if self.config_stack.current().trace {
+            let line = match result {
+                Ok(value) => format!("<- {} returned {}", func_name, Self::trace_summary(value)),
+                Err(e) => format!("<- {} raised {}", func_name, e.error_type()),
+            };
+            self.write_trace_line(&line);
+        }
+    }
+
+    /// Bookkeeping on entry to a user function body: trace, call depth, the
+    /// call site reported by `caller_location()` and a frame for `defer`.
+    fn enter_function(&mut self, func_name: &str, args: &[Value]) {
+        self.trace_call_enter(func_name, args);
+        self.function_call_depth += 1;
+        self.push_call_site();
+        self.defer_frames.push(Vec::new());
+    }
+
+    /// Undo `enter_function` once the body has finished, running the
+    /// function's deferred blocks first. Returns the call's final result.
+    fn exit_function(&mut self, func_name: &str, result: Result<Value>) -> Result<Value> {
+        let result = self.pop_defer_frame(result);
+        self.pop_call_site();
+        self.function_call_depth -= 1;
+        self.trace_call_exit(func_name, &result);
+        result
+    }
+
+    /// Run and discard the innermost frame of deferred blocks.
+    fn pop_defer_frame(&mut self, result: Result<Value>) -> Result<Value> {
+        let deferred = self.defer_frames.pop().unwrap_or_default();
+        if self.defer_frames.is_empty() {
+            self.defer_frames.push(Vec::new());
+        }
+        self.run_deferred(deferred, result)
+    }
+
+    /// Run blocks deferred at the top level by statements executed one at a
+    /// time with `eval_stmt`. Hosts call this once the program has finished,
+    /// passing how it finished.
+    pub fn run_top_level_defers(&mut self, result: Result<()>) -> Result<()> {
+        let deferred = std::mem::take(&mut self.defer_frames[0]);
+        self.run_deferred(deferred, result.map(|_| Value::none())).map(|_| ())
+    }
+
+    /// Run deferred blocks newest first. Every block runs even if an earlier
+    /// one fails. The scope's own error wins; a block error raised while that
+    /// error is unwinding is chained onto it as a cause instead of replacing
+    /// it, so `catch` sees it through `cause()` and an uncaught report lists
+    /// it under "Caused by".
+    fn run_deferred(&mut self, deferred: Vec<DeferredBlock>, mut result: Result<Value>) -> Result<Value> {
+        if deferred.is_empty() {
+            return result;
+        }
+        // A raise inside a block must not overwrite where an unwinding error came from
+        let unwinding = result
+            .is_err()
+            .then(|| (self.raise_stack.take(), self.raise_position.take(), self.raise_cause.take()));
+        let mut secondary = Vec::new();
+        for block in deferred.into_iter().rev() {
+            let outcome = match self.run_deferred_block(block) {
+                Err(GraphoidError::ReturnControl { .. }) => Ok(Value::none()),
+                other => other,
+            };
+            if let Err(e) = outcome {
+                if result.is_ok() {
+                    result = Err(e);
+                } else {
+                    let (error_type, message) = e.type_and_message();
+                    let (line, column) = self.raise_position.take()
+                        .map(|p| (p.line, p.column))
+                        .unwrap_or((0, 0));
+                    secondary.push(crate::values::ErrorObject::new(
+                        error_type,
+                        message,
+                        self.current_file.as_ref().map(|p| p.to_string_lossy().to_string()),
+                        line,
+                        column,
+                    ));
+                }
+            }
+        }
+        if let Some((stack, position, cause)) = unwinding {
+            self.raise_stack = stack;
+            self.raise_position = position;
+            self.raise_cause = secondary.into_iter().fold(cause, |chain, error| {
+                Some(Self::append_cause(chain, error))
+            });
+        }
+        result
+    }
+
+    /// Run one deferred block in a child scope holding the variables it
+    /// captured. Assignments it makes to captured variables are written
+    /// back to the scope being exited.
+    fn run_deferred_block(&mut self, block: DeferredBlock) -> Result<Value> {
+        let parent_env_clone = self.env.clone();
+        self.env = Environment::with_parent(self.env.clone());
+        for (name, value) in &block.bindings {
+            self.env.define(name.clone(), value.clone());
+        }
+
+        let result = self.execute_node(block.body_ref);
+
+        let changed: Vec<(String, Value)> = self.env.get_all_bindings().into_iter()
+            .filter(|(name, value)| {
+                block.bindings.iter().any(|(captured, original)| captured == name && original != value)
+            })
+            .collect();
+        self.env = match self.env.take_parent() {
+            Some(boxed_parent) => *boxed_parent,
+            None => parent_env_clone,
+        };
+        for (name, value) in changed {
+            if self.env.exists(&name) {
+                self.env.set(&name, value)?;
+            }
+        }
+        result
+    }
+
+    /// `error` with `cause` added at the end of its cause chain.
+    fn append_cause(error: Option<crate::values::ErrorObject>, cause: crate::values::ErrorObject) -> crate::values::ErrorObject {
+        match error {
+            None => cause,
+            Some(mut error) => {
+                let inner = error.cause.take().map(|boxed| *boxed);
+                error.with_cause(Self::append_cause(inner, cause))
+            }
+        }
+    }
+
+    /// Remember the statement making a call.
+    fn push_call_site(&mut self) {
+        self.call_sites.push(self.current_statement);
+    }
+
+    /// Back in the caller, its statement is the current one again.
+    fn pop_call_site(&mut self) {
+        if let Some(site) = self.call_sites.pop() {
+            self.current_statement = site;
+        }
+    }
+
+    /// `{file, line, column}` of the statement that called the running
+    /// function, or of the current statement at the top level.
+    fn caller_location(&self) -> Result<Value> {
+        let site = self.call_sites.last().copied().unwrap_or(self.current_statement);
+        let position = site
+            .and_then(|node_ref| self.get_node(node_ref).ok())
+            .map(|node| node.position.clone())
+            .unwrap_or_else(SourcePosition::unknown);
+        let file = position.file.clone()
+            .or_else(|| self.current_file.as_ref().map(|path| path.display().to_string()))
+            .map(Value::string)
+            .unwrap_or_else(Value::none);
+        let mut map = crate::values::Hash::new();
+        map.insert("file".to_string(), file)?;
+        map.insert("line".to_string(), Value::number(position.line as f64))?;
+        map.insert("column".to_string(), Value::number(position.column as f64))?;
+        Ok(Value::map(map))
+    }
+
     /// Get captured output and reset the buffer.
     pub fn get_captured_output(&mut self) -> String {
         std::mem::take(&mut self.output_buffer)
@@ -7172,7 +8724,9 @@ impl GraphExecutor {
     ) -> Result<Value> {
         use crate::graph::RuleSpec;
 
-        if arg_values.len() != func.parameters.len() {
+        // Trailing parameters with defaults may be left out
+        let required = func.parameters.iter().take_while(|p| p.default_value.is_none()).count();
+        if arg_values.len() > func.parameters.len() || arg_values.len() < required {
             return Err(GraphoidError::runtime(format!(
                 "Method '{}' expects {} arguments, but got {}",
                 func.name.as_ref().unwrap_or(&"<anonymous>".to_string()),
@@ -7181,6 +8735,19 @@ impl GraphExecutor {
             )));
         }
 
+        // Fill in defaults before any call state is pushed (evaluated in the
+        // caller's scope, as for plain functions)
+        let mut arg_values = arg_values.to_vec();
+        for i in arg_values.len()..func.parameters.len() {
+            let default_ref = func.node_id.as_ref()
+                .and_then(|id| self.graph_function_bodies.get(&format!("{}__default_{}", id, i)).copied());
+            let default_val = match default_ref {
+                Some(default_ref) => self.execute_node(default_ref)?,
+                None => Value::none(),
+            };
+            arg_values.push(default_val);
+        }
+
         // Capture graph state before method execution for constraint checking
         let before_node_ids: std::collections::HashSet<String> = graph.constrainable_node_ids().into_iter().collect();
         let before_edge_count = graph.data_edge_list().len();
@@ -7254,6 +8821,8 @@ impl GraphExecutor {
         // Execute function body
         // Phase 19.3: Prefer graph-based execution, fallback to AST body (for actor threads)
         let mut return_value = Value::none();
+        self.push_call_site();
+        self.defer_frames.push(Vec::new());
         let execution_result: Result<()> = (|| {
             // Try graph-based execution first (main thread has graph_function_bodies populated)
             let mut executed = false;
@@ -7286,6 +8855,8 @@ impl GraphExecutor {
             }
             Ok(())
         })();
+        let execution_result = self.pop_defer_frame(execution_result.map(|_| Value::none())).map(|_| ());
+        self.pop_call_site();
 
         // Enhance undefined variable errors with property suggestions
         let execution_result = execution_result.map_err(|err| {
@@ -7539,7 +9110,7 @@ impl GraphExecutor {
         &self,
         graph: &crate::values::Graph,
         pattern: &crate::ast::GraphPattern,
-        edges: &std::collections::HashMap<String, crate::values::graph::EdgeInfo>,
+        edges: &indexmap::IndexMap<String, crate::values::graph::EdgeInfo>,
         edge_pattern: &crate::ast::PatternEdge,
         next_node_pattern: &crate::ast::PatternNode,
         bindings: &std::collections::HashMap<String, String>,
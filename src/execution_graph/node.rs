@@ -32,6 +32,7 @@ pub enum AstNodeType {
     CallExpr,
     MethodCallExpr,
     SuperMethodCallExpr,
+    SuperPropertyAccessExpr,
     PropertyAccessExpr,
     IndexExpr,
     LambdaExpr,
@@ -60,8 +61,10 @@ pub enum AstNodeType {
     ConfigureStmt,
     PrecisionStmt,
     TryStmt,
+    DeferStmt,
     GraphDeclStmt,
     ExpressionStmt,
+    AssertStmt,
 
     // Structural
     Program,
@@ -79,6 +82,37 @@ pub enum AstNodeType {
     PrivBlockStmt,   // Phase 17: priv { } block (children are private declarations)
     SpawnStmt,       // Phase 19: spawn { } concurrent task
     SpawnActorExpr,  // Phase 19.3: spawn Counter{} actor expression
+    SpreadExpr,      // ...expr inside a list or map literal
+}
+
+impl AstNodeType {
+    /// True for statement nodes (the units reported by execution tracing).
+    pub fn is_statement(&self) -> bool {
+        matches!(
+            self,
+            AstNodeType::VarDeclStmt
+                | AstNodeType::AssignStmt
+                | AstNodeType::FuncDeclStmt
+                | AstNodeType::IfStmt
+                | AstNodeType::WhileStmt
+                | AstNodeType::ForStmt
+                | AstNodeType::ReturnStmt
+                | AstNodeType::BreakStmt
+                | AstNodeType::ContinueStmt
+                | AstNodeType::ImportStmt
+                | AstNodeType::ModuleDeclStmt
+                | AstNodeType::LoadStmt
+                | AstNodeType::ConfigureStmt
+                | AstNodeType::PrecisionStmt
+                | AstNodeType::TryStmt
+                | AstNodeType::DeferStmt
+                | AstNodeType::GraphDeclStmt
+                | AstNodeType::ExpressionStmt
+                | AstNodeType::AssertStmt
+                | AstNodeType::PrivBlockStmt
+                | AstNodeType::SpawnStmt
+        )
+    }
 }
 
 /// Edge types connecting nodes in the execution graph.
@@ -108,6 +142,9 @@ pub enum ExecEdgeType {
     Target,
     ValueEdge,
 
+    // Computed map key
+    KeyEdge,
+
     // Ordered children (blocks, lists, programs)
     Element(u32),
 
@@ -187,6 +224,7 @@ impl ExecEdgeType {
             ExecEdgeType::Body => "Body",
             ExecEdgeType::Target => "Target",
             ExecEdgeType::ValueEdge => "ValueEdge",
+            ExecEdgeType::KeyEdge => "KeyEdge",
             ExecEdgeType::Element(_) => "Element",
             ExecEdgeType::Object => "Object",
             ExecEdgeType::Parameter(_) => "Parameter",
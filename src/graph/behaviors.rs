@@ -519,7 +519,7 @@ fn value_to_key(value: &Value) -> String {
                 n.to_string()
             }
         }
-        ValueKind::Symbol(s) => s.clone(),
+        ValueKind::Symbol(s) => s.to_string(),
         ValueKind::Boolean(b) => b.to_string(),
         ValueKind::None => "none".to_string(),
         // For complex types, use Debug representation
@@ -431,6 +431,18 @@ pub trait Rule {
     /// Returns true if the rule needs to validate this operation
     fn should_run_on(&self, operation: &GraphOperation) -> bool;
 
+    /// Validate the graph as it currently stands, independent of any pending operation
+    ///
+    /// The default validates a removal, which makes structural rules inspect the
+    /// whole graph. Rules that only look at the operation's own node or edge
+    /// override this to replay every existing node and edge.
+    fn validate_existing(&self, graph: &Graph) -> Result<(), GraphoidError> {
+        let context = RuleContext::new(GraphOperation::RemoveNode {
+            id: "__validation_check__".to_string(),
+        });
+        self.validate(graph, &context)
+    }
+
     /// Attempt to clean existing violations (retroactive application)
     /// Returns Err if the rule doesn't support cleaning
     fn clean(&self, _graph: &mut Graph) -> Result<(), GraphoidError> {
@@ -955,7 +967,7 @@ impl Rule for WeightedEdgesRule {
         // Remove unweighted edges
         for (from, to) in edges_to_remove {
             if let Some(node) = graph.nodes.get_mut(&from) {
-                node.neighbors.remove(&to);
+                node.neighbors.shift_remove(&to);
             }
         }
 
@@ -1140,6 +1152,28 @@ impl Rule for BSTOrderingRule {
         // and edge additions (to check ordering)
         matches!(operation, GraphOperation::AddNode { .. } | GraphOperation::AddEdge { .. })
     }
+
+    fn validate_existing(&self, graph: &Graph) -> Result<(), GraphoidError> {
+        // Ordering is only checked per operation, so replay every node and edge
+        for (id, node) in &graph.nodes {
+            self.validate(graph, &RuleContext::new(GraphOperation::AddNode {
+                id: id.clone(),
+                value: node.value.clone(),
+            }))?;
+        }
+        for (from, node) in &graph.nodes {
+            for (to, edge) in &node.neighbors {
+                self.validate(graph, &RuleContext::new(GraphOperation::AddEdge {
+                    from: from.clone(),
+                    to: to.clone(),
+                    edge_type: edge.edge_type.clone(),
+                    weight: edge.weight,
+                    properties: edge.properties.clone(),
+                }))?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A no-op rule for method constraints
@@ -62,6 +62,7 @@ impl Lexer {
 
             let start_line = self.line;
             let start_column = self.column;
+            let start = self.current;
             let ch = self.advance();
 
             let token_type = match ch {
@@ -291,6 +292,8 @@ impl Lexer {
                     TokenType::Bang
                 }
             }
+            // Trailing ? on predicate method names (e.g., any?())
+            '?' => TokenType::Question,
             '<' => {
                 if self.match_char('<') {
                     // Left shift <<
@@ -331,6 +334,8 @@ impl Lexer {
             '|' => {
                 if self.match_char('|') {
                     TokenType::PipePipe
+                } else if self.match_char('>') {
+                    TokenType::PipeGreater
                 } else {
                     TokenType::Pipe
                 }
@@ -357,9 +362,13 @@ impl Lexer {
                 }
             }
 
-            // Single-line comments
+            // Comments: `#[ ... ]#` blocks (nestable) or `#` to end of line
             '#' => {
-                self.skip_line_comment();
+                if self.peek() == '[' {
+                    self.skip_hash_block_comment(start_line, start_column)?;
+                } else {
+                    self.skip_line_comment();
+                }
                 self.skip_whitespace_except_newline();
                 continue; // Loop back to get next token
             }
@@ -406,9 +415,10 @@ impl Lexer {
             }
         };
 
+            // Multi-character operators (`>=`, `|>`, ...) keep their full text
             return Ok(Token::new(
                 token_type,
-                ch.to_string(),
+                self.source[start..self.current].iter().collect(),
                 start_line,
                 start_column,
             ));
@@ -484,12 +494,42 @@ impl Lexer {
                 return Ok(());
             }
 
-            if self.peek() == '\n' {
-                self.line += 1;
-                self.column = 1;
-            }
+            self.advance_tracking_newline();
+        }
 
-            self.advance();
+        Err(GraphoidError::SyntaxError {
+            message: "Unterminated block comment".to_string(),
+            position: SourcePosition {
+                line: start_line,
+                column: start_column,
+                file: None,
+            },
+        })
+    }
+
+    /// Skips a `#[ ... ]#` block comment. Blocks nest, so `#[ a #[ b ]# c ]#`
+    /// is a single comment. The opening `#` has already been consumed; an
+    /// unterminated comment is reported at the opener.
+    fn skip_hash_block_comment(&mut self, start_line: usize, start_column: usize) -> Result<()> {
+        // Skip the [
+        self.advance();
+        let mut depth = 1;
+
+        while !self.is_at_end() {
+            if self.peek() == '#' && self.peek_next() == '[' {
+                self.advance();
+                self.advance();
+                depth += 1;
+            } else if self.peek() == ']' && self.peek_next() == '#' {
+                self.advance();
+                self.advance();
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(());
+                }
+            } else {
+                self.advance_tracking_newline();
+            }
         }
 
         Err(GraphoidError::SyntaxError {
@@ -502,6 +542,14 @@ impl Lexer {
         })
     }
 
+    /// Advances one character inside a comment, moving to the next line on `\n`.
+    fn advance_tracking_newline(&mut self) {
+        if self.advance() == '\n' {
+            self.line += 1;
+            self.column = 1;
+        }
+    }
+
     fn string(&mut self, quote: char) -> Result<Token> {
         let start_line = self.line;
         let start_column = self.column - 1;
@@ -583,20 +631,34 @@ impl Lexer {
         let start_column = self.column;
         let mut lexeme = String::new();
 
-        while !self.is_at_end() && self.peek().is_ascii_digit() {
-            lexeme.push(self.advance());
-        }
+        self.decimal_digits(&mut lexeme);
 
         // Check for decimal point
         if !self.is_at_end() && self.peek() == '.' && self.peek_next().is_ascii_digit() {
             lexeme.push(self.advance()); // consume .
+            self.decimal_digits(&mut lexeme);
+        }
 
-            while !self.is_at_end() && self.peek().is_ascii_digit() {
+        // Optional exponent: e/E, optional sign, then at least one digit
+        if self.peek() == 'e' || self.peek() == 'E' {
+            lexeme.push(self.advance());
+            if self.peek() == '+' || self.peek() == '-' {
                 lexeme.push(self.advance());
             }
+            if !self.peek().is_ascii_digit() {
+                return Err(GraphoidError::SyntaxError {
+                    message: format!("Exponent in number literal '{}' must have at least one digit", lexeme),
+                    position: SourcePosition {
+                        line: start_line,
+                        column: start_column,
+                        file: None,
+                    },
+                });
+            }
+            self.decimal_digits(&mut lexeme);
         }
 
-        let value = lexeme.parse::<f64>().map_err(|_| GraphoidError::SyntaxError {
+        let value = lexeme.replace('_', "").parse::<f64>().map_err(|_| GraphoidError::SyntaxError {
             message: format!("Invalid number: {}", lexeme),
             position: SourcePosition {
                 line: start_line,
@@ -613,6 +675,19 @@ impl Lexer {
         ))
     }
 
+    /// Consume a run of decimal digits. Underscores are allowed between
+    /// digits for readability (`1_000_000`) and are kept in the lexeme.
+    fn decimal_digits(&mut self, lexeme: &mut String) {
+        while !self.is_at_end() {
+            let ch = self.peek();
+            if ch.is_ascii_digit() || (ch == '_' && self.peek_next().is_ascii_digit() && lexeme.ends_with(|c: char| c.is_ascii_digit())) {
+                lexeme.push(self.advance());
+            } else {
+                break;
+            }
+        }
+    }
+
     fn binary_literal(&mut self) -> Result<Token> {
         let start_line = self.line;
         let start_column = self.column;
@@ -777,7 +852,8 @@ impl Lexer {
         }
 
         // Note: Trailing ! for mutating methods is now handled at the parser level
-        // The parser checks for a Bang token after method names and mutable arguments
+        // The parser checks for a Bang token after method names and mutable arguments,
+        // and likewise for a Question token after predicate method names
 
         let token_type = match lexeme.as_str() {
             // Keywords
@@ -795,6 +871,7 @@ impl Lexer {
             "try" => TokenType::Try,
             "catch" => TokenType::Catch,
             "finally" => TokenType::Finally,
+            "defer" => TokenType::Defer,
             "raise" => TokenType::Raise,
             "as" => TokenType::As,
             "import" => TokenType::Import,
@@ -29,6 +29,7 @@ pub enum TokenType {
     Try,
     Catch,
     Finally,
+    Defer,       // defer keyword for scope-exit cleanup blocks
     Raise,
     As,
     Import,
@@ -76,6 +77,7 @@ pub enum TokenType {
     Equal,
     EqualEqual,
     Bang,            // ! (for mutation operators like sort!())
+    Question,        // ? (for predicate methods like any?())
     BangEqual,
     Less,
     LessEqual,
@@ -88,6 +90,7 @@ pub enum TokenType {
     Pipe,            // | (bitwise OR, also used in ||)
     AmpersandAmpersand, // &&
     PipePipe,        // ||
+    PipeGreater,     // |> (pipe operator)
 
     // Element-wise operators (dot-prefix)
     DotPlus,         // .+
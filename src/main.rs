@@ -2,14 +2,15 @@
 //!
 //! Usage:
 //!   gr file.gr          Run a Graphoid file
+//!   gr file.gr -- args  Run a file, passing args to the script's args()
 //!   gr spec [path]      Run spec files (test runner)
 //!   gr repl             Start interactive REPL
+//!   gr eval <source>    Evaluate source and print the result
 //!   gr version          Show version
 //!   gr help             Show help
 
-use graphoid::execution::Executor;
-use graphoid::lexer::Lexer;
-use graphoid::parser::Parser;
+use graphoid::error::GraphoidError;
+use graphoid::execution::{Executor, OutputFormat};
 use graphoid::values::Value;
 use std::env;
 use std::fs;
@@ -29,10 +30,11 @@ fn main() {
     match args[1].as_str() {
         "spec" => run_spec_command(&args[2..]),
         "repl" => run_repl(),
+        "eval" => run_eval_command(&args[2..]),
         "version" | "--version" | "-v" => println!("Graphoid v{}", VERSION),
         "help" | "--help" | "-h" => print_usage(),
-        path if path.ends_with(".gr") => run_file(path),
-        path if Path::new(path).exists() => run_file(path),
+        path if path.ends_with(".gr") => run_file(path, script_args(&args[2..])),
+        path if Path::new(path).exists() => run_file(path, script_args(&args[2..])),
         unknown => {
             eprintln!("Unknown command or file: {}", unknown);
             eprintln!("Run 'gr help' for usage information.");
@@ -46,16 +48,20 @@ fn print_usage() {
     println!();
     println!("Usage:");
     println!("  gr <file.gr>        Run a Graphoid program");
+    println!("  gr <file.gr> -- ... Run a program, passing the rest to its args()");
     println!("  gr spec [path]      Run spec files (discovers *_spec.gr)");
     println!("  gr repl             Start interactive REPL");
+    println!("  gr eval <source>    Evaluate source and print the result");
     println!("  gr version          Show version information");
     println!("  gr help             Show this help message");
     println!();
     println!("Examples:");
     println!("  gr myprogram.gr           Run a single file");
+    println!("  gr tool.gr -- in.txt -v   args() returns [\"in.txt\", \"-v\"]");
     println!("  gr spec                   Run all specs in current directory");
     println!("  gr spec tests/            Run all specs in tests/");
     println!("  gr spec tests/math_spec.gr  Run a specific spec file");
+    println!("  gr eval '[1, 2, 3].length()'  Print 3");
 }
 
 // =============================================================================
@@ -68,11 +74,7 @@ fn run_spec_command(args: &[String]) {
     let mut executor = Executor::new();
 
     // Set the path for the Graphoid spec runner
-    let setup = format!("__SPEC_PATH__ = \"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""));
-    if let Err(e) = execute_source(&setup, &mut executor) {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
-    }
+    executor.set_variable("__SPEC_PATH__", Value::string(path.to_string()));
 
     // Run the pure Graphoid spec runner
     if let Err(e) = execute_source("import \"spec_runner\"", &mut executor) {
@@ -81,20 +83,9 @@ fn run_spec_command(args: &[String]) {
     }
 
     // Check the result
-    let result_code = "__SPEC_RESULT__";
-    let mut lexer = Lexer::new(result_code);
-    if let Ok(tokens) = lexer.tokenize() {
-        let mut parser = Parser::new(tokens);
-        if let Ok(program) = parser.parse() {
-            if let Some(stmt) = program.statements.first() {
-                if let graphoid::ast::Stmt::Expression { expr, .. } = stmt {
-                    if let Ok(value) = executor.eval_expr(expr) {
-                        if let graphoid::values::ValueKind::Boolean(true) = value.kind {
-                            std::process::exit(1);
-                        }
-                    }
-                }
-            }
+    if let Some(value) = executor.get_variable("__SPEC_RESULT__") {
+        if let graphoid::values::ValueKind::Boolean(true) = value.kind {
+            std::process::exit(1);
         }
     }
 }
@@ -103,10 +94,20 @@ fn run_spec_command(args: &[String]) {
 // File Runner
 // =============================================================================
 
-fn run_file(path: &str) {
+/// The arguments after `--` in `gr file.gr -- arg1 arg2`. Anything between
+/// the path and `--` is ignored, as is everything when there is no `--`.
+fn script_args(rest: &[String]) -> Vec<String> {
+    match rest.iter().position(|arg| arg == "--") {
+        Some(index) => rest[index + 1..].to_vec(),
+        None => Vec::new(),
+    }
+}
+
+fn run_file(path: &str, script_args: Vec<String>) {
     match fs::read_to_string(path) {
         Ok(source) => {
             let mut executor = Executor::new();
+            executor.set_script_args(script_args);
 
             // Set current file for module resolution
             let abs_path = PathBuf::from(path).canonicalize()
@@ -116,7 +117,7 @@ fn run_file(path: &str) {
             match execute_source(&source, &mut executor) {
                 Ok(_) => {}
                 Err(e) => {
-                    eprintln!("Error: {}", e);
+                    report_uncaught_error(&executor, &e);
                     std::process::exit(1);
                 }
             }
@@ -167,13 +168,14 @@ fn run_repl() {
             continue;
         }
 
-        match execute_repl_line(input, &mut executor) {
-            Ok(Some(value)) => println!("{}", value),
-            Ok(None) => {}
-            Err(e) => eprintln!("Error: {}", e),
-        }
+        let result = execute_repl_line(input, &mut executor);
+        report_line_result(&executor, &result);
     }
 
+    // Blocks deferred at the REPL's top level run when the session ends
+    let result = finish_program(Ok(None), &mut executor);
+    report_line_result(&executor, &result);
+
     println!("Goodbye!");
 }
 
@@ -188,6 +190,27 @@ fn print_repl_help() {
     println!("  > x * 2");
     println!("  > [1, 2, 3]");
     println!("  > \"hello\" + \" world\"");
+    println!();
+    println!("  > configure {{ output: :json }}   JSON results for tools");
+}
+
+// =============================================================================
+// Eval
+// =============================================================================
+
+fn run_eval_command(args: &[String]) {
+    if args.is_empty() {
+        eprintln!("Usage: gr eval <source>");
+        std::process::exit(1);
+    }
+
+    let mut executor = Executor::new();
+    let result = execute_repl_line(&args.join(" "), &mut executor);
+    let result = finish_program(result, &mut executor);
+    report_line_result(&executor, &result);
+    if result.is_err() {
+        std::process::exit(1);
+    }
 }
 
 // =============================================================================
@@ -195,36 +218,92 @@ fn print_repl_help() {
 // =============================================================================
 
 fn execute_source(source: &str, executor: &mut Executor) -> Result<(), String> {
-    let mut lexer = Lexer::new(source);
-    let tokens = lexer.tokenize().map_err(|e| format!("Lexer error: {}", e))?;
+    let result = execute_repl_line(source, executor);
+    finish_program(result, executor)
+        .map(|_| ())
+        .map_err(|e| e.text())
+}
 
-    let mut parser = Parser::new(tokens);
-    let program = parser.parse().map_err(|e| format!("Parser error: {}", e))?;
+/// An error from a REPL line or `gr eval` source, with the stage it came
+/// from ("Parser" or "Runtime") for text output.
+struct LineError {
+    stage: &'static str,
+    error: GraphoidError,
+}
 
-    for stmt in &program.statements {
-        executor.eval_stmt(stmt).map_err(|e| format!("Runtime error: {}", e))?;
+impl LineError {
+    fn text(&self) -> String {
+        match &self.error {
+            GraphoidError::Abort { stack_trace, .. } => {
+                let mut report = self.error.to_string();
+                for frame in stack_trace.iter().rev() {
+                    report.push_str(&format!("\n  at {}", frame));
+                }
+                report
+            }
+            e => format!("{} error: {}", self.stage, e),
+        }
     }
+}
 
-    Ok(())
+/// Print a line's result in the active output format. With
+/// `configure { output: :json }` results are `{"type": ..., "value": ...}`
+/// and errors `{"error": {...}}`, one JSON document per line on stdout.
+fn report_line_result(executor: &Executor, result: &Result<Option<Value>, LineError>) {
+    match executor.config_stack.current().output_format {
+        OutputFormat::Text => match result {
+            Ok(Some(value)) => println!("{}", value),
+            Ok(None) => {}
+            Err(e) => report_uncaught_error(executor, &e.text()),
+        },
+        OutputFormat::Json => match result {
+            Ok(Some(value)) => println!(
+                "{}",
+                serde_json::json!({ "type": value.type_name(), "value": value.to_json() })
+            ),
+            Ok(None) => {}
+            Err(e) => println!("{}", e.error.to_json()),
+        },
+    }
 }
 
-fn execute_repl_line(source: &str, executor: &mut Executor) -> Result<Option<Value>, String> {
-    let mut lexer = Lexer::new(source);
-    let tokens = lexer.tokenize().map_err(|e| format!("Lexer error: {}", e))?;
+/// Print an uncaught error as text, with where it was raised and the
+/// "Caused by:" chain of errors attached to it.
+fn report_uncaught_error(executor: &Executor, text: &str) {
+    match executor.uncaught_error_position() {
+        Some(position) => eprintln!("Error: {} ({})", text, position),
+        None => eprintln!("Error: {}", text),
+    }
+    if let Some(cause) = executor.uncaught_error_cause() {
+        eprintln!("Caused by: {}", cause.full_chain());
+    }
+}
 
-    let mut parser = Parser::new(tokens);
-    let program = parser.parse().map_err(|e| format!("Parser error: {}", e))?;
+/// Run every statement; if the last one is an expression, return its value.
+fn execute_repl_line(source: &str, executor: &mut Executor) -> Result<Option<Value>, LineError> {
+    executor.eval_source(source).map_err(|error| {
+        let stage = match error {
+            GraphoidError::SyntaxError { .. } => "Parser",
+            _ => "Runtime",
+        };
+        LineError { stage, error }
+    })
+}
 
-    if program.statements.len() == 1 {
-        if let graphoid::ast::Stmt::Expression { expr, .. } = &program.statements[0] {
-            let value = executor.eval_expr(expr).map_err(|e| format!("Runtime error: {}", e))?;
-            return Ok(Some(value));
+/// Run the blocks deferred at the top level once a program has finished.
+/// An error the program already raised is kept over one from a block.
+fn finish_program(
+    result: Result<Option<Value>, LineError>,
+    executor: &mut Executor,
+) -> Result<Option<Value>, LineError> {
+    match result {
+        Ok(value) => executor
+            .run_top_level_defers(Ok(()))
+            .map(|_| value)
+            .map_err(|error| LineError { stage: "Runtime", error }),
+        Err(LineError { stage, error }) => {
+            let error = executor.run_top_level_defers(Err(error)).unwrap_err();
+            Err(LineError { stage, error })
         }
     }
-
-    for stmt in &program.statements {
-        executor.eval_stmt(stmt).map_err(|e| format!("Runtime error: {}", e))?;
-    }
-
-    Ok(None)
 }
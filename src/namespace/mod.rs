@@ -9,6 +9,7 @@
 //! operations while maintaining graph semantics.
 
 use std::collections::HashMap;
+use crate::ast::TypeAnnotation;
 use crate::error::{GraphoidError, Result};
 use crate::values::Value;
 
@@ -42,6 +43,12 @@ struct Scope {
     /// Variables defined in this scope (name -> value)
     /// Each entry represents a variable node with a "binds_to" edge to its value
     variables: HashMap<String, Value>,
+    /// Names in `variables` declared with `const`, mapped to whether they
+    /// were declared by the running code (false once a function call has
+    /// inherited them, so the function body may shadow them)
+    constants: HashMap<String, bool>,
+    /// Type annotations of names in `variables` declared with one (`num x = 5`)
+    types: HashMap<String, TypeAnnotation>,
     /// Parent scope ID (represents the "parent" edge to enclosing scope)
     parent: Option<ScopeId>,
 }
@@ -52,6 +59,8 @@ impl Scope {
         Scope {
             scope_type,
             variables: HashMap::new(),
+            constants: HashMap::new(),
+            types: HashMap::new(),
             parent,
         }
     }
@@ -136,10 +145,82 @@ impl NamespaceGraph {
     /// This creates a variable node with a "binds_to" edge to the value.
     pub fn define(&mut self, name: String, value: Value) {
         if let Some(scope) = self.scopes.get_mut(&self.current_scope_id) {
+            scope.constants.remove(&name);
+            scope.types.remove(&name);
             scope.variables.insert(name, value);
         }
     }
 
+    /// Defines a variable in the current scope along with its declared type
+    pub fn define_typed(&mut self, name: String, value: Value, annotation: TypeAnnotation) {
+        if let Some(scope) = self.scopes.get_mut(&self.current_scope_id) {
+            scope.constants.remove(&name);
+            scope.types.insert(name.clone(), annotation);
+            scope.variables.insert(name, value);
+        }
+    }
+
+    /// The type annotation of the binding a name resolves to, if it was
+    /// declared with one
+    pub fn declared_type(&self, name: &str) -> Option<&TypeAnnotation> {
+        let mut scope_id = self.current_scope_id;
+        while let Some(scope) = self.scopes.get(&scope_id) {
+            if scope.variables.contains_key(name) {
+                return scope.types.get(name);
+            }
+            scope_id = scope.parent?;
+        }
+        None
+    }
+
+    /// Defines a constant in the current scope
+    ///
+    /// Inner scopes may shadow it with their own binding, but `set` on a
+    /// name that resolves to this binding fails.
+    pub fn define_const(&mut self, name: String, value: Value) {
+        if let Some(scope) = self.scopes.get_mut(&self.current_scope_id) {
+            scope.constants.insert(name.clone(), true);
+            scope.types.remove(&name);
+            scope.variables.insert(name, value);
+        }
+    }
+
+    /// Checks if a name resolves to a constant binding
+    pub fn is_const(&self, name: &str) -> bool {
+        let mut scope_id = self.current_scope_id;
+        while let Some(scope) = self.scopes.get(&scope_id) {
+            if scope.variables.contains_key(name) {
+                return scope.constants.contains_key(name);
+            }
+            match scope.parent {
+                Some(parent_id) => scope_id = parent_id,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// Checks if a name is a constant declared in the current scope by the
+    /// running code, so that declaring it again would replace the constant
+    pub fn is_const_in_current_scope(&self, name: &str) -> bool {
+        self.scopes
+            .get(&self.current_scope_id)
+            .and_then(|s| s.constants.get(name))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Marks every constant as inherited. Function calls run in a copy of
+    /// the captured namespace; this lets the body shadow an outer constant
+    /// with its own declaration while assignments to it still fail.
+    pub fn inherit_constants(&mut self) {
+        for scope in self.scopes.values_mut() {
+            for declared_here in scope.constants.values_mut() {
+                *declared_here = false;
+            }
+        }
+    }
+
     /// Gets a variable value by name
     ///
     /// Traverses the scope chain (following parent edges) until the variable
@@ -180,6 +261,11 @@ impl NamespaceGraph {
             if has_var {
                 // Found it - update the variable
                 if let Some(scope) = self.scopes.get_mut(&scope_id) {
+                    if scope.constants.contains_key(name) {
+                        return Err(GraphoidError::runtime(format!(
+                            "cannot reassign constant {}", name
+                        )));
+                    }
                     scope.variables.insert(name.to_string(), value);
                 }
                 return Ok(());
@@ -244,6 +330,7 @@ impl NamespaceGraph {
     /// Returns true if the variable was found and removed, false otherwise.
     pub fn remove_variable(&mut self, name: &str) -> bool {
         if let Some(scope) = self.scopes.get_mut(&self.current_scope_id) {
+            scope.types.remove(name);
             scope.variables.remove(name).is_some()
         } else {
             false
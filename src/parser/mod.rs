@@ -5,7 +5,7 @@
 
 use crate::ast::{
     Argument, AssignmentTarget, BinaryOp, Expr, GraphMethod, GraphProperty, GraphRule,
-    LiteralValue, Parameter, Pattern, PatternClause, Program, Stmt, TypeAnnotation,
+    LiteralValue, MapKey, Parameter, Pattern, PatternClause, Program, Stmt, TypeAnnotation,
     UnaryOp,
 };
 use std::collections::HashMap;
@@ -80,10 +80,20 @@ impl Parser {
             }
         }
 
+        // `const NAME = value`. `const` is only a keyword in this position, so
+        // the constants module alias (`const.pi`) keeps working.
+        if self.is_const_declaration() {
+            self.advance();
+            let result = self.const_declaration(is_private);
+            self.match_token(&TokenType::Newline);
+            return result;
+        }
+
         // Check for type annotations or keywords
-        // BUT: If ListType or StringType is followed by dot, it's a static method call, not a declaration
+        // BUT: If ListType, StringType or GraphType is followed by dot, it's a static method call, not a declaration
         let is_list_static_call = self.check(&TokenType::ListType) && self.check_next(&TokenType::Dot);
         let is_string_static_call = self.check(&TokenType::StringType) && self.check_next(&TokenType::Dot);
+        let is_graph_static_call = self.check(&TokenType::GraphType) && self.check_next(&TokenType::Dot);
 
         // Check for named graph declaration: graph Name { }
         // GraphType followed by Identifier (not { or from or () is a named declaration
@@ -91,7 +101,7 @@ impl Parser {
 
         let result = if is_named_graph_decl {
             self.graph_declaration()
-        } else if !is_list_static_call && !is_string_static_call && (
+        } else if !is_list_static_call && !is_string_static_call && !is_graph_static_call && (
             self.check(&TokenType::NumType)
             || self.check(&TokenType::BigNumType)  // Phase 1B
             || self.check(&TokenType::StringType)
@@ -143,6 +153,11 @@ impl Parser {
             self.try_catch_statement()
         } else if self.match_token(&TokenType::Spawn) {
             self.spawn_statement()
+        } else if self.match_token(&TokenType::Defer) {
+            self.defer_statement()
+        } else if self.is_assert_statement() {
+            self.advance();
+            self.assert_statement()
         } else {
             // Try to parse as assignment or expression
             self.assignment_or_expression()
@@ -164,6 +179,31 @@ impl Parser {
         self.variable_declaration_common(position, Some(type_annotation), is_private)
     }
 
+    fn is_const_declaration(&self) -> bool {
+        matches!(&self.peek().token_type, TokenType::Identifier(word) if word == "const")
+            && matches!(
+                self.tokens.get(self.current + 1).map(|t| &t.token_type),
+                Some(TokenType::Identifier(_))
+            )
+            && matches!(
+                self.tokens.get(self.current + 2).map(|t| &t.token_type),
+                Some(TokenType::Equal)
+            )
+    }
+
+    /// Parse `NAME = value` after the `const` keyword
+    fn const_declaration(&mut self, is_private: bool) -> Result<Stmt> {
+        let position = self.previous_position();
+        // is_const_declaration() already checked for `NAME =`
+        let name = match &self.advance().token_type {
+            TokenType::Identifier(id) => id.clone(),
+            _ => unreachable!("const declaration without a name"),
+        };
+        self.advance();
+        let value = self.expression()?;
+        Ok(Stmt::ConstDecl { name, value, is_private, position })
+    }
+
     /// Parse a private variable declaration without an explicit type annotation
     /// e.g., priv SECRET = "value"
     fn priv_variable_declaration_without_type(&mut self) -> Result<Stmt> {
@@ -239,7 +279,7 @@ impl Parser {
         // See: dev_docs/NO_GENERICS_POLICY.md
         let constraint = if self.match_token(&TokenType::Less) {
             // Type constraints only allowed on built-in collections
-            if base_type != "list" && base_type != "hash" && base_type != "tree" && base_type != "graph" {
+            if base_type != "list" && base_type != "map" && base_type != "hash" && base_type != "tree" && base_type != "graph" {
                 return Err(GraphoidError::SyntaxError {
                     message: format!("Type parameters only allowed on built-in collections (list, hash, tree, graph), not '{}'", base_type),
                     position: self.peek().position(),
@@ -609,6 +649,20 @@ impl Parser {
             });
         };
 
+        // Optional further names: for i, x in ...
+        let mut unpack = Vec::new();
+        while self.match_token(&TokenType::Comma) {
+            if let TokenType::Identifier(id) = &self.peek().token_type {
+                unpack.push(id.clone());
+                self.advance();
+            } else {
+                return Err(GraphoidError::SyntaxError {
+                    message: "Expected variable name after ',' in for loop".to_string(),
+                    position: self.peek().position(),
+                });
+            }
+        }
+
         // Expect 'in'
         if !self.match_token(&TokenType::In) {
             return Err(GraphoidError::SyntaxError {
@@ -641,6 +695,7 @@ impl Parser {
 
         Ok(Stmt::For {
             variable,
+            unpack,
             iterable,
             body,
             position,
@@ -818,6 +873,7 @@ impl Parser {
             // Mark the statement as private
             match &mut stmt {
                 Stmt::VariableDecl { is_private, .. } => *is_private = true,
+                Stmt::ConstDecl { is_private, .. } => *is_private = true,
                 Stmt::FunctionDecl { is_private, .. } => *is_private = true,
                 _ => {} // Other statement types don't have is_private
             }
@@ -897,6 +953,12 @@ impl Parser {
         let graph_type = if self.match_token(&TokenType::LeftParen) {
             // Expect a symbol like :dag, :tree (tokenized as Symbol)
             let gtype = if let TokenType::Symbol(s) = &self.peek().token_type {
+                if !crate::values::graph::GRAPH_TYPE_NAMES.contains(&s.as_str()) {
+                    return Err(GraphoidError::SyntaxError {
+                        message: crate::values::graph::unknown_graph_type_message(s),
+                        position: self.peek().position(),
+                    });
+                }
                 let t = s.clone();
                 self.advance();
                 Some(t)
@@ -1202,11 +1264,15 @@ impl Parser {
     ) -> Result<GraphMethod> {
         let position = self.peek().position();
 
-        // Get method name
+        // Get method name (keywords are allowed, matching `obj.from(...)` call syntax)
+        let lexeme = self.peek().lexeme.clone();
         let name = if let TokenType::Identifier(id) = &self.peek().token_type {
             let n = id.clone();
             self.advance();
             n
+        } else if !lexeme.is_empty() && lexeme.chars().all(|c| c.is_alphabetic() || c == '_') {
+            self.advance();
+            lexeme
         } else {
             return Err(GraphoidError::SyntaxError {
                 message: "Expected method name".to_string(),
@@ -1512,6 +1578,112 @@ impl Parser {
         Ok(Stmt::Spawn { body, position })
     }
 
+    /// Parse defer statement — `defer { block }`
+    fn defer_statement(&mut self) -> Result<Stmt> {
+        let position = self.previous_position();
+
+        if !self.match_token(&TokenType::LeftBrace) {
+            return Err(GraphoidError::SyntaxError {
+                message: "Expected '{' after 'defer'".to_string(),
+                position: self.peek().position(),
+            });
+        }
+
+        let body = self.block()?;
+
+        if !self.match_token(&TokenType::RightBrace) {
+            return Err(GraphoidError::SyntaxError {
+                message: "Expected '}' after defer body".to_string(),
+                position: self.peek().position(),
+            });
+        }
+
+        Ok(Stmt::Defer { body, position })
+    }
+
+    /// `assert` is not a keyword. It stays an identifier before `=` or `.`,
+    /// and in a call written `assert(...)` with no `, message` after it
+    /// (gspec defines an `assert` function). Anything else starts an assert
+    /// statement, including `assert !flag` and `assert (a == b), "message"`.
+    fn is_assert_statement(&self) -> bool {
+        let name = self.peek();
+        if !matches!(&name.token_type, TokenType::Identifier(n) if n == "assert") {
+            return false;
+        }
+        let next = match self.tokens.get(self.current + 1) {
+            Some(token) => token,
+            None => return false,
+        };
+        match next.token_type {
+            TokenType::Equal | TokenType::Dot | TokenType::Newline | TokenType::Eof => false,
+            TokenType::LeftParen => {
+                let is_call = next.line == name.line
+                    && next.column == name.column + name.lexeme.chars().count();
+                !is_call || matches!(self.token_after_parens(self.current + 1), Some(TokenType::Comma))
+            }
+            _ => true,
+        }
+    }
+
+    /// The token type following the parenthesized group that opens at `open`.
+    fn token_after_parens(&self, open: usize) -> Option<&TokenType> {
+        let mut depth = 0usize;
+        for (offset, token) in self.tokens[open..].iter().enumerate() {
+            match token.token_type {
+                TokenType::LeftParen => depth += 1,
+                TokenType::RightParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return self.tokens.get(open + offset + 1).map(|t| &t.token_type);
+                    }
+                }
+                TokenType::Eof => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Parse assert statement — `assert condition` or `assert condition, "message"`
+    fn assert_statement(&mut self) -> Result<Stmt> {
+        let position = self.previous_position();
+
+        let start = self.current;
+        let condition = self.expression()?;
+        let source = self.source_text(start, self.current);
+
+        let message = if self.match_token(&TokenType::Comma) {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        Ok(Stmt::Assert { condition, message, source, position })
+    }
+
+    /// Approximate source text of the tokens in `start..end`, for messages.
+    fn source_text(&self, start: usize, end: usize) -> String {
+        let mut text = String::new();
+        let mut previous: Option<&TokenType> = None;
+        for token in &self.tokens[start..end] {
+            if matches!(token.token_type, TokenType::Newline) {
+                continue;
+            }
+            let attach = matches!(
+                token.token_type,
+                TokenType::Dot | TokenType::Comma | TokenType::RightParen | TokenType::RightBracket
+            ) || (matches!(token.token_type, TokenType::LeftParen | TokenType::LeftBracket)
+                && matches!(previous, Some(TokenType::Identifier(_)) | Some(TokenType::RightParen) | Some(TokenType::RightBracket)))
+                || matches!(previous, Some(TokenType::Dot) | Some(TokenType::LeftParen) | Some(TokenType::LeftBracket));
+            if !text.is_empty() && !attach {
+                text.push(' ');
+            }
+            text.push_str(&token.lexeme);
+            previous = Some(&token.token_type);
+        }
+        text
+    }
+
     fn try_catch_statement(&mut self) -> Result<Stmt> {
         use crate::ast::CatchClause;
         let position = self.previous_position();
@@ -2102,7 +2274,7 @@ impl Parser {
 
     fn conditional_expression(&mut self) -> Result<Expr> {
         // Parse the base expression
-        let expr = self.or_expression()?;
+        let expr = self.pipe_expression()?;
 
         // Check for inline conditional (if-then-else or suffix if/unless)
         if self.check(&TokenType::If) || self.check(&TokenType::Unless) {
@@ -2157,6 +2329,55 @@ impl Parser {
         Ok(expr)
     }
 
+    /// Pipe operator: `value |> f(args)` desugars to `f(value, args)`.
+    /// Left-associative and lower precedence than `or`, so `xs |> f() |> g()`
+    /// reads top-down. A pipeline may continue on the next line with a leading `|>`.
+    fn pipe_expression(&mut self) -> Result<Expr> {
+        let mut expr = self.or_expression()?;
+
+        loop {
+            let checkpoint = self.current;
+            while self.match_token(&TokenType::Newline) {}
+            if !self.match_token(&TokenType::PipeGreater) {
+                self.current = checkpoint;
+                break;
+            }
+            let position = self.previous_position();
+            while self.match_token(&TokenType::Newline) {}
+            let target = self.or_expression()?;
+            expr = Self::desugar_pipe(expr, target, position)?;
+        }
+
+        Ok(expr)
+    }
+
+    /// Feed `value` as the first argument of the pipe target.
+    fn desugar_pipe(value: Expr, target: Expr, position: SourcePosition) -> Result<Expr> {
+        let piped = Argument::Positional { expr: value, mutable: false };
+        match target {
+            Expr::Call { callee, mut args, position } => {
+                args.insert(0, piped);
+                Ok(Expr::Call { callee, args, position })
+            }
+            Expr::MethodCall { object, method, mut args, position } => {
+                args.insert(0, piped);
+                Ok(Expr::MethodCall { object, method, args, position })
+            }
+            callee @ (Expr::Variable { .. }
+            | Expr::Lambda { .. }
+            | Expr::PropertyAccess { .. }
+            | Expr::Index { .. }) => Ok(Expr::Call {
+                callee: Box::new(callee),
+                args: vec![piped],
+                position,
+            }),
+            _ => Err(GraphoidError::SyntaxError {
+                message: "Right side of '|>' must be a function or call".to_string(),
+                position,
+            }),
+        }
+    }
+
     fn or_expression(&mut self) -> Result<Expr> {
         let mut expr = self.and_expression()?;
 
@@ -2443,7 +2664,8 @@ impl Parser {
             });
         }
 
-        if self.match_token(&TokenType::Not) {
+        // `!` is the symbolic spelling of `not`
+        if self.match_token(&TokenType::Not) || self.match_token(&TokenType::Bang) {
             let position = self.previous_position();
             let operand = self.unary()?;
             return Ok(Expr::Unary {
@@ -2636,8 +2858,11 @@ impl Parser {
                         self.advance();
 
                         // Check for ! suffix (mutating method convention)
+                        // or ? suffix (predicate method convention)
                         if self.match_token(&TokenType::Bang) {
                             m.push('!');
+                        } else if self.match_token(&TokenType::Question) {
+                            m.push('?');
                         }
 
                         m
@@ -2649,9 +2874,11 @@ impl Parser {
                         if lexeme.chars().all(|c| c.is_alphabetic() || c == '_') && !lexeme.is_empty() {
                             self.advance();
 
-                            // Check for ! suffix (mutating method convention)
+                            // Check for ! or ? suffix (mutating / predicate method convention)
                             if self.match_token(&TokenType::Bang) {
                                 lexeme.push('!');
+                            } else if self.match_token(&TokenType::Question) {
+                                lexeme.push('?');
                             }
 
                             lexeme
@@ -2914,6 +3141,21 @@ impl Parser {
     fn primary(&mut self) -> Result<Expr> {
         let position = self.peek().position();
 
+        // try/catch in expression context: `x = try { risky() } catch { default }`
+        // evaluates to the try body's last value, or that of the catch that ran
+        if self.match_token(&TokenType::Try) {
+            let try_stmt = self.try_catch_statement()?;
+            // The statement parser skips newlines while looking for more
+            // clauses; give them back so the expression ends at its last block
+            while matches!(self.previous().token_type, TokenType::Newline) {
+                self.current -= 1;
+            }
+            return Ok(Expr::Block {
+                statements: vec![try_stmt],
+                position,
+            });
+        }
+
         // Phase 19.3: Spawn actor expression — `spawn Counter{}` in expression context
         if self.match_token(&TokenType::Spawn) {
             let actor_expr = self.expression()?;
@@ -2925,9 +3167,21 @@ impl Parser {
 
         // Raise expressions
         if self.match_token(&TokenType::Raise) {
-            let error_expr = Box::new(self.expression()?);
+            let mut error_expr = self.expression()?;
+            // `raise err caused_by original` is sugar for `raise err.caused_by(original)`
+            if matches!(&self.peek().token_type, TokenType::Identifier(name) if name == "caused_by") {
+                let cause_position = self.peek().position();
+                self.advance();
+                let cause = self.expression()?;
+                error_expr = Expr::MethodCall {
+                    object: Box::new(error_expr),
+                    method: "caused_by".to_string(),
+                    args: vec![Argument::Positional { expr: cause, mutable: false }],
+                    position: cause_position,
+                };
+            }
             return Ok(Expr::Raise {
-                error: error_expr,
+                error: Box::new(error_expr),
                 position,
             });
         }
@@ -2937,7 +3191,7 @@ impl Parser {
             return self.match_expression(position);
         }
 
-        // Super method calls: super.method(args)
+        // Super method calls and property access: super.method(args), super.name
         if self.match_token(&TokenType::Super) {
             if !self.match_token(&TokenType::Dot) {
                 return Err(GraphoidError::SyntaxError {
@@ -2953,16 +3207,16 @@ impl Parser {
                 name
             } else {
                 return Err(GraphoidError::SyntaxError {
-                    message: "Expected method name after 'super.'".to_string(),
+                    message: "Expected method or property name after 'super.'".to_string(),
                     position: self.peek().position(),
                 });
             };
 
-            // Parse arguments (required for super calls)
+            // Without arguments this reads the parent's property or getter
             if !self.match_token(&TokenType::LeftParen) {
-                return Err(GraphoidError::SyntaxError {
-                    message: "Expected '(' after super method name".to_string(),
-                    position: self.peek().position(),
+                return Ok(Expr::SuperPropertyAccess {
+                    property: method,
+                    position,
                 });
             }
 
@@ -3036,8 +3290,13 @@ impl Parser {
 
         // Identifiers (variables)
         if let TokenType::Identifier(id) = &self.peek().token_type {
-            let name = id.clone();
+            let mut name = id.clone();
             self.advance();
+            // Predicate function call: can_add?(a, b)
+            if self.check(&TokenType::Question) && self.check_next(&TokenType::LeftParen) {
+                self.advance();
+                name.push('?');
+            }
             return Ok(Expr::Variable { name, position });
         }
 
@@ -3097,7 +3356,7 @@ impl Parser {
                     // Skip newlines before each element
                     self.skip_newlines();
 
-                    elements.push(self.expression()?);
+                    elements.push(self.list_element()?);
 
                     // Skip newlines before comma check
                     self.skip_newlines();
@@ -3133,7 +3392,7 @@ impl Parser {
                     // Skip newlines before each element
                     self.skip_newlines();
 
-                    elements.push(self.expression()?);
+                    elements.push(self.list_element()?);
 
                     // Skip newlines before comma check
                     self.skip_newlines();
@@ -3176,7 +3435,15 @@ impl Parser {
         }
 
         // Graphs: graph { type: :directed } or graph from Parent {}
+        // OR static method call on graph type: graph.from_edge_list()
         if self.match_token(&TokenType::GraphType) {
+            if self.check(&TokenType::Dot) {
+                return Ok(Expr::Variable {
+                    name: "graph".to_string(),
+                    position,
+                });
+            }
+
             // Check for inheritance: graph from Parent {}
             let parent = if self.match_token(&TokenType::From) {
                 // Parse the parent expression (e.g., ParentGraph, module.Graph)
@@ -3290,33 +3557,7 @@ impl Parser {
                     // Skip newlines before each entry
                     while self.match_token(&TokenType::Newline) {}
 
-                    // Parse key (must be string or identifier)
-                    let key = if let TokenType::String(s) = &self.peek().token_type {
-                        let k = s.clone();
-                        self.advance();
-                        k
-                    } else if let TokenType::Identifier(id) = &self.peek().token_type {
-                        let k = id.clone();
-                        self.advance();
-                        k
-                    } else {
-                        return Err(GraphoidError::SyntaxError {
-                            message: "Expected string or identifier as map key".to_string(),
-                            position: self.peek().position(),
-                        });
-                    };
-
-                    // Expect ':'
-                    if !self.match_token(&TokenType::Colon) {
-                        return Err(GraphoidError::SyntaxError {
-                            message: "Expected ':' after map key".to_string(),
-                            position: self.peek().position(),
-                        });
-                    }
-
-                    // Parse value - support lambdas
-                    let value = self.lambda_or_expression()?;
-                    entries.push((key, value));
+                    entries.push(self.map_entry()?);
 
                     if !self.match_token(&TokenType::Comma) {
                         break;
@@ -3425,33 +3666,7 @@ impl Parser {
                     // Skip newlines before each entry
                     while self.match_token(&TokenType::Newline) {}
 
-                    // Parse key (must be string or identifier)
-                    let key = if let TokenType::String(s) = &self.peek().token_type {
-                        let k = s.clone();
-                        self.advance();
-                        k
-                    } else if let TokenType::Identifier(id) = &self.peek().token_type {
-                        let k = id.clone();
-                        self.advance();
-                        k
-                    } else {
-                        return Err(GraphoidError::SyntaxError {
-                            message: "Expected string or identifier as map key".to_string(),
-                            position: self.peek().position(),
-                        });
-                    };
-
-                    // Expect ':'
-                    if !self.match_token(&TokenType::Colon) {
-                        return Err(GraphoidError::SyntaxError {
-                            message: "Expected ':' after map key".to_string(),
-                            position: self.peek().position(),
-                        });
-                    }
-
-                    // Parse value - support lambdas
-                    let value = self.lambda_or_expression()?;
-                    entries.push((key, value));
+                    entries.push(self.map_entry()?);
 
                     if !self.match_token(&TokenType::Comma) {
                         break;
@@ -3550,6 +3765,67 @@ impl Parser {
         while self.match_token(&TokenType::Newline) {}
     }
 
+    /// Parse a list literal element: an expression, or `...expr` to splice
+    /// in the elements of another list
+    fn list_element(&mut self) -> Result<Expr> {
+        if self.match_token(&TokenType::DotDotDot) {
+            let position = self.previous_position();
+            let expr = self.expression()?;
+            return Ok(Expr::Spread { expr: Box::new(expr), position });
+        }
+        self.expression()
+    }
+
+    /// Parse a map literal entry: `key: value`, or `...expr` to copy in the
+    /// entries of another map
+    fn map_entry(&mut self) -> Result<(MapKey, Expr)> {
+        if self.match_token(&TokenType::DotDotDot) {
+            return Ok((MapKey::Spread, self.expression()?));
+        }
+
+        let key = self.map_key()?;
+
+        // Expect ':'
+        if !self.match_token(&TokenType::Colon) {
+            return Err(GraphoidError::SyntaxError {
+                message: "Expected ':' after map key".to_string(),
+                position: self.peek().position(),
+            });
+        }
+
+        // Parse value - support lambdas
+        let value = self.lambda_or_expression()?;
+        Ok((key, value))
+    }
+
+    /// Parse a map literal key: a string, an identifier, or a computed `[expr]`
+    fn map_key(&mut self) -> Result<MapKey> {
+        if let TokenType::String(s) = &self.peek().token_type {
+            let k = s.clone();
+            self.advance();
+            return Ok(MapKey::Static(k));
+        }
+        if let TokenType::Identifier(id) = &self.peek().token_type {
+            let k = id.clone();
+            self.advance();
+            return Ok(MapKey::Static(k));
+        }
+        if self.match_token(&TokenType::LeftBracket) {
+            let key_expr = self.expression()?;
+            if !self.match_token(&TokenType::RightBracket) {
+                return Err(GraphoidError::SyntaxError {
+                    message: "Expected ']' after computed map key".to_string(),
+                    position: self.peek().position(),
+                });
+            }
+            return Ok(MapKey::Computed(key_expr));
+        }
+        Err(GraphoidError::SyntaxError {
+            message: "Expected string, identifier or [expression] as map key".to_string(),
+            position: self.peek().position(),
+        })
+    }
+
     /// Parses key-value entries for graph/tree/map config
     fn parse_config_entries(&mut self) -> Result<Vec<(String, Expr)>> {
         let mut entries = Vec::new();
@@ -31,6 +31,15 @@ impl RandomModule {
         });
     }
 
+    /// Draw from the seeded generator if `seed()` was called, otherwise from
+    /// the thread generator.
+    pub(crate) fn next_f64() -> f64 {
+        SEEDED_RNG.with(|rng| match rng.borrow_mut().as_mut() {
+            Some(seeded) => seeded.gen(),
+            None => Self::get_thread_rng().gen(),
+        })
+    }
+
     fn get_seeded_rng() -> Result<StdRng> {
         SEEDED_RNG.with(|rng| {
             rng.borrow().clone().ok_or_else(|| GraphoidError::runtime(
@@ -55,6 +64,7 @@ impl NativeModule for RandomModule {
         functions.insert("randint".to_string(), randint as NativeFunction);
         functions.insert("uniform".to_string(), uniform as NativeFunction);
         functions.insert("choice".to_string(), choice as NativeFunction);
+        functions.insert("weighted_choice".to_string(), weighted_choice as NativeFunction);
         functions.insert("shuffle".to_string(), shuffle as NativeFunction);
         functions.insert("sample".to_string(), sample as NativeFunction);
         functions.insert("normal".to_string(), normal as NativeFunction);
@@ -138,6 +148,69 @@ fn choice(args: &[Value]) -> Result<Value> {
     }
 }
 
+/// Pick an item with probability proportional to its weight.
+///
+/// Accepts either `(items, weights)` or a single map of item to weight. Draws
+/// from the seeded generator once `seed()` has been called, so simulations can
+/// be replayed.
+fn weighted_choice(args: &[Value]) -> Result<Value> {
+    let (items, weights) = match args {
+        [items, weights] => match (&items.kind, &weights.kind) {
+            (ValueKind::List(items), ValueKind::List(weights)) => (items.to_vec(), weights.to_vec()),
+            (ValueKind::List(_), _) => return Err(GraphoidError::type_error("list", weights.type_name())),
+            _ => return Err(GraphoidError::type_error("list", items.type_name())),
+        },
+        [choices] => match &choices.kind {
+            ValueKind::Map(hash) => {
+                let keys = hash.keys();
+                let weights = keys.iter().filter_map(|k| hash.get(k).cloned()).collect();
+                (keys.into_iter().map(Value::string).collect(), weights)
+            }
+            _ => return Err(GraphoidError::type_error("map", choices.type_name())),
+        },
+        _ => return Err(GraphoidError::runtime(
+            "weighted_choice() requires a list of items and a list of weights, or a map of item to weight".to_string()
+        )),
+    };
+
+    if items.len() != weights.len() {
+        return Err(GraphoidError::runtime(format!(
+            "ValueError: weighted_choice() got {} items but {} weights", items.len(), weights.len()
+        )));
+    }
+
+    let mut numbers = Vec::with_capacity(weights.len());
+    for weight in &weights {
+        match &weight.kind {
+            ValueKind::Number(n) if *n >= 0.0 && n.is_finite() => numbers.push(*n),
+            ValueKind::Number(n) => return Err(GraphoidError::runtime(format!(
+                "ValueError: weighted_choice() weights must be non-negative, got {}", n
+            ))),
+            _ => return Err(GraphoidError::runtime(format!(
+                "TypeError: weighted_choice() weights must be numbers, got {}", weight.type_name()
+            ))),
+        }
+    }
+
+    let total: f64 = numbers.iter().sum();
+    if total <= 0.0 {
+        return Err(GraphoidError::runtime(
+            "ValueError: weighted_choice() needs at least one positive weight".to_string()
+        ));
+    }
+
+    let mut target = RandomModule::next_f64() * total;
+    let mut chosen = numbers.iter().rposition(|w| *w > 0.0).unwrap_or(0);
+    for (index, weight) in numbers.iter().enumerate() {
+        if *weight > 0.0 && target < *weight {
+            chosen = index;
+            break;
+        }
+        target -= weight;
+    }
+    Ok(items[chosen].clone())
+}
+
 fn shuffle(args: &[Value]) -> Result<Value> {
     if args.is_empty() {
         return Err(GraphoidError::runtime("shuffle() requires a list argument".to_string()));
@@ -6,7 +6,8 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::rc::Rc;
-use super::{Value, ValueKind, PatternNode, PatternEdge, PatternPath, Function, List, ComparisonLayer};
+use indexmap::IndexMap;
+use super::{Value, ValueKind, PatternNode, PatternEdge, PatternPath, Function, List, ComparisonLayer, PersistentMap};
 use crate::graph::rules::{Rule, RuleContext, GraphOperation, RuleSpec, RuleInstance, RuleSeverity};
 use crate::graph::rulesets::get_ruleset_rules;
 use crate::error::GraphoidError;
@@ -25,6 +26,21 @@ pub enum GraphType {
     Undirected,
 }
 
+/// Graph type symbols accepted by `graph Name(:type)` and `graph { type: ... }`
+pub const GRAPH_TYPE_NAMES: &[&str] = &["directed", "undirected", "dag", "tree", "multigraph"];
+
+/// Id of the synthetic node `to_tree(root, forest)` hangs disconnected parts off
+pub const FOREST_ROOT_ID: &str = "__forest_root__";
+
+/// Nodes `show()` lists before folding the rest into a "+N more" line
+pub const SHOW_NODE_LIMIT: usize = 20;
+
+/// Error message for a graph type symbol that isn't in `GRAPH_TYPE_NAMES`
+pub fn unknown_graph_type_message(name: &str) -> String {
+    let valid: Vec<String> = GRAPH_TYPE_NAMES.iter().map(|t| format!(":{}", t)).collect();
+    format!("Unknown graph type ':{}' (valid types: {})", name, valid.join(", "))
+}
+
 /// Policy for handling orphaned nodes (nodes with no edges)
 #[derive(Debug, Clone, PartialEq)]
 pub enum OrphanPolicy {
@@ -79,11 +95,11 @@ pub struct GraphNode {
     pub node_type: Option<String>,
     /// Node properties (for property-based indexing)
     pub properties: HashMap<String, Value>,
-    /// Outgoing edges (neighbor_id -> edge_info)
-    pub neighbors: HashMap<String, EdgeInfo>,
-    /// Incoming edges (predecessor_id -> edge_info)
+    /// Outgoing edges (neighbor_id -> edge_info), in the order they were added
+    pub neighbors: IndexMap<String, EdgeInfo>,
+    /// Incoming edges (predecessor_id -> edge_info), in the order they were added
     /// Maintained automatically when edges are added/removed
-    pub predecessors: HashMap<String, EdgeInfo>,
+    pub predecessors: IndexMap<String, EdgeInfo>,
 }
 
 /// Information about an edge
@@ -206,6 +222,205 @@ enum ValidationResult {
     },
 }
 
+/// Node storage for a graph, keyed by node ID and kept in insertion order
+///
+/// Graphs own their nodes in an `IndexMap`. The backing graph of a `List`
+/// uses a `PersistentMap` instead, so list copies share every node until one
+/// side changes it; see `persistent_map.rs` for the trade-off.
+#[derive(Debug, Clone)]
+pub enum NodeStore {
+    /// Plain owned storage; cloning copies every node
+    Owned(IndexMap<String, GraphNode>),
+    /// Structurally shared storage; cloning is O(1)
+    Shared(PersistentMap<String, GraphNode>),
+}
+
+impl Default for NodeStore {
+    fn default() -> Self {
+        NodeStore::Owned(IndexMap::new())
+    }
+}
+
+impl NodeStore {
+    /// Empty owned storage
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty structurally shared storage
+    pub fn shared() -> Self {
+        NodeStore::Shared(PersistentMap::new())
+    }
+
+    /// Whether clones of this storage share nodes
+    pub fn is_shared(&self) -> bool {
+        matches!(self, NodeStore::Shared(_))
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            NodeStore::Owned(nodes) => nodes.len(),
+            NodeStore::Shared(nodes) => nodes.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every node, keeping the kind of storage
+    pub fn clear(&mut self) {
+        match self {
+            NodeStore::Owned(nodes) => nodes.clear(),
+            NodeStore::Shared(nodes) => nodes.clear(),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&GraphNode> {
+        match self {
+            NodeStore::Owned(nodes) => nodes.get(id),
+            NodeStore::Shared(nodes) => nodes.get(id),
+        }
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut GraphNode> {
+        match self {
+            NodeStore::Owned(nodes) => nodes.get_mut(id),
+            NodeStore::Shared(nodes) => nodes.get_mut(id),
+        }
+    }
+
+    pub fn contains_key(&self, id: &str) -> bool {
+        match self {
+            NodeStore::Owned(nodes) => nodes.contains_key(id),
+            NodeStore::Shared(nodes) => nodes.contains_key(id),
+        }
+    }
+
+    /// Insert a node, returning the previous node with that ID. Replacing a
+    /// node keeps its position.
+    pub fn insert(&mut self, id: String, node: GraphNode) -> Option<GraphNode> {
+        match self {
+            NodeStore::Owned(nodes) => nodes.insert(id, node),
+            NodeStore::Shared(nodes) => nodes.insert(id, node),
+        }
+    }
+
+    /// Remove a node, keeping the order of the others
+    pub fn remove(&mut self, id: &str) -> Option<GraphNode> {
+        match self {
+            NodeStore::Owned(nodes) => nodes.shift_remove(id),
+            NodeStore::Shared(nodes) => nodes.remove(id),
+        }
+    }
+
+    /// Iterate over nodes in insertion order
+    pub fn iter(&self) -> NodeIter<'_> {
+        match self {
+            NodeStore::Owned(nodes) => NodeIter::Owned(nodes.iter()),
+            NodeStore::Shared(nodes) => NodeIter::Shared(nodes.iter()),
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.iter().map(|(id, _)| id)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &GraphNode> {
+        self.iter().map(|(_, node)| node)
+    }
+
+    /// Iterate over nodes mutably. Shared storage is unshared only along the
+    /// paths the iterator visits, and its order is unspecified.
+    pub fn values_mut(&mut self) -> NodeValuesMut<'_> {
+        match self {
+            NodeStore::Owned(nodes) => NodeValuesMut::Owned(nodes.values_mut()),
+            NodeStore::Shared(nodes) => NodeValuesMut::Shared(nodes.iter_mut()),
+        }
+    }
+
+    /// Keep only the nodes for which the predicate returns true
+    pub fn retain<F: FnMut(&String, &GraphNode) -> bool>(&mut self, mut keep: F) {
+        match self {
+            NodeStore::Owned(nodes) => nodes.retain(|id, node| keep(id, node)),
+            NodeStore::Shared(nodes) => nodes.retain(keep),
+        }
+    }
+
+    /// Whether both stores are shared and one is an unmodified clone of the other
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (NodeStore::Shared(a), NodeStore::Shared(b)) => a.ptr_eq(b),
+            _ => false,
+        }
+    }
+
+    /// Detach shared storage from every other copy, e.g. before handing the
+    /// graph to another thread. Owned storage is left alone.
+    pub fn unshare(&mut self) {
+        if let NodeStore::Shared(nodes) = self {
+            *nodes = nodes.unshared();
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a NodeStore {
+    type Item = (&'a String, &'a GraphNode);
+    type IntoIter = NodeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the nodes of a `NodeStore`, in insertion order
+pub enum NodeIter<'a> {
+    Owned(indexmap::map::Iter<'a, String, GraphNode>),
+    Shared(super::persistent_map::Iter<'a, String, GraphNode>),
+}
+
+impl<'a> Iterator for NodeIter<'a> {
+    type Item = (&'a String, &'a GraphNode);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            NodeIter::Owned(iter) => iter.next(),
+            NodeIter::Shared(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            NodeIter::Owned(iter) => iter.size_hint(),
+            NodeIter::Shared(iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// Mutable iterator over the nodes of a `NodeStore`
+pub enum NodeValuesMut<'a> {
+    Owned(indexmap::map::ValuesMut<'a, String, GraphNode>),
+    Shared(super::persistent_map::IterMut<'a, String, GraphNode>),
+}
+
+impl<'a> Iterator for NodeValuesMut<'a> {
+    type Item = &'a mut GraphNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            NodeValuesMut::Owned(iter) => iter.next(),
+            NodeValuesMut::Shared(iter) => iter.next().map(|(_, node)| node),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            NodeValuesMut::Owned(iter) => iter.size_hint(),
+            NodeValuesMut::Shared(iter) => iter.size_hint(),
+        }
+    }
+}
+
 /// Graph data structure with index-free adjacency and auto-optimization
 #[derive(Debug, Clone)]
 pub struct Graph {
@@ -213,8 +428,8 @@ pub struct Graph {
     pub graph_type: GraphType,
     /// Graph configuration (orphan policies, etc.)
     pub config: GraphConfig,
-    /// Nodes by ID for O(1) lookup
-    pub nodes: HashMap<String, GraphNode>,
+    /// Nodes by ID, in insertion order
+    pub nodes: NodeStore,
     /// Active rulesets (e.g., "tree", "dag", "bst")
     /// Predefined bundles of rules applied via with_ruleset()
     pub rulesets: Vec<String>,
@@ -252,6 +467,13 @@ pub struct Graph {
     property_indices: HashMap<String, HashMap<String, Vec<String>>>,
     /// Threshold for auto-index creation (default: 10 accesses)
     auto_index_threshold: usize,
+
+    /// Whether parallel edges between the same pair of nodes are allowed
+    /// (the `:multigraph` graph type)
+    pub multigraph: bool,
+    /// Edges added after the first one between the same (from, to) pair,
+    /// in the order they were added. Only used by multigraphs.
+    pub parallel_edges: HashMap<(String, String), Vec<EdgeInfo>>,
     // Note: Methods are stored as nodes with node_type "__method__"
     // This follows Graphoid's "everything is a graph" principle
 }
@@ -281,7 +503,7 @@ impl Graph {
         Graph {
             graph_type,
             config: GraphConfig::default(),
-            nodes: HashMap::new(),
+            nodes: NodeStore::new(),
             rulesets: Vec::new(),
             rules: Vec::new(),
             parent: None,
@@ -293,10 +515,20 @@ impl Graph {
             property_access_counts: HashMap::new(),
             property_indices: HashMap::new(),
             auto_index_threshold: 10, // Create index after 10 lookups
+            multigraph: false,
+            parallel_edges: HashMap::new(),
             // Methods are stored as nodes with node_type "__method__"
         }
     }
 
+    /// Create the empty directed graph that backs a `List`
+    ///
+    /// Its node storage is shared between copies, so copying a list and
+    /// updating the copy only copies the nodes that change.
+    pub fn new_list_backing() -> Self {
+        Graph { nodes: NodeStore::shared(), ..Graph::new(GraphType::Directed) }
+    }
+
     /// Compare graphs with specified layer options
     ///
     /// # Arguments
@@ -371,7 +603,7 @@ impl Graph {
                 .filter(|(id, _)| is_data_node(id))
                 .collect();
 
-            if self_data != other_data {
+            if self_data != other_data || self.parallel_edges != other.parallel_edges {
                 return false;
             }
         }
@@ -454,8 +686,8 @@ impl Graph {
                 value: Value::string(parent_name.clone()),
                 node_type: Some("parent_reference".to_string()),
                 properties: HashMap::new(),
-                neighbors: HashMap::new(),
-                predecessors: HashMap::new(),
+                neighbors: IndexMap::new(),
+                predecessors: IndexMap::new(),
             });
 
             // Create inherits_from edge from child's type node to __parent__
@@ -466,8 +698,8 @@ impl Graph {
                     value: Value::string("self".to_string()),
                     node_type: Some("graph_self".to_string()),
                     properties: HashMap::new(),
-                    neighbors: HashMap::new(),
-                    predecessors: HashMap::new(),
+                    neighbors: IndexMap::new(),
+                    predecessors: IndexMap::new(),
                 });
             }
 
@@ -560,6 +792,7 @@ impl Graph {
         match self.validate_rules(operation) {
             ValidationResult::Allowed => {
                 // All rules passed - perform the operation
+                self.unindex_node(&id);
                 // If node exists, update value while preserving edges and properties
                 if let Some(existing) = self.nodes.get_mut(&id) {
                     existing.value = value;
@@ -568,15 +801,16 @@ impl Graph {
                     self.nodes.insert(
                         id.clone(),
                         GraphNode {
-                            id,
+                            id: id.clone(),
                             value,
                             node_type: None,
                             properties: HashMap::new(),
-                            neighbors: HashMap::new(),
-                            predecessors: HashMap::new(),
+                            neighbors: IndexMap::new(),
+                            predecessors: IndexMap::new(),
                         },
                     );
                 }
+                self.index_node(&id);
                 Ok(())
             }
             ValidationResult::Rejected {
@@ -625,11 +859,15 @@ impl Graph {
 
     /// Set properties for a node (replaces existing properties)
     pub fn set_node_properties(&mut self, id: &str, properties: HashMap<String, Value>) -> Result<(), GraphoidError> {
-        let node = self.nodes.get_mut(id).ok_or_else(|| {
-            GraphoidError::runtime(format!("Node '{}' not found", id))
-        })?;
+        if !self.nodes.contains_key(id) {
+            return Err(GraphoidError::runtime(format!("Node '{}' not found", id)));
+        }
 
-        node.properties = properties;
+        self.unindex_node(id);
+        if let Some(node) = self.nodes.get_mut(id) {
+            node.properties = properties;
+        }
+        self.index_node(id);
         Ok(())
     }
 
@@ -661,6 +899,19 @@ impl Graph {
                     EdgeInfo::new(edge_type.clone(), properties.clone())
                 };
 
+                // Multigraphs keep a repeated edge alongside the existing one
+                if self.multigraph && self.nodes.get(from).is_some_and(|node| node.neighbors.contains_key(to)) {
+                    self.parallel_edges.entry((from.to_string(), to.to_string()))
+                        .or_default()
+                        .push(edge_info.clone());
+                    if self.graph_type == GraphType::Undirected && from != to {
+                        self.parallel_edges.entry((to.to_string(), from.to_string()))
+                            .or_default()
+                            .push(edge_info);
+                    }
+                    return Ok(());
+                }
+
                 // Add forward edge (from -> to)
                 if let Some(from_node) = self.nodes.get_mut(from) {
                     from_node.neighbors.insert(
@@ -835,6 +1086,56 @@ impl Graph {
         self.get_edge_weight(from, to).is_some()
     }
 
+    /// Cost of walking `path`: the sum of the weights of the edges between
+    /// consecutive nodes, counting an unweighted edge as 1. On a multigraph
+    /// the first edge of each pair is used. A single node costs 0.
+    ///
+    /// A missing node, or a consecutive pair with no edge between them, is a
+    /// `ValueError`.
+    pub fn weight_of_path(&self, path: &[String]) -> Result<f64, GraphoidError> {
+        if let Some(missing) = path.iter().find(|id| !self.has_node(id)) {
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: weight_of_path() node '{}' not found", missing
+            )));
+        }
+
+        let mut total = 0.0;
+        for pair in path.windows(2) {
+            let Some(edge) = self.nodes.get(&pair[0]).and_then(|node| node.neighbors.get(&pair[1])) else {
+                return Err(GraphoidError::runtime(format!(
+                    "ValueError: weight_of_path() has no edge from '{}' to '{}'", pair[0], pair[1]
+                )));
+            };
+            total += edge.weight.unwrap_or(1.0);
+        }
+        Ok(total)
+    }
+
+    /// Sum of the weights of every data edge, counting an unweighted edge as
+    /// 1 and each parallel edge of a multigraph separately. An undirected
+    /// edge is counted once, not once per stored direction.
+    pub fn total_weight(&self) -> f64 {
+        let undirected = self.graph_type == GraphType::Undirected;
+        let data_nodes: HashSet<String> = self.data_node_ids().into_iter().collect();
+
+        let mut total = 0.0;
+        for (from, node) in self.nodes.iter() {
+            if !data_nodes.contains(from) {
+                continue;
+            }
+            for (to, edge) in &node.neighbors {
+                if !data_nodes.contains(to) || (undirected && to < from) {
+                    continue;
+                }
+                total += edge.weight.unwrap_or(1.0);
+                total += self.parallel_edges_for(from, to).iter()
+                    .map(|parallel| parallel.weight.unwrap_or(1.0))
+                    .sum::<f64>();
+            }
+        }
+        total
+    }
+
     /// Get neighbors of a node (O(1) lookup, O(degree) iteration)
     pub fn neighbors(&self, id: &str) -> Vec<String> {
         if let Some(node) = self.nodes.get(id) {
@@ -869,7 +1170,25 @@ impl Graph {
                     .filter(|(to_id, _)| data_nodes.contains(to_id))
                     .count()
             })
-            .sum()
+            .sum::<usize>()
+            + self.parallel_edges.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Number of edges from `from` to `to`. Only multigraphs can have more than one.
+    pub fn edge_multiplicity(&self, from: &str, to: &str) -> usize {
+        if !self.has_edge(from, to) {
+            return 0;
+        }
+        1 + self.parallel_edges.get(&(from.to_string(), to.to_string())).map_or(0, Vec::len)
+    }
+
+    /// Parallel edges stored for a (from, to) pair, after the first one
+    fn parallel_edges_for(&self, from: &str, to: &str) -> &[EdgeInfo] {
+        if self.parallel_edges.is_empty() {
+            return &[];
+        }
+        self.parallel_edges.get(&(from.to_string(), to.to_string()))
+            .map_or(&[], Vec::as_slice)
     }
 
     /// Get all node IDs as a list (data nodes only by default)
@@ -888,6 +1207,9 @@ impl Graph {
         for (from_id, node) in &self.nodes {
             for (to_id, edge_info) in &node.neighbors {
                 edges.push((from_id.clone(), to_id.clone(), edge_info.edge_type.clone()));
+                for parallel in self.parallel_edges_for(from_id, to_id) {
+                    edges.push((from_id.clone(), to_id.clone(), parallel.edge_type.clone()));
+                }
             }
         }
         edges
@@ -907,11 +1229,95 @@ impl Graph {
                     continue;
                 }
                 edges.push((from_id.clone(), to_id.clone(), edge_info.edge_type.clone()));
+                for parallel in self.parallel_edges_for(from_id, to_id) {
+                    edges.push((from_id.clone(), to_id.clone(), parallel.edge_type.clone()));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Data edges with their full edge info, in the same order as
+    /// `data_edge_list()`
+    pub fn data_edge_infos(&self) -> Vec<(&str, &str, &EdgeInfo)> {
+        let mut edges = Vec::new();
+        for (from_id, node) in &self.nodes {
+            if from_id.starts_with("__methods__") {
+                continue;
+            }
+            for (to_id, edge_info) in &node.neighbors {
+                if to_id.starts_with("__methods__") {
+                    continue;
+                }
+                edges.push((from_id.as_str(), to_id.as_str(), edge_info));
+                for parallel in self.parallel_edges_for(from_id, to_id) {
+                    edges.push((from_id.as_str(), to_id.as_str(), parallel));
+                }
             }
         }
         edges
     }
 
+    /// Each data node (in insertion order) with its out-neighbors sorted by id
+    /// and the weight of the connecting edge. Undirected edges are stored in
+    /// both directions, so every neighbor appears; isolated nodes get an empty list.
+    pub fn adjacency_list(&self) -> Vec<(String, Vec<(String, Option<f64>)>)> {
+        self.data_node_ids().into_iter().map(|id| {
+            let mut neighbors: Vec<(String, Option<f64>)> = self.nodes.get(&id)
+                .map(|node| node.neighbors.iter()
+                    .filter(|(to, _)| !to.starts_with("__methods__"))
+                    .map(|(to, edge)| (to.clone(), edge.weight()))
+                    .collect())
+                .unwrap_or_default();
+            neighbors.sort_by(|a, b| a.0.cmp(&b.0));
+            (id, neighbors)
+        }).collect()
+    }
+
+    /// Data edges as `(from, to, weight)` triples in storage order.
+    /// Undirected edges are stored in both directions; each is listed once.
+    pub fn to_edge_list(&self) -> Vec<(String, String, Option<f64>)> {
+        let directed = self.graph_type == GraphType::Directed;
+        self.data_edge_infos().into_iter()
+            .filter(|(from, to, _)| directed || from <= to)
+            .map(|(from, to, edge)| (from.to_string(), to.to_string(), edge.weight()))
+            .collect()
+    }
+
+    /// Build a graph from `(from, to, weight)` triples, creating endpoint
+    /// nodes (with value `none`) as they are first seen.
+    ///
+    /// A multigraph keeps every repeated edge. Otherwise an exact repeat is
+    /// dropped and a repeat with a different weight is rejected, naming the
+    /// index of the offending entry.
+    pub fn from_edge_list(
+        edges: &[(String, String, Option<f64>)],
+        graph_type: GraphType,
+        multigraph: bool,
+    ) -> Result<Self, GraphoidError> {
+        let mut graph = Graph::new(graph_type);
+        graph.multigraph = multigraph;
+        for (index, (from, to, weight)) in edges.iter().enumerate() {
+            for id in [from, to] {
+                if !graph.has_node(id) {
+                    graph.add_node(id.clone(), Value::none())?;
+                }
+            }
+            if !multigraph && graph.has_edge(from, to) {
+                let existing = graph.get_edge_weight(from, to);
+                if existing == *weight {
+                    continue;
+                }
+                return Err(GraphoidError::runtime(format!(
+                    "ValueError: from_edge_list() entry {} repeats edge {} -> {} with a different weight",
+                    index, from, to
+                )));
+            }
+            graph.add_edge(from, to, "edge".to_string(), *weight, HashMap::new())?;
+        }
+        Ok(graph)
+    }
+
     /// Remove a node from the graph
     /// Remove a node with optional orphan handling policy override
     pub fn remove_node(
@@ -994,12 +1400,23 @@ impl Graph {
             ValidationResult::Allowed => {
                 // All rules passed - perform the operation
                 // Remove the node
+                self.unindex_node(id);
                 let removed = self.nodes.remove(id);
+                if !self.parallel_edges.is_empty() {
+                    self.parallel_edges.retain(|(from, to), _| from != id && to != id);
+                }
 
-                // Remove all edges pointing to/from this node
-                for node in self.nodes.values_mut() {
-                    node.neighbors.remove(id);     // Remove outgoing edges to this node
-                    node.predecessors.remove(id);  // Remove incoming edges from this node
+                // Remove all edges pointing to/from this node. Only touch nodes
+                // that reference it, so untouched nodes stay shared with copies.
+                let referencing: Vec<String> = self.nodes.iter()
+                    .filter(|(_, node)| node.neighbors.contains_key(id) || node.predecessors.contains_key(id))
+                    .map(|(node_id, _)| node_id.clone())
+                    .collect();
+                for node_id in referencing {
+                    if let Some(node) = self.nodes.get_mut(&node_id) {
+                        node.neighbors.shift_remove(id);     // Remove outgoing edges to this node
+                        node.predecessors.shift_remove(id);  // Remove incoming edges from this node
+                    }
                 }
 
                 Ok(removed)
@@ -1033,6 +1450,18 @@ impl Graph {
         }
     }
 
+    fn remove_parallel_edge(&mut self, from: &str, to: &str) -> bool {
+        let key = (from.to_string(), to.to_string());
+        let Some(parallel) = self.parallel_edges.get_mut(&key) else {
+            return false;
+        };
+        parallel.pop();
+        if parallel.is_empty() {
+            self.parallel_edges.remove(&key);
+        }
+        true
+    }
+
     /// Remove an edge
     pub fn remove_edge(&mut self, from: &str, to: &str) -> Result<bool, GraphoidError> {
         // Check if graph is frozen
@@ -1051,28 +1480,36 @@ impl Graph {
         match self.validate_rules(operation) {
             ValidationResult::Allowed => {
                 // All rules passed - perform the operation
+                // On a multigraph, remove the most recent parallel edge first
+                if self.remove_parallel_edge(from, to) {
+                    if self.graph_type == GraphType::Undirected {
+                        self.remove_parallel_edge(to, from);
+                    }
+                    return Ok(true);
+                }
+
                 let mut removed = false;
 
                 // Remove forward edge (from -> to)
                 if let Some(from_node) = self.nodes.get_mut(from) {
-                    removed = from_node.neighbors.remove(to).is_some();
+                    removed = from_node.neighbors.shift_remove(to).is_some();
                 }
 
                 // Remove reverse index (to <- from)
                 if let Some(to_node) = self.nodes.get_mut(to) {
-                    to_node.predecessors.remove(from);
+                    to_node.predecessors.shift_remove(from);
                 }
 
                 // For undirected graphs, remove reverse edge
                 if self.graph_type == GraphType::Undirected {
                     // Remove reverse edge (to -> from)
                     if let Some(to_node) = self.nodes.get_mut(to) {
-                        to_node.neighbors.remove(from);
+                        to_node.neighbors.shift_remove(from);
                     }
 
                     // Remove reverse predecessor (from <- to)
                     if let Some(from_node) = self.nodes.get_mut(from) {
-                        from_node.predecessors.remove(to);
+                        from_node.predecessors.shift_remove(to);
                     }
                 }
 
@@ -1229,6 +1666,153 @@ impl Graph {
         result
     }
 
+    /// Spanning tree discovered by a BFS (`depth_first == false`) or DFS from
+    /// `start`, visiting neighbors in the same order as `bfs()` / `dfs()`.
+    ///
+    /// The result is a new directed graph with an edge from each node to every
+    /// node first discovered through it, keeping that edge's type and weight.
+    /// Node values are copied; nodes unreachable from `start` are left out, so
+    /// the result carries the `tree` ruleset. Empty if `start` is not a node.
+    pub fn traversal_tree(&self, start: &str, depth_first: bool) -> Result<Self, GraphoidError> {
+        let mut tree = Graph::new(GraphType::Directed);
+        let mut visited = HashSet::new();
+        self.grow_traversal_tree(&mut tree, start, depth_first, &mut visited)?;
+        Ok(tree.with_ruleset("tree".to_string()))
+    }
+
+    /// BFS tree rooted at `root`, for viewing a general graph as a tree.
+    ///
+    /// Same as `traversal_tree(root, false)`, except that with `forest` set
+    /// every node unreachable from `root` is kept too: the remaining nodes are
+    /// taken in sorted order, each one grows a BFS tree over the nodes not yet
+    /// placed, and `root` and those sub-roots all hang off a synthetic
+    /// `FOREST_ROOT_ID` node (value none) through `forest` edges. Either way
+    /// the result carries and satisfies the `tree` ruleset.
+    ///
+    /// A missing `root`, or `forest` on a graph that already has a node named
+    /// `FOREST_ROOT_ID`, is a `ValueError`.
+    pub fn to_tree(&self, root: &str, forest: bool) -> Result<Self, GraphoidError> {
+        if !self.has_node(root) {
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: to_tree() node '{}' not found", root
+            )));
+        }
+        if !forest {
+            return self.traversal_tree(root, false);
+        }
+        if self.has_node(FOREST_ROOT_ID) {
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: to_tree() cannot add forest root '{}', the graph already has a node with that id",
+                FOREST_ROOT_ID
+            )));
+        }
+
+        let mut tree = Graph::new(GraphType::Directed);
+        tree.add_node(FOREST_ROOT_ID.to_string(), Value::none())?;
+        let mut visited = HashSet::new();
+        visited.insert(FOREST_ROOT_ID.to_string());
+
+        let mut ids = self.data_node_ids();
+        ids.sort();
+        let sub_roots = std::iter::once(root.to_string()).chain(ids);
+        for sub_root in sub_roots {
+            if visited.contains(&sub_root) {
+                continue;
+            }
+            self.grow_traversal_tree(&mut tree, &sub_root, false, &mut visited)?;
+            tree.add_edge(FOREST_ROOT_ID, &sub_root, "forest".to_string(), None, HashMap::new())?;
+        }
+
+        Ok(tree.with_ruleset("tree".to_string()))
+    }
+
+    /// Adds the BFS or DFS tree discovered from `start` to `tree`, skipping
+    /// anything already in `visited` (and marking what it adds).
+    fn grow_traversal_tree(
+        &self,
+        tree: &mut Graph,
+        start: &str,
+        depth_first: bool,
+        visited: &mut HashSet<String>,
+    ) -> Result<(), GraphoidError> {
+        // (node, parent and the edge it was reached through)
+        let mut pending: VecDeque<(String, Option<(String, EdgeInfo)>)> = VecDeque::new();
+        if self.has_node(start) && !visited.contains(start) {
+            pending.push_back((start.to_string(), None));
+            if !depth_first {
+                visited.insert(start.to_string());
+            }
+        }
+
+        loop {
+            let next = if depth_first { pending.pop_back() } else { pending.pop_front() };
+            let Some((node_id, via)) = next else { break };
+            if depth_first && !visited.insert(node_id.clone()) {
+                continue;
+            }
+            let Some(node) = self.nodes.get(&node_id) else { continue };
+
+            tree.add_node(node_id.clone(), node.value.deep_copy_unfrozen())?;
+            if let Some(copied) = tree.nodes.get_mut(&node_id) {
+                copied.node_type = node.node_type.clone();
+            }
+            if let Some((parent, edge)) = via {
+                tree.add_edge(&parent, &node_id, edge.edge_type, edge.weight, edge.properties)?;
+            }
+
+            for (neighbor_id, edge) in &node.neighbors {
+                if visited.contains(neighbor_id) {
+                    continue;
+                }
+                if !depth_first {
+                    visited.insert(neighbor_id.clone());
+                }
+                pending.push_back((neighbor_id.clone(), Some((node_id.clone(), edge.clone()))));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Complement of an undirected simple graph: a new undirected graph with
+    /// the same data nodes (values and node types copied) and an edge between
+    /// every pair of distinct nodes that are not adjacent here. Self-loops are
+    /// never added. Rules and rulesets are not carried over.
+    ///
+    /// Directed graphs and multigraphs are a `ValueError`.
+    pub fn complement(&self) -> Result<Self, GraphoidError> {
+        if self.graph_type == GraphType::Directed {
+            return Err(GraphoidError::runtime(
+                "ValueError: complement() requires an undirected graph".to_string()
+            ));
+        }
+        if self.multigraph {
+            return Err(GraphoidError::runtime(
+                "ValueError: complement() requires a simple graph, not a multigraph".to_string()
+            ));
+        }
+
+        let mut ids = self.data_node_ids();
+        ids.sort();
+
+        let mut result = Graph::new(GraphType::Undirected);
+        for id in &ids {
+            let Some(node) = self.nodes.get(id) else { continue };
+            result.add_node(id.clone(), node.value.deep_copy_unfrozen())?;
+            if let Some(copied) = result.nodes.get_mut(id) {
+                copied.node_type = node.node_type.clone();
+            }
+        }
+        for (i, from) in ids.iter().enumerate() {
+            for to in &ids[i + 1..] {
+                if !self.has_edge(from, to) {
+                    result.add_edge(from, to, "edge".to_string(), None, HashMap::new())?;
+                }
+            }
+        }
+        Ok(result)
+    }
+
     /// Find the shortest path between two nodes using BFS (or optimized algorithm if rules apply)
     ///
     /// Returns a vector of node IDs representing the path from `from` to `to`.
@@ -1388,26 +1972,133 @@ impl Graph {
         None
     }
 
-    /// BFS-based shortest path with edge type filtering
-    fn shortest_path_bfs_filtered(&self, from: &str, to: &str, edge_type: Option<&str>) -> Vec<String> {
-        // Handle special cases
-        if !self.has_node(from) || !self.has_node(to) {
-            return Vec::new();
-        }
+    /// Shortest path by A* search
+    ///
+    /// `heuristic(id)` estimates the remaining cost from `id` to `to` and
+    /// decides which frontier node is expanded next; it is called at most once
+    /// per node. Edges cost their weight, or 1 when unweighted. The path is
+    /// optimal when the heuristic never overestimates; an inadmissible
+    /// heuristic can return a longer path.
+    ///
+    /// Returns `Ok(None)` if either node is missing or no path exists. A
+    /// negative or non-finite estimate is a `ValueError`, and errors from
+    /// `heuristic` are passed through.
+    pub fn astar_path<F>(&self, from: &str, to: &str, mut heuristic: F) -> Result<Option<Vec<String>>, GraphoidError>
+    where
+        F: FnMut(&str) -> Result<f64, GraphoidError>,
+    {
+        use std::collections::BinaryHeap;
+        use std::cmp::Ordering;
 
-        if from == to {
-            return vec![from.to_string()];
+        // Frontier entry ordered by estimated total cost, lowest first
+        struct State {
+            estimate: f64,
+            cost: f64,
+            node: String,
         }
 
-        // BFS with parent tracking for path reconstruction
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
-        let mut parent: HashMap<String, String> = HashMap::new();
+        impl PartialEq for State {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
 
-        queue.push_back(from.to_string());
-        visited.insert(from.to_string());
+        impl Eq for State {}
 
-        while let Some(current) = queue.pop_front() {
+        impl PartialOrd for State {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for State {
+            fn cmp(&self, other: &Self) -> Ordering {
+                other.estimate.total_cmp(&self.estimate)
+            }
+        }
+
+        if !self.has_node(from) || !self.has_node(to) {
+            return Ok(None);
+        }
+
+        let mut estimates: HashMap<String, f64> = HashMap::new();
+        let mut estimate = |id: &str| -> Result<f64, GraphoidError> {
+            if let Some(h) = estimates.get(id) {
+                return Ok(*h);
+            }
+            let h = heuristic(id)?;
+            if !(h >= 0.0 && h.is_finite()) {
+                return Err(GraphoidError::runtime(format!(
+                    "ValueError: astar_path() heuristic must return a non-negative number, got {} for '{}'", h, id
+                )));
+            }
+            estimates.insert(id.to_string(), h);
+            Ok(h)
+        };
+
+        let mut dist: HashMap<String, f64> = HashMap::new();
+        let mut parent: HashMap<String, String> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        dist.insert(from.to_string(), 0.0);
+        heap.push(State { estimate: estimate(from)?, cost: 0.0, node: from.to_string() });
+
+        while let Some(State { cost, node, .. }) = heap.pop() {
+            if node == to {
+                let mut path = vec![to.to_string()];
+                let mut current = to;
+                while current != from {
+                    let Some(prev) = parent.get(current) else { break };
+                    path.push(prev.clone());
+                    current = prev;
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+
+            // Skip entries superseded by a cheaper route
+            if cost > *dist.get(&node).unwrap_or(&f64::INFINITY) {
+                continue;
+            }
+
+            let Some(node_data) = self.nodes.get(&node) else { continue };
+            for (neighbor_id, edge_info) in &node_data.neighbors {
+                let new_cost = cost + edge_info.weight.unwrap_or(1.0);
+                if new_cost < *dist.get(neighbor_id).unwrap_or(&f64::INFINITY) {
+                    dist.insert(neighbor_id.clone(), new_cost);
+                    parent.insert(neighbor_id.clone(), node.clone());
+                    heap.push(State {
+                        estimate: new_cost + estimate(neighbor_id)?,
+                        cost: new_cost,
+                        node: neighbor_id.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// BFS-based shortest path with edge type filtering
+    fn shortest_path_bfs_filtered(&self, from: &str, to: &str, edge_type: Option<&str>) -> Vec<String> {
+        // Handle special cases
+        if !self.has_node(from) || !self.has_node(to) {
+            return Vec::new();
+        }
+
+        if from == to {
+            return vec![from.to_string()];
+        }
+
+        // BFS with parent tracking for path reconstruction
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        let mut parent: HashMap<String, String> = HashMap::new();
+
+        queue.push_back(from.to_string());
+        visited.insert(from.to_string());
+
+        while let Some(current) = queue.pop_front() {
             // Found the target?
             if current == to {
                 // Reconstruct path from parent pointers
@@ -1627,13 +2318,13 @@ impl Graph {
         queue.push_back(from.to_string());
         visited.insert(from.to_string());
 
+        // Stop as soon as `to` is discovered rather than when it is dequeued
         while let Some(current) = queue.pop_front() {
-            if current == to {
-                return true;
-            }
-
             if let Some(node) = self.nodes.get(&current) {
                 for neighbor_id in node.neighbors.keys() {
+                    if neighbor_id == to {
+                        return true;
+                    }
                     if !visited.contains(neighbor_id) {
                         visited.insert(neighbor_id.clone());
                         queue.push_back(neighbor_id.clone());
@@ -1645,6 +2336,105 @@ impl Graph {
         false
     }
 
+    /// All nodes reachable from `id` by following outgoing edges, in
+    /// breadth-first order. `id` itself is left out unless it lies on a cycle.
+    ///
+    /// Returns an empty list if `id` does not exist.
+    pub fn descendants_of(&self, id: &str) -> Vec<String> {
+        self.transitive_closure(id, |node| &node.neighbors)
+    }
+
+    /// All nodes that can reach `id` by following incoming edges, in
+    /// breadth-first order. `id` itself is left out unless it lies on a cycle.
+    ///
+    /// Returns an empty list if `id` does not exist.
+    pub fn ancestors_of(&self, id: &str) -> Vec<String> {
+        self.transitive_closure(id, |node| &node.predecessors)
+    }
+
+    /// Every node paired with whether it can be reached from `id` by following
+    /// outgoing edges, in node insertion order. `id` itself counts as reached.
+    /// All nodes are unreached if `id` does not exist.
+    pub fn reachable_from(&self, id: &str) -> Vec<(String, bool)> {
+        let mut reached: HashSet<String> = self.descendants_of(id).into_iter().collect();
+        if self.has_node(id) {
+            reached.insert(id.to_string());
+        }
+        self.data_node_ids()
+            .into_iter()
+            .map(|node| {
+                let is_reached = reached.contains(&node);
+                (node, is_reached)
+            })
+            .collect()
+    }
+
+    fn transitive_closure<F>(&self, id: &str, edges: F) -> Vec<String>
+    where
+        F: Fn(&GraphNode) -> &IndexMap<String, EdgeInfo>,
+    {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([id.to_string()]);
+        let mut result = Vec::new();
+
+        while let Some(current) = queue.pop_front() {
+            let Some(node) = self.nodes.get(&current) else { continue };
+            for next in edges(node).keys() {
+                if visited.insert(next.clone()) {
+                    result.push(next.clone());
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+        result
+    }
+
+    /// A random walk of up to `steps` moves from `start`, as the list of
+    /// visited node ids beginning with `start`.
+    ///
+    /// Each move goes to an out-neighbor picked uniformly, or in proportion to
+    /// edge weight when `weighted` is set (unweighted edges count as 1, and
+    /// edges with a non-positive weight are never taken). The walk stops early
+    /// at a node with nowhere to go. `next_f64` supplies draws in `[0, 1)`, so
+    /// a seeded generator makes the walk reproducible.
+    ///
+    /// Returns an empty list if `start` does not exist.
+    pub fn random_walk<F>(&self, start: &str, steps: usize, weighted: bool, mut next_f64: F) -> Vec<String>
+    where
+        F: FnMut() -> f64,
+    {
+        let Some(mut node) = self.nodes.get(start) else { return Vec::new() };
+        let mut walk = vec![start.to_string()];
+
+        for _ in 0..steps {
+            let choices: Vec<(&String, f64)> = node.neighbors.iter()
+                .map(|(id, edge)| (id, if weighted { edge.weight.unwrap_or(1.0) } else { 1.0 }))
+                .filter(|(_, weight)| *weight > 0.0)
+                .collect();
+            let total: f64 = choices.iter().map(|(_, weight)| weight).sum();
+            if choices.is_empty() || !total.is_finite() {
+                break;
+            }
+
+            let mut target = next_f64() * total;
+            let mut next = choices[choices.len() - 1].0;
+            for (id, weight) in &choices {
+                if target < *weight {
+                    next = id;
+                    break;
+                }
+                target -= weight;
+            }
+
+            walk.push(next.clone());
+            match self.nodes.get(next) {
+                Some(next_node) => node = next_node,
+                None => break,
+            }
+        }
+        walk
+    }
+
     /// Returns the shortest path distance (number of edges) between two nodes.
     ///
     /// Returns the length of the shortest path from `from` to `to`.
@@ -1763,7 +2553,7 @@ impl Graph {
     /// Find all paths from start node with length in range [min_len, max_len].
     /// Uses BFS to explore paths level by level.
     fn find_variable_length_paths(
-        graph_nodes: &HashMap<String, GraphNode>,
+        graph_nodes: &NodeStore,
         start_node: &str,
         min_len: usize,
         max_len: usize,
@@ -1841,7 +2631,7 @@ impl Graph {
     /// Extend a partial match by following edges or variable-length paths (unified recursive algorithm).
     /// Uses backtracking to find all complete matches.
     fn extend_pattern_match(
-        graph_nodes: &HashMap<String, GraphNode>,
+        graph_nodes: &NodeStore,
         binding: &mut HashMap<String, String>,
         current_node: &str,
         pattern_nodes: &[PatternNode],
@@ -2081,10 +2871,12 @@ impl Graph {
         result
     }
 
-    /// Finds all paths from one node to another up to a maximum length.
+    /// Finds all simple paths from one node to another up to a maximum length.
     ///
     /// Returns a list of all paths (each path is a list of node IDs) from `from` to `to`
-    /// where the path has at most `max_len` edges.
+    /// where the path has at most `max_len` edges. The DFS never revisits a node on the
+    /// current path, so no path is longer than `n - 1` edges whatever `max_len` is.
+    /// Missing endpoints or unreachable targets give an empty list.
     pub fn all_paths(&self, from: &str, to: &str, max_len: usize) -> Vec<Vec<String>> {
         // Handle special cases
         if !self.has_node(from) || !self.has_node(to) {
@@ -2197,6 +2989,53 @@ impl Graph {
         result
     }
 
+    /// Group nodes into dependency generations: generation 0 holds every node
+    /// with no incoming edges, and each later generation holds the nodes whose
+    /// predecessors all sit in earlier ones. Ids within a generation are sorted.
+    /// Returns None if the graph has a cycle.
+    pub fn topological_generations(&self) -> Option<Vec<Vec<String>>> {
+        let ids = self.data_node_ids();
+        let mut in_degree: HashMap<&str, usize> = ids.iter().map(|id| (id.as_str(), 0)).collect();
+        for node in ids.iter().filter_map(|id| self.nodes.get(id)) {
+            for neighbor_id in node.neighbors.keys() {
+                if let Some(degree) = in_degree.get_mut(neighbor_id.as_str()) {
+                    *degree += 1;
+                }
+            }
+        }
+
+        let mut current: Vec<String> = ids.iter()
+            .filter(|id| in_degree[id.as_str()] == 0)
+            .cloned()
+            .collect();
+        let mut generations = Vec::new();
+        let mut placed = 0;
+
+        while !current.is_empty() {
+            current.sort();
+            let mut next = Vec::new();
+            for node in current.iter().filter_map(|id| self.nodes.get(id)) {
+                for neighbor_id in node.neighbors.keys() {
+                    if let Some(degree) = in_degree.get_mut(neighbor_id.as_str()) {
+                        *degree -= 1;
+                        if *degree == 0 {
+                            next.push(neighbor_id.clone());
+                        }
+                    }
+                }
+            }
+            placed += current.len();
+            generations.push(current);
+            current = next;
+        }
+
+        if placed == ids.len() {
+            Some(generations)
+        } else {
+            None
+        }
+    }
+
     /// In-order traversal (left, root, right) starting from a given node
     /// Assumes binary tree structure where first child is left, second is right
     /// Returns values in in-order
@@ -2327,6 +3166,28 @@ impl Graph {
         self.rulesets.contains(&ruleset.to_string())
     }
 
+    /// Check whether the graph as it stands satisfies a predefined ruleset
+    ///
+    /// Runs every rule of the ruleset against the current nodes and edges
+    /// without applying the ruleset. Returns an error for unknown rulesets.
+    pub fn satisfies_ruleset(&self, ruleset: &str) -> Result<bool, GraphoidError> {
+        if !crate::graph::is_valid_ruleset(ruleset) {
+            let valid: Vec<String> = crate::graph::available_rulesets()
+                .iter()
+                .map(|name| format!(":{}", name))
+                .collect();
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: unknown ruleset :{} (valid rulesets: {})",
+                ruleset,
+                valid.join(", ")
+            )));
+        }
+
+        Ok(get_ruleset_rules(ruleset)
+            .iter()
+            .all(|rule| rule.spec.instantiate().validate_existing(self).is_ok()))
+    }
+
     /// Get all active rulesets
     pub fn get_rulesets(&self) -> &[String] {
         &self.rulesets
@@ -2523,6 +3384,9 @@ impl Graph {
     /// Tracks access patterns and automatically creates indices after threshold (default: 10 lookups).
     /// First lookups are O(n) but become O(1) after index is created.
     ///
+    /// Properties are read with `node_property()`, so map-valued nodes match on
+    /// their map entries as well as their explicit properties.
+    ///
     /// # Example
     /// ```no_run
     /// use graphoid::values::{Graph, Value};
@@ -2542,28 +3406,79 @@ impl Graph {
             self.create_property_index(property);
         }
 
-        // Use index if available (O(1) lookup)
+        self.nodes_with_property(property, value)
+    }
+
+    /// Find nodes whose property equals `value`, without tracking access
+    ///
+    /// Uses the property's index when one exists (O(1) lookup), otherwise
+    /// scans all nodes (O(n)). See `node_property()` for what counts as a property.
+    pub fn nodes_with_property(&self, property: &str, value: &Value) -> Vec<String> {
         if let Some(index) = self.property_indices.get(property) {
-            let value_key = value.to_string();
-            if let Some(node_ids) = index.get(&value_key) {
-                return node_ids.clone();
-            } else {
-                return Vec::new();
-            }
+            // Index keys are display strings; confirm real equality for each candidate
+            return index.get(&value.to_string())
+                .map(|ids| ids.iter()
+                    .filter(|id| self.nodes.get(id.as_str())
+                        .and_then(|node| Self::node_property(node, property)) == Some(value))
+                    .cloned()
+                    .collect())
+                .unwrap_or_default();
         }
 
-        // Otherwise, linear scan (O(n))
         let mut result = Vec::new();
         for (node_id, node) in &self.nodes {
-            if let Some(prop_value) = node.properties.get(property) {
-                if prop_value == value {
-                    result.push(node_id.clone());
-                }
+            if Self::node_property(node, property) == Some(value) {
+                result.push(node_id.clone());
             }
         }
         result
     }
 
+    /// A node's value for a property
+    ///
+    /// Explicit node properties take precedence; otherwise, if the node's
+    /// value is a map, the map entry with that key is used. A property set on
+    /// the node therefore shadows a map entry of the same name, and the map
+    /// entry is not found by lookups on that property.
+    pub fn node_property<'a>(node: &'a GraphNode, property: &str) -> Option<&'a Value> {
+        if let Some(value) = node.properties.get(property) {
+            return Some(value);
+        }
+        match &node.value.kind {
+            ValueKind::Map(map) => map.get(property),
+            _ => None,
+        }
+    }
+
+    /// Create an index on a property so lookups by that property are hash lookups
+    ///
+    /// The index is kept up to date as nodes are added, removed, or changed
+    /// through the graph API. Creating an existing index rebuilds it.
+    pub fn create_index(&mut self, property: &str) {
+        self.create_property_index(property);
+    }
+
+    /// Drop the index on a property. Returns false if there was no index.
+    ///
+    /// Lookups on the property fall back to scanning all nodes.
+    pub fn drop_index(&mut self, property: &str) -> bool {
+        // Restart access tracking so the auto-indexer doesn't immediately rebuild it
+        self.property_access_counts.remove(property);
+        self.property_indices.remove(property).is_some()
+    }
+
+    /// Check if a property is indexed (explicitly or automatically)
+    pub fn has_index(&self, property: &str) -> bool {
+        self.property_indices.contains_key(property)
+    }
+
+    /// Names of all indexed properties, sorted
+    pub fn indexed_properties(&self) -> Vec<String> {
+        let mut properties: Vec<String> = self.property_indices.keys().cloned().collect();
+        properties.sort();
+        properties
+    }
+
     /// Create an index for a property
     ///
     /// Scans all nodes and builds a HashMap: property_value_string -> Vec<node_id>
@@ -2571,11 +3486,11 @@ impl Graph {
         let mut index: HashMap<String, Vec<String>> = HashMap::new();
 
         for (node_id, node) in &self.nodes {
-            if let Some(value) = node.properties.get(property) {
+            if let Some(value) = Self::node_property(node, property) {
                 let value_key = value.to_string();
                 index
                     .entry(value_key)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(node_id.clone());
             }
         }
@@ -2583,6 +3498,38 @@ impl Graph {
         self.property_indices.insert(property.to_string(), index);
     }
 
+    /// Add a node's current property values to every index
+    fn index_node(&mut self, node_id: &str) {
+        if self.property_indices.is_empty() {
+            return;
+        }
+        let Some(node) = self.nodes.get(node_id) else { return };
+        for (property, index) in self.property_indices.iter_mut() {
+            if let Some(value) = Self::node_property(node, property) {
+                index.entry(value.to_string()).or_default().push(node_id.to_string());
+            }
+        }
+    }
+
+    /// Remove a node's current property values from every index
+    fn unindex_node(&mut self, node_id: &str) {
+        if self.property_indices.is_empty() {
+            return;
+        }
+        let Some(node) = self.nodes.get(node_id) else { return };
+        for (property, index) in self.property_indices.iter_mut() {
+            if let Some(value) = Self::node_property(node, property) {
+                let value_key = value.to_string();
+                if let Some(ids) = index.get_mut(&value_key) {
+                    ids.retain(|id| id != node_id);
+                    if ids.is_empty() {
+                        index.remove(&value_key);
+                    }
+                }
+            }
+        }
+    }
+
     /// Get comprehensive statistics about the graph
     ///
     /// Returns detailed information including:
@@ -2748,6 +3695,116 @@ impl Graph {
         copy
     }
 
+    /// Copy this graph's shape without its data: same node ids, node types,
+    /// edges (with their types and weights), graph type, rules and methods,
+    /// but every data node's value becomes a fresh copy of `default_value` and
+    /// node and edge properties are cleared. The copy is unfrozen and shares
+    /// nothing mutable with the original.
+    pub fn clone_structure(&self, default_value: &Value) -> Self {
+        let mut copy = self.deep_copy_unfrozen();
+        for id in copy.data_node_ids() {
+            if let Some(node) = copy.nodes.get_mut(&id) {
+                node.value = default_value.deep_copy_unfrozen();
+                node.properties.clear();
+                for edge in node.neighbors.values_mut() {
+                    edge.properties.clear();
+                }
+                for edge in node.predecessors.values_mut() {
+                    edge.properties.clear();
+                }
+            }
+        }
+        for edges in copy.parallel_edges.values_mut() {
+            for edge in edges {
+                edge.properties.clear();
+            }
+        }
+
+        // Keep the same indexes, rebuilt over the reset values
+        copy.rebuild_property_indices();
+        copy
+    }
+
+    /// Copy of this graph with each data node's value replaced by
+    /// `f(id, value)`, visiting nodes in insertion order. Ids, edges, graph
+    /// type, rules and methods are kept. The first error from `f` aborts.
+    pub fn map_node_values<F>(&self, mut f: F) -> Result<Self, GraphoidError>
+    where
+        F: FnMut(&str, &Value) -> Result<Value, GraphoidError>,
+    {
+        let mut copy = self.deep_copy_unfrozen();
+        for id in copy.data_node_ids() {
+            if let Some(node) = copy.nodes.get_mut(&id) {
+                node.value = f(&id, &node.value)?;
+            }
+        }
+        copy.rebuild_property_indices();
+        Ok(copy)
+    }
+
+    /// Copy of this graph with each data edge's properties replaced by
+    /// `f(from, to, properties)`, in `to_edge_list()` order. Undirected edges
+    /// and parallel edges are each passed once; both stored directions get
+    /// the result. Types and weights are kept. The first error from `f` aborts.
+    pub fn map_edge_properties<F>(&self, mut f: F) -> Result<Self, GraphoidError>
+    where
+        F: FnMut(&str, &str, &HashMap<String, Value>) -> Result<HashMap<String, Value>, GraphoidError>,
+    {
+        let mut copy = self.deep_copy_unfrozen();
+        let undirected = self.graph_type == GraphType::Undirected;
+        let pairs: Vec<(String, String)> = self.data_edge_infos().into_iter()
+            .filter(|(from, to, _)| !undirected || from <= to)
+            .map(|(from, to, _)| (from.to_string(), to.to_string()))
+            .collect();
+        let mut seen = std::collections::HashSet::new();
+        for (from, to) in pairs {
+            // data_edge_infos() repeats a pair once per parallel edge
+            if !seen.insert((from.clone(), to.clone())) {
+                continue;
+            }
+            let mirrored = undirected && from != to;
+
+            let Some(edge) = self.nodes.get(&from).and_then(|node| node.neighbors.get(&to)) else { continue };
+            let properties = f(&from, &to, &edge.properties)?;
+            if let Some(edge) = copy.nodes.get_mut(&from).and_then(|node| node.neighbors.get_mut(&to)) {
+                edge.properties = properties.clone();
+            }
+            if let Some(edge) = copy.nodes.get_mut(&to).and_then(|node| node.predecessors.get_mut(&from)) {
+                edge.properties = properties.clone();
+            }
+            if mirrored {
+                if let Some(edge) = copy.nodes.get_mut(&to).and_then(|node| node.neighbors.get_mut(&from)) {
+                    edge.properties = properties.clone();
+                }
+                if let Some(edge) = copy.nodes.get_mut(&from).and_then(|node| node.predecessors.get_mut(&to)) {
+                    edge.properties = properties;
+                }
+            }
+
+            for (index, parallel) in self.parallel_edges_for(&from, &to).iter().enumerate() {
+                let properties = f(&from, &to, &parallel.properties)?;
+                if mirrored {
+                    if let Some(edge) = copy.parallel_edges.get_mut(&(to.clone(), from.clone())).and_then(|edges| edges.get_mut(index)) {
+                        edge.properties = properties.clone();
+                    }
+                }
+                if let Some(edge) = copy.parallel_edges.get_mut(&(from.clone(), to.clone())).and_then(|edges| edges.get_mut(index)) {
+                    edge.properties = properties;
+                }
+            }
+        }
+        Ok(copy)
+    }
+
+    /// Recreate every existing property index over the current node values
+    fn rebuild_property_indices(&mut self) {
+        self.property_access_counts.clear();
+        let indexed: Vec<String> = self.property_indices.keys().cloned().collect();
+        for property in indexed {
+            self.create_property_index(&property);
+        }
+    }
+
     // =========================================================================
     // Method Storage (Class-like Graphs)
     // =========================================================================
@@ -2785,8 +3842,8 @@ impl Graph {
                 value: Value::none(),
                 node_type: Some("__branch__".to_string()),
                 properties: HashMap::new(),
-                neighbors: HashMap::new(),
-                predecessors: HashMap::new(),
+                neighbors: IndexMap::new(),
+                predecessors: IndexMap::new(),
             };
             self.nodes.insert(Self::METHOD_BRANCH.to_string(), branch_node);
         }
@@ -2818,6 +3875,7 @@ impl Graph {
     ///     is_setter: false,
     ///     is_static: false,
     ///     guard: None,
+    ///     synthetic: None,
     /// };
     /// g.attach_method("add".to_string(), func);
     ///
@@ -2862,8 +3920,8 @@ impl Graph {
                     value: Value::function(func),
                     node_type: Some("__method__".to_string()),
                     properties: HashMap::new(),
-                    neighbors: HashMap::new(),
-                    predecessors: HashMap::new(),
+                    neighbors: IndexMap::new(),
+                    predecessors: IndexMap::new(),
                 };
                 self.nodes.insert(method_id.clone(), method_node);
 
@@ -2891,8 +3949,8 @@ impl Graph {
                 value: Value::function(func),
                 node_type: Some("__method__".to_string()),
                 properties: HashMap::new(),
-                neighbors: HashMap::new(),
-                predecessors: HashMap::new(),
+                neighbors: IndexMap::new(),
+                predecessors: IndexMap::new(),
             };
             self.nodes.insert(method_id.clone(), method_node);
 
@@ -2965,7 +4023,7 @@ impl Graph {
             .filter(|id| !id.starts_with("__"))
             .filter(|id| {
                 // Also exclude computed_property_alias nodes
-                if let Some(node) = self.nodes.get(*id) {
+                if let Some(node) = self.nodes.get(id) {
                     node.node_type.as_deref() != Some("computed_property_alias")
                 } else {
                     true
@@ -3210,7 +4268,7 @@ impl Graph {
 
             // Update predecessor references in __parent__ if it exists
             if let Some(parent_node) = self.nodes.get_mut("__parent__") {
-                if let Some(edge) = parent_node.predecessors.remove("__self__") {
+                if let Some(edge) = parent_node.predecessors.shift_remove("__self__") {
                     parent_node.predecessors.insert(type_name.to_string(), edge);
                 }
             }
@@ -3343,7 +4401,7 @@ impl Graph {
 
         // Remove edge from __methods__ branch to this method
         if let Some(branch) = self.nodes.get_mut(Self::METHOD_BRANCH) {
-            branch.neighbors.remove(&method_id);
+            branch.neighbors.shift_remove(&method_id);
         }
 
         // Remove the method node itself
@@ -3414,8 +4472,8 @@ impl Graph {
             value: Value::function(func),
             node_type: Some("__setter__".to_string()),
             properties: HashMap::new(),
-            neighbors: HashMap::new(),
-            predecessors: HashMap::new(),
+            neighbors: IndexMap::new(),
+            predecessors: IndexMap::new(),
         };
 
         // Add the setter node to the graph
@@ -3473,8 +4531,8 @@ impl Graph {
             value: Value::function(func),
             node_type: Some("__static__".to_string()),
             properties: HashMap::new(),
-            neighbors: HashMap::new(),
-            predecessors: HashMap::new(),
+            neighbors: IndexMap::new(),
+            predecessors: IndexMap::new(),
         };
 
         // Add the static method node to the graph
@@ -4148,5 +5206,1051 @@ impl Graph {
 
         Ok(result)
     }
+
+    /// Union of this graph and `other`, as a new graph
+    ///
+    /// The result keeps this graph's type, rulesets, rules and methods, and
+    /// every addition is validated against them. When both graphs have a node
+    /// with the same id, `resolve(id, mine, theirs)` picks the merged value and
+    /// the node properties are combined, with `other`'s winning on shared keys.
+    /// Edges already present between a pair of nodes are kept as they are.
+    ///
+    /// Merging graphs of different types is a `TypeError`; a rule violation
+    /// while merging is a `ValueError`.
+    pub fn merge_with<F>(&self, other: &Graph, mut resolve: F) -> Result<Graph, GraphoidError>
+    where
+        F: FnMut(&str, &Value, &Value) -> Result<Value, GraphoidError>,
+    {
+        if self.graph_type != other.graph_type {
+            return Err(GraphoidError::runtime(
+                "TypeError: cannot merge a directed graph with an undirected graph".to_string()
+            ));
+        }
+
+        let violation = |err: GraphoidError| match err {
+            GraphoidError::RuleViolation { rule, message } => GraphoidError::runtime(format!(
+                "ValueError: merge violates rule '{}': {}", rule, message
+            )),
+            other => other,
+        };
+
+        let mut result = self.deep_copy_unfrozen();
+
+        for (node_id, node) in &other.nodes {
+            if node_id.starts_with("__") {
+                continue;
+            }
+            let value = match self.nodes.get(node_id) {
+                Some(mine) => resolve(node_id, &mine.value, &node.value)?,
+                None => node.value.clone(),
+            };
+            result.add_node(node_id.clone(), value).map_err(violation)?;
+            if let Some(merged) = result.nodes.get_mut(node_id) {
+                for (key, prop) in &node.properties {
+                    merged.properties.insert(key.clone(), prop.clone());
+                }
+                if node.node_type.is_some() {
+                    merged.node_type = node.node_type.clone();
+                }
+            }
+        }
+
+        for (from_id, from_node) in &other.nodes {
+            if from_id.starts_with("__") {
+                continue;
+            }
+            for (to_id, edge_info) in &from_node.neighbors {
+                if to_id.starts_with("__") || result.has_edge(from_id, to_id) {
+                    continue;
+                }
+                let edges = std::iter::once(edge_info).chain(other.parallel_edges_for(from_id, to_id));
+                for edge in edges {
+                    result.add_edge(
+                        from_id,
+                        to_id,
+                        edge.edge_type.clone(),
+                        edge.weight,
+                        edge.properties.clone(),
+                    ).map_err(violation)?;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Copy of this graph with data node ids rewritten through `mapping`
+    ///
+    /// Ids missing from `mapping` keep their id, and mapping entries for ids
+    /// that are not in the graph are ignored. Values, node types, properties
+    /// and edges (including parallel edges) follow their nodes. When several
+    /// nodes end up with the same id, `resolve(id, kept, incoming)` picks the
+    /// merged value, properties are combined with the incoming node's winning,
+    /// and edges already present between a pair of nodes are kept as they are.
+    ///
+    /// A rule violation while rebuilding is a `ValueError`.
+    pub fn relabel_nodes_with<F>(&self, mapping: &HashMap<String, String>, mut resolve: F) -> Result<Graph, GraphoidError>
+    where
+        F: FnMut(&str, &Value, &Value) -> Result<Value, GraphoidError>,
+    {
+        if let Some(internal) = mapping.values().find(|id| id.starts_with("__")) {
+            return Err(GraphoidError::runtime(format!(
+                "ValueError: cannot relabel a node to the reserved id '{}'", internal
+            )));
+        }
+
+        let violation = |err: GraphoidError| match err {
+            GraphoidError::RuleViolation { rule, message } => GraphoidError::runtime(format!(
+                "ValueError: relabel_nodes violates rule '{}': {}", rule, message
+            )),
+            other => other,
+        };
+        let relabel = |id: &str| mapping.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+        let data_ids = self.data_node_ids();
+        let mut result = self.deep_copy_unfrozen();
+        for id in &data_ids {
+            result.nodes.remove(id);
+        }
+        result.parallel_edges.clear();
+
+        for id in &data_ids {
+            let Some(node) = self.nodes.get(id) else { continue };
+            let new_id = relabel(id);
+            let value = match result.nodes.get(&new_id) {
+                Some(kept) => resolve(&new_id, &kept.value, &node.value)?,
+                None => node.value.clone(),
+            };
+            result.add_node(new_id.clone(), value).map_err(violation)?;
+            if let Some(relabeled) = result.nodes.get_mut(&new_id) {
+                for (key, prop) in &node.properties {
+                    relabeled.properties.insert(key.clone(), prop.clone());
+                }
+                if node.node_type.is_some() {
+                    relabeled.node_type = node.node_type.clone();
+                }
+            }
+        }
+
+        for from_id in &data_ids {
+            let Some(from_node) = self.nodes.get(from_id) else { continue };
+            for (to_id, edge_info) in &from_node.neighbors {
+                if to_id.starts_with("__") {
+                    continue;
+                }
+                let (from, to) = (relabel(from_id), relabel(to_id));
+                if result.has_edge(&from, &to) {
+                    continue;
+                }
+                let edges = std::iter::once(edge_info).chain(self.parallel_edges_for(from_id, to_id));
+                for edge in edges {
+                    result.add_edge(
+                        &from,
+                        &to,
+                        edge.edge_type.clone(),
+                        edge.weight,
+                        edge.properties.clone(),
+                    ).map_err(violation)?;
+                }
+            }
+        }
+
+        result.rebuild_property_indices();
+        Ok(result)
+    }
+
+    // =========================================================================
+    // Graph Metrics
+    // =========================================================================
+
+    /// Degree of every data node. Directed graphs count in- plus out-edges;
+    /// undirected graphs count neighbors (each edge is stored both ways).
+    fn data_degrees(&self) -> HashMap<String, usize> {
+        let node_ids = self.data_node_ids();
+        let mut degrees: HashMap<String, usize> = node_ids.iter().map(|id| (id.clone(), 0)).collect();
+        for id in &node_ids {
+            let Some(node) = self.nodes.get(id) else { continue };
+            for to_id in node.neighbors.keys() {
+                if !degrees.contains_key(to_id) {
+                    continue;
+                }
+                *degrees.get_mut(id).unwrap() += 1;
+                if self.graph_type == GraphType::Directed {
+                    *degrees.get_mut(to_id).unwrap() += 1;
+                }
+            }
+        }
+        degrees
+    }
+
+    /// Number of distinct data edges (an undirected edge counts once).
+    fn distinct_data_edge_count(&self) -> usize {
+        let node_ids: HashSet<String> = self.data_node_ids().into_iter().collect();
+        let mut count = 0;
+        for id in &node_ids {
+            let Some(node) = self.nodes.get(id) else { continue };
+            for to_id in node.neighbors.keys() {
+                if !node_ids.contains(to_id) {
+                    continue;
+                }
+                // Undirected edges are stored in both directions; count one of them
+                if self.graph_type == GraphType::Directed || id <= to_id {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Map of degree -> number of nodes with that degree.
+    pub fn degree_histogram(&self) -> std::collections::BTreeMap<usize, usize> {
+        let mut histogram = std::collections::BTreeMap::new();
+        for degree in self.data_degrees().values() {
+            *histogram.entry(*degree).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Edges present over edges possible: `E / (N(N-1))` for directed graphs,
+    /// `2E / (N(N-1))` for undirected ones. Graphs with fewer than two nodes have
+    /// density 0.
+    pub fn density(&self) -> f64 {
+        let n = self.node_count() as f64;
+        if n < 2.0 {
+            return 0.0;
+        }
+        let possible = match self.graph_type {
+            GraphType::Directed => n * (n - 1.0),
+            GraphType::Undirected => n * (n - 1.0) / 2.0,
+        };
+        self.distinct_data_edge_count() as f64 / possible
+    }
+
+    /// Mean node degree (0 for an empty graph).
+    pub fn average_degree(&self) -> f64 {
+        let degrees = self.data_degrees();
+        if degrees.is_empty() {
+            return 0.0;
+        }
+        degrees.values().sum::<usize>() as f64 / degrees.len() as f64
+    }
+
+    /// Longest shortest path, in hops, over all pairs of nodes: one BFS
+    /// per node. Returns None for an empty graph or when some node cannot
+    /// reach another (for directed graphs, edge direction is respected).
+    pub fn diameter(&self) -> Option<usize> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        if ids.is_empty() {
+            return None;
+        }
+        let mut diameter = 0;
+        for source in 0..ids.len() {
+            for hops in Self::hop_distances(&adjacency, source) {
+                diameter = diameter.max(hops?);
+            }
+        }
+        Some(diameter)
+    }
+
+    /// Whether every data node can reach every other when edge direction is
+    /// ignored (weak connectivity for directed graphs). A single BFS from the
+    /// first node stops as soon as all nodes are seen. An empty graph is
+    /// vacuously connected.
+    pub fn is_connected(&self) -> bool {
+        let ids = self.data_node_ids();
+        let Some(start) = ids.first().cloned() else { return true };
+        let node_ids: HashSet<String> = ids.into_iter().collect();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(start.clone());
+        queue.push_back(start);
+
+        while let Some(node_id) = queue.pop_front() {
+            if visited.len() == node_ids.len() {
+                return true;
+            }
+            let Some(node) = self.nodes.get(&node_id) else { continue };
+            for next in node.neighbors.keys().chain(node.predecessors.keys()) {
+                if node_ids.contains(next) && visited.insert(next.clone()) {
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+        visited.len() == node_ids.len()
+    }
+
+    // =========================================================================
+    // Centrality
+    // =========================================================================
+
+    /// Degree of each node over `n - 1`, the most neighbors it could have.
+    /// Degrees are counted as in `degree_histogram`, so directed graphs add
+    /// in- and out-edges and can score above 1. A lone node scores 0.
+    pub fn degree_centrality(&self) -> HashMap<String, f64> {
+        let others = self.node_count().saturating_sub(1);
+        self.data_degrees()
+            .into_iter()
+            .map(|(id, degree)| {
+                let score = if others == 0 { 0.0 } else { degree as f64 / others as f64 };
+                (id, score)
+            })
+            .collect()
+    }
+
+    /// Closeness of each node from hop distances (one BFS per node) to the
+    /// nodes it can reach, following edge direction. Reaching `r` nodes at total distance `d`
+    /// scores `(r / d) * (r / (n - 1))`, which scales partial reach down
+    /// (the Wasserman-Faust variant). Nodes that reach nothing score 0.
+    pub fn closeness_centrality(&self) -> HashMap<String, f64> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        let others = ids.len().saturating_sub(1) as f64;
+        ids.iter()
+            .enumerate()
+            .map(|(source, id)| {
+                let distances: Vec<usize> = Self::hop_distances(&adjacency, source)
+                    .into_iter()
+                    .flatten()
+                    .filter(|&hops| hops > 0)
+                    .collect();
+                let reached = distances.len() as f64;
+                let total: usize = distances.iter().sum();
+                let score = if total == 0 { 0.0 } else { (reached / total as f64) * (reached / others) };
+                (id.clone(), score)
+            })
+            .collect()
+    }
+
+    /// Share of shortest paths between other pairs of nodes that pass through
+    /// each node (Brandes' algorithm, unweighted hops), normalized by
+    /// `(n - 1)(n - 2)` so scores fall in 0..=1. Graphs with fewer than three
+    /// nodes score 0 everywhere.
+    pub fn betweenness_centrality(&self) -> HashMap<String, f64> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        let n = ids.len();
+        let mut scores = vec![0.0; n];
+
+        for source in 0..n {
+            let mut order = Vec::with_capacity(n);
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut paths = vec![0.0; n];
+            let mut distance = vec![usize::MAX; n];
+            let mut queue = VecDeque::new();
+            paths[source] = 1.0;
+            distance[source] = 0;
+            queue.push_back(source);
+
+            while let Some(v) = queue.pop_front() {
+                order.push(v);
+                for &w in &adjacency[v] {
+                    if distance[w] == usize::MAX {
+                        distance[w] = distance[v] + 1;
+                        queue.push_back(w);
+                    }
+                    if distance[w] == distance[v] + 1 {
+                        paths[w] += paths[v];
+                        predecessors[w].push(v);
+                    }
+                }
+            }
+
+            // Accumulate dependencies back from the farthest nodes
+            let mut dependency = vec![0.0; n];
+            while let Some(w) = order.pop() {
+                for &v in &predecessors[w] {
+                    dependency[v] += paths[v] / paths[w] * (1.0 + dependency[w]);
+                }
+                if w != source {
+                    scores[w] += dependency[w];
+                }
+            }
+        }
+
+        let scale = if n > 2 { 1.0 / ((n - 1) * (n - 2)) as f64 } else { 0.0 };
+        ids.into_iter().zip(scores).map(|(id, score)| (id, score * scale)).collect()
+    }
+
+    /// PageRank of each node by power iteration with the given damping factor,
+    /// stopping after 100 rounds or once the total change drops below 1e-9.
+    /// Rank held by nodes without outgoing edges is spread evenly over all
+    /// nodes, so scores sum to 1. Undirected edges count in both directions.
+    pub fn pagerank(&self, damping: f64) -> HashMap<String, f64> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        let n = ids.len();
+        if n == 0 {
+            return HashMap::new();
+        }
+        let mut ranks = vec![1.0 / n as f64; n];
+
+        for _ in 0..100 {
+            let dangling: f64 = (0..n).filter(|&v| adjacency[v].is_empty()).map(|v| ranks[v]).sum();
+            let base = (1.0 - damping + damping * dangling) / n as f64;
+            let mut next = vec![base; n];
+            for (v, targets) in adjacency.iter().enumerate() {
+                if targets.is_empty() {
+                    continue;
+                }
+                let share = damping * ranks[v] / targets.len() as f64;
+                for &w in targets {
+                    next[w] += share;
+                }
+            }
+            let change: f64 = next.iter().zip(&ranks).map(|(a, b)| (a - b).abs()).sum();
+            ranks = next;
+            if change < 1e-9 {
+                break;
+            }
+        }
+
+        ids.into_iter().zip(ranks).collect()
+    }
+
+    // =========================================================================
+    // Cycle Detection
+    // =========================================================================
+
+    /// Every cycle in the graph, each as the sequence of node ids along it.
+    ///
+    /// Directed graphs return all elementary cycles (Johnson's algorithm); each
+    /// cycle starts at its smallest node id. Undirected graphs return one cycle
+    /// per DFS back edge (a cycle basis), since walking an edge there and back
+    /// is not a cycle. Self-loops are single-element cycles in both cases.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        let cycles = match self.graph_type {
+            GraphType::Directed => directed_cycles(&adjacency),
+            GraphType::Undirected => undirected_cycle_basis(&adjacency),
+        };
+        cycles.into_iter()
+            .map(|cycle| cycle.into_iter().map(|i| ids[i].clone()).collect())
+            .collect()
+    }
+
+    /// Whether the graph contains any cycle (see `find_cycles`).
+    pub fn has_cycle(&self) -> bool {
+        let (_, adjacency) = self.indexed_adjacency();
+        match self.graph_type {
+            GraphType::Directed => !is_acyclic(&adjacency),
+            GraphType::Undirected => !undirected_cycle_basis(&adjacency).is_empty(),
+        }
+    }
+
+    /// One shortest cycle, as the sequence of node ids along it starting at its
+    /// smallest id, or None if the graph has no cycle. A self-loop is a cycle
+    /// of length 1, and in an undirected multigraph a repeated edge is a cycle
+    /// of length 2. Repeated edges in a directed graph are not cycles.
+    pub fn shortest_cycle(&self) -> Option<Vec<String>> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        let directed = self.graph_type == GraphType::Directed;
+
+        let mut cycle = if let Some(node) = (0..ids.len()).find(|&i| adjacency[i].contains(&i)) {
+            vec![node]
+        } else if let Some(pair) = self.parallel_edge_pair(&ids).filter(|_| !directed) {
+            pair
+        } else {
+            shortest_cycle_bfs(&adjacency, directed)?
+        };
+
+        // Start at the smallest id; undirected cycles also walk toward the
+        // smaller of its two neighbors
+        let start = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap_or(0);
+        cycle.rotate_left(start);
+        if !directed && cycle.len() > 2 && cycle[cycle.len() - 1] < cycle[1] {
+            cycle[1..].reverse();
+        }
+        Some(cycle.into_iter().map(|i| ids[i].clone()).collect())
+    }
+
+    /// Length of the shortest cycle (see `shortest_cycle`), or None if acyclic.
+    pub fn girth(&self) -> Option<usize> {
+        self.shortest_cycle().map(|cycle| cycle.len())
+    }
+
+    /// Indices of the smallest pair of distinct data nodes joined by repeated edges.
+    fn parallel_edge_pair(&self, ids: &[String]) -> Option<Vec<usize>> {
+        self.parallel_edges.iter()
+            .filter(|((from, to), edges)| from != to && !edges.is_empty())
+            .filter_map(|((from, to), _)| {
+                let from = ids.binary_search(from).ok()?;
+                let to = ids.binary_search(to).ok()?;
+                Some(vec![from.min(to), from.max(to)])
+            })
+            .min()
+    }
+
+    /// Sorted data node ids and, for each, the sorted indices of its neighbors.
+    fn indexed_adjacency(&self) -> (Vec<String>, Vec<Vec<usize>>) {
+        let mut ids = self.data_node_ids();
+        ids.sort();
+        let index: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, id)| (id.as_str(), i)).collect();
+        let adjacency = ids.iter().map(|id| {
+            let mut targets: Vec<usize> = self.nodes.get(id)
+                .map(|node| node.neighbors.keys().filter_map(|to| index.get(to.as_str()).copied()).collect())
+                .unwrap_or_default();
+            targets.sort_unstable();
+            targets
+        }).collect();
+        (ids, adjacency)
+    }
+
+    /// Hop distance from `source` to every node of an `indexed_adjacency`,
+    /// or None for nodes it cannot reach.
+    fn hop_distances(adjacency: &[Vec<usize>], source: usize) -> Vec<Option<usize>> {
+        let mut distances = vec![None; adjacency.len()];
+        let mut queue = VecDeque::new();
+        distances[source] = Some(0);
+        queue.push_back(source);
+
+        while let Some(v) = queue.pop_front() {
+            let next = distances[v].map(|hops| hops + 1);
+            for &w in &adjacency[v] {
+                if distances[w].is_none() {
+                    distances[w] = next;
+                    queue.push_back(w);
+                }
+            }
+        }
+        distances
+    }
+
+    // =========================================================================
+    // Graph Coloring
+    // =========================================================================
+
+    /// Greedy vertex coloring using the largest-first heuristic: nodes are
+    /// colored in order of decreasing degree (ties by id), each taking the
+    /// smallest color index not used by a neighbor. Isolated nodes get color 0
+    /// and self-loops are ignored. Returns `(id, color)` pairs sorted by id.
+    ///
+    /// Meant for undirected graphs; on a directed graph only outgoing edges
+    /// would be seen, so callers should reject those.
+    pub fn greedy_coloring(&self) -> Vec<(String, usize)> {
+        let (ids, adjacency) = self.indexed_adjacency();
+        let degree = |i: usize| adjacency[i].iter().filter(|&&j| j != i).count();
+        let mut order: Vec<usize> = (0..ids.len()).collect();
+        order.sort_by(|&a, &b| degree(b).cmp(&degree(a)).then(a.cmp(&b)));
+
+        let mut colors: Vec<Option<usize>> = vec![None; ids.len()];
+        for &node in &order {
+            let used: HashSet<usize> = adjacency[node].iter()
+                .filter(|&&neighbor| neighbor != node)
+                .filter_map(|&neighbor| colors[neighbor])
+                .collect();
+            colors[node] = (0..).find(|color| !used.contains(color));
+        }
+
+        ids.into_iter()
+            .zip(colors)
+            .map(|(id, color)| (id, color.unwrap_or(0)))
+            .collect()
+    }
+
+    /// Number of colors `greedy_coloring` uses: an upper bound on the
+    /// chromatic number (0 for an empty graph).
+    pub fn chromatic_number_estimate(&self) -> usize {
+        self.greedy_coloring().iter().map(|(_, color)| color + 1).max().unwrap_or(0)
+    }
+
+    // =========================================================================
+    // Shape Predicates
+    // =========================================================================
+    //
+    // These look only at the data nodes and edges, never at declared rulesets,
+    // so they answer the same way for plain graphs and for :tree/:dag ones.
+
+    /// Connected (ignoring edge direction), acyclic, with exactly `n - 1`
+    /// edges. An empty graph is not a tree.
+    pub fn is_tree(&self) -> bool {
+        let n = self.node_count();
+        if n == 0 || self.distinct_data_edge_count() != n - 1 {
+            return false;
+        }
+        let adjacency = self.undirected_adjacency();
+        let mut seen = vec![false; n];
+        let mut stack = vec![0];
+        seen[0] = true;
+        while let Some(v) = stack.pop() {
+            for &to in &adjacency[v] {
+                if !seen[to] {
+                    seen[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        // Connected with exactly n - 1 edges leaves no room for a cycle (a
+        // self-loop or a pair of opposite edges would leave a node unreached)
+        seen.iter().all(|&s| s)
+    }
+
+    /// Directed and free of cycles (self-loops included).
+    pub fn is_dag(&self) -> bool {
+        self.graph_type == GraphType::Directed && !self.has_cycle()
+    }
+
+    /// Whether the nodes can be split into two sets with every edge running
+    /// between them, i.e. the graph is two-colorable. Edge direction is
+    /// ignored; a self-loop makes a graph non-bipartite.
+    pub fn is_bipartite(&self) -> bool {
+        let adjacency = self.undirected_adjacency();
+        let mut side: Vec<Option<bool>> = vec![None; adjacency.len()];
+        for start in 0..adjacency.len() {
+            if side[start].is_some() {
+                continue;
+            }
+            side[start] = Some(false);
+            let mut queue = VecDeque::from([start]);
+            while let Some(v) = queue.pop_front() {
+                let v_side = side[v].unwrap_or(false);
+                for &to in &adjacency[v] {
+                    match side[to] {
+                        Some(to_side) if to_side == v_side => return false,
+                        Some(_) => {}
+                        None => {
+                            side[to] = Some(!v_side);
+                            queue.push_back(to);
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// `indexed_adjacency` with every edge also added in reverse.
+    fn undirected_adjacency(&self) -> Vec<Vec<usize>> {
+        let (_, mut adjacency) = self.indexed_adjacency();
+        if self.graph_type == GraphType::Directed {
+            let edges: Vec<(usize, usize)> = adjacency.iter().enumerate()
+                .flat_map(|(from, targets)| targets.iter().map(move |&to| (from, to)))
+                .collect();
+            for (from, to) in edges {
+                if !adjacency[to].contains(&from) {
+                    adjacency[to].push(from);
+                }
+            }
+        }
+        adjacency
+    }
+
+    // =========================================================================
+    // Visualization: Text Display
+    // =========================================================================
+
+    /// Multi-line view of the data layer for `show()`: the compact
+    /// `<graph: N nodes, M edges>` header, then one line per node in insertion
+    /// order listing its neighbors, e.g. `a -> b, c` (`--` for undirected
+    /// graphs). Only the first `limit` nodes are listed; the rest are folded
+    /// into a final `... +N more nodes` line. `None` lists every node.
+    pub fn show(&self, limit: Option<usize>) -> String {
+        let ids = self.data_node_ids();
+        let arrow = if self.graph_type == GraphType::Directed { "->" } else { "--" };
+        let mut output = format!("<graph: {} nodes, {} edges>", ids.len(), self.edge_count());
+
+        let shown = limit.unwrap_or(ids.len()).min(ids.len());
+        for id in &ids[..shown] {
+            output.push_str("\n  ");
+            output.push_str(id);
+            let neighbors: Vec<&str> = self.nodes.get(id)
+                .map(|node| node.neighbors.keys()
+                    .filter(|n| !n.starts_with("__methods__"))
+                    .map(String::as_str)
+                    .collect())
+                .unwrap_or_default();
+            if !neighbors.is_empty() {
+                output.push_str(&format!(" {} {}", arrow, neighbors.join(", ")));
+            }
+        }
+        if shown < ids.len() {
+            output.push_str(&format!("\n  ... +{} more nodes", ids.len() - shown));
+        }
+        output
+    }
+
+    // =========================================================================
+    // Visualization: SVG Export
+    // =========================================================================
+
+    /// Render the data layer as a standalone SVG document.
+    ///
+    /// Nodes are placed on a circle in sorted ID order, so the same graph always
+    /// produces the same image, and isolated nodes sit on the circle like any
+    /// other. The viewport is sized to fit every node and its label. Nodes are
+    /// labeled by value (falling back to the ID when the value is none); directed
+    /// edges get arrowheads.
+    pub fn to_svg(&self) -> String {
+        const NODE_RADIUS: f64 = 20.0;
+        const MARGIN: f64 = 40.0;
+        const SPACING: f64 = 60.0;
+        // Approximate glyph width at font-size 12, and the longest label drawn in full
+        const CHAR_WIDTH: f64 = 7.0;
+        const LABEL_PADDING: f64 = 6.0;
+        const MAX_LABEL_CHARS: usize = 16;
+
+        fn escape(text: &str) -> String {
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        }
+
+        let mut node_ids = self.node_ids();
+        node_ids.sort();
+        let n = node_ids.len();
+
+        // Each node gets its label (truncated past MAX_LABEL_CHARS) and a
+        // circle wide enough to hold it
+        let labels: HashMap<&str, (String, String, f64)> = node_ids
+            .iter()
+            .map(|id| {
+                let full = match self.get_node(id) {
+                    Some(value) if !matches!(value.kind, ValueKind::None) => value.to_string_value(),
+                    _ => id.clone(),
+                };
+                let shown = if full.chars().count() > MAX_LABEL_CHARS {
+                    let mut head: String = full.chars().take(MAX_LABEL_CHARS - 1).collect();
+                    head.push('\u{2026}');
+                    head
+                } else {
+                    full.clone()
+                };
+                let radius = (shown.chars().count() as f64 * CHAR_WIDTH / 2.0 + LABEL_PADDING).max(NODE_RADIUS);
+                (id.as_str(), (full, shown, radius))
+            })
+            .collect();
+        let radius_of = |id: &str| labels.get(id).map_or(NODE_RADIUS, |(_, _, r)| *r);
+        let max_radius = labels.values().map(|(_, _, r)| *r).fold(NODE_RADIUS, f64::max);
+
+        // Circumference grows with the node count so neighbors never overlap;
+        // wider circles keep the same gap between them as default-sized ones
+        let spacing = SPACING + 2.0 * (max_radius - NODE_RADIUS);
+        let layout_radius = if n <= 1 {
+            0.0
+        } else {
+            (n as f64 * spacing / std::f64::consts::TAU).max(spacing)
+        };
+        let center = layout_radius + max_radius + MARGIN;
+        let size = center * 2.0;
+
+        let positions: HashMap<&str, (f64, f64)> = node_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                // Start at the top and go clockwise
+                let angle = std::f64::consts::TAU * i as f64 / n as f64 - std::f64::consts::FRAC_PI_2;
+                (id.as_str(), (center + layout_radius * angle.cos(), center + layout_radius * angle.sin()))
+            })
+            .collect();
+
+        let directed = self.graph_type == GraphType::Directed;
+        let mut edges = self.data_edge_list();
+        edges.sort();
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" viewBox=\"0 0 {:.0} {:.0}\">\n",
+            size, size, size, size
+        ));
+        if directed {
+            svg.push_str("  <defs>\n");
+            svg.push_str("    <marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\">\n");
+            svg.push_str("      <path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"#555\"/>\n");
+            svg.push_str("    </marker>\n");
+            svg.push_str("  </defs>\n");
+        }
+
+        svg.push_str("  <g class=\"edges\" stroke=\"#555\" stroke-width=\"1.5\" fill=\"none\">\n");
+        let marker = if directed { " marker-end=\"url(#arrow)\"" } else { "" };
+        for (from, to, _) in &edges {
+            // Undirected edges are stored in both directions; draw each once
+            if !directed && from > to {
+                continue;
+            }
+            let (Some(&(x1, y1)), Some(&(x2, y2))) = (positions.get(from.as_str()), positions.get(to.as_str())) else {
+                continue;
+            };
+            if from == to {
+                // Self-loop: small arc above the node
+                let top = y1 - radius_of(from) + 4.0;
+                svg.push_str(&format!(
+                    "    <path d=\"M {:.1} {:.1} A 12 12 0 1 1 {:.1} {:.1}\"{}/>\n",
+                    x1 - 8.0, top, x1 + 8.0, top, marker
+                ));
+                continue;
+            }
+            // Trim the line to the circle boundaries so arrowheads stay visible
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let length = (dx * dx + dy * dy).sqrt().max(f64::EPSILON);
+            let (ux, uy) = (dx / length, dy / length);
+            svg.push_str(&format!(
+                "    <line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\"{}/>\n",
+                x1 + ux * radius_of(from), y1 + uy * radius_of(from),
+                x2 - ux * radius_of(to), y2 - uy * radius_of(to),
+                marker
+            ));
+        }
+        svg.push_str("  </g>\n");
+
+        svg.push_str("  <g class=\"nodes\" font-family=\"sans-serif\" font-size=\"12\" text-anchor=\"middle\">\n");
+        for id in &node_ids {
+            let (x, y) = positions[id.as_str()];
+            let (full, shown, radius) = &labels[id.as_str()];
+            // A truncated label keeps its full text as a hover tooltip
+            let title = if full != shown {
+                format!("      <title>{}</title>\n", escape(full))
+            } else {
+                String::new()
+            };
+            svg.push_str(&format!(
+                "    <g class=\"node\" data-id=\"{}\">\n{}      <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.0}\" fill=\"#e8f0fe\" stroke=\"#333\"/>\n      <text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"central\">{}</text>\n    </g>\n",
+                escape(id), title, x, y, radius, x, y, escape(shown)
+            ));
+        }
+        svg.push_str("  </g>\n");
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+// =============================================================================
+// Cycle search helpers (nodes are indices into a sorted id list)
+// =============================================================================
+
+/// All elementary cycles of a directed graph (Johnson's algorithm).
+fn directed_cycles(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = adjacency.len();
+    if is_acyclic(adjacency) {
+        return Vec::new();
+    }
+    let mut reverse = vec![Vec::new(); n];
+    for (from, targets) in adjacency.iter().enumerate() {
+        for &to in targets {
+            reverse[to].push(from);
+        }
+    }
+
+    let mut search = JohnsonSearch {
+        adjacency,
+        start: 0,
+        in_component: vec![false; n],
+        blocked: vec![false; n],
+        blocked_by: vec![HashSet::new(); n],
+        stack: Vec::new(),
+        cycles: Vec::new(),
+    };
+    for start in 0..n {
+        // Cycles through `start` that only use nodes >= start, so each cycle
+        // is found once, from its smallest node
+        let forward = reachable_within(adjacency, start);
+        let backward = reachable_within(&reverse, start);
+        let component: Vec<bool> = (0..n).map(|v| forward[v] && backward[v]).collect();
+        let has_self_loop = adjacency[start].contains(&start);
+        if component.iter().filter(|&&c| c).count() == 1 && !has_self_loop {
+            continue;
+        }
+
+        search.start = start;
+        search.in_component = component;
+        for v in start..n {
+            search.blocked[v] = false;
+            search.blocked_by[v].clear();
+        }
+        search.circuit(start);
+    }
+    search.cycles
+}
+
+/// Nodes >= `start` reachable from `start` (including itself).
+fn reachable_within(adjacency: &[Vec<usize>], start: usize) -> Vec<bool> {
+    let mut seen = vec![false; adjacency.len()];
+    let mut stack = vec![start];
+    seen[start] = true;
+    while let Some(v) = stack.pop() {
+        for &w in &adjacency[v] {
+            if w >= start && !seen[w] {
+                seen[w] = true;
+                stack.push(w);
+            }
+        }
+    }
+    seen
+}
+
+/// Kahn's algorithm: true when every node can be removed in topological order.
+fn is_acyclic(adjacency: &[Vec<usize>]) -> bool {
+    let mut in_degree = vec![0usize; adjacency.len()];
+    for targets in adjacency {
+        for &to in targets {
+            in_degree[to] += 1;
+        }
+    }
+    let mut ready: Vec<usize> = (0..adjacency.len()).filter(|&v| in_degree[v] == 0).collect();
+    let mut removed = 0;
+    while let Some(v) = ready.pop() {
+        removed += 1;
+        for &to in &adjacency[v] {
+            in_degree[to] -= 1;
+            if in_degree[to] == 0 {
+                ready.push(to);
+            }
+        }
+    }
+    removed == adjacency.len()
+}
+
+struct JohnsonSearch<'a> {
+    adjacency: &'a [Vec<usize>],
+    start: usize,
+    in_component: Vec<bool>,
+    blocked: Vec<bool>,
+    blocked_by: Vec<HashSet<usize>>,
+    stack: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+}
+
+impl JohnsonSearch<'_> {
+    fn circuit(&mut self, v: usize) -> bool {
+        let mut found = false;
+        self.stack.push(v);
+        self.blocked[v] = true;
+        for &w in &self.adjacency[v] {
+            if !self.in_component[w] {
+                continue;
+            }
+            if w == self.start {
+                self.cycles.push(self.stack.clone());
+                found = true;
+            } else if !self.blocked[w] && self.circuit(w) {
+                found = true;
+            }
+        }
+        if found {
+            self.unblock(v);
+        } else {
+            for &w in &self.adjacency[v] {
+                if self.in_component[w] {
+                    self.blocked_by[w].insert(v);
+                }
+            }
+        }
+        self.stack.pop();
+        found
+    }
+
+    fn unblock(&mut self, v: usize) {
+        self.blocked[v] = false;
+        for w in std::mem::take(&mut self.blocked_by[v]) {
+            if self.blocked[w] {
+                self.unblock(w);
+            }
+        }
+    }
 }
 
+/// A shortest cycle of length 2 or more, from a breadth-first search rooted at
+/// every node. Self-loops are skipped, so the caller checks for them first.
+/// Stops as soon as a cycle of the smallest possible length is found: 2 for
+/// directed graphs, 3 for undirected ones (which cannot use an edge twice).
+fn shortest_cycle_bfs(adjacency: &[Vec<usize>], directed: bool) -> Option<Vec<usize>> {
+    let n = adjacency.len();
+    let shortest_possible = if directed { 2 } else { 3 };
+    let mut best: Option<Vec<usize>> = None;
+
+    for root in 0..n {
+        if best.as_ref().is_some_and(|cycle| cycle.len() == shortest_possible) {
+            break;
+        }
+        let mut dist: Vec<Option<usize>> = vec![None; n];
+        let mut parent: Vec<Option<usize>> = vec![None; n];
+        dist[root] = Some(0);
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(u) = queue.pop_front() {
+            let du = dist[u].unwrap_or(0);
+            // No cycle closed from here on can beat the best one so far
+            let shortest_from_here = if directed { du + 1 } else { 2 * du + 1 };
+            if shortest_from_here >= best.as_ref().map_or(usize::MAX, Vec::len) {
+                break;
+            }
+            for &v in &adjacency[u] {
+                if v == u {
+                    continue;
+                }
+                if directed && v == root {
+                    best = Some(bfs_path(&parent, u));
+                    continue;
+                }
+                match dist[v] {
+                    None => {
+                        dist[v] = Some(du + 1);
+                        parent[v] = Some(u);
+                        queue.push_back(v);
+                    }
+                    // A non-tree edge closes a cycle through the root
+                    Some(dv) if !directed && parent[u] != Some(v)
+                        && du + dv + 1 < best.as_ref().map_or(usize::MAX, Vec::len) =>
+                    {
+                        let mut cycle = bfs_path(&parent, u);
+                        cycle.extend(bfs_path(&parent, v).into_iter().skip(1).rev());
+                        best = Some(cycle);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Breadth-first tree path from the root to `node`.
+fn bfs_path(parent: &[Option<usize>], node: usize) -> Vec<usize> {
+    let mut path = vec![node];
+    let mut current = node;
+    while let Some(p) = parent[current] {
+        path.push(p);
+        current = p;
+    }
+    path.reverse();
+    path
+}
+
+/// One cycle per back edge of a depth-first search over an undirected graph.
+fn undirected_cycle_basis(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut depth: Vec<Option<usize>> = vec![None; adjacency.len()];
+    let mut path = Vec::new();
+    let mut cycles = Vec::new();
+    for root in 0..adjacency.len() {
+        if depth[root].is_none() {
+            undirected_cycles_dfs(adjacency, root, None, &mut depth, &mut path, &mut cycles);
+        }
+    }
+    cycles
+}
+
+fn undirected_cycles_dfs(
+    adjacency: &[Vec<usize>],
+    v: usize,
+    parent: Option<usize>,
+    depth: &mut [Option<usize>],
+    path: &mut Vec<usize>,
+    cycles: &mut Vec<Vec<usize>>,
+) {
+    depth[v] = Some(path.len());
+    path.push(v);
+    for &w in &adjacency[v] {
+        if w == v {
+            cycles.push(vec![v]);
+            continue;
+        }
+        if Some(w) == parent {
+            continue;
+        }
+        match depth[w] {
+            None => undirected_cycles_dfs(adjacency, w, Some(v), depth, path, cycles),
+            // Back edge to an ancestor still on the path
+            Some(d) if d < path.len() && path[d] == w && d + 1 < path.len() => {
+                cycles.push(path[d..].to_vec());
+            }
+            _ => {}
+        }
+    }
+    path.pop();
+}
@@ -2,11 +2,21 @@
 //!
 //! In Graphoid, hashes are graphs where node IDs are keys and node values are the map values.
 //! This allows hashes to use the full rule system and graph operations.
+//!
+//! String keys are used as node IDs directly. Any other hashable key (a
+//! number, symbol, list, map, graph, ...) is stored under an ID derived from
+//! its content, and the hash keeps the original key value beside the graph so
+//! it can be handed back by `keys()`.
 
-use super::{Value, Graph};
+use super::{Value, ValueKind, Graph};
 use crate::values::graph::GraphType;
 use crate::graph::{RuleSpec, RuleInstance};
 use crate::error::GraphoidError;
+use std::collections::HashMap;
+
+/// Prefix of node IDs for keys that are not strings. String keys may not
+/// start with it, so the two kinds of ID never collide.
+const VALUE_KEY_PREFIX: char = '\u{0}';
 
 /// Hash is a key-value graph
 ///
@@ -16,6 +26,8 @@ use crate::error::GraphoidError;
 pub struct Hash {
     /// Underlying graph storing key-value pairs
     pub graph: Graph,
+    /// Original values of the keys that are not strings, by node ID
+    value_keys: HashMap<String, Value>,
 }
 
 impl Hash {
@@ -23,11 +35,12 @@ impl Hash {
     pub fn new() -> Self {
         Hash {
             graph: Graph::new(GraphType::Directed),
+            value_keys: HashMap::new(),
         }
     }
 
     /// Create a hash from a HashMap
-    pub fn from_hashmap(map: std::collections::HashMap<String, Value>) -> Self {
+    pub fn from_hashmap(map: HashMap<String, Value>) -> Self {
         let mut hash = Hash::new();
         for (key, value) in map {
             hash.insert(key, value).unwrap(); // Should never fail for new hash
@@ -37,18 +50,18 @@ impl Hash {
 
     /// Insert a key-value pair
     pub fn insert(&mut self, key: String, value: Value) -> Result<Option<Value>, GraphoidError> {
+        Self::check_string_key(&key)?;
+        self.insert_node(key, value)
+    }
+
+    fn insert_node(&mut self, key: String, value: Value) -> Result<Option<Value>, GraphoidError> {
         // Apply transformation rules to incoming value (proactive application)
         let transformed = self.apply_transformation_rules(value)?;
 
         // Check if key already exists
         let old_value = self.graph.get_node(&key).cloned();
 
-        // Remove old node if it exists
-        if old_value.is_some() {
-            self.graph.remove_node(&key, None)?;
-        }
-
-        // Add new node with key as ID and transformed value
+        // Add the key, or replace its value in place so it keeps its position
         self.graph.add_node(key, transformed)?;
 
         Ok(old_value)
@@ -66,20 +79,133 @@ impl Hash {
     /// # Returns
     /// `Ok(Some(old_value))` if key existed, `Ok(None)` if new key, or an error if the operation fails
     pub fn insert_raw(&mut self, key: String, value: Value) -> Result<Option<Value>, GraphoidError> {
+        Self::check_string_key(&key)?;
+        self.insert_node_raw(key, value)
+    }
+
+    fn insert_node_raw(&mut self, key: String, value: Value) -> Result<Option<Value>, GraphoidError> {
         // Check if key already exists
         let old_value = self.graph.get_node(&key).cloned();
 
-        // Remove old node if it exists
-        if old_value.is_some() {
-            self.graph.remove_node(&key, None)?;
+        // Add or replace in place (no behavior application)
+        self.graph.add_node(key, value)?;
+
+        Ok(old_value)
+    }
+
+    /// Reject string keys that could be mistaken for the ID of a non-string key
+    fn check_string_key(key: &str) -> Result<(), GraphoidError> {
+        if key.starts_with(VALUE_KEY_PREFIX) {
+            return Err(GraphoidError::runtime(
+                "ValueError: map keys cannot start with a NUL character".to_string(),
+            ));
         }
+        Ok(())
+    }
 
-        // Add new node with key as ID (no behavior application)
-        self.graph.add_node(key, value)?;
+    /// Node ID for a key value. Strings are their own ID; other keys must be
+    /// values with a stable content (not functions, modules, channels, ...).
+    pub fn key_id(key: &Value) -> Result<String, GraphoidError> {
+        match &key.kind {
+            ValueKind::String(s) => {
+                Self::check_string_key(s)?;
+                Ok(s.clone())
+            }
+            ValueKind::Number(_) | ValueKind::BigNumber(_) | ValueKind::Boolean(_) | ValueKind::None
+            | ValueKind::Symbol(_) | ValueKind::Time(_) | ValueKind::List(_) | ValueKind::Map(_)
+            | ValueKind::Graph(_) => {
+                Ok(format!("{}{}", VALUE_KEY_PREFIX, key.content_key()))
+            }
+            _ => Err(GraphoidError::type_error("hashable map key", key.type_name())),
+        }
+    }
 
+    /// Insert a value under any hashable key
+    pub fn insert_key(&mut self, key: Value, value: Value) -> Result<Option<Value>, GraphoidError> {
+        let id = Self::key_id(&key)?;
+        let old_value = self.insert_node(id.clone(), value)?;
+        self.remember_key(id, key);
         Ok(old_value)
     }
 
+    /// Like `insert_key`, without applying behaviors (see `insert_raw`)
+    pub fn insert_key_raw(&mut self, key: Value, value: Value) -> Result<Option<Value>, GraphoidError> {
+        let id = Self::key_id(&key)?;
+        let old_value = self.insert_node_raw(id.clone(), value)?;
+        self.remember_key(id, key);
+        Ok(old_value)
+    }
+
+    fn remember_key(&mut self, id: String, key: Value) {
+        if !matches!(key.kind, ValueKind::String(_)) {
+            self.value_keys.insert(id, key);
+        }
+    }
+
+    /// Get value for any hashable key
+    pub fn get_key(&self, key: &Value) -> Option<&Value> {
+        Self::key_id(key).ok().and_then(|id| self.get(&id))
+    }
+
+    /// Check if any hashable key exists
+    pub fn contains(&self, key: &Value) -> bool {
+        self.get_key(key).is_some()
+    }
+
+    /// Remove the entry for any hashable key
+    pub fn remove_key(&mut self, key: &Value) -> Result<Option<Value>, GraphoidError> {
+        let id = Self::key_id(key)?;
+        self.remove(&id)
+    }
+
+    /// The key value stored under a node ID: the original key for non-string
+    /// keys, otherwise the ID itself as a string
+    pub fn key_value(&self, id: &str) -> Value {
+        match self.value_keys.get(id) {
+            Some(key) => key.clone(),
+            None => Value::string(id.to_string()),
+        }
+    }
+
+    /// All keys as values, in the same order as `keys()`
+    pub fn key_values(&self) -> Vec<Value> {
+        self.keys().iter().map(|id| self.key_value(id)).collect()
+    }
+
+    /// Display text for the key stored under a node ID: string keys are
+    /// quoted, other keys are shown as their value
+    pub fn display_key(&self, id: &str) -> String {
+        let key = self.key_value(id);
+        match &key.kind {
+            ValueKind::String(s) => format!("\"{}\"", s),
+            _ => key.to_string_value(),
+        }
+    }
+
+    /// Display text with entries in sorted key order instead of insertion
+    /// order, for canonical output such as snapshots. Number keys come first
+    /// in numeric order, then the other keys by their display text. Nested
+    /// maps are sorted the same way.
+    pub fn to_string_sorted(&self) -> String {
+        let mut entries: Vec<(Value, String, &Value)> = self.keys().iter()
+            .filter_map(|id| self.get(id).map(|value| (self.key_value(id), self.display_key(id), value)))
+            .collect();
+        entries.sort_by(|(a, a_text, _), (b, b_text, _)| match (&a.kind, &b.kind) {
+            (ValueKind::Number(x), ValueKind::Number(y)) => x.total_cmp(y),
+            (ValueKind::Number(_), _) => std::cmp::Ordering::Less,
+            (_, ValueKind::Number(_)) => std::cmp::Ordering::Greater,
+            _ => a_text.cmp(b_text),
+        });
+
+        let pairs: Vec<String> = entries.into_iter()
+            .map(|(_, key, value)| match &value.kind {
+                ValueKind::Map(nested) => format!("{}: {}", key, nested.to_string_sorted()),
+                _ => format!("{}: {}", key, value.to_string_value()),
+            })
+            .collect();
+        format!("{{{}}}", pairs.join(", "))
+    }
+
     /// Get value for a key
     pub fn get(&self, key: &str) -> Option<&Value> {
         self.graph.get_node(key)
@@ -94,6 +220,7 @@ impl Hash {
     pub fn remove(&mut self, key: &str) -> Result<Option<Value>, GraphoidError> {
         let value = self.graph.get_node(key).cloned();
         self.graph.remove_node(key, None)?;
+        self.value_keys.remove(key);
         Ok(value)
     }
 
@@ -118,8 +245,8 @@ impl Hash {
     }
 
     /// Convert hash to a HashMap
-    pub fn to_hashmap(&self) -> std::collections::HashMap<String, Value> {
-        let mut map = std::collections::HashMap::new();
+    pub fn to_hashmap(&self) -> HashMap<String, Value> {
+        let mut map = HashMap::new();
         for key in self.keys() {
             if let Some(value) = self.get(&key) {
                 map.insert(key, value.clone());
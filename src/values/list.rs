@@ -4,7 +4,6 @@
 //! This allows lists to use the full rule system and graph operations.
 
 use super::{Value, Graph};
-use crate::values::graph::GraphType;
 use crate::graph::{RuleSpec, RuleInstance};
 use crate::error::GraphoidError;
 use std::collections::HashMap;
@@ -13,6 +12,10 @@ use std::collections::HashMap;
 ///
 /// Representation: node_0 → node_1 → node_2 → ...
 /// Each node stores a value, edges represent sequential order
+///
+/// Cloning a list is cheap: the backing graph's node storage is persistent,
+/// so a copy shares every node with the original until one side changes it.
+/// `append`/`set` on a copy only copy the handful of nodes they touch.
 #[derive(Debug, Clone, PartialEq)]
 pub struct List {
     /// Underlying graph with linear structure
@@ -24,7 +27,7 @@ pub struct List {
 impl List {
     /// Create a new empty list
     pub fn new() -> Self {
-        let graph = Graph::new(GraphType::Directed);
+        let graph = Graph::new_list_backing();
         // Lists have linear structure rules
         // Note: We don't enforce these strictly to allow list construction
         // But they're available for validation if needed
@@ -123,19 +126,8 @@ impl List {
 
         let node_id = format!("node_{}", index);
 
-        // Remove old node and add new one with same ID
-        self.graph.remove_node(&node_id, None)?;
-        self.graph.add_node(node_id.clone(), transformed)?;
-
-        // Restore edges
-        if index > 0 {
-            let prev_id = format!("node_{}", index - 1);
-            self.graph.add_edge(&prev_id, &node_id, "next".to_string(), None, HashMap::new())?;
-        }
-        if index < self.length - 1 {
-            let next_id = format!("node_{}", index + 1);
-            self.graph.add_edge(&node_id, &next_id, "next".to_string(), None, HashMap::new())?;
-        }
+        // Replace the value in place; the node keeps its edges
+        self.graph.add_node(node_id, transformed)?;
 
         Ok(())
     }
@@ -161,19 +153,8 @@ impl List {
 
         let node_id = format!("node_{}", index);
 
-        // Remove old node and add new one with same ID (no behavior application)
-        self.graph.remove_node(&node_id, None)?;
-        self.graph.add_node(node_id.clone(), value)?;
-
-        // Restore edges
-        if index > 0 {
-            let prev_id = format!("node_{}", index - 1);
-            self.graph.add_edge(&prev_id, &node_id, "next".to_string(), None, HashMap::new())?;
-        }
-        if index < self.length - 1 {
-            let next_id = format!("node_{}", index + 1);
-            self.graph.add_edge(&node_id, &next_id, "next".to_string(), None, HashMap::new())?;
-        }
+        // Replace the value in place (no behavior application); the node keeps its edges
+        self.graph.add_node(node_id, value)?;
 
         Ok(())
     }
@@ -233,7 +214,7 @@ impl List {
 
         // Rebuild list from scratch
         // Clear the graph
-        self.graph = Graph::new(GraphType::Directed);
+        self.graph = Graph::new_list_backing();
         self.length = 0;
 
         // Restore the rules and rulesets
@@ -260,6 +241,10 @@ impl List {
     /// # Returns
     /// `Ok(())` if successful, or an error if the index is out of bounds
     pub fn insert_at_raw(&mut self, index: usize, value: Value) -> Result<(), GraphoidError> {
+        // Rebuilding below would silently drop the frozen flag
+        if self.graph.is_frozen() {
+            return Err(GraphoidError::runtime("Cannot modify frozen list".to_string()));
+        }
         if index > self.length {
             return Err(GraphoidError::runtime(format!(
                 "Index {} out of bounds for list of length {} (insert)",
@@ -279,7 +264,7 @@ impl List {
 
         // Rebuild list from scratch
         // Clear the graph
-        self.graph = Graph::new(GraphType::Directed);
+        self.graph = Graph::new_list_backing();
         self.length = 0;
 
         // Restore the rules and rulesets
@@ -389,6 +374,10 @@ impl List {
 
     /// Remove element at a specific index
     pub fn remove_at_index(&mut self, index: usize) -> Result<(), GraphoidError> {
+        // Rebuilding below would silently drop the frozen flag
+        if self.graph.is_frozen() {
+            return Err(GraphoidError::runtime("Cannot modify frozen list".to_string()));
+        }
         if index >= self.len() {
             return Err(GraphoidError::runtime(format!(
                 "Index {} out of bounds for list of length {}",
@@ -410,7 +399,7 @@ impl List {
         let old_rules = self.graph.rules.clone();
 
         // Rebuild the graph
-        self.graph = Graph::new(GraphType::Directed);
+        self.graph = Graph::new_list_backing();
         self.graph.rules = old_rules;
         self.length = 0; // Reset length
 
@@ -439,7 +428,7 @@ impl List {
     /// Clear all elements from the list
     pub fn clear(&mut self) {
         let old_rules = self.graph.rules.clone();
-        self.graph = Graph::new(GraphType::Directed);
+        self.graph = Graph::new_list_backing();
         self.graph.rules = old_rules;
         self.length = 0; // Reset length
     }
@@ -1,6 +1,6 @@
 use std::fmt;
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 use crate::ast::{Stmt, Parameter, PatternClause, Expr};
 use crate::execution::Environment;
@@ -16,10 +16,16 @@ pub mod hash;
 pub mod channel;
 pub mod actor;
 pub mod foreign;
+pub mod persistent_map;
+pub mod symbol;
+pub mod value_set;
 
 pub use channel::Channel;
 pub use actor::ActorRef;
 pub use foreign::{ForeignLib, ForeignPtr, ForeignStruct, ForeignCallback};
+pub use persistent_map::PersistentMap;
+pub use symbol::Symbol;
+pub use value_set::ValueSet;
 
 /// Layers that can be compared when using graph.equals() with include:/only: options
 ///
@@ -234,6 +240,25 @@ pub struct Function {
     /// Phase 21: Optional guard expression for structure-based dispatch.
     /// When present, this function only matches if the guard evaluates to true.
     pub guard: Option<Box<Expr>>,
+    /// Set for functions the executor builds itself (builtins used as values,
+    /// partials, compositions, memoized wrappers). These have no body.
+    pub synthetic: Option<SyntheticKind>,
+}
+
+/// What a body-less function built by the executor does when called.
+#[derive(Debug, Clone)]
+pub enum SyntheticKind {
+    /// A builtin used as a value, e.g. `xs.map(identity)`
+    Builtin(String),
+    /// Calls `target` with `bound` ahead of the call's own arguments
+    Partial { target: Box<Value>, bound: Vec<Value> },
+    /// Calls the functions right to left, each on the previous result
+    Composed(Vec<Value>),
+    /// Returns the value whatever it is called with
+    Constant(Box<Value>),
+    /// Calls `target`, caching results by argument list. Copies of the
+    /// wrapper share one cache, which lives as long as the wrapper does.
+    Memoized { target: Box<Value>, cache: Rc<RefCell<std::collections::HashMap<String, Value>>> },
 }
 
 impl PartialEq for Function {
@@ -368,6 +393,9 @@ impl PatternMatchResults {
     }
 
     /// Filter results based on a node's property value
+    ///
+    /// The value passed to `predicate` comes from `Graph::node_property()`:
+    /// the explicit node property, or else the entry in a map-valued node.
     pub fn where_node_property<F>(&self, variable: &str, property: &str, predicate: F) -> Result<Self, crate::error::GraphoidError>
     where
         F: Fn(Option<&Value>) -> bool,
@@ -377,7 +405,7 @@ impl PatternMatchResults {
             .filter(|binding| {
                 if let Some(node_id) = binding.get(variable) {
                     if let Some(node) = self.graph.nodes.get(node_id) {
-                        let prop_value = node.properties.get(property);
+                        let prop_value = Graph::node_property(node, property);
                         return predicate(prop_value);
                     }
                 }
@@ -389,6 +417,52 @@ impl PatternMatchResults {
         Ok(PatternMatchResults::new(filtered, self.graph.clone()))
     }
 
+    /// Keep results where a node's property equals `value`
+    ///
+    /// Uses the graph's index on `property` when one exists (see `Graph::create_index`),
+    /// so the check per binding is a set lookup rather than a property comparison.
+    pub fn where_node_property_equals(&self, variable: &str, property: &str, value: &Value) -> Self {
+        let matching: std::collections::HashSet<String> =
+            self.graph.nodes_with_property(property, value).into_iter().collect();
+
+        let filtered = self.bindings
+            .iter()
+            .filter(|binding| binding.get(variable).is_some_and(|node_id| matching.contains(node_id)))
+            .cloned()
+            .collect();
+
+        PatternMatchResults::new(filtered, self.graph.clone())
+    }
+
+}
+
+/// Handle to one node of a graph, returned by `graph.node(id)`
+///
+/// Graph mutators rebind the variable they were called through to an
+/// updated graph, so a handle taken from a variable follows that variable:
+/// reads and writes go to the graph the variable holds at the time. A handle
+/// taken from any other expression, or used where its variable is not in
+/// scope, uses the graph it was taken from.
+#[derive(Debug, Clone)]
+pub struct NodeHandle {
+    /// The graph the node belonged to when the handle was taken
+    pub graph: Rc<RefCell<Graph>>,
+    /// The node's id
+    pub id: String,
+    /// The variable the graph was read from, if any
+    pub binding: Option<String>,
+}
+
+impl PartialEq for NodeHandle {
+    /// Handles are equal when they refer to the same node of the same graph
+    /// or of the same graph variable
+    fn eq(&self, other: &Self) -> bool {
+        let same_graph = match (&self.binding, &other.binding) {
+            (Some(a), Some(b)) => a == b,
+            _ => Rc::ptr_eq(&self.graph, &other.graph),
+        };
+        same_graph && self.id == other.id
+    }
 }
 
 // Implement Index trait for array-like access
@@ -513,8 +587,8 @@ pub enum ValueKind {
     Boolean(bool),
     /// None/null value
     None,
-    /// Symbol literal (e.g., :symbol_name)
-    Symbol(String),
+    /// Symbol literal (e.g., :symbol_name), interned
+    Symbol(Symbol),
     /// List/array of values (backed by linear graph)
     List(List),
     /// Map/dictionary with string keys (backed by key-value graph)
@@ -558,6 +632,9 @@ pub enum ValueKind {
     /// Foreign callback (Phase 20b) - pinned C function pointer wrapping a Graphoid function
     /// Clone shares the same callback (via Arc)
     ForeignCallback(ForeignCallback),
+    /// Node handle - live view of one node in a graph
+    /// Clone refers to the same graph (via Rc)
+    Node(NodeHandle),
 }
 
 // Manual PartialEq implementation for ValueKind
@@ -592,6 +669,7 @@ impl PartialEq for ValueKind {
             (ValueKind::ForeignPtr(a), ValueKind::ForeignPtr(b)) => a == b,
             (ValueKind::ForeignStruct(a), ValueKind::ForeignStruct(b)) => a == b,
             (ValueKind::ForeignCallback(a), ValueKind::ForeignCallback(b)) => a == b,
+            (ValueKind::Node(a), ValueKind::Node(b)) => a == b, // Identity: same node of the same graph
             _ => false, // Different variants are not equal
         }
     }
@@ -623,6 +701,100 @@ impl PartialEq for Value {
 
 impl Eq for Value {}
 
+// Hashing agrees with equality: values that compare equal hash the same.
+// Numbers hash by numeric value (so a num and an equal bignum collide), maps
+// hash their entries independent of order, and graphs hash their node ids.
+impl std::hash::Hash for Value {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+    }
+}
+
+impl std::hash::Hash for ValueKind {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        fn hash_number<H: std::hash::Hasher>(n: f64, state: &mut H) {
+            // 0.0 and -0.0 are equal, so they must hash alike
+            let n = if n == 0.0 { 0.0 } else { n };
+            n.to_bits().hash(state);
+        }
+
+        match self {
+            ValueKind::Number(n) => {
+                0u8.hash(state);
+                hash_number(*n, state);
+            }
+            ValueKind::BigNumber(bn) => {
+                0u8.hash(state);
+                hash_number(bn.to_f64(), state);
+            }
+            ValueKind::Time(t) => {
+                std::mem::discriminant(self).hash(state);
+                hash_number(*t, state);
+            }
+            ValueKind::String(s) => {
+                std::mem::discriminant(self).hash(state);
+                s.hash(state);
+            }
+            ValueKind::Boolean(b) => {
+                std::mem::discriminant(self).hash(state);
+                b.hash(state);
+            }
+            ValueKind::Symbol(s) => {
+                std::mem::discriminant(self).hash(state);
+                // By name: `Symbol` itself hashes by pointer, which is not
+                // stable once a symbol is released and interned again
+                s.as_str().hash(state);
+            }
+            ValueKind::List(list) => {
+                std::mem::discriminant(self).hash(state);
+                list.len().hash(state);
+                for element in list.to_vec() {
+                    element.hash(state);
+                }
+            }
+            ValueKind::Map(hash) => {
+                std::mem::discriminant(self).hash(state);
+                // Combine per-entry hashes with a commutative sum so that key
+                // order does not matter
+                let mut combined: u64 = 0;
+                for key in hash.keys() {
+                    let mut entry_hasher = std::collections::hash_map::DefaultHasher::new();
+                    key.hash(&mut entry_hasher);
+                    hash.get(&key).hash(&mut entry_hasher);
+                    combined = combined.wrapping_add(std::hash::Hasher::finish(&entry_hasher));
+                }
+                hash.len().hash(state);
+                combined.hash(state);
+            }
+            ValueKind::Graph(graph) => {
+                std::mem::discriminant(self).hash(state);
+                // Node ids only, combined independent of order; node values
+                // may refer back to the graph itself
+                let graph = graph.borrow();
+                let mut combined: u64 = 0;
+                for id in graph.node_ids() {
+                    let mut node_hasher = std::collections::hash_map::DefaultHasher::new();
+                    id.hash(&mut node_hasher);
+                    combined = combined.wrapping_add(std::hash::Hasher::finish(&node_hasher));
+                }
+                graph.node_count().hash(state);
+                combined.hash(state);
+            }
+            ValueKind::Function(func) => {
+                std::mem::discriminant(self).hash(state);
+                func.hash(state);
+            }
+            ValueKind::Error(error) => {
+                std::mem::discriminant(self).hash(state);
+                error.hash(state);
+            }
+            // Remaining kinds compare by identity or structure that is not
+            // worth hashing; the variant alone keeps hashing consistent
+            _ => std::mem::discriminant(self).hash(state),
+        }
+    }
+}
+
 impl Value {
     // Constructors
     pub fn number(n: f64) -> Self {
@@ -673,8 +845,8 @@ impl Value {
         Value { kind: ValueKind::ForeignCallback(cb), frozen: false, tainted: false, taint_source: None }
     }
 
-    pub fn symbol(s: String) -> Self {
-        Value { kind: ValueKind::Symbol(s), frozen: false, tainted: false, taint_source: None }
+    pub fn symbol(s: impl Into<Symbol>) -> Self {
+        Value { kind: ValueKind::Symbol(s.into()), frozen: false, tainted: false, taint_source: None }
     }
 
     pub fn list(l: List) -> Self {
@@ -704,6 +876,10 @@ impl Value {
         Value { kind: ValueKind::Error(e), frozen: false, tainted: false, taint_source: None }
     }
 
+    pub fn node_handle(graph: Rc<RefCell<Graph>>, id: String, binding: Option<String>) -> Self {
+        Value { kind: ValueKind::Node(NodeHandle { graph, id, binding }), frozen: false, tainted: false, taint_source: None }
+    }
+
     pub fn pattern_node(variable: Option<String>, node_type: Option<String>) -> Self {
         Value {
             kind: ValueKind::PatternNode(PatternNode { variable, node_type }),
@@ -774,6 +950,21 @@ impl Value {
             ValueKind::ForeignPtr(_) => true, // Foreign pointers are always truthy
             ValueKind::ForeignStruct(_) => true, // Foreign structs are always truthy
             ValueKind::ForeignCallback(_) => true, // Foreign callbacks are always truthy
+            ValueKind::Node(_) => true, // Node handles are always truthy
+        }
+    }
+
+    /// Whether a container holds nothing: empty strings, lists, maps,
+    /// graphs without data nodes, and match results without matches.
+    /// Returns `None` for values that are not containers.
+    pub fn is_empty_container(&self) -> Option<bool> {
+        match &self.kind {
+            ValueKind::String(s) => Some(s.is_empty()),
+            ValueKind::List(l) => Some(l.is_empty()),
+            ValueKind::Map(h) => Some(h.is_empty()),
+            ValueKind::Graph(g) => Some(g.borrow().node_count() == 0),
+            ValueKind::PatternMatchResults(results) => Some(results.is_empty()),
+            _ => None,
         }
     }
 
@@ -793,26 +984,12 @@ impl Value {
     /// Converts value to a string.
     pub fn to_string_value(&self) -> String {
         match &self.kind {
-            ValueKind::Number(n) => {
-                // Format numbers nicely (no .0 for integers)
-                if n.fract() == 0.0 {
-                    format!("{:.0}", n)
-                } else {
-                    n.to_string()
-                }
-            }
+            ValueKind::Number(n) => format_float(*n),
             ValueKind::BigNumber(bn) => {
                 match bn {
                     BigNum::Int64(n) => n.to_string(),
                     BigNum::UInt64(n) => n.to_string(),
-                    BigNum::Float128(f) => {
-                        let f64_val: f64 = (*f).into();
-                        if f64_val.fract() == 0.0 {
-                            format!("{:.0}", f64_val)
-                        } else {
-                            f64_val.to_string()
-                        }
-                    }
+                    BigNum::Float128(f) => format_float((*f).into()),
                     BigNum::BigInt(bi) => bi.to_string(),
                 }
             }
@@ -825,9 +1002,8 @@ impl Value {
                 format!("[{}]", strs.join(", "))
             }
             ValueKind::Map(hash) => {
-                let pairs: Vec<String> = hash.to_hashmap()
-                    .iter()
-                    .map(|(k, v)| format!("\"{}\": {}", k, v.to_string_value()))
+                let pairs: Vec<String> = hash.keys().iter()
+                    .filter_map(|k| hash.get(k).map(|v| format!("{}: {}", hash.display_key(k), v.to_string_value())))
                     .collect();
                 format!("{{{}}}", pairs.join(", "))
             }
@@ -886,6 +1062,140 @@ impl Value {
             ValueKind::ForeignPtr(ref ptr) => ptr.to_string(),
             ValueKind::ForeignStruct(ref fs) => fs.to_string(),
             ValueKind::ForeignCallback(ref cb) => cb.to_string(),
+            ValueKind::Node(handle) => format!("<node {}>", handle.id),
+        }
+    }
+
+    /// Canonical text for this value's content: equal values give equal
+    /// keys. Strings are quoted and collections are keyed element by element,
+    /// so `"1"` and `1`, or `["a, b"]` and `["a", "b"]`, never collide. Maps
+    /// and graphs are keyed independent of insertion order. Numbers are keyed
+    /// on their exact value, never on the display precision.
+    pub fn content_key(&self) -> String {
+        match &self.kind {
+            ValueKind::String(s) => format!("{:?}", s),
+            // -0.0 == 0.0, so both get the same key
+            ValueKind::Number(n) => format!("num:{:?}", if *n == 0.0 { 0.0 } else { *n }),
+            ValueKind::BigNumber(BigNum::Float128(f)) => format!("bignum:{:?}", f),
+            ValueKind::List(list) => {
+                let parts: Vec<String> = list.to_vec().iter().map(Value::content_key).collect();
+                format!("[{}]", parts.join(","))
+            }
+            ValueKind::Map(hash) => {
+                let mut pairs: Vec<String> = hash.keys()
+                    .iter()
+                    .filter_map(|k| hash.get(k).map(|v| format!("{:?}:{}", k, v.content_key())))
+                    .collect();
+                pairs.sort();
+                format!("{{{}}}", pairs.join(","))
+            }
+            ValueKind::Graph(graph) => {
+                let graph = graph.borrow();
+                let mut nodes: Vec<String> = graph.node_ids()
+                    .iter()
+                    .map(|id| format!("{:?}:{}", id, graph.get_node(id).map(Value::content_key).unwrap_or_default()))
+                    .collect();
+                nodes.sort();
+                let mut edges: Vec<String> = graph.data_edge_list()
+                    .iter()
+                    .map(|(from, to, edge_type)| format!(
+                        "{:?}->{:?}:{:?}:{:?}", from, to, edge_type, graph.get_edge_weight(from, to)
+                    ))
+                    .collect();
+                edges.sort();
+                format!("graph({};{})", nodes.join(","), edges.join(","))
+            }
+            _ => format!("{}:{}", self.type_name(), self.to_string_value()),
+        }
+    }
+
+    /// Stable numeric hash of this value, consistent with `==`. Kept within
+    /// the 53 bits a number can hold exactly.
+    pub fn hash_code(&self) -> f64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        (hasher.finish() >> 11) as f64
+    }
+
+    /// JSON form of this value, following the `json` module: none is `null`,
+    /// whole numbers have no fraction, and kinds JSON cannot represent become
+    /// their display string.
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value as Json;
+        let number = |n: f64| {
+            if n.fract() == 0.0 && n.abs() < 9_007_199_254_740_992.0 {
+                Json::from(n as i64)
+            } else {
+                serde_json::Number::from_f64(n).map(Json::Number).unwrap_or(Json::Null)
+            }
+        };
+        match &self.kind {
+            ValueKind::None => Json::Null,
+            ValueKind::Boolean(b) => Json::Bool(*b),
+            ValueKind::Number(n) => number(*n),
+            ValueKind::BigNumber(bn) => number(bn.to_f64()),
+            ValueKind::String(s) => Json::String(s.clone()),
+            ValueKind::List(list) => Json::Array(list.to_vec().iter().map(Value::to_json).collect()),
+            ValueKind::Map(hash) => Json::Object(
+                hash.keys()
+                    .iter()
+                    .filter_map(|id| hash.get(id).map(|v| (hash.key_value(id).to_string_value(), v.to_json())))
+                    .collect(),
+            ),
+            _ => Json::String(self.to_string_value()),
+        }
+    }
+
+    /// Unambiguous debugging representation, unlike the user-facing
+    /// `to_string_value()`: strings are quoted, symbols keep their `:`, and
+    /// graphs list their nodes and edges. A graph reached again while it is
+    /// already being inspected is shown as `...`.
+    pub fn inspect(&self) -> String {
+        self.inspect_with(&mut Vec::new())
+    }
+
+    fn inspect_with(&self, open_graphs: &mut Vec<*const RefCell<Graph>>) -> String {
+        match &self.kind {
+            ValueKind::String(s) => format!("{:?}", s),
+            ValueKind::List(list) => {
+                let items: Vec<String> = list.to_vec().iter().map(|v| v.inspect_with(open_graphs)).collect();
+                format!("[{}]", items.join(", "))
+            }
+            ValueKind::Map(hash) => {
+                let pairs: Vec<String> = hash.keys().iter()
+                    .filter_map(|k| hash.get(k).map(|v| {
+                        format!("{}: {}", hash.key_value(k).inspect_with(open_graphs), v.inspect_with(open_graphs))
+                    }))
+                    .collect();
+                format!("{{{}}}", pairs.join(", "))
+            }
+            ValueKind::Graph(rc) => {
+                let ptr = Rc::as_ptr(rc);
+                if open_graphs.contains(&ptr) {
+                    return "...".to_string();
+                }
+                open_graphs.push(ptr);
+                let graph = rc.borrow();
+                let nodes: Vec<String> = graph.data_node_ids().iter()
+                    .filter_map(|id| graph.get_node(id).map(|v| format!("{:?}: {}", id, v.inspect_with(open_graphs))))
+                    .collect();
+                let directed = graph.graph_type == GraphType::Directed;
+                let arrow = if directed { "->" } else { "--" };
+                let mut shown = std::collections::HashSet::new();
+                let edges: Vec<String> = graph.data_edge_list().iter()
+                    // An undirected edge is stored both ways; show it once
+                    .filter(|(from, to, _)| directed || shown.insert(if from <= to { (from, to) } else { (to, from) }))
+                    .map(|(from, to, edge_type)| format!("{:?} {} {:?} :{}", from, arrow, to, edge_type))
+                    .collect();
+                open_graphs.pop();
+                let kind = match &graph.type_name {
+                    Some(name) => format!("{} graph", name),
+                    None => "graph".to_string(),
+                };
+                format!("<{} nodes: {{{}}}, edges: [{}]>", kind, nodes.join(", "), edges.join(", "))
+            }
+            _ => self.to_string_value(),
         }
     }
 
@@ -916,6 +1226,7 @@ impl Value {
             ValueKind::ForeignPtr(_) => "foreign_ptr",
             ValueKind::ForeignStruct(_) => "foreign_struct",
             ValueKind::ForeignCallback(_) => "foreign_callback",
+            ValueKind::Node(_) => "node",
         }
     }
 
@@ -925,21 +1236,16 @@ impl Value {
 
     /// Mark this value as frozen (immutable)
     ///
-    /// All values can be frozen, including primitives.
+    /// All values can be frozen, including primitives. Collections are frozen
+    /// deeply: every nested element is frozen too (see `freeze_shallow`).
     pub fn freeze(&mut self) {
         self.frozen = true;
         // Deep freeze: also freeze nested elements in collections
         match &mut self.kind {
             ValueKind::List(list) => {
                 // Freeze each element BEFORE freezing the backing graph
-                let len = list.len();
-                for i in 0..len {
-                    if let Some(node_id) = list.graph.nodes.keys().nth(i) {
-                        let node_id = node_id.clone();
-                        if let Some(node) = list.graph.nodes.get_mut(&node_id) {
-                            node.value.freeze(); // Recursive freeze
-                        }
-                    }
+                for node in list.graph.nodes.values_mut() {
+                    node.value.freeze(); // Recursive freeze
                 }
                 list.graph.freeze();
             }
@@ -968,6 +1274,21 @@ impl Value {
         }
     }
 
+    /// Mark only this value as frozen, leaving nested elements as they are.
+    ///
+    /// A shallow-frozen list or map rejects adding, removing or replacing
+    /// entries, but the values held in those entries keep their own frozen
+    /// state and can still be mutated.
+    pub fn freeze_shallow(&mut self) {
+        self.frozen = true;
+        match &mut self.kind {
+            ValueKind::List(list) => list.graph.freeze(),
+            ValueKind::Map(map) => map.graph.freeze(),
+            ValueKind::Graph(graph) => graph.borrow_mut().freeze(),
+            _ => {},
+        }
+    }
+
     /// Check if this value is frozen
     pub fn is_frozen(&self) -> bool {
         self.frozen
@@ -1021,13 +1342,18 @@ impl Value {
             }
             ValueKind::Map(hash) => {
                 let mut new_hash = Hash::new();
-                for (k, v) in hash.to_hashmap() {
-                    let _ = new_hash.insert(k, v.deep_clone_recursive());
+                for k in hash.keys() {
+                    if let Some(v) = hash.get(&k) {
+                        let _ = new_hash.insert_key(hash.key_value(&k), v.deep_clone_recursive());
+                    }
                 }
                 ValueKind::Map(new_hash)
             }
             ValueKind::Graph(g) => {
-                ValueKind::Graph(Rc::new(RefCell::new(g.borrow().deep_copy_unfrozen())))
+                let mut copy = g.borrow().deep_copy_unfrozen();
+                // Node storage taken from a list is structurally shared; detach it
+                copy.nodes.unshare();
+                ValueKind::Graph(Rc::new(RefCell::new(copy)))
             }
             ValueKind::Function(func) => {
                 // Deep clone function: body is Vec<Stmt> (Send), env needs fresh Rc
@@ -1043,6 +1369,7 @@ impl Value {
                     is_setter: func.is_setter,
                     is_static: func.is_static,
                     guard: func.guard.clone(),
+                    synthetic: func.synthetic.clone(),
                 })
             }
             ValueKind::Channel(ch) => {
@@ -1053,6 +1380,16 @@ impl Value {
                 // Actors are SHARED across threads (Arc-based channel) — intentionally NOT deep-copied
                 ValueKind::Actor(actor.clone())
             }
+            ValueKind::Node(handle) => {
+                // Detach the graph exactly as for a graph value
+                let mut copy = handle.graph.borrow().deep_copy_unfrozen();
+                copy.nodes.unshare();
+                ValueKind::Node(NodeHandle {
+                    graph: Rc::new(RefCell::new(copy)),
+                    id: handle.id.clone(),
+                    binding: None,
+                })
+            }
             // Primitives, symbols, errors, etc. — just clone
             other => other.clone(),
         };
@@ -1082,6 +1419,39 @@ pub fn propagate_taint(mut result: Value, left: &Value, right: &Value) -> Value
     result
 }
 
+thread_local! {
+    /// Decimal places used when displaying floats, set from
+    /// `configure { display_precision: n }`. `None` prints the shortest form.
+    static DISPLAY_PRECISION: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Set the number of decimal places floats are displayed with on this thread.
+/// Only affects `to_string_value()`; stored values are never rounded.
+pub fn set_display_precision(places: Option<usize>) {
+    DISPLAY_PRECISION.with(|precision| precision.set(places));
+}
+
+/// Format a float for display: whole numbers have no decimal point, other
+/// values follow the display precision with trailing zeros dropped.
+fn format_float(n: f64) -> String {
+    if n.fract() == 0.0 {
+        return format!("{:.0}", n);
+    }
+    match DISPLAY_PRECISION.with(Cell::get) {
+        Some(places) if n.is_finite() => {
+            let fixed = format!("{:.*}", places, n);
+            let trimmed = if fixed.contains('.') {
+                fixed.trim_end_matches('0').trim_end_matches('.')
+            } else {
+                fixed.as_str()
+            };
+            // -0.00001 at 2 places would otherwise print as "-0"
+            if trimmed == "-0" { "0".to_string() } else { trimmed.to_string() }
+        }
+        _ => n.to_string(),
+    }
+}
+
 /// Display implementation for user-friendly output.
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
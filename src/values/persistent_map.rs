@@ -0,0 +1,726 @@
+//! Persistent hash map with structural sharing
+//!
+//! `PersistentMap` is built on a hash array mapped trie (HAMT). Cloning a map
+//! is O(1): the clone shares every trie node with the original. Mutating
+//! either copy only copies the path from the root to the changed entry
+//! (O(log32 n) nodes), so the two copies never observe each other's changes.
+//!
+//! A `List`'s backing graph stores its nodes in a `PersistentMap` (see
+//! `NodeStore` in `graph.rs`), which is what makes copying a list and then
+//! updating the copy cheap. Other graphs keep plain owned node storage, since
+//! the trie makes reads and builds slower; `benches/list_persistence.rs`
+//! measures both sides (`cargo bench --features profiling --bench list_persistence`).
+//!
+//! The API mirrors the subset of `std::collections::HashMap` that the graph
+//! code relies on. Iteration follows insertion order: each key remembers the
+//! sequence number it was inserted with, and a persistent vector indexed by
+//! sequence number lists the keys in that order. Replacing a value keeps the
+//! key's position; removing and re-inserting a key moves it to the end.
+//! `iter_mut` is the exception: it walks the trie so that it can unshare
+//! nodes as it goes, and its order is unspecified.
+
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Bits of the hash consumed per trie level
+const BITS: u32 = 5;
+/// Mask selecting one level's worth of hash bits
+const MASK: u64 = (1 << BITS) - 1;
+
+fn hash_key<Q: Hash + ?Sized>(key: &Q) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Bit selecting a hash's slot at the given trie depth
+fn slot_bit(hash: u64, shift: u32) -> u32 {
+    1 << ((hash >> shift) & MASK)
+}
+
+/// An entry in a trie node
+enum Entry<K, V> {
+    /// A single key-value pair, with the key's full hash
+    Leaf(u64, Arc<(K, V)>),
+    /// Several pairs whose keys share the same full hash
+    Collision(u64, Vec<Arc<(K, V)>>),
+    /// A child node for the next level
+    Branch(Arc<Node<K, V>>),
+}
+
+impl<K, V> Clone for Entry<K, V> {
+    fn clone(&self) -> Self {
+        match self {
+            Entry::Leaf(hash, pair) => Entry::Leaf(*hash, pair.clone()),
+            Entry::Collision(hash, pairs) => Entry::Collision(*hash, pairs.clone()),
+            Entry::Branch(node) => Entry::Branch(node.clone()),
+        }
+    }
+}
+
+/// A trie node: a bitmap of occupied slots and the entries for those slots,
+/// ordered by slot
+struct Node<K, V> {
+    bitmap: u32,
+    entries: Vec<Entry<K, V>>,
+}
+
+impl<K, V> Clone for Node<K, V> {
+    fn clone(&self) -> Self {
+        Node { bitmap: self.bitmap, entries: self.entries.clone() }
+    }
+}
+
+impl<K, V> Node<K, V> {
+    fn empty() -> Self {
+        Node { bitmap: 0, entries: Vec::new() }
+    }
+
+    /// Position in `entries` for an occupied or to-be-occupied slot
+    fn index(&self, bit: u32) -> usize {
+        (self.bitmap & (bit - 1)).count_ones() as usize
+    }
+
+    /// A node holding a single existing leaf or collision entry
+    fn with_entry(hash: u64, shift: u32, entry: Entry<K, V>) -> Self {
+        Node { bitmap: slot_bit(hash, shift), entries: vec![entry] }
+    }
+}
+
+/// Unwrap a shared pair's value, cloning it only if another map still holds it
+fn take_value<K: Clone, V: Clone>(pair: Arc<(K, V)>) -> V {
+    match Arc::try_unwrap(pair) {
+        Ok((_, value)) => value,
+        Err(shared) => shared.1.clone(),
+    }
+}
+
+/// Hash array mapped trie: the unordered storage behind `PersistentMap`
+struct Trie<K, V> {
+    root: Arc<Node<K, V>>,
+    len: usize,
+}
+
+impl<K, V> Clone for Trie<K, V> {
+    fn clone(&self) -> Self {
+        Trie { root: self.root.clone(), len: self.len }
+    }
+}
+
+impl<K, V> Default for Trie<K, V> {
+    fn default() -> Self {
+        Trie { root: Arc::new(Node::empty()), len: 0 }
+    }
+}
+
+impl<K, V> Trie<K, V> {
+    /// Iterate over all key-value pairs in trie order
+    fn iter(&self) -> TrieIter<'_, K, V> {
+        TrieIter {
+            stack: vec![self.root.entries.iter()],
+            collision: None,
+            remaining: self.len,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Trie<K, V> {
+    /// Look up a value by key
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = hash_key(key);
+        let mut node = &*self.root;
+        let mut shift = 0;
+        loop {
+            let bit = slot_bit(hash, shift);
+            if node.bitmap & bit == 0 {
+                return None;
+            }
+            match &node.entries[node.index(bit)] {
+                Entry::Leaf(h, pair) => {
+                    return if *h == hash && pair.0.borrow() == key { Some(&pair.1) } else { None };
+                }
+                Entry::Collision(h, pairs) => {
+                    if *h != hash {
+                        return None;
+                    }
+                    return pairs.iter().find(|pair| pair.0.borrow() == key).map(|pair| &pair.1);
+                }
+                Entry::Branch(child) => {
+                    node = child;
+                    shift += BITS;
+                }
+            }
+        }
+    }
+
+    /// Whether the trie contains a key
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Trie<K, V> {
+    /// Insert a key-value pair, returning the previous value for the key
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = hash_key(&key);
+        let previous = Self::insert_into(&mut self.root, hash, 0, key, value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    fn insert_into(node: &mut Arc<Node<K, V>>, hash: u64, shift: u32, key: K, value: V) -> Option<V> {
+        let node = Arc::make_mut(node);
+        let bit = slot_bit(hash, shift);
+        let idx = node.index(bit);
+
+        if node.bitmap & bit == 0 {
+            node.entries.insert(idx, Entry::Leaf(hash, Arc::new((key, value))));
+            node.bitmap |= bit;
+            return None;
+        }
+
+        match &mut node.entries[idx] {
+            Entry::Leaf(h, pair) if *h == hash && pair.0 == key => {
+                Some(std::mem::replace(&mut Arc::make_mut(pair).1, value))
+            }
+            Entry::Leaf(h, pair) if *h == hash => {
+                // Different keys with the same full hash
+                let existing = pair.clone();
+                node.entries[idx] = Entry::Collision(hash, vec![existing, Arc::new((key, value))]);
+                None
+            }
+            Entry::Collision(h, pairs) if *h == hash => {
+                if let Some(pair) = pairs.iter_mut().find(|pair| pair.0 == key) {
+                    return Some(std::mem::replace(&mut Arc::make_mut(pair).1, value));
+                }
+                pairs.push(Arc::new((key, value)));
+                None
+            }
+            Entry::Branch(child) => Self::insert_into(child, hash, shift + BITS, key, value),
+            Entry::Leaf(existing_hash, _) | Entry::Collision(existing_hash, _) => {
+                // Slot taken by a different hash: push the existing entry one level down
+                let existing_hash = *existing_hash;
+                let existing = node.entries[idx].clone();
+                let mut child = Arc::new(Node::with_entry(existing_hash, shift + BITS, existing));
+                let previous = Self::insert_into(&mut child, hash, shift + BITS, key, value);
+                node.entries[idx] = Entry::Branch(child);
+                previous
+            }
+        }
+    }
+
+    /// Look up a value for mutation, copying only the path to it if shared
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        // Avoid copying any path when the key is absent
+        if !self.contains_key(key) {
+            return None;
+        }
+
+        let hash = hash_key(key);
+        let mut node = Arc::make_mut(&mut self.root);
+        let mut shift = 0;
+        loop {
+            let current = node;
+            let idx = current.index(slot_bit(hash, shift));
+            match &mut current.entries[idx] {
+                Entry::Leaf(_, pair) => return Some(&mut Arc::make_mut(pair).1),
+                Entry::Collision(_, pairs) => {
+                    return pairs.iter_mut()
+                        .find(|pair| pair.0.borrow() == key)
+                        .map(|pair| &mut Arc::make_mut(pair).1);
+                }
+                Entry::Branch(child) => {
+                    node = Arc::make_mut(child);
+                    shift += BITS;
+                }
+            }
+        }
+    }
+
+    /// Remove a key, returning its value
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if !self.contains_key(key) {
+            return None;
+        }
+        let removed = Self::remove_from(&mut self.root, hash_key(key), 0, key);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    fn remove_from<Q>(node: &mut Arc<Node<K, V>>, hash: u64, shift: u32, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = Arc::make_mut(node);
+        let bit = slot_bit(hash, shift);
+        if node.bitmap & bit == 0 {
+            return None;
+        }
+        let idx = node.index(bit);
+
+        match &mut node.entries[idx] {
+            Entry::Leaf(h, pair) => {
+                if *h != hash || pair.0.borrow() != key {
+                    return None;
+                }
+                node.bitmap &= !bit;
+                match node.entries.remove(idx) {
+                    Entry::Leaf(_, pair) => Some(take_value(pair)),
+                    _ => unreachable!(),
+                }
+            }
+            Entry::Collision(h, pairs) => {
+                if *h != hash {
+                    return None;
+                }
+                let pos = pairs.iter().position(|pair| pair.0.borrow() == key)?;
+                let removed = take_value(pairs.remove(pos));
+                if pairs.len() == 1 {
+                    let last = pairs.pop().unwrap();
+                    node.entries[idx] = Entry::Leaf(hash, last);
+                }
+                Some(removed)
+            }
+            Entry::Branch(child) => {
+                let removed = Self::remove_from(child, hash, shift + BITS, key)?;
+                // Collapse branches that no longer need their own level
+                if child.entries.is_empty() {
+                    node.entries.remove(idx);
+                    node.bitmap &= !bit;
+                } else if child.entries.len() == 1 && !matches!(child.entries[0], Entry::Branch(_)) {
+                    let only = child.entries[0].clone();
+                    node.entries[idx] = only;
+                }
+                Some(removed)
+            }
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Trie<K, V> {
+    /// Iterate over all pairs with mutable access to the values, in trie order
+    ///
+    /// Shared trie nodes are copied lazily, as the iterator reaches them, so
+    /// stopping early leaves the rest of the trie shared.
+    fn iter_mut(&mut self) -> TrieIterMut<'_, K, V> {
+        TrieIterMut {
+            stack: vec![Arc::make_mut(&mut self.root).entries.iter_mut()],
+            collision: None,
+            remaining: self.len,
+        }
+    }
+}
+
+/// Iterator over the pairs of a `Trie`, in trie order
+struct TrieIter<'a, K, V> {
+    stack: Vec<std::slice::Iter<'a, Entry<K, V>>>,
+    collision: Option<std::slice::Iter<'a, Arc<(K, V)>>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for TrieIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pairs) = &mut self.collision {
+                if let Some(pair) = pairs.next() {
+                    self.remaining -= 1;
+                    return Some((&pair.0, &pair.1));
+                }
+                self.collision = None;
+            }
+            let level = self.stack.last_mut()?;
+            match level.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(Entry::Leaf(_, pair)) => {
+                    self.remaining -= 1;
+                    return Some((&pair.0, &pair.1));
+                }
+                Some(Entry::Collision(_, pairs)) => self.collision = Some(pairs.iter()),
+                Some(Entry::Branch(child)) => self.stack.push(child.entries.iter()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Mutable iterator over the pairs of a `Trie`, in trie order
+struct TrieIterMut<'a, K, V> {
+    stack: Vec<std::slice::IterMut<'a, Entry<K, V>>>,
+    collision: Option<std::slice::IterMut<'a, Arc<(K, V)>>>,
+    remaining: usize,
+}
+
+impl<'a, K: Clone, V: Clone> Iterator for TrieIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pairs) = &mut self.collision {
+                if let Some(pair) = pairs.next() {
+                    self.remaining -= 1;
+                    let (key, value) = Arc::make_mut(pair);
+                    return Some((&*key, value));
+                }
+                self.collision = None;
+            }
+            let level = self.stack.last_mut()?;
+            match level.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(Entry::Leaf(_, pair)) => {
+                    self.remaining -= 1;
+                    let (key, value) = Arc::make_mut(pair);
+                    return Some((&*key, value));
+                }
+                Some(Entry::Collision(_, pairs)) => self.collision = Some(pairs.iter_mut()),
+                Some(Entry::Branch(child)) => self.stack.push(Arc::make_mut(child).entries.iter_mut()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Slots per chunk of the insertion-order vector
+const CHUNK: usize = 32;
+
+/// Keys in insertion order, indexed by sequence number
+///
+/// A two-level persistent vector: cloning shares every chunk, and writing to
+/// a shared copy copies the chunk table plus the one chunk being changed.
+/// Removed keys leave an empty slot behind until the owning map compacts.
+struct Order<K> {
+    chunks: Arc<Vec<Arc<Vec<Option<K>>>>>,
+    len: usize,
+}
+
+impl<K> Clone for Order<K> {
+    fn clone(&self) -> Self {
+        Order { chunks: self.chunks.clone(), len: self.len }
+    }
+}
+
+impl<K> Default for Order<K> {
+    fn default() -> Self {
+        Order { chunks: Arc::new(Vec::new()), len: 0 }
+    }
+}
+
+impl<K> Order<K> {
+    fn slot(&self, seq: usize) -> Option<&K> {
+        self.chunks.get(seq / CHUNK)?.get(seq % CHUNK)?.as_ref()
+    }
+}
+
+impl<K: Clone> Order<K> {
+    /// Append a key, returning its sequence number
+    fn push(&mut self, key: K) -> u64 {
+        let seq = self.len;
+        let chunks = Arc::make_mut(&mut self.chunks);
+        if seq.is_multiple_of(CHUNK) {
+            chunks.push(Arc::new(Vec::with_capacity(CHUNK)));
+        }
+        let last = chunks.last_mut().expect("chunk allocated above");
+        Arc::make_mut(last).push(Some(key));
+        self.len += 1;
+        seq as u64
+    }
+
+    /// Empty the slot for a removed key
+    fn vacate(&mut self, seq: u64) {
+        let seq = seq as usize;
+        let chunks = Arc::make_mut(&mut self.chunks);
+        if let Some(chunk) = chunks.get_mut(seq / CHUNK) {
+            if let Some(slot) = Arc::make_mut(chunk).get_mut(seq % CHUNK) {
+                *slot = None;
+            }
+        }
+    }
+}
+
+/// A persistent, insertion-ordered hash map with O(1) clones and
+/// path-copying updates
+pub struct PersistentMap<K, V> {
+    entries: Trie<K, (u64, V)>,
+    order: Order<K>,
+}
+
+impl<K, V> Clone for PersistentMap<K, V> {
+    fn clone(&self) -> Self {
+        PersistentMap { entries: self.entries.clone(), order: self.order.clone() }
+    }
+}
+
+impl<K, V> Default for PersistentMap<K, V> {
+    fn default() -> Self {
+        PersistentMap { entries: Trie::default(), order: Order::default() }
+    }
+}
+
+impl<K, V> PersistentMap<K, V> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len
+    }
+
+    /// Whether the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.len == 0
+    }
+
+    /// Remove every entry
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether both maps share the same storage (one is an unmodified clone
+    /// of the other)
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.entries.root, &other.entries.root)
+    }
+}
+
+impl<K: Hash + Eq, V> PersistentMap<K, V> {
+    /// Iterate over all key-value pairs in insertion order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { map: self, seq: 0, remaining: self.len() }
+    }
+
+    /// Iterate over all keys in insertion order
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Iterate over all values in insertion order
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Look up a value by key
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get(key).map(|(_, value)| value)
+    }
+
+    /// Whether the map contains a key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.contains_key(key)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> PersistentMap<K, V> {
+    /// Insert a key-value pair, returning the previous value for the key
+    ///
+    /// Replacing the value of an existing key keeps its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some((_, existing)) = self.entries.get_mut(&key) {
+            return Some(std::mem::replace(existing, value));
+        }
+        let seq = self.order.push(key.clone());
+        self.entries.insert(key, (seq, value));
+        None
+    }
+
+    /// Look up a value for mutation, copying only the path to it if shared
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get_mut(key).map(|(_, value)| value)
+    }
+
+    /// Remove a key, returning its value
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (seq, value) = self.entries.remove(key)?;
+        if self.entries.len == 0 {
+            self.order = Order::default();
+        } else {
+            self.order.vacate(seq);
+            if self.order.len - self.entries.len > self.entries.len + CHUNK {
+                self.compact();
+            }
+        }
+        Some(value)
+    }
+
+    /// Renumber the entries so the order vector has no empty slots
+    fn compact(&mut self) {
+        *self = self.iter().map(|(key, value)| (key.clone(), value.clone())).collect();
+    }
+
+    /// Iterate over all pairs with mutable access to the values
+    ///
+    /// Unlike `iter`, this walks the trie directly, so the order is
+    /// unspecified. Shared storage is copied only along the paths the
+    /// iterator actually visits.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { pairs: self.entries.iter_mut() }
+    }
+
+    /// Iterate over all values mutably, in unspecified order
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Keep only the entries for which the predicate returns true
+    ///
+    /// Entries are inspected without unsharing anything; only the paths to
+    /// removed entries are copied.
+    pub fn retain<F: FnMut(&K, &V) -> bool>(&mut self, mut keep: F) {
+        let doomed: Vec<K> = self.iter()
+            .filter(|(key, value)| !keep(key, value))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in doomed {
+            self.remove(&key);
+        }
+    }
+
+    /// A copy that shares no storage with this map
+    ///
+    /// Used before handing a map to another thread, so neither side touches
+    /// the other's reference counts afterwards.
+    pub fn unshared(&self) -> Self {
+        self.iter().map(|(key, value)| (key.clone(), value.clone())).collect()
+    }
+}
+
+/// Iterator over the pairs of a `PersistentMap`, in insertion order
+pub struct Iter<'a, K, V> {
+    map: &'a PersistentMap<K, V>,
+    seq: usize,
+    remaining: usize,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 && self.seq < self.map.order.len {
+            let seq = self.seq;
+            self.seq += 1;
+            if let Some(key) = self.map.order.slot(seq) {
+                if let Some(value) = self.map.get(key) {
+                    self.remaining -= 1;
+                    return Some((key, value));
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Mutable iterator over the pairs of a `PersistentMap`, in unspecified order
+pub struct IterMut<'a, K, V> {
+    pairs: TrieIterMut<'a, K, (u64, V)>,
+}
+
+impl<'a, K: Clone, V: Clone> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs.next().map(|(key, (_, value))| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pairs.size_hint()
+    }
+}
+
+impl<'a, K: Hash + Eq, V> IntoIterator for &'a PersistentMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> FromIterator<(K, V)> for PersistentMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = PersistentMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Extend<(K, V)> for PersistentMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// Equality ignores insertion order: two maps are equal when they hold the
+/// same keys with equal values.
+impl<K: Hash + Eq, V: PartialEq> PartialEq for PersistentMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        if Arc::ptr_eq(&self.entries.root, &other.entries.root) {
+            return true;
+        }
+        self.len() == other.len()
+            && self.entries.iter().all(|(key, (_, value))| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug> fmt::Debug for PersistentMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,150 @@
+//! Interned symbol names
+//!
+//! Symbols (`:name`) are used heavily as map keys, rule names and mode flags.
+//! Every symbol name is interned in one process-wide pool so that all
+//! occurrences of the same symbol share one `Arc<str>` allocation. Cloning a
+//! symbol is a reference-count bump, and equality and hashing use the pointer
+//! alone. The backing storage is an `Arc` rather than an `Rc` because symbols
+//! travel between threads inside values sent to actors and channels.
+//!
+//! The pool does not keep symbols alive. When it has grown to twice its size
+//! after the previous sweep, `intern` drops every name no symbol refers to any
+//! more, so the pool stays within about twice the number of live symbols. A
+//! name that is dropped and later interned again gets a new allocation; no
+//! live symbol can still hold the old one, so pointer equality stays exact.
+//!
+//! `benches/symbol_interning.rs` compares symbols against plain strings
+//! (`cargo bench --features profiling --bench symbol_interning`).
+
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+/// Pool size below which no sweep happens
+const MIN_SWEEP_SIZE: usize = 1024;
+
+/// Interned names, and the pool size that triggers the next sweep
+struct SymbolPool {
+    names: HashSet<Arc<str>>,
+    sweep_at: usize,
+}
+
+lazy_static::lazy_static! {
+    static ref SYMBOL_POOL: Mutex<SymbolPool> = Mutex::new(SymbolPool {
+        names: HashSet::new(),
+        sweep_at: MIN_SWEEP_SIZE,
+    });
+}
+
+/// An interned symbol name (without the leading colon)
+#[derive(Clone)]
+pub struct Symbol(Arc<str>);
+
+impl Symbol {
+    /// Intern a symbol name, reusing the shared allocation if it already exists
+    pub fn intern(name: &str) -> Self {
+        let mut pool = SYMBOL_POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(existing) = pool.names.get(name) {
+            return Symbol(existing.clone());
+        }
+        if pool.names.len() >= pool.sweep_at {
+            // Only the pool holds these; new clones are made under this lock
+            pool.names.retain(|interned| Arc::strong_count(interned) > 1);
+            pool.sweep_at = (pool.names.len() * 2).max(MIN_SWEEP_SIZE);
+        }
+        let interned: Arc<str> = Arc::from(name);
+        pool.names.insert(interned.clone());
+        Symbol(interned)
+    }
+
+    /// Number of names currently in the intern pool
+    pub fn pool_size() -> usize {
+        SYMBOL_POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).names.len()
+    }
+
+    /// The symbol name as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        // Every live symbol with a given name shares one allocation
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if Arc::ptr_eq(&self.0, &other.0) {
+            return std::cmp::Ordering::Equal;
+        }
+        self.0.cmp(&other.0)
+    }
+}
+
+impl std::hash::Hash for Symbol {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<u8>().hash(state);
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Self {
+        Symbol::intern(name)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(name: String) -> Self {
+        Symbol::intern(&name)
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(name: &String) -> Self {
+        Symbol::intern(name)
+    }
+}
@@ -0,0 +1,66 @@
+//! Hash set of values
+//!
+//! A `HashSet<Value>` would work, but values can hold graphs behind a
+//! `RefCell`, which makes them awkward set keys. `ValueSet` buckets values by
+//! their hash instead and resolves collisions with `Value` equality, giving
+//! O(1) membership for deduplication and list set operations.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use super::Value;
+
+/// Set of values compared by value equality
+#[derive(Debug, Clone, Default)]
+pub struct ValueSet {
+    buckets: HashMap<u64, Vec<Value>>,
+    len: usize,
+}
+
+impl ValueSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn hash_of(value: &Value) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Add a value. Returns false if an equal value was already present.
+    pub fn insert(&mut self, value: Value) -> bool {
+        let bucket = self.buckets.entry(Self::hash_of(&value)).or_default();
+        if bucket.contains(&value) {
+            return false;
+        }
+        bucket.push(value);
+        self.len += 1;
+        true
+    }
+
+    pub fn contains(&self, value: &Value) -> bool {
+        self.buckets
+            .get(&Self::hash_of(value))
+            .is_some_and(|bucket| bucket.contains(value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl FromIterator<Value> for ValueSet {
+    fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> Self {
+        let mut set = ValueSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
@@ -7,7 +7,7 @@
 /// 4. Named parameters
 
 use graphoid::execution::Executor;
-use graphoid::values::{Value, ValueKind};
+use graphoid::values::{List, Value, ValueKind};
 
 // ============================================================================
 // CLOSURES - Environment Capture
@@ -463,17 +463,21 @@ result = add(1, 2, 3)
 }
 
 #[test]
-fn test_too_few_arguments_error() {
+fn test_too_few_arguments_returns_partial() {
+    // Calling with fewer arguments than required parameters curries the function
     let source = r#"
 fn multiply(a, b, c) {
     return a * b * c
 }
 
-result = multiply(2, 3)
+partial = multiply(2, 3)
+result = partial(4)
 "#;
     let mut executor = Executor::new();
-    let result = executor.execute_source(source);
-    assert!(result.is_err());
+    executor.execute_source(source).unwrap();
+
+    assert!(matches!(executor.get_variable("partial").unwrap().kind, ValueKind::Function(_)));
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(24.0));
 }
 
 #[test]
@@ -503,3 +507,623 @@ result = add(a: 1, a: 2, b: 3)
     let result = executor.execute_source(source);
     assert!(result.is_err());
 }
+
+// ============================================================================
+// PIPE OPERATOR - Left-to-right function application
+// ============================================================================
+
+#[test]
+fn test_pipe_chains_function_calls() {
+    let source = r#"
+fn keep_even(xs) {
+    return xs.filter(x => x % 2 == 0)
+}
+fn scale(xs, factor) {
+    return xs.map(x => x * factor)
+}
+fn total(xs) {
+    return xs.reduce(0, (acc, x) => acc + x)
+}
+
+result = [1, 2, 3, 4] |> keep_even() |> scale(10) |> total
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(60.0));
+}
+
+#[test]
+fn test_pipe_across_lines() {
+    let source = r#"
+double = x => x * 2
+inc = x => x + 1
+
+result = 5
+    |> double
+    |> inc
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(11.0));
+}
+
+#[test]
+fn test_pipe_into_non_function_errors() {
+    let source = r#"
+not_a_function = 3
+result = 5 |> not_a_function
+"#;
+    let mut executor = Executor::new();
+    let result = executor.execute_source(source);
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// PARTIAL APPLICATION - Currying and f.partial()
+// ============================================================================
+
+#[test]
+fn test_curried_calls_chain() {
+    let source = r#"
+fn add3(a, b, c) {
+    return a + b + c
+}
+
+add1 = add3(1)
+add1_2 = add1(2)
+result = add1_2(3)
+direct = add3(1)(2, 3)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(6.0));
+    assert_eq!(executor.get_variable("direct").unwrap(), Value::number(6.0));
+}
+
+#[test]
+fn test_curried_lambda_in_map() {
+    let source = r#"
+scale = (factor, x) => factor * x
+result = [1, 2, 3].map(scale(10))
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    let expected = Value::list(graphoid::values::List::from_vec(vec![
+        Value::number(10.0), Value::number(20.0), Value::number(30.0),
+    ]));
+    assert_eq!(executor.get_variable("result").unwrap(), expected);
+}
+
+#[test]
+fn test_explicit_partial_with_default_parameter() {
+    // Functions with defaults are not implicitly curried; partial() binds explicitly
+    let source = r#"
+fn greet(greeting, name = "world") {
+    return greeting + ", " + name
+}
+
+hello = greet.partial("Hello")
+default_name = hello()
+custom_name = hello("Ada")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("default_name").unwrap(), Value::string("Hello, world".to_string()));
+    assert_eq!(executor.get_variable("custom_name").unwrap(), Value::string("Hello, Ada".to_string()));
+}
+
+#[test]
+fn test_explicit_partial_with_variadic() {
+    let source = r#"
+fn join_all(sep, ...parts) {
+    return parts.join(sep)
+}
+
+dashed = join_all.partial("-")
+result = dashed("a", "b", "c")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::string("a-b-c".to_string()));
+}
+
+#[test]
+fn test_partial_shares_closure_state() {
+    let source = r#"
+fn make_counter() {
+    count = 0
+    fn add(step, times) {
+        count = count + step * times
+        return count
+    }
+    return add
+}
+
+add = make_counter()
+by_two = add.partial(2)
+by_two(1)
+by_two(3)
+result = add(1, 1)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(9.0));
+}
+
+#[test]
+fn test_partial_captured_args_shadow_outer_names() {
+    let source = r#"
+a = 100
+fn sub(a, b) {
+    return a - b
+}
+
+from_ten = sub.partial(10)
+result = from_ten(3)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(7.0));
+}
+
+#[test]
+fn test_partial_too_many_arguments_error() {
+    let source = r#"
+fn add(a, b) {
+    return a + b
+}
+
+bad = add.partial(1, 2, 3)
+"#;
+    let mut executor = Executor::new();
+    let result = executor.execute_source(source);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// FUNCTION UTILITIES - compose, identity, constant, arity()
+// ============================================================================
+
+#[test]
+fn test_compose_two_functions() {
+    let source = r#"
+fn double(x) {
+    return x * 2
+}
+fn inc(x) {
+    return x + 1
+}
+
+double_after_inc = compose(double, inc)
+result = double_after_inc(3)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(8.0));
+}
+
+#[test]
+fn test_compose_many_functions_right_to_left() {
+    let source = r#"
+fn add(a, b) {
+    return a + b
+}
+fn inc(x) {
+    return x + 1
+}
+fn double(x) {
+    return x * 2
+}
+
+f = compose(double, inc, add)
+result = f(1, 2)
+arity = f.arity()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(8.0));
+    assert_eq!(executor.get_variable("arity").unwrap(), Value::number(2.0));
+}
+
+#[test]
+fn test_compose_with_lambdas() {
+    let source = r#"
+shout = compose(s => s + "!", s => s.upper())
+result = shout("hi")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::string("HI!".to_string()));
+}
+
+#[test]
+fn test_compose_arity_mismatch_errors_at_call() {
+    let source = r#"
+fn add(a, b) {
+    return a + b
+}
+fn double(x) {
+    return x * 2
+}
+
+f = compose(add, double)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    // double hands add a single value; add needs two, so the call fails
+    // instead of passing a partial of add back as the result
+    let err = executor.execute_source("bad = f(1)").unwrap_err();
+    assert!(err.to_string().contains("Function 'add' expects 2 argument(s), got 1"), "{}", err);
+}
+
+#[test]
+fn test_compose_too_few_arguments_curries_composition() {
+    let source = r#"
+fn add(a, b) {
+    return a + b
+}
+fn double(x) {
+    return x * 2
+}
+
+f = compose(double, add)
+add_one_then_double = f(1)
+result = add_one_then_double(2)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(6.0));
+}
+
+#[test]
+fn test_compose_rejects_non_functions() {
+    let mut executor = Executor::new();
+    let result = executor.execute_source("f = compose(5)");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_identity_and_constant() {
+    let source = r#"
+same = [1, 2, 3].map(identity)
+always = constant(7)
+results = [always(), always(1), always(1, 2, 3)]
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(
+        executor.get_variable("same").unwrap(),
+        Value::list(List::from_vec(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)]))
+    );
+    assert_eq!(
+        executor.get_variable("results").unwrap(),
+        Value::list(List::from_vec(vec![Value::number(7.0), Value::number(7.0), Value::number(7.0)]))
+    );
+}
+
+#[test]
+fn test_user_function_shadows_builtin_identity() {
+    let source = r#"
+fn identity(x) {
+    return "mine"
+}
+result = identity(1)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::string("mine".to_string()));
+}
+
+#[test]
+fn test_function_arity() {
+    let source = r#"
+fn add3(a, b, c) {
+    return a + b + c
+}
+fn greet(name, greeting = "Hello") {
+    return greeting + " " + name
+}
+square = x => x * x
+
+a = add3.arity()
+b = add3.partial(1).arity()
+c = greet.arity()
+d = square.arity()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("a").unwrap(), Value::number(3.0));
+    assert_eq!(executor.get_variable("b").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("c").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("d").unwrap(), Value::number(1.0));
+}
+
+// ============================================================================
+// MEMOIZATION - memoize(f)
+// ============================================================================
+
+#[test]
+fn test_memoize_returns_cached_result() {
+    let source = r#"
+import "random"
+fn roll(x) {
+    return rand.random()
+}
+
+m = memoize(roll)
+same = m(1) == m(1)
+distinct = m(1) == m("1")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("same").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("distinct").unwrap(), Value::boolean(false));
+}
+
+#[test]
+fn test_memoize_multiple_arguments() {
+    let source = r#"
+fn mul(a, b) {
+    return a * b
+}
+
+fast = memoize(mul)
+results = [fast(2, 3), fast(2, 3), fast(3, 4)]
+arity = fast.arity()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(
+        executor.get_variable("results").unwrap(),
+        Value::list(List::from_vec(vec![Value::number(6.0), Value::number(6.0), Value::number(12.0)]))
+    );
+    assert_eq!(executor.get_variable("arity").unwrap(), Value::number(2.0));
+}
+
+#[test]
+fn test_memoize_recursive_calls_use_cache() {
+    let source = r#"
+import "random"
+fn noise(n) {
+    if n == 0 {
+        return []
+    }
+    return noise(n - 1).append(rand.random())
+}
+
+noise = memoize(noise)
+short = noise(3)
+long = noise(4)
+shared_prefix = long.slice(0, 3) == short
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("shared_prefix").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_memoize_recursive_fibonacci() {
+    let source = r#"
+fn fib(n) {
+    if n <= 1 {
+        return n
+    }
+    return fib(n - 1) + fib(n - 2)
+}
+
+fib = memoize(fib)
+result = fib(12)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(144.0));
+}
+
+#[test]
+fn test_memoize_leaves_wrapped_function_unchanged() {
+    let source = r#"
+import "random"
+fn noise(n) {
+    if n == 0 {
+        return []
+    }
+    return noise(n - 1).append(rand.random())
+}
+
+cached = memoize(noise)
+cached_repeats = cached(3) == cached(3)
+plain_repeats = noise(3) == noise(3)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("cached_repeats").unwrap(), Value::boolean(true));
+    // Neither the wrapped function nor its recursive calls see the wrapper's cache
+    assert_eq!(executor.get_variable("plain_repeats").unwrap(), Value::boolean(false));
+}
+
+#[test]
+fn test_reserved_looking_names_do_not_affect_function_values() {
+    let source = r#"
+__partial_target__ = 1
+__composed__ = 2
+__memoized__ = 3
+__builtin__ = "identity"
+fn add(a, b) {
+    return a + b
+}
+inc = add(1)
+twice = compose(inc, inc)
+m = memoize(add)
+results = [inc(1), twice(1), m(2, 3), identity(7)]
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(
+        executor.get_variable("results").unwrap(),
+        Value::list(List::from_vec(vec![
+            Value::number(2.0), Value::number(3.0), Value::number(5.0), Value::number(7.0),
+        ]))
+    );
+}
+
+#[test]
+fn test_memoize_skips_cache_for_unhashable_arguments() {
+    let source = r#"
+import "random"
+fn jitter(f) {
+    return f(rand.random())
+}
+
+m = memoize(jitter)
+same = (x) => x
+uncached = m(same) != m(same)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    // Functions can't be map keys, so each call runs the function again
+    assert_eq!(executor.get_variable("uncached").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_memoize_rejects_non_function() {
+    let mut executor = Executor::new();
+    let result = executor.execute_source("m = memoize(42)");
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// BENCHMARKING - bench(name, iterations, block)
+// ============================================================================
+
+#[test]
+fn test_bench_warms_up_and_reports_timings() {
+    let source = r#"
+graph Counter {
+    count: 0
+    fn tick() { count = count + 1 }
+}
+c = Counter {}
+report = bench("tick", 10, () => c.tick())
+runs = c.count
+keys = report.keys()
+ordered = report["min_ms"] <= report["mean_ms"] and report["mean_ms"] <= report["max_ms"]
+"#;
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(source).unwrap();
+
+    // 3 warm-up runs, then 10 measured runs
+    assert_eq!(executor.get_variable("runs").unwrap(), Value::number(13.0));
+    assert_eq!(
+        executor.get_variable("keys").unwrap().to_string_value(),
+        "[name, iterations, total_ms, mean_ms, min_ms, max_ms]"
+    );
+    assert_eq!(executor.get_variable("ordered").unwrap(), Value::boolean(true));
+    assert!(executor.get_captured_output().starts_with("bench tick: 10 iterations, mean "));
+}
+
+#[test]
+fn test_bench_bypasses_memoize_cache_unless_cached() {
+    let source = r#"
+import "random"
+fn roll(x) {
+    return rand.random()
+}
+m = memoize(roll)
+first = m(1)
+bench("uncached", 3, () => m(2))
+bench("cached", 3, () => m(3), :cached)
+kept = m(1) == first
+"#;
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("kept").unwrap(), Value::boolean(true));
+    let err = executor.execute_source(r#"bench("bad", 0, () => 1)"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: bench() iterations must be a positive whole number"), "got: {}", err);
+}
+
+// ============================================================================
+// BOUNDED EXECUTION - timeout(seconds, block)
+// ============================================================================
+
+#[test]
+fn test_timeout_returns_block_value_when_fast() {
+    let mut executor = Executor::new();
+    executor.execute_source("result = timeout(5, () => 1 + 2)").unwrap();
+    assert_eq!(executor.get_variable("result").unwrap(), Value::number(3.0));
+}
+
+#[test]
+fn test_timeout_interrupts_endless_loop_with_catchable_error() {
+    let source = r#"
+kind = ""
+try {
+    timeout(0.05, () => {
+        while true {
+            x = 1
+        }
+    })
+}
+catch TimeoutError as e {
+    kind = e.type()
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+    assert_eq!(executor.get_variable("kind").unwrap(), Value::string("TimeoutError".to_string()));
+}
+
+#[test]
+fn test_nested_timeout_honors_tightest_deadline() {
+    let source = r#"
+fn spin() {
+    while true {
+        x = 1
+    }
+}
+message = ""
+try {
+    timeout(0.05, () => timeout(30, () => spin()))
+}
+catch TimeoutError as e {
+    message = e.message()
+}
+"#;
+    let mut executor = Executor::new();
+    let started = std::time::Instant::now();
+    executor.execute_source(source).unwrap();
+    assert!(started.elapsed().as_secs() < 5);
+    assert_eq!(
+        executor.get_variable("message").unwrap(),
+        Value::string("block exceeded timeout of 0.05 seconds".to_string())
+    );
+
+    let err = executor.execute_source("timeout(0, () => 1)").unwrap_err();
+    assert!(err.to_string().contains("ValueError: timeout() seconds must be a positive number"), "got: {}", err);
+}
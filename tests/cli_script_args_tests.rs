@@ -0,0 +1,80 @@
+use graphoid::execution::Executor;
+use graphoid::values::Value;
+use std::fs;
+use std::process::Command;
+
+/// Arguments after `--` reach the script through args(), and env() reads
+/// the environment the CLI was started with
+#[test]
+fn test_cli_passes_args_after_double_dash_and_env() {
+    let temp_dir = std::env::temp_dir().join("graphoid_cli_args_test");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let main_path = temp_dir.join("main.gr");
+    fs::write(&main_path, r#"
+print("args:", args())
+print("count:", args().length())
+print("greeting:", env("GRAPHOID_TEST_GREETING"))
+print("missing:", env("GRAPHOID_TEST_MISSING"))
+print("in map:", env()["GRAPHOID_TEST_GREETING"])
+"#).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_gr"))
+        .arg(main_path.to_str().unwrap())
+        .args(["--", "in.txt", "--verbose"])
+        .env("GRAPHOID_TEST_GREETING", "hello")
+        .env_remove("GRAPHOID_TEST_MISSING")
+        .output()
+        .expect("Failed to execute CLI");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "CLI failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("args: [in.txt, --verbose]"), "got: {}", stdout);
+    assert!(stdout.contains("count: 2"), "got: {}", stdout);
+    assert!(stdout.contains("greeting: hello"), "got: {}", stdout);
+    assert!(stdout.contains("missing: none"), "got: {}", stdout);
+    assert!(stdout.contains("in map: hello"), "got: {}", stdout);
+
+    // Without `--` the script gets no arguments
+    let output = Command::new(env!("CARGO_BIN_EXE_gr"))
+        .arg(main_path.to_str().unwrap())
+        .output()
+        .expect("Failed to execute CLI");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count: 0"), "got: {}", stdout);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+/// `gr eval` reports an uncaught error the same way running a file does
+#[test]
+fn test_cli_eval_reports_error_position_and_cause() {
+    let output = Command::new(env!("CARGO_BIN_EXE_gr"))
+        .args(["eval", r#"raise RuntimeError("save failed") caused_by IOError("disk full")"#])
+        .output()
+        .expect("Failed to execute CLI");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("save failed (line 1, column 1)"), "got: {}", stderr);
+    assert!(stderr.contains("Caused by: IOError: disk full"), "got: {}", stderr);
+}
+
+#[test]
+fn test_env_is_read_only_and_user_definitions_win() {
+    let mut executor = Executor::new();
+    executor.set_script_args(vec!["a".to_string()]);
+    executor.execute_source(r#"
+first = args()[0]
+fn env(name) {
+    return "shadowed " + name
+}
+shadowed = env("HOME")
+"#).unwrap();
+    assert_eq!(executor.get_variable("first").unwrap(), Value::string("a".to_string()));
+    assert_eq!(executor.get_variable("shadowed").unwrap(), Value::string("shadowed HOME".to_string()));
+
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"env("HOME", "/tmp")"#).unwrap_err();
+    assert!(err.to_string().contains("env() is read-only"), "got: {}", err);
+}
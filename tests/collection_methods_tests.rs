@@ -278,6 +278,93 @@ result
     assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 3.0]));
 }
 
+#[test]
+fn test_list_each_with_index_and_early_exit() {
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(r#"
+result = [10, 20, 30, 40, 50].each((x, i) => {
+    if x == 20 {
+        continue
+    }
+    if i == 3 {
+        break
+    }
+    print(i.to_string() + ": " + x.to_string())
+})
+"#).unwrap();
+    assert_eq!(executor.get_captured_output(), "0: 10\n2: 30\n");
+    assert_eq!(executor.get_variable("result").unwrap(), list_nums(vec![10.0, 20.0, 30.0, 40.0, 50.0]));
+}
+
+#[test]
+fn test_each_break_only_stops_the_each() {
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(r#"
+for n in [1, 2] {
+    [1, 2, 3].each(x => {
+        if x == 2 {
+            break
+        }
+        print("item " + x.to_string())
+    })
+    print("after " + n.to_string())
+}
+"#).unwrap();
+    assert_eq!(executor.get_captured_output(), "item 1\nafter 1\nitem 1\nafter 2\n");
+}
+
+#[test]
+fn test_hash_each_yields_key_value_pairs() {
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(r#"
+prices = {"apple": 3, "pear": 5, "plum": 2, "fig": 9}
+result = prices.each((k, v) => {
+    if v == 5 {
+        continue
+    }
+    if k == "fig" {
+        break
+    }
+    print(k + "=" + v.to_string())
+})
+same = result == prices
+"#).unwrap();
+    assert_eq!(executor.get_captured_output(), "apple=3\nplum=2\n");
+    assert_eq!(executor.get_variable("same").unwrap(), Value::boolean(true));
+
+    let err = executor.execute_source("prices.each(1)").unwrap_err();
+    assert!(err.to_string().contains("expected function, got num"), "got: {}", err);
+}
+
+#[test]
+fn test_list_each_slice_yields_final_partial_slice() {
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source("result = [1, 2, 3, 4, 5].each_slice(2, s => print(s))").unwrap();
+    assert_eq!(executor.get_captured_output(), "[1, 2]\n[3, 4]\n[5]\n");
+    assert_eq!(executor.get_variable("result").unwrap(), list_nums(vec![1.0, 2.0, 3.0, 4.0, 5.0]));
+}
+
+#[test]
+fn test_list_each_cons_yields_overlapping_groups() {
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(r#"
+[1, 2, 3, 4].each_cons(3, g => print(g))
+short = [1, 2].each_cons(3, g => print("never"))
+"#).unwrap();
+    assert_eq!(executor.get_captured_output(), "[1, 2, 3]\n[2, 3, 4]\n");
+    assert_eq!(executor.get_variable("short").unwrap(), list_nums(vec![1.0, 2.0]));
+
+    for size in ["0", "-2", "1.5"] {
+        let err = executor.execute_source(&format!("[1, 2].each_slice({}, s => s)", size)).unwrap_err();
+        assert!(err.to_string().contains("ValueError: each_slice() size must be a positive whole number"), "got: {}", err);
+    }
+}
+
 #[test]
 fn test_list_reduce_sum() {
     let code = "[1, 2, 3, 4, 5].reduce(0, (acc, x) => acc + x)";
@@ -388,6 +475,75 @@ items
     assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 4.0]));
 }
 
+#[test]
+fn test_list_insert_negative_index_and_end() {
+    assert_eq!(eval("[1, 2, 4].insert(-1, 3)"), list_nums(vec![1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(eval("[1, 2, 3].insert(3, 4)"), list_nums(vec![1.0, 2.0, 3.0, 4.0]));
+}
+
+#[test]
+fn test_list_remove_at_immutable() {
+    let code = r#"
+items = [1, 2, 3, 4]
+removed = items.remove_at(-1)
+result = [removed, items.length()]
+result
+"#;
+    // remove_at() without ! returns the element and leaves the list alone
+    assert_eq!(eval(code), list_nums(vec![4.0, 4.0]));
+}
+
+#[test]
+fn test_list_remove_at_mutable() {
+    let code = r#"
+items = [1, 2, 3, 4]
+removed = items.remove_at!(1)
+items.append(removed)
+"#;
+    // remove_at!() returns the removed element
+    assert_eq!(eval(code), list_nums(vec![1.0, 3.0, 4.0, 2.0]));
+}
+
+#[test]
+fn test_list_with_inserted_and_without_at_copy() {
+    let code = r#"
+items = [1, 2, 3]
+result = [items.with_inserted(0, 0), items.without_at(-1), items]
+result
+"#;
+    let expected = Value::list(List::from_vec(vec![
+        list_nums(vec![0.0, 1.0, 2.0, 3.0]),
+        list_nums(vec![1.0, 2.0]),
+        list_nums(vec![1.0, 2.0, 3.0]),
+    ]));
+    assert_eq!(eval(code), expected);
+}
+
+#[test]
+fn test_list_positional_index_out_of_range() {
+    for source in [
+        "[1, 2, 3].insert(4, 0)",
+        "[1, 2, 3].insert(-4, 0)",
+        "[1, 2, 3].remove_at(3)",
+        "[].remove_at(0)",
+        "[1, 2, 3].without_at(-4)",
+    ] {
+        let mut executor = Executor::new();
+        let err = executor.execute_source(source).unwrap_err();
+        assert!(err.to_string().contains("ValueError"), "{}: {}", source, err);
+    }
+}
+
+#[test]
+fn test_list_positional_mutation_respects_frozen() {
+    for method in ["insert!(0, 0)", "remove_at!(0)"] {
+        let mut executor = Executor::new();
+        executor.execute_source("items = [1, 2, 3]\nitems.freeze!()").unwrap();
+        let err = executor.execute_source(&format!("items.{}", method)).unwrap_err();
+        assert!(err.to_string().contains("frozen"), "{}: {}", method, err);
+    }
+}
+
 #[test]
 fn test_list_pop_immutable() {
     let code = "[1, 2, 3].pop()";
@@ -490,6 +646,69 @@ items
     assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 3.0]));
 }
 
+#[test]
+fn test_list_compact_without_nones_returns_equal_copy() {
+    let code = r#"
+items = [1, 2, 3]
+copy = items.compact()
+copy.append!(4)
+result = [items == [1, 2, 3], copy.length()]
+result
+"#;
+    assert_eq!(eval(code).to_string_value(), "[true, 4]");
+}
+
+#[test]
+fn test_list_compact_map() {
+    let code = r#"
+fn tens_of_evens(x) {
+    if x % 2 == 0 {
+        return x * 10
+    }
+    return none
+}
+items = [1, 2, 3, 4, 5]
+evens = items.compact_map(tens_of_evens)
+result = [evens, items]
+result
+"#;
+    assert_eq!(eval(code).to_string_value(), "[[20, 40], [1, 2, 3, 4, 5]]");
+
+    // to_num() gives none for strings that are not numbers
+    let code = r#"["1", "x", "3"].compact_map(s => s.to_num())"#;
+    assert_eq!(eval(code), list_nums(vec![1.0, 3.0]));
+}
+
+#[test]
+fn test_list_flatten_deep() {
+    assert_eq!(eval("[1, [2, [3, [4, []]]], 5].flatten_deep()"), list_nums(vec![1.0, 2.0, 3.0, 4.0, 5.0]));
+    assert_eq!(eval("[].flatten_deep()"), list_nums(vec![]));
+
+    // Maps are leaves, not descended into
+    let code = r#"[[{"a": [1, 2]}], 3].flatten_deep()"#;
+    assert_eq!(eval(code).to_string_value(), eval(r#"[{"a": [1, 2]}, 3]"#).to_string_value());
+}
+
+#[test]
+fn test_list_flatten_deep_nesting_built_in_a_loop() {
+    let code = r#"
+nested = [0]
+for i in range(1, 200) {
+    nested = [i, nested]
+}
+[nested.flatten_deep().length(), nested.depth()]
+"#;
+    assert_eq!(eval(code), list_nums(vec![200.0, 200.0]));
+}
+
+#[test]
+fn test_list_depth() {
+    assert_eq!(eval("[].depth()"), Value::number(1.0));
+    assert_eq!(eval("[1, 2, 3].depth()"), Value::number(1.0));
+    assert_eq!(eval("[1, [2], [[3]]].depth()"), Value::number(3.0));
+    assert_eq!(eval(r#"[{"a": [[1]]}].depth()"#), Value::number(1.0));
+}
+
 // ============================================================================
 // STRING METHODS - CASE CONVERSION (IMMUTABLE)
 // ============================================================================
@@ -1139,3 +1358,515 @@ m["value"]
 "#;
     assert_eq!(eval(code), Value::none());
 }
+
+#[test]
+fn test_map_get_or_default_does_not_insert() {
+    let code = r#"
+m = {"a": 1}
+hit = m.get_or_default("a", 0)
+miss = m.get_or_default("b", 0)
+result = [hit, miss, m.size()]
+result
+"#;
+    assert_eq!(eval(code), list_nums(vec![1.0, 0.0, 1.0]));
+}
+
+#[test]
+fn test_map_get_or_insert_inserts_on_miss() {
+    let code = r#"
+m = {"a": 1}
+hit = m.get_or_insert!("a", 10)
+miss = m.get_or_insert!("b", 20)
+result = [hit, miss, m["b"], m.size()]
+result
+"#;
+    assert_eq!(eval(code), list_nums(vec![1.0, 20.0, 20.0, 2.0]));
+}
+
+#[test]
+fn test_map_get_or_insert_with_only_calls_block_on_miss() {
+    let code = r#"
+m = {"a": 1}
+hit = m.get_or_insert_with!("a", () => raise ValueError("block called on a hit"))
+miss = m.get_or_insert_with!("b", () => 7)
+result = [hit, miss, m["b"]]
+result
+"#;
+    assert_eq!(eval(code), list_nums(vec![1.0, 7.0, 7.0]));
+}
+
+#[test]
+fn test_map_get_or_insert_rejects_frozen_and_non_mutating_calls() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"
+m = {"a": 1}.freeze()
+m.get_or_insert!("b", 2)
+"#).unwrap_err();
+    assert!(err.to_string().contains("cannot modify a frozen hash"), "got: {}", err);
+
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"
+m = {"a": 1}
+m.get_or_insert("b", 2)
+"#).unwrap_err();
+    assert!(err.to_string().contains("call it as get_or_insert!(...)"), "got: {}", err);
+
+    let frozen_lookup = eval(r#"
+m = {"a": 1}.freeze()
+m.get_or_default("b", 2)
+"#);
+    assert_eq!(frozen_lookup, Value::number(2.0));
+}
+
+#[test]
+fn test_map_deep_merge_merges_nested_maps() {
+    let code = r#"
+defaults = {"db": {"host": "localhost", "port": 5432, "opts": {"ssl": false}}, "debug": false}
+merged = defaults.deep_merge({"db": {"port": 6543, "opts": {"timeout": 5}}, "debug": true})
+result = [merged["db"]["host"], merged["db"]["port"], merged["db"]["opts"]["ssl"], merged["db"]["opts"]["timeout"], merged["debug"], defaults["db"]["port"]]
+result
+"#;
+    let expected = Value::list(List::from_vec(vec![
+        Value::string("localhost".to_string()),
+        Value::number(6543.0),
+        Value::boolean(false),
+        Value::number(5.0),
+        Value::boolean(true),
+        Value::number(5432.0),
+    ]));
+    assert_eq!(eval(code), expected);
+}
+
+#[test]
+fn test_map_deep_merge_lists_replaced_unless_concat() {
+    let replaced = eval(r#"
+m = {"tags": [1, 2], "nested": {"ids": [1]}}
+m.deep_merge({"tags": [3], "nested": {"ids": [2]}})["tags"]
+"#);
+    assert_eq!(replaced, list_nums(vec![3.0]));
+
+    let concatenated = eval(r#"
+m = {"tags": [1, 2], "nested": {"ids": [1]}}
+merged = m.deep_merge({"tags": [3], "nested": {"ids": [2]}}, :concat_lists)
+result = [merged["tags"], merged["nested"]["ids"]]
+result
+"#);
+    assert_eq!(
+        concatenated,
+        Value::list(List::from_vec(vec![list_nums(vec![1.0, 2.0, 3.0]), list_nums(vec![1.0, 2.0])]))
+    );
+}
+
+#[test]
+fn test_map_deep_merge_non_map_conflicts_and_errors() {
+    assert_eq!(eval(r#"{"a": {"b": 1}}.deep_merge({"a": 2})["a"]"#), Value::number(2.0));
+    assert_eq!(eval(r#"{"a": 1}.freeze().deep_merge({"b": 2})["b"]"#), Value::number(2.0));
+
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"{"a": 1}.deep_merge([1])"#).unwrap_err();
+    assert!(err.to_string().contains("TypeError: deep_merge() expects a map, got list"), "got: {}", err);
+}
+
+#[test]
+fn test_map_transform_keys_stringifies_and_keeps_order() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+m = {"b": 1, "a": 2}
+m[3] = "three"
+upper = m.transform_keys(k => k.to_string().upper())
+upper_keys = upper.keys()
+numbered = {"x": 1, "y": 2}.transform_keys(k => 7)
+collided = {"a": 1, "A": 2, "b": 3}.transform_keys(k => k.lower())
+"#).unwrap();
+
+    assert_eq!(executor.get_variable("upper_keys").unwrap(), Value::list(List::from_vec(vec![
+        Value::string("B".to_string()),
+        Value::string("A".to_string()),
+        Value::string("3".to_string()),
+    ])));
+    let upper = executor.get_variable("upper").unwrap();
+    assert_eq!(upper.to_string_value(), r#"{"B": 1, "A": 2, "3": three}"#);
+    // Last write wins; the key stays where it first appeared
+    assert_eq!(executor.get_variable("numbered").unwrap().to_string_value(), r#"{"7": 2}"#);
+    assert_eq!(executor.get_variable("collided").unwrap().to_string_value(), r#"{"a": 2, "b": 3}"#);
+}
+
+#[test]
+fn test_map_invert_swaps_keys_and_values() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+ids = {"alice": 1, "bob": 2}
+by_id = ids.invert()
+alice = by_id[1]
+dupes = {"x": "same", "y": "other", "z": "same"}.invert()
+"#).unwrap();
+
+    assert_eq!(executor.get_variable("alice").unwrap(), Value::string("alice".to_string()));
+    assert_eq!(executor.get_variable("by_id").unwrap().to_string_value(), r#"{1: alice, 2: bob}"#);
+    assert_eq!(executor.get_variable("dupes").unwrap().to_string_value(), r#"{"same": z, "other": y}"#);
+}
+
+#[test]
+fn test_map_transform_keys_and_invert_strict_collisions() {
+    for (code, message) in [
+        (r#"{"a": 1, "A": 2}.transform_keys(k => k.lower(), :strict)"#,
+         r#"ValueError: transform_keys() maps keys "a" and "A" to the same key "a""#),
+        (r#"{"x": 1, "y": 1}.invert(strict: true)"#,
+         r#"ValueError: invert() maps keys "x" and "y" to the same key 1"#),
+        ("f = x => x\nm = {\"f\": f}\nm.invert()", "TypeError: invert() cannot use the function value of key \"f\" as a key"),
+        (r#"{"a": 1}.transform_keys(1)"#, "TypeError: transform_keys() expects a function, got num"),
+    ] {
+        let mut executor = Executor::new();
+        let err = executor.execute_source(code).unwrap_err();
+        assert!(err.to_string().contains(message), "{}: got {}", code, err);
+    }
+
+    assert_eq!(eval(r#"{"a": 1, "b": 2}.invert(:strict)[2]"#), Value::string("b".to_string()));
+}
+
+// ============================================================================
+// ANY? / ALL? / NONE? PREDICATES
+// ============================================================================
+
+#[test]
+fn test_any_all_none_with_block() {
+    assert_eq!(eval("[1, 2, 3].any?(x => x > 2)"), Value::boolean(true));
+    assert_eq!(eval("[1, 2, 3].all?(x => x > 1)"), Value::boolean(false));
+    assert_eq!(eval("[1, 2, 3].none?(x => x > 3)"), Value::boolean(true));
+    assert_eq!(eval("[1, 2, 3].any?(:even)"), Value::boolean(true));
+}
+
+#[test]
+fn test_any_all_none_without_block_use_truthiness() {
+    assert_eq!(eval("[0, none, 3].any?()"), Value::boolean(true));
+    assert_eq!(eval("[1, none, 3].all?()"), Value::boolean(false));
+    assert_eq!(eval("[false, none].none?()"), Value::boolean(true));
+}
+
+#[test]
+fn test_any_all_none_empty_list() {
+    assert_eq!(eval("[].any?(x => true)"), Value::boolean(false));
+    assert_eq!(eval("[].all?(x => false)"), Value::boolean(true));
+    assert_eq!(eval("[].none?(x => true)"), Value::boolean(true));
+}
+
+#[test]
+fn test_any_short_circuits() {
+    // The block would fail on the string, but any? stops at the first match
+    assert_eq!(eval(r#"[5, "x"].any?(x => x * 2 > 5)"#), Value::boolean(true));
+}
+
+#[test]
+fn test_all_block_error_reports_element_index() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"[1, 2, "x"].all?(x => x.foo())"#).unwrap_err();
+    assert!(err.to_string().contains("at element 0"), "got: {}", err);
+}
+
+// ============================================================================
+// CONTAINS? / INDEX_OF / FIND / FIND_INDEX
+// ============================================================================
+
+#[test]
+fn test_contains_and_index_of_use_value_equality() {
+    assert_eq!(eval("[1, [2, 3], 4].contains?([2, 3])"), Value::boolean(true));
+    assert_eq!(eval(r#"[{"id": 1}, {"id": 2}].index_of({"id": 2})"#), Value::number(1.0));
+    assert_eq!(eval("[0.1 + 0.2].contains?(0.3)"), Value::boolean(false));
+    assert_eq!(eval("[].contains?(1)"), Value::boolean(false));
+    assert_eq!(eval("[].index_of(1)"), Value::number(-1.0));
+}
+
+#[test]
+fn test_find_and_find_index_with_block() {
+    assert_eq!(eval("[1, 4, 6].find(x => x > 3)"), Value::number(4.0));
+    assert_eq!(eval("[1, 4, 6].find_index(x => x > 3)"), Value::number(1.0));
+    assert_eq!(eval("[1, 3, 5].find_index(:even)"), Value::number(-1.0));
+    assert_eq!(eval("[1, 3, 5].find(x => x > 5)"), Value::none());
+    assert_eq!(eval("[0.1 + 0.2].find_index(x => (x - 0.3).abs() < 0.000001)"), Value::number(0.0));
+    assert_eq!(eval("[].find_index(x => true)"), Value::number(-1.0));
+}
+
+// ============================================================================
+// SUM / AVERAGE / MIN / MAX AGGREGATES
+// ============================================================================
+
+#[test]
+fn test_sum_and_average() {
+    assert_eq!(eval("[1, 2, 3, 4].sum()"), Value::number(10.0));
+    assert_eq!(eval("[1, 2, 3, 4].average()"), Value::number(2.5));
+    assert_eq!(eval("[].sum()"), Value::number(0.0));
+}
+
+#[test]
+fn test_min_and_max() {
+    assert_eq!(eval("[3, 1, 4, 1, 5].min()"), Value::number(1.0));
+    assert_eq!(eval("[3, 1, 4, 1, 5].max()"), Value::number(5.0));
+    assert_eq!(eval(r#"["pear", "apple"].min()"#), Value::string("apple".to_string()));
+}
+
+#[test]
+fn test_min_by_max_by_return_element_and_prefer_earlier() {
+    assert_eq!(eval(r#"["bb", "a", "cc"].max_by(s => s.length())"#), Value::string("bb".to_string()));
+    assert_eq!(eval(r#"["bb", "a", "c"].min_by(s => s.length())"#), Value::string("a".to_string()));
+}
+
+#[test]
+fn test_aggregate_errors() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"[1, "two"].sum()"#).unwrap_err();
+    assert!(err.to_string().contains("TypeError"), "got: {}", err);
+
+    for code in ["[].average()", "[].min()", "[].max()"] {
+        let err = executor.execute_source(code).unwrap_err();
+        assert!(err.to_string().contains("ValueError"), "{} gave: {}", code, err);
+    }
+
+    let result = executor.execute_source(r#"
+caught = "none"
+try {
+    [].average()
+} catch ValueError as e {
+    caught = "value"
+}
+"#);
+    assert!(result.is_ok());
+    assert_eq!(executor.env().get("caught").unwrap(), Value::string("value".to_string()));
+}
+
+// ============================================================================
+// UNIQUE / UNIQUE_BY
+// ============================================================================
+
+#[test]
+fn test_unique_keeps_first_occurrence() {
+    assert_eq!(eval("[3, 1, 3, 2, 1].unique()"), list_nums(vec![3.0, 1.0, 2.0]));
+    assert_eq!(eval("[3, 1, 3].uniq()"), list_nums(vec![3.0, 1.0]));
+}
+
+#[test]
+fn test_unique_compares_lists_and_maps_by_value() {
+    let code = r#"
+items = [[1, 2], [1, 2], {"a": 1, "b": 2}, {"b": 2, "a": 1}, :x, :x]
+items.unique().size()
+"#;
+    assert_eq!(eval(code), Value::number(3.0));
+}
+
+#[test]
+fn test_unique_by_key_does_not_mutate_receiver() {
+    let code = r#"
+words = ["apple", "avocado", "banana", "blueberry", "cherry"]
+firsts = words.unique_by(w => w.substring(0, 1))
+sizes = [firsts.size(), words.size()]
+sizes
+"#;
+    assert_eq!(eval(code), list_nums(vec![3.0, 5.0]));
+}
+
+// ============================================================================
+// SET OPERATIONS
+// ============================================================================
+
+#[test]
+fn test_list_set_operations_dedupe_in_first_appearance_order() {
+    assert_eq!(eval("[3, 1, 3, 2].union([2, 4, 1, 5])"), list_nums(vec![3.0, 1.0, 2.0, 4.0, 5.0]));
+    assert_eq!(eval("[3, 1, 3, 2, 1].intersect([1, 3, 9])"), list_nums(vec![3.0, 1.0]));
+    assert_eq!(eval("[3, 1, 3, 2, 4].difference([1, 4])"), list_nums(vec![3.0, 2.0]));
+    assert_eq!(eval("[1, 2].intersect([])"), list_nums(vec![]));
+}
+
+#[test]
+fn test_list_set_operations_compare_by_value() {
+    let code = r#"
+a = [[1, 2], {"k": 1}, :x]
+b = [{"k": 1}, [1, 2]]
+result = a.intersect(b).size() + a.difference(b).size() * 10
+result
+"#;
+    assert_eq!(eval(code), Value::number(12.0));
+}
+
+#[test]
+fn test_list_set_operations_require_list_argument() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"
+caught = none
+try {
+    [1, 2].union("12")
+}
+catch TypeError as e {
+    caught = e.message
+}
+"#);
+    assert!(err.is_ok());
+    assert_eq!(
+        executor.get_variable("caught").unwrap(),
+        Value::string("union() expects a list, got string".to_string())
+    );
+}
+
+// ============================================================================
+// PAR_MAP
+// ============================================================================
+
+#[test]
+fn test_par_map_matches_map_in_order() {
+    let code = r#"
+fn square(n) {
+    return n * n
+}
+offset = 10
+nums = [1, 2, 3, 4, 5, 6, 7, 8]
+nums.par_map(n => square(n) + offset)
+"#;
+    assert_eq!(
+        eval(code),
+        list_nums(vec![11.0, 14.0, 19.0, 26.0, 35.0, 46.0, 59.0, 74.0])
+    );
+}
+
+#[test]
+fn test_par_map_with_captured_writes_runs_sequentially() {
+    // Assigning to a captured variable falls back to sequential map semantics
+    let code = r#"
+total = 0
+nums = [1, 2, 3]
+nums.par_map(x => {
+    total = total + x
+    return total
+})
+"#;
+    assert_eq!(eval(code), list_nums(vec![1.0, 3.0, 6.0]));
+}
+
+#[test]
+fn test_par_map_reading_captured_graph_runs_sequentially() {
+    // Graphs are shared by reference, so they never reach a worker
+    let code = r#"
+g = graph{}
+g.add_node("a", 1)
+nums = [1, 2]
+nums.par_map(x => g.node_count() + x)
+"#;
+    assert_eq!(eval(code), list_nums(vec![2.0, 3.0]));
+}
+
+#[test]
+fn test_par_map_returns_earliest_error() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"[2, "a", 1, "b"].par_map(x => x.foo())"#).unwrap_err();
+    assert!(err.to_string().contains("number method"), "got: {}", err);
+}
+
+#[test]
+fn test_par_map_workers_use_active_config() {
+    let code = r#"
+configure { display_precision: 2, error_mode: :lenient } {
+    texts = [1, 2].par_map(x => (x / 3).to_string())
+    missing = [[1], [2]].par_map(x => x[5])
+}
+[texts, missing]
+"#;
+    assert_eq!(eval(code), Value::list(List::from_vec(vec![
+        Value::list(List::from_vec(vec![Value::string("0.33".to_string()), Value::string("0.67".to_string())])),
+        Value::list(List::from_vec(vec![Value::none(), Value::none()])),
+    ])));
+}
+
+#[test]
+fn test_par_map_output_and_collected_errors_reach_caller_in_order() {
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(r#"
+doubled = [1, 2, 3, 4].par_map(x => {
+    print("item " + x.to_string())
+    return x * 2
+})
+configure { error_mode: :collect } {
+    [[1], [2]].par_map(x => x[5])
+    collected = get_errors().length()
+}
+"#).unwrap();
+    assert_eq!(executor.get_captured_output(), "item 1\nitem 2\nitem 3\nitem 4\n");
+    assert_eq!(executor.get_variable("doubled").unwrap(), list_nums(vec![2.0, 4.0, 6.0, 8.0]));
+    assert_eq!(executor.get_variable("collected").unwrap(), Value::number(2.0));
+}
+
+#[test]
+fn test_empty_and_present_on_containers() {
+    assert_eq!(eval(r#""".empty?()"#), Value::boolean(true));
+    assert_eq!(eval(r#""a".present?()"#), Value::boolean(true));
+    assert_eq!(eval("[].empty?()"), Value::boolean(true));
+    assert_eq!(eval("[1].empty?()"), Value::boolean(false));
+    assert_eq!(eval("m = {}\nm.present?()"), Value::boolean(false));
+    assert_eq!(eval("m = {\"k\": 1}\nm.empty?()"), Value::boolean(false));
+
+    let code = r#"
+g = graph {}
+before = g.empty?()
+g.add_node("a", 1)
+result = [before, g.empty?(), g.present?()]
+result
+"#;
+    assert_eq!(eval(code), Value::list(List::from_vec(vec![
+        Value::boolean(true),
+        Value::boolean(false),
+        Value::boolean(true),
+    ])));
+}
+
+#[test]
+fn test_empty_rejects_non_containers() {
+    for source in ["n = 5\nx = n.empty?()", "b = true\nx = b.present?()"] {
+        let mut executor = Executor::new();
+        let err = executor.execute_source(source).unwrap_err();
+        assert!(err.to_string().contains("Type error: expected string, list, map, or graph"), "{}: got {}", source, err);
+    }
+}
+
+// ============================================================================
+// SAMPLE
+// ============================================================================
+
+#[test]
+fn test_sample_returns_distinct_elements_reproducibly() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+import "random"
+items = [1, 2, 3, 4, 5, 6, 7, 8]
+rand.seed(7)
+first = items.sample(5)
+one = items.sample()
+rand.seed(7)
+second = items.sample(5)
+again = items.sample()
+all = items.sample(8).sort()
+none_taken = items.sample(0)
+"#).unwrap();
+
+    let first = executor.get_variable("first").unwrap();
+    assert_eq!(first, executor.get_variable("second").unwrap());
+    assert_eq!(executor.get_variable("one").unwrap(), executor.get_variable("again").unwrap());
+    assert_eq!(executor.get_variable("all").unwrap(), list_nums(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]));
+    assert_eq!(executor.get_variable("none_taken").unwrap(), list_nums(vec![]));
+
+    let ValueKind::List(sampled) = &first.kind else { panic!("expected list, got {:?}", first) };
+    let mut nums: Vec<f64> = sampled.to_vec().iter().map(|v| v.to_number().unwrap()).collect();
+    assert_eq!(nums.len(), 5);
+    nums.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    nums.dedup();
+    assert_eq!(nums.len(), 5, "sample(n) must not repeat elements");
+}
+
+#[test]
+fn test_sample_errors() {
+    for (code, message) in [
+        ("[].sample()", "ValueError: sample() cannot choose from an empty list"),
+        ("[1, 2].sample(3)", "ValueError: sample() count (3) exceeds list length (2)"),
+        ("[1, 2].sample(1.5)", "ValueError: sample() expects a non-negative whole number"),
+    ] {
+        let mut executor = Executor::new();
+        let err = executor.execute_source(code).unwrap_err();
+        assert!(err.to_string().contains(message), "{}: got {}", code, err);
+    }
+}
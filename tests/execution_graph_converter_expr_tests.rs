@@ -229,6 +229,18 @@ fn test_convert_super_method_call() {
     assert_eq!(args.len(), 1);
 }
 
+#[test]
+fn test_convert_super_property_access() {
+    let expr = Expr::SuperPropertyAccess {
+        property: "name".to_string(),
+        position: dummy_pos(),
+    };
+    let (graph, root) = convert_expr(&expr);
+    let node = graph.get_node(root).unwrap();
+    assert_eq!(node.node_type, AstNodeType::SuperPropertyAccessExpr);
+    assert_eq!(node.properties.get("property"), Some(&AstProperty::Str("name".to_string())));
+}
+
 // --- PropertyAccess ---
 
 #[test]
@@ -341,8 +353,8 @@ fn test_convert_list() {
 fn test_convert_map() {
     let expr = Expr::Map {
         entries: vec![
-            ("a".to_string(), Expr::Literal { value: LiteralValue::Number(1.0), position: dummy_pos() }),
-            ("b".to_string(), Expr::Literal { value: LiteralValue::Number(2.0), position: dummy_pos() }),
+            (MapKey::Static("a".to_string()), Expr::Literal { value: LiteralValue::Number(1.0), position: dummy_pos() }),
+            (MapKey::Static("b".to_string()), Expr::Literal { value: LiteralValue::Number(2.0), position: dummy_pos() }),
         ],
         position: dummy_pos(),
     };
@@ -182,6 +182,7 @@ fn test_convert_while_stmt() {
 fn test_convert_for_stmt() {
     let stmt = Stmt::For {
         variable: "i".to_string(),
+        unpack: vec![],
         iterable: Expr::List { elements: vec![num_expr(1.0), num_expr(2.0)], position: dummy_pos() },
         body: vec![Stmt::Expression { expr: var_expr("i"), position: dummy_pos() }],
         position: dummy_pos(),
@@ -5,7 +5,7 @@ use graphoid::values::ValueKind;
 
 fn as_symbol(val: &graphoid::values::Value) -> String {
     match &val.kind {
-        ValueKind::Symbol(s) => s.clone(),
+        ValueKind::Symbol(s) => s.to_string(),
         _ => panic!("Expected symbol, got {:?}", val.kind),
     }
 }
@@ -194,6 +194,31 @@ fn test_map_literal() {
     assert!(matches!(val.kind, ValueKind::Map(_)));
 }
 
+#[test]
+fn test_spread_in_list_and_map_literals() {
+    let source = r#"
+a = [1, 2]
+b = [4]
+xs = [...a, 3, ...b, ...[]]
+base = {"host": "localhost", "port": 80}
+config = {...base, port: 8080, "debug": true}
+overridden = {port: 1, ...base}
+"#;
+    assert_eq!(eval_var(source, "xs").to_string_value(), "[1, 2, 3, 4]");
+    let probe = format!("{}\nresult = [config[\"host\"], config[\"port\"], config[\"debug\"], config.keys().length(), overridden[\"port\"]]", source);
+    assert_eq!(eval_var(&probe, "result").to_string_value(), "[localhost, 8080, true, 3, 80]");
+    assert_eq!(eval_var(source, "a").to_string_value(), "[1, 2]");
+}
+
+#[test]
+fn test_spread_of_wrong_type_is_type_error() {
+    let mut executor = GraphExecutor::new();
+    let err = executor.execute_source("xs = [...{\"a\": 1}]").unwrap_err();
+    assert!(err.to_string().contains("TypeError: cannot spread map into a list literal"), "got: {}", err);
+    let err = executor.execute_source("m = {...[1, 2]}").unwrap_err();
+    assert!(err.to_string().contains("TypeError: cannot spread list into a map literal"), "got: {}", err);
+}
+
 // --- Expression statement ---
 
 #[test]
@@ -209,3 +234,43 @@ fn test_integer_division() {
     let val = eval_var("x = 7 // 2", "x");
     assert_eq!(as_number(&val), 3.0);
 }
+
+// --- Embedding API ---
+
+#[test]
+fn test_eval_source_returns_last_expression() {
+    let mut executor = GraphExecutor::new();
+    let val = executor.eval_source("x = 20\nx + 22").unwrap().unwrap();
+    assert_eq!(as_number(&val), 42.0);
+}
+
+#[test]
+fn test_eval_source_statement_returns_none() {
+    let mut executor = GraphExecutor::new();
+    assert!(executor.eval_source("x = 1").unwrap().is_none());
+}
+
+#[test]
+fn test_eval_source_reports_parse_errors() {
+    let mut executor = GraphExecutor::new();
+    let err = executor.eval_source("x = (1 +").unwrap_err();
+    assert_eq!(err.error_type(), "SyntaxError", "{}", err);
+}
+
+#[test]
+fn test_eval_source_leaves_top_level_defers_to_host() {
+    let mut executor = GraphExecutor::new();
+    executor.eval_source("runs = 0\ndefer { runs = runs + 1 }").unwrap();
+    assert_eq!(as_number(&executor.get_variable("runs").unwrap()), 0.0);
+    executor.run_top_level_defers(Ok(())).unwrap();
+    assert_eq!(as_number(&executor.get_variable("runs").unwrap()), 1.0);
+}
+
+#[test]
+fn test_variables_round_trip_through_eval_source() {
+    let mut executor = GraphExecutor::new();
+    executor.set_variable("name", graphoid::values::Value::string("graphoid".to_string()));
+    executor.eval_source("greeting = \"hello \" + name").unwrap();
+    assert_eq!(as_string(&executor.get_variable("greeting").unwrap()), "hello graphoid");
+    assert!(executor.get_variable("missing").is_none());
+}
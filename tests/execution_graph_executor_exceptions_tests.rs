@@ -1,5 +1,5 @@
 mod execution_graph_test_helpers;
-use execution_graph_test_helpers::{eval_var, as_string};
+use execution_graph_test_helpers::{eval_var, as_number, as_string};
 
 // --- Try/catch ---
 
@@ -106,3 +106,169 @@ try {
     let outer = eval_var(source, "outer");
     assert_eq!(as_string(&outer), "outer ok");
 }
+
+// --- Try/catch as an expression ---
+
+#[test]
+fn test_try_expression_value() {
+    let source = r#"
+ok = try { 2 * 21 } catch { 0 }
+fallback = try {
+  x = 1
+  raise ValueError("bad")
+} catch ValueError as e {
+  "handled " + e.message()
+}
+fn safe_div(a, b) {
+  return try { a / b } catch { -1 }
+}
+divided = safe_div(1, 0)
+listed = [try { raise "x" } catch { "a" }, "b"]
+after = "next line"
+"#;
+    assert_eq!(as_number(&eval_var(source, "ok")), 42.0);
+    assert_eq!(as_string(&eval_var(source, "fallback")), "handled bad");
+    assert_eq!(as_number(&eval_var(source, "divided")), -1.0);
+    assert_eq!(eval_var(source, "listed").to_string_value(), "[a, b]");
+    assert_eq!(as_string(&eval_var(source, "after")), "next line");
+}
+
+#[test]
+fn test_try_expression_finally_keeps_value() {
+    let source = r#"
+cleanup = "no"
+value = try {
+  "body"
+} finally {
+  cleanup = "yes"
+  "from finally"
+}
+caught = try { raise "boom" } catch { "caught" } finally { "ignored" }
+"#;
+    assert_eq!(as_string(&eval_var(source, "value")), "body");
+    assert_eq!(as_string(&eval_var(source, "cleanup")), "yes");
+    assert_eq!(as_string(&eval_var(source, "caught")), "caught");
+}
+
+#[test]
+fn test_try_expression_uncaught_error_propagates() {
+    let mut executor = graphoid::execution::Executor::new();
+    let err = executor
+        .execute_source(r#"x = try { raise ValueError("loud") } catch TypeError { 0 }"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("loud"), "got: {}", err);
+}
+
+// --- Defer ---
+
+fn run_captured(source: &str) -> (graphoid::execution::Executor, String) {
+    let mut executor = graphoid::execution::Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(source).unwrap();
+    let output = executor.get_captured_output();
+    (executor, output)
+}
+
+#[test]
+fn test_defer_runs_at_function_exit_in_lifo_order() {
+    let source = r#"
+fn work(flag) {
+  name = "file"
+  defer { print("close " + name) }
+  if flag {
+    defer { print("unlock") }
+  }
+  print("body")
+  return 1
+}
+result = work(true)
+print("after")
+"#;
+    let (executor, output) = run_captured(source);
+    assert_eq!(output, "body\nunlock\nclose file\nafter\n");
+    assert_eq!(as_number(&executor.get_variable("result").unwrap()), 1.0);
+}
+
+#[test]
+fn test_defer_runs_when_function_raises() {
+    let source = r#"
+fn work() {
+  defer { print("cleanup") }
+  raise ValueError("boom")
+}
+caught = try { work() } catch ValueError as e { e.message() }
+"#;
+    let (executor, output) = run_captured(source);
+    assert_eq!(output, "cleanup\n");
+    assert_eq!(as_string(&executor.get_variable("caught").unwrap()), "boom");
+}
+
+#[test]
+fn test_defer_error_does_not_mask_original_error() {
+    let source = r#"
+fn work() {
+  defer { print("still runs") }
+  defer { raise TypeError("from defer") }
+  raise ValueError("original")
+}
+caught = try { work() } catch ValueError as e { e.message() } catch TypeError { "masked" }
+"#;
+    let (executor, output) = run_captured(source);
+    assert_eq!(output, "still runs\n");
+    assert_eq!(as_string(&executor.get_variable("caught").unwrap()), "original");
+}
+
+#[test]
+fn test_defer_error_while_unwinding_is_chained_as_cause() {
+    let source = r#"
+fn work() {
+  defer { raise TypeError("from defer") }
+  raise ValueError("original")
+}
+caught = try { work() } catch ValueError as e { e.cause().message() }
+"#;
+    assert_eq!(as_string(&eval_var(source, "caught")), "from defer");
+}
+
+#[test]
+fn test_defer_reads_variables_as_of_the_defer_statement() {
+    let source = r#"
+fn work() {
+  for i in [1, 2] {
+    defer { print(i) }
+  }
+  x = 5
+  defer { print(x) }
+  x = 6
+}
+work()
+"#;
+    let (_, output) = run_captured(source);
+    assert_eq!(output, "5\n2\n1\n");
+
+    // Assignments in the block still reach the scope being exited
+    let (executor, _) = run_captured("n = 1\ndefer { n = n + 10 }\nn = 2\n");
+    assert_eq!(as_number(&executor.get_variable("n").unwrap()), 11.0);
+}
+
+#[test]
+fn test_defer_error_after_normal_exit_propagates() {
+    let source = r#"
+fn work() {
+  defer { raise ValueError("cleanup failed") }
+  return 1
+}
+caught = try { work() } catch ValueError as e { e.message() }
+"#;
+    assert_eq!(as_string(&eval_var(source, "caught")), "cleanup failed");
+}
+
+#[test]
+fn test_defer_at_top_level_runs_when_program_ends() {
+    let (_, output) = run_captured("defer { print(\"bye\") }\nprint(\"hi\")\n");
+    assert_eq!(output, "hi\nbye\n");
+
+    let mut executor = graphoid::execution::Executor::new();
+    let err = executor.execute_source("defer {\n  x = 1\n").unwrap_err();
+    assert!(err.to_string().contains("Expected '}' after defer body"), "got: {}", err);
+}
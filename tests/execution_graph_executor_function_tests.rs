@@ -268,3 +268,141 @@ result = times3(10)
     let val = eval_var(source, "result");
     assert_eq!(as_number(&val), 30.0);
 }
+
+// --- Execution tracing ---
+
+fn traced_lines(source: &str) -> Vec<String> {
+    let mut executor = GraphExecutor::new();
+    executor.enable_output_capture();
+    let _ = executor.execute_source(source);
+    executor.get_captured_output()
+        .lines()
+        .filter(|line| line.starts_with("[trace]"))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+#[test]
+fn test_trace_statements_and_calls_indented_by_depth() {
+    let source = r#"
+fn double(n) {
+  return n * 2
+}
+configure { trace: true } {
+  x = double(21)
+}
+y = double(1)
+"#;
+    let lines = traced_lines(source);
+    assert_eq!(lines.len(), 4, "got: {:?}", lines);
+    assert!(lines[0].starts_with("[trace] ") && lines[0].contains(" at line 6, column "), "got: {:?}", lines);
+    assert_eq!(lines[1], "[trace] -> double(21)");
+    assert!(lines[2].starts_with("[trace]   ReturnStmt at line 3,"), "got: {:?}", lines);
+    assert_eq!(lines[3], "[trace] <- double returned 42");
+}
+
+#[test]
+fn test_trace_toggles_with_nested_configure_and_reports_errors() {
+    let source = r#"
+fn echo(s) {
+  return s
+}
+fn boom() {
+  raise "bad"
+}
+configure { trace: true } {
+  configure { trace: false } {
+    quiet = echo("hidden")
+  }
+  loud = echo("shown")
+  try {
+    boom()
+  } catch {
+    recovered = true
+  }
+}
+"#;
+    let lines = traced_lines(source);
+    assert!(!lines.iter().any(|line| line.contains("hidden")), "got: {:?}", lines);
+    assert!(lines.contains(&"[trace] -> echo(\"shown\")".to_string()), "got: {:?}", lines);
+    assert!(lines.contains(&"[trace] <- echo returned \"shown\"".to_string()), "got: {:?}", lines);
+    assert!(lines.iter().any(|line| line.starts_with("[trace] <- boom raised ")), "got: {:?}", lines);
+}
+
+#[test]
+fn test_trace_truncates_long_values() {
+    let items: Vec<String> = (1..=40).map(|n| n.to_string()).collect();
+    let source = format!(
+        "fn count(items) {{\n  return items.length()\n}}\nconfigure {{ trace: true }}\nn = count([{}])\n",
+        items.join(", ")
+    );
+    let lines = traced_lines(&source);
+    let call = lines.iter().find(|line| line.contains("-> count(")).expect("call traced");
+    assert!(call.ends_with("...)"), "got: {}", call);
+    assert!(call.len() < 90, "got: {}", call);
+    assert!(lines.contains(&"[trace] <- count returned 40".to_string()), "got: {:?}", lines);
+}
+
+// --- caller_location() ---
+
+fn location_line(source: &str, var: &str) -> f64 {
+    let val = eval_var(source, var);
+    match &val.kind {
+        ValueKind::Map(map) => as_number(map.get("line").expect("line key")),
+        other => panic!("Expected map, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_caller_location_at_top_level_is_the_current_line() {
+    let source = r#"
+x = 1
+here = caller_location()
+"#;
+    assert_eq!(location_line(source, "here"), 3.0);
+}
+
+#[test]
+fn test_caller_location_reports_the_call_site() {
+    let source = r#"
+fn where_called() {
+  return caller_location()
+}
+first = where_called()
+
+second = where_called()
+"#;
+    assert_eq!(location_line(source, "first"), 5.0);
+    assert_eq!(location_line(source, "second"), 7.0);
+}
+
+#[test]
+fn test_caller_location_in_nested_helpers() {
+    let source = r#"
+fn log_site() {
+  return caller_location()
+}
+fn outer() {
+  site = log_site()
+  return site
+}
+result = outer()
+after = [outer(), caller_location()]
+"#;
+    assert_eq!(location_line(source, "result"), 6.0);
+    // Back in the caller, its own line is reported again
+    let after = eval_var(source, "after");
+    let ValueKind::List(items) = &after.kind else { panic!("Expected list") };
+    match &items.to_vec()[1].kind {
+        ValueKind::Map(map) => assert_eq!(as_number(map.get("line").unwrap()), 10.0),
+        other => panic!("Expected map, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_caller_location_has_file_and_column_keys() {
+    let val = eval_var("here = caller_location()", "here");
+    let ValueKind::Map(map) = &val.kind else { panic!("Expected map") };
+    assert!(map.get("file").is_some());
+    assert!(as_number(map.get("column").unwrap()) >= 1.0);
+}
@@ -34,6 +34,29 @@ fn test_string_split_method() {
     assert!(matches!(val.kind, ValueKind::List(_)));
 }
 
+#[test]
+fn test_string_lines_method() {
+    let val = eval_var(r#"result = "one\r\ntwo\nthree".lines() == ["one", "two", "three"]"#, "result");
+    assert!(as_bool(&val));
+
+    // A trailing newline leaves a final empty line, and "" is a single empty line
+    let val = eval_var(r#"result = "a\n".lines() == ["a", ""]"#, "result");
+    assert!(as_bool(&val));
+    let val = eval_var(r#"result = "".lines() == [""]"#, "result");
+    assert!(as_bool(&val));
+}
+
+#[test]
+fn test_string_words_method() {
+    let val = eval_var("result = \"  alpha\\tbeta\\n\\n gamma  \".words() == [\"alpha\", \"beta\", \"gamma\"]", "result");
+    assert!(as_bool(&val));
+
+    let val = eval_var(r#"result = "   ".words().length()"#, "result");
+    assert_eq!(as_number(&val), 0.0);
+    let val = eval_var(r#"result = "".words().length()"#, "result");
+    assert_eq!(as_number(&val), 0.0);
+}
+
 // --- List methods ---
 
 #[test]
@@ -145,3 +168,129 @@ result = m["name"]
     let val = eval_var(source, "result");
     assert_eq!(as_string(&val), "Alice");
 }
+
+// --- Node handles ---
+
+#[test]
+fn test_graph_node_handle_reads_and_writes_through() {
+    let source = r#"
+g = graph {}
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_edge("a", "b")
+n = g.node("a")
+first = n.value
+n.value = 10
+stored = g.get_node("a")
+g["a"] = 20
+live = n.value
+n.set_property("role", "root").set_property("rank", 1)
+role = g.node("a").property("role")
+rank = n.property("rank")
+absent = n.property("color")
+neighbor_ids = n.neighbors().map(h => h.id())
+neighbor_value = n.neighbors()[0].value
+same = g.node("a") == n
+"#;
+    assert_eq!(as_number(&eval_var(source, "first")), 1.0);
+    assert_eq!(as_number(&eval_var(source, "stored")), 10.0);
+    assert_eq!(as_number(&eval_var(source, "live")), 20.0);
+    assert_eq!(as_string(&eval_var(source, "role")), "root");
+    assert_eq!(as_number(&eval_var(source, "rank")), 1.0);
+    assert!(matches!(eval_var(source, "absent").kind, ValueKind::None));
+    assert_eq!(eval_var(source, "neighbor_ids").to_string_value(), "[b]");
+    assert_eq!(as_number(&eval_var(source, "neighbor_value")), 2.0);
+    assert!(as_bool(&eval_var(source, "same")));
+}
+
+#[test]
+fn test_graph_node_handle_missing_node_is_value_error() {
+    let source = r#"
+g = graph {}
+g.add_node("a", 1)
+caught = try { g.node("zzz") } catch ValueError as e { e.message() }
+"#;
+    assert_eq!(as_string(&eval_var(source, "caught")), "node() found no node 'zzz' in the graph");
+}
+
+#[test]
+fn test_graph_node_handle_follows_variable_after_mutation() {
+    let source = r#"
+g = graph {}
+g.add_node("a", 1)
+n = g.node("a")
+g.add_node("x", 0)
+n.value = 5
+written = g.get_node("a")
+g.remove_node("a")
+read_removed = try { n.value } catch ValueError as e { e.message() }
+write_removed = try { n.value = 3 } catch ValueError as e { e.message() }
+restored = g.has_node("a")
+"#;
+    assert_eq!(as_number(&eval_var(source, "written")), 5.0);
+    assert_eq!(as_string(&eval_var(source, "read_removed")), "node 'a' is no longer in the graph");
+    assert_eq!(as_string(&eval_var(source, "write_removed")), "node 'a' is no longer in the graph");
+    assert!(!as_bool(&eval_var(source, "restored")));
+}
+
+// --- Edge weights and properties ---
+
+#[test]
+fn test_graph_add_edge_with_weight_and_properties() {
+    let source = r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_edge("a", "b", 2.5, {"color": "red"})
+g.add_edge("b", "c", "road", 1, {"lanes": 2})
+g.add_edge("a", "c", "road")
+g.add_edge("c", "a")
+red = g.count_edges(e => e[2].get_or_default("color", none) == "red")
+heavy = g.count_edges(e => e[2]["weight"] == 2.5)
+roads = g.count_edges(e => e[2]["type"] == "road")
+lanes = g.count_edges(e => e[2].get_or_default("lanes", none) == 2)
+unweighted = g.count_edges(e => e[2]["weight"] == none)
+
+w = graph { type: :directed }
+w.add_node("a", 1)
+w.add_node("b", 2)
+w.add_rule(:weighted_edges)
+rejected = try { w.add_edge("a", "b", "link", none, {"note": "x"}) } catch { "rejected" }
+w.add_edge("a", "b", 3, {"note": "x"})
+weighted = w.edge_count()
+"#;
+    assert_eq!(as_number(&eval_var(source, "red")), 1.0);
+    assert_eq!(as_number(&eval_var(source, "heavy")), 1.0);
+    assert_eq!(as_number(&eval_var(source, "roads")), 2.0);
+    assert_eq!(as_number(&eval_var(source, "lanes")), 1.0);
+    assert_eq!(as_number(&eval_var(source, "unweighted")), 2.0);
+    assert_eq!(as_string(&eval_var(source, "rejected")), "rejected");
+    assert_eq!(as_number(&eval_var(source, "weighted")), 1.0);
+}
+
+// --- Centrality ---
+
+#[test]
+fn test_graph_centrality_dispatches_by_measure() {
+    let source = r#"
+g = graph { type: :undirected }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_edge("a", "b")
+g.add_edge("b", "c")
+degree = g.centrality(:degree)
+same = degree == g.degree_centrality()
+hub = g.centrality(:betweenness)["b"]
+closeness = g.closeness_centrality()["a"]
+ranked = g.pagerank().keys()
+caught = try { g.centrality(:fame) } catch ValueError as e { e.message() }
+"#;
+    assert_eq!(eval_var(source, "degree").to_string_value(), "{\"a\": 0.5, \"b\": 1, \"c\": 0.5}");
+    assert!(as_bool(&eval_var(source, "same")));
+    assert_eq!(as_number(&eval_var(source, "hub")), 1.0);
+    assert!((as_number(&eval_var(source, "closeness")) - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(eval_var(source, "ranked").to_string_value(), "[a, b, c]");
+    assert!(as_string(&eval_var(source, "caught")).starts_with("Unknown centrality measure ':fame'"));
+}
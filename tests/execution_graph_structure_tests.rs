@@ -220,6 +220,7 @@ fn test_node_type_variants_exist() {
         AstNodeType::CallExpr,
         AstNodeType::MethodCallExpr,
         AstNodeType::SuperMethodCallExpr,
+        AstNodeType::SuperPropertyAccessExpr,
         AstNodeType::PropertyAccessExpr,
         AstNodeType::IndexExpr,
         AstNodeType::LambdaExpr,
@@ -119,6 +119,59 @@ fn test_freeze_bang_on_hash() {
     assert_eq!(result, Value::boolean(true));
 }
 
+// ============================================================================
+// freeze_shallow() Tests - Only the receiver is frozen
+// ============================================================================
+
+#[test]
+fn test_freeze_shallow_leaves_elements_mutable() {
+    let code = r#"
+        items = [[1, 2], [3, 4]]
+        items.freeze_shallow!()
+        inner = items[0]
+        inner.append!(5)
+        outer_frozen = items.is_frozen()
+        element_frozen = items[0].is_frozen()
+    "#;
+
+    let mut executor = Executor::new();
+    executor.execute_source(code).unwrap();
+    assert_eq!(executor.get_variable("outer_frozen").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("element_frozen").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("inner").unwrap().to_string_value(), "[1, 2, 5]");
+}
+
+#[test]
+fn test_freeze_shallow_rejects_replacing_an_element() {
+    let code = r#"
+        items = [[1, 2], [3, 4]].freeze_shallow()
+        items[0] = [9]
+    "#;
+
+    let result = execute_and_return(code);
+    assert!(result.unwrap_err().contains("frozen"));
+
+    let code = r#"
+        config = {"db": {"port": 5432}}.freeze_shallow()
+        config["db"] = {}
+    "#;
+
+    let result = execute_and_return(code);
+    assert!(result.unwrap_err().contains("frozen"));
+}
+
+#[test]
+fn test_freeze_deep_rejects_mutating_an_element() {
+    let code = r#"
+        items = [[1, 2], [3, 4]].freeze_deep()
+        inner = items[0]
+        inner.append!(5)
+    "#;
+
+    let result = execute_and_return(code);
+    assert!(result.unwrap_err().contains("frozen"));
+}
+
 // ============================================================================
 // has_frozen() Tests - Boolean query for frozen elements
 // ============================================================================
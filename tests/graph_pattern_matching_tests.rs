@@ -350,6 +350,50 @@ fn test_bidirectional_with_two_way_edges() {
     assert_eq!(result.unwrap(), Value::number(4.0));
 }
 
+#[test]
+fn test_where_property_filter_uses_index() {
+    let code = r#"
+        g = graph{}
+        g.add_node("A", {city: "Boston"})
+        g.add_node("B", {city: "NYC"})
+        g.add_node("C", {city: "Boston"})
+        g.add_edge("A", "B", "KNOWS")
+        g.add_edge("C", "B", "KNOWS")
+        g.create_index(:city)
+
+        # Moving A out of Boston must update the index
+        g.add_node("A", {city: "NYC"})
+        results = g.match(node("person"), edge(type: "KNOWS"), node("friend"))
+        results.where("person", :city, "Boston").size()
+    "#;
+
+    let result = execute_and_return(code);
+    assert!(result.is_ok(), "Expected execution to succeed, got: {:?}", result.err());
+    assert_eq!(result.unwrap(), Value::number(1.0));
+}
+
+#[test]
+fn test_where_property_filter_without_index() {
+    let code = r#"
+        g = graph{}
+        g.add_node("A", {city: "Boston"})
+        g.add_node("B", {city: "NYC"})
+        g.add_edge("A", "B", "KNOWS")
+        g.add_edge("B", "A", "KNOWS")
+        g.create_index(:city)
+        dropped = g.drop_index(:city)
+        dropped_again = g.drop_index(:city)
+
+        results = g.match(node("person"), edge(type: "KNOWS"), node("friend"))
+        summary = [dropped, dropped_again, g.has_index(:city), results.where("friend", :city, "NYC").size()]
+        summary
+    "#;
+
+    let result = execute_and_return(code);
+    assert!(result.is_ok(), "Expected execution to succeed, got: {:?}", result.err());
+    assert_eq!(result.unwrap().to_string_value(), "[true, false, false, 1]");
+}
+
 // ============================================================================
 // Subgraph Operations Tests - Day 6-8 (TDD RED)
 // ============================================================================
@@ -236,3 +236,41 @@ fn test_all_paths_no_paths() {
         panic!("Expected list of paths");
     }
 }
+
+#[test]
+fn test_all_paths_without_max_length_stays_simple() {
+    let code = r#"
+        g = graph { type: :undirected }
+        g.add_node("A", 1)
+        g.add_node("B", 2)
+        g.add_node("C", 3)
+        g.add_edge("A", "B")
+        g.add_edge("B", "C")
+        g.add_edge("C", "A")
+        g.all_paths("A", "C")
+    "#;
+    // The cycle never repeats a node, so only two paths exist
+    let result = eval(code);
+
+    if let ValueKind::List(paths) = &result.kind {
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths.get(0).unwrap(), &list_strings(vec!["A", "B", "C"]));
+        assert_eq!(paths.get(1).unwrap(), &list_strings(vec!["A", "C"]));
+    } else {
+        panic!("Expected list of paths, got {:?}", result);
+    }
+}
+
+#[test]
+fn test_all_paths_default_length_and_missing_node() {
+    let code = r#"
+        g = graph{}
+        g.add_node("A", 1)
+        g.add_node("B", 2)
+        g.add_edge("A", "B")
+        result = [g.all_paths("A", "B"), g.all_paths("A", "Z", 3)]
+        result
+    "#;
+    let result = eval(code);
+    assert_eq!(result.to_string_value(), "[[[A, B]], []]");
+}
@@ -341,3 +341,230 @@ fn test_to_ascii_empty_graph() {
     let ascii = exec.env().get("ascii").expect("Should have ascii");
     assert!(matches!(ascii.kind, ValueKind::String(_)));
 }
+
+// =============================================================================
+// to_svg() tests
+// =============================================================================
+
+fn svg_of(code: &str) -> String {
+    let exec = execute_with_result(code).expect("Should execute");
+    match exec.env().get("svg").expect("Should have svg").kind {
+        ValueKind::String(s) => s,
+        other => panic!("to_svg() should return a string, got {:?}", other),
+    }
+}
+
+/// All numeric values of an attribute, e.g. every `cx="..."` in the document
+fn attr_values(svg: &str, attr: &str) -> Vec<f64> {
+    let needle = format!(" {}=\"", attr);
+    svg.match_indices(&needle)
+        .map(|(i, _)| {
+            let rest = &svg[i + needle.len()..];
+            rest[..rest.find('"').unwrap()].parse().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_to_svg_draws_nodes_and_edges() {
+    let svg = svg_of(r#"
+        g = graph{}
+        g.add_node("A", "alpha")
+        g.add_node("B", "beta")
+        g.add_node("C", none)
+        g.add_edge("A", "B")
+        svg = g.to_svg()
+    "#);
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert_eq!(svg.matches("<circle").count(), 3);
+    assert_eq!(svg.matches("<line").count(), 1);
+    assert!(svg.contains(">alpha</text>"));
+    assert!(svg.contains(">beta</text>"));
+    // Nodes without a value are labeled by ID
+    assert!(svg.contains(">C</text>"));
+    assert!(svg.contains("marker-end=\"url(#arrow)\""));
+}
+
+#[test]
+fn test_to_svg_is_deterministic() {
+    let first = svg_of(r#"
+        g = graph{}
+        g.add_node("x", 1)
+        g.add_node("y", 2)
+        g.add_node("z", 3)
+        g.add_edge("x", "y")
+        g.add_edge("y", "z")
+        svg = g.to_svg()
+    "#);
+    let second = svg_of(r#"
+        g = graph{}
+        g.add_node("z", 3)
+        g.add_node("y", 2)
+        g.add_node("x", 1)
+        g.add_edge("y", "z")
+        g.add_edge("x", "y")
+        svg = g.to_svg()
+    "#);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_to_svg_keeps_isolated_nodes_on_canvas() {
+    let svg = svg_of(r#"
+        g = graph{}
+        for i in [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12] {
+            g.add_node("n" + i.to_string(), i)
+        }
+        g.add_edge("n1", "n2")
+        svg = g.to_svg()
+    "#);
+
+    assert_nodes_on_canvas(&svg, 12);
+}
+
+fn assert_nodes_on_canvas(svg: &str, count: usize) {
+    let width = attr_values(svg, "width")[0];
+    let height = attr_values(svg, "height")[0];
+    let radii = attr_values(svg, "r");
+    let xs = attr_values(svg, "cx");
+    let ys = attr_values(svg, "cy");
+    assert_eq!(xs.len(), count);
+    for ((x, y), radius) in xs.iter().zip(ys.iter()).zip(radii.iter()) {
+        assert!(*x - radius >= 0.0 && *x + radius <= width, "x={} outside width {}", x, width);
+        assert!(*y - radius >= 0.0 && *y + radius <= height, "y={} outside height {}", y, height);
+    }
+}
+
+#[test]
+fn test_to_svg_sizes_nodes_to_labels_and_truncates_long_ones() {
+    let svg = svg_of(r#"
+        g = graph{}
+        g.add_node("a", 1)
+        g.add_node("b", "a medium label")
+        g.add_node("c", "a label far too long to draw inside a node")
+        g.add_edge("a", "b")
+        g.add_edge("b", "c")
+        svg = g.to_svg()
+    "#);
+
+    let radii = attr_values(&svg, "r");
+    assert_eq!(radii[0], 20.0);
+    assert!(radii[1] > radii[0], "medium label should widen its node: {:?}", radii);
+    assert!(radii[2] >= radii[1], "truncated label still fills its node: {:?}", radii);
+    assert!(svg.contains(">a medium label</text>"));
+    // Long labels are cut with an ellipsis, and the full text is the tooltip
+    assert!(svg.contains(">a label far too\u{2026}</text>"));
+    assert!(svg.contains("<title>a label far too long to draw inside a node</title>"));
+    assert_eq!(svg.matches("<title>").count(), 1);
+    assert_nodes_on_canvas(&svg, 3);
+}
+
+#[test]
+fn test_to_svg_undirected_edges_drawn_once_without_arrows() {
+    let svg = svg_of(r#"
+        g = graph { type: :undirected }
+        g.add_node("a", 1)
+        g.add_node("b", 2)
+        g.add_edge("a", "b")
+        svg = g.to_svg()
+    "#);
+
+    assert_eq!(svg.matches("<line").count(), 1);
+    assert!(!svg.contains("marker-end"));
+}
+
+#[test]
+fn test_to_svg_escapes_labels() {
+    let svg = svg_of(r#"
+        g = graph{}
+        g.add_node("a", "<b> & co")
+        svg = g.to_svg()
+    "#);
+
+    assert!(svg.contains(">&lt;b&gt; &amp; co</text>"));
+}
+
+#[test]
+fn test_to_svg_empty_graph() {
+    let svg = svg_of(r#"
+        g = graph{}
+        svg = g.to_svg()
+    "#);
+
+    assert!(svg.contains("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("<circle").count(), 0);
+}
+
+// =============================================================================
+// show() tests
+// =============================================================================
+
+fn show_of(code: &str) -> String {
+    let exec = execute_with_result(code).expect("Should execute");
+    match exec.env().get("shown").expect("Should have shown").kind {
+        ValueKind::String(s) => s,
+        other => panic!("show() should return a string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_show_lists_each_node_with_its_neighbors() {
+    let code = r#"
+        g = graph { type: :directed }
+        g.add_node("a", 1)
+        g.add_node("b", 2)
+        g.add_node("c", 3)
+        g.add_edge("a", "b")
+        g.add_edge("a", "c")
+        g.add_edge("b", "c")
+        shown = g.show()
+        compact = g.to_string()
+    "#;
+
+    assert_eq!(
+        show_of(code),
+        "<graph: 3 nodes, 3 edges>\n  a -> b, c\n  b -> c\n  c"
+    );
+
+    // The default string form stays compact
+    let exec = execute_with_result(code).expect("Should execute");
+    let compact = exec.env().get("compact").expect("Should have compact");
+    assert!(matches!(compact.kind, ValueKind::String(ref s) if s == "<graph: 3 nodes, 3 edges>"));
+}
+
+#[test]
+fn test_show_truncates_large_graphs() {
+    let shown = show_of(r#"
+        g = graph { type: :directed }
+        for i in range(25) {
+            g.add_node("n" + i.to_string(), i)
+        }
+        shown = g.show()
+    "#);
+    assert!(shown.contains("\n  n19"));
+    assert!(!shown.contains("\n  n20"));
+    assert!(shown.ends_with("\n  ... +5 more nodes"));
+
+    let limited = show_of(r#"
+        g = graph { type: :undirected }
+        g.add_node("x", 1)
+        g.add_node("y", 2)
+        g.add_node("z", 3)
+        g.add_edge("x", "y")
+        shown = g.show(1)
+    "#);
+    assert_eq!(limited.lines().nth(1), Some("  x -- y"));
+    assert!(limited.ends_with("\n  ... +2 more nodes"));
+
+    let all = show_of(r#"
+        g = graph { type: :directed }
+        for i in range(25) {
+            g.add_node("n" + i.to_string(), i)
+        }
+        shown = g.show(:all)
+    "#);
+    assert!(all.contains("\n  n24") && !all.contains("more nodes"));
+}
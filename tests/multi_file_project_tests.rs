@@ -218,3 +218,56 @@ fn test_module_caching_across_imports() {
         _ => panic!("sum2 should be number"),
     }
 }
+
+#[test]
+fn test_configure_module_paths() {
+    let mut executor = Executor::new();
+
+    let test_file = PathBuf::from("tests/fixtures/multi_file_project/main.gr");
+    executor.set_current_file(Some(test_file));
+
+    // module_paths are relative to the current file's directory
+    let source = r#"
+        configure { module_paths: ["models", "services"] }
+        import "user"
+        import "calculator"
+        bob = user.create_user("Bob", 40)
+        sum = calculator.sum_two_numbers(2, 3)
+    "#;
+    executor.execute_source(source).unwrap();
+
+    assert!(matches!(executor.get_variable("bob").unwrap().kind, ValueKind::Map(_)));
+    match &executor.get_variable("sum").unwrap().kind {
+        ValueKind::Number(n) => assert_eq!(*n, 5.0),
+        _ => panic!("sum should be number"),
+    }
+}
+
+#[test]
+fn test_missing_module_is_catchable_io_error() {
+    let mut executor = Executor::new();
+
+    let test_file = PathBuf::from("tests/fixtures/multi_file_project/main.gr");
+    executor.set_current_file(Some(test_file));
+
+    let source = r#"
+        fn try_import() {
+            try {
+                import "no_such_module"
+            } catch ModuleNotFound as e {
+                return e.message()
+            }
+            return "imported"
+        }
+        kind = try_import()
+    "#;
+    executor.execute_source(source).unwrap();
+
+    match &executor.get_variable("kind").unwrap().kind {
+        ValueKind::String(s) => {
+            assert!(s.starts_with("Module not found: 'no_such_module'"));
+            assert!(s.contains("multi_file_project/no_such_module.gr"));
+        }
+        _ => panic!("kind should be string"),
+    }
+}
@@ -3,7 +3,7 @@
 //!
 //! Every transformative method must have two versions:
 //! - Immutable (no suffix): Returns new collection, original unchanged
-//! - Mutating (`!` suffix): Modifies in place, returns none
+//! - Mutating (`!` suffix): Modifies in place, returns the updated collection
 
 use graphoid::ast;
 use graphoid::execution::Executor;
@@ -69,12 +69,12 @@ fn test_sort_mutating() {
 }
 
 #[test]
-fn test_sort_mutating_returns_none() {
+fn test_sort_mutating_returns_updated_list() {
     let code = r#"
         nums = [3, 1, 2]
         nums.sort!()
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 3.0]));
 }
 
 // ============================================================================
@@ -112,12 +112,45 @@ fn test_reverse_mutating() {
 }
 
 #[test]
-fn test_reverse_mutating_returns_none() {
+fn test_reverse_mutating_returns_updated_list() {
     let code = r#"
         nums = [1, 2, 3]
         nums.reverse!()
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![3.0, 2.0, 1.0]));
+}
+
+// ============================================================================
+// ROTATE - rotate(n) vs rotate!(n)
+// ============================================================================
+
+#[test]
+fn test_rotate_immutable() {
+    assert_eq!(eval("[1, 2, 3, 4].rotate(1)"), list_nums(vec![2.0, 3.0, 4.0, 1.0]));
+    assert_eq!(eval("[1, 2, 3, 4].rotate(-1)"), list_nums(vec![4.0, 1.0, 2.0, 3.0]));
+    assert_eq!(eval("[1, 2, 3, 4].rotate(9)"), list_nums(vec![2.0, 3.0, 4.0, 1.0]));
+    assert_eq!(eval("[1, 2, 3].rotate(3)"), list_nums(vec![1.0, 2.0, 3.0]));
+    assert_eq!(eval("[].rotate(2)"), list_nums(vec![]));
+}
+
+#[test]
+fn test_rotate_original_unchanged() {
+    let code = r#"
+        original = [1, 2, 3]
+        rotated = original.rotate(1)
+        original
+    "#;
+    assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn test_rotate_mutating() {
+    let code = r#"
+        nums = [1, 2, 3]
+        nums.rotate!(-1)
+        nums
+    "#;
+    assert_eq!(eval(code), list_nums(vec![3.0, 1.0, 2.0]));
 }
 
 // ============================================================================
@@ -155,12 +188,12 @@ fn test_uniq_mutating() {
 }
 
 #[test]
-fn test_uniq_mutating_returns_none() {
+fn test_uniq_mutating_returns_updated_list() {
     let code = r#"
         nums = [1, 2, 2, 3, 1]
         nums.uniq!()
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 3.0]));
 }
 
 // ============================================================================
@@ -198,12 +231,12 @@ fn test_map_mutating() {
 }
 
 #[test]
-fn test_map_mutating_returns_none() {
+fn test_map_mutating_returns_updated_list() {
     let code = r#"
         nums = [1, 2, 3]
         nums.map!(x => x * 2)
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![2.0, 4.0, 6.0]));
 }
 
 // ============================================================================
@@ -241,12 +274,12 @@ fn test_filter_mutating() {
 }
 
 #[test]
-fn test_filter_mutating_returns_none() {
+fn test_filter_mutating_returns_updated_list() {
     let code = r#"
         nums = [1, 2, 3, 4, 5]
         nums.filter!(x => x % 2 == 0)
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![2.0, 4.0]));
 }
 
 // ============================================================================
@@ -284,12 +317,12 @@ fn test_reject_mutating() {
 }
 
 #[test]
-fn test_reject_mutating_returns_none() {
+fn test_reject_mutating_returns_updated_list() {
     let code = r#"
         nums = [1, 2, 3, 4, 5]
         nums.reject!(x => x % 2 == 0)
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![1.0, 3.0, 5.0]));
 }
 
 // ============================================================================
@@ -327,12 +360,12 @@ fn test_compact_mutating() {
 }
 
 #[test]
-fn test_compact_mutating_returns_none() {
+fn test_compact_mutating_returns_updated_list() {
     let code = r#"
         nums = [1, none, 2, none, 3]
         nums.compact!()
     "#;
-    assert_eq!(eval(code), Value::none());
+    assert_eq!(eval(code), list_nums(vec![1.0, 2.0, 3.0]));
 }
 
 // ============================================================================
@@ -357,3 +390,87 @@ fn test_select_mutating() {
     "#;
     assert_eq!(eval(code), list_nums(vec![3.0, 4.0, 5.0]));
 }
+
+// ============================================================================
+// GRAPH MUTATORS - Return the graph for fluent chaining
+// ============================================================================
+
+fn node_count(value: &Value) -> usize {
+    match &value.kind {
+        graphoid::values::ValueKind::Graph(g) => g.borrow().node_count(),
+        other => panic!("Expected graph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_graph_built_in_one_expression() {
+    let code = r#"
+        g = graph{}.add_node("a", 1).add_node("b", 2).add_node("c", 3).add_edge("a", "b").add_edge("b", "c")
+        g
+    "#;
+    let g = eval(code);
+    assert_eq!(node_count(&g), 3);
+    match &g.kind {
+        graphoid::values::ValueKind::Graph(g) => assert!(g.borrow().has_path("a", "c")),
+        other => panic!("Expected graph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_graph_mutator_still_updates_receiver() {
+    let code = r#"
+        g = graph{}
+        g.add_node("a", 1)
+        g.add_node("b", 2)
+        g
+    "#;
+    assert_eq!(node_count(&eval(code)), 2);
+}
+
+#[test]
+fn test_graph_mutating_suffix_keeps_graph() {
+    let code = r#"
+        g = graph{}
+        g.add_node!("a", 1)
+        g.remove_node!("a")
+        g.add_node!("b", 2)
+        g
+    "#;
+    assert_eq!(node_count(&eval(code)), 1);
+}
+
+#[test]
+fn test_list_chaining_produces_copies() {
+    let code = r#"
+        nums = [1]
+        more = nums.append(2).append(3).prepend(0)
+        nums
+    "#;
+    assert_eq!(eval(code), list_nums(vec![1.0]));
+}
+
+#[test]
+fn test_list_mutator_returns_updated_list_for_chaining() {
+    let code = r#"
+        nums = [3, 1]
+        count = nums.append!(2).length()
+        result = [count, nums]
+        result
+    "#;
+    assert_eq!(
+        eval(code),
+        Value::list(List::from_vec(vec![Value::number(3.0), list_nums(vec![3.0, 1.0, 2.0])]))
+    );
+}
+
+#[test]
+fn test_hash_mutator_returns_updated_hash_for_chaining() {
+    let code = r#"
+        m = {"a": 1, "b": 2}
+        keys = m.remove!("a").keys()
+        result = [keys, m.keys()]
+        result
+    "#;
+    let b = Value::list(List::from_vec(vec![Value::string("b".to_string())]));
+    assert_eq!(eval(code), Value::list(List::from_vec(vec![b.clone(), b])));
+}
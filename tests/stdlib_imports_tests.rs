@@ -115,3 +115,24 @@ fn test_multiple_stdlib_imports() {
     let json_result = executor.get_variable("json_result");
     assert!(json_result.is_some());
 }
+
+#[test]
+fn test_matrix_module_operations_and_shape_errors() {
+    let mut executor = Executor::new();
+
+    let source = r#"
+        import "matrix"
+        product = matrix.multiply([[1, 2], [3, 4]], matrix.transpose([[5, 6], [7, 8]]))
+        total = matrix.add(matrix.identity(2), [[1, 1], [1, 1]])
+        mismatch = try { matrix.add([[1, 2]], [[1, 2], [3, 4]]) } catch ValueError as e { e.message() }
+        ragged = try { matrix.multiply([[1, 2], [3]], [[1], [2]]) } catch ValueError as e { e.message() }
+    "#;
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("product").unwrap().to_string_value(), "[[17, 23], [39, 53]]");
+    assert_eq!(executor.get_variable("total").unwrap().to_string_value(), "[[2, 1], [1, 2]]");
+    let mismatch = executor.get_variable("mismatch").unwrap().to_string_value();
+    assert!(mismatch.contains("same shape, got 1x2 and 2x2"), "got: {}", mismatch);
+    let ragged = executor.get_variable("ragged").unwrap().to_string_value();
+    assert!(ragged.contains("row 0 has 2 columns and row 1 has 1"), "got: {}", ragged);
+}
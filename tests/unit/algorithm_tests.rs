@@ -4,7 +4,7 @@
 //! They should fail initially, then pass once we implement the algorithms.
 
 use graphoid::values::{Value, Graph};
-use graphoid::values::graph::GraphType;
+use graphoid::values::graph::{GraphType, FOREST_ROOT_ID};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -303,3 +303,1139 @@ fn test_shortest_path_undirected() {
     let path_reverse = g.shortest_path("C", "A", None, false).unwrap();
     assert_eq!(path_reverse, vec!["C", "B", "A"]);
 }
+
+// ============================================================================
+// Graph Metrics Tests
+// ============================================================================
+
+fn path_graph(graph_type: GraphType) -> Graph {
+    // A - B - C - D
+    let mut g = Graph::new(graph_type);
+    for id in ["A", "B", "C", "D"] {
+        g.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    g.add_edge("A", "B", "edge".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("B", "C", "edge".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("C", "D", "edge".to_string(), None, HashMap::new()).unwrap();
+    g
+}
+
+#[test]
+fn test_degree_histogram_undirected() {
+    let g = path_graph(GraphType::Undirected);
+    let histogram = g.degree_histogram();
+
+    // Endpoints have degree 1, inner nodes degree 2
+    assert_eq!(histogram.get(&1), Some(&2));
+    assert_eq!(histogram.get(&2), Some(&2));
+    assert_eq!(histogram.len(), 2);
+}
+
+#[test]
+fn test_degree_histogram_directed_counts_in_and_out() {
+    let g = path_graph(GraphType::Directed);
+    let histogram = g.degree_histogram();
+
+    assert_eq!(histogram.get(&1), Some(&2));
+    assert_eq!(histogram.get(&2), Some(&2));
+}
+
+#[test]
+fn test_density_directed_vs_undirected() {
+    // 3 edges among 4 nodes: directed max is 12, undirected max is 6
+    let directed = path_graph(GraphType::Directed);
+    assert!((directed.density() - 0.25).abs() < 1e-9);
+
+    let undirected = path_graph(GraphType::Undirected);
+    assert!((undirected.density() - 0.5).abs() < 1e-9);
+
+    assert_eq!(Graph::new(GraphType::Directed).density(), 0.0);
+}
+
+#[test]
+fn test_average_degree() {
+    let g = path_graph(GraphType::Undirected);
+    assert!((g.average_degree() - 1.5).abs() < 1e-9);
+
+    assert_eq!(Graph::new(GraphType::Undirected).average_degree(), 0.0);
+}
+
+#[test]
+fn test_diameter_connected() {
+    let g = path_graph(GraphType::Undirected);
+    assert_eq!(g.diameter(), Some(3));
+
+    let mut single = Graph::new(GraphType::Undirected);
+    single.add_node("A".to_string(), Value::none()).unwrap();
+    assert_eq!(single.diameter(), Some(0));
+}
+
+#[test]
+fn test_diameter_disconnected_is_none() {
+    let mut g = path_graph(GraphType::Undirected);
+    g.add_node("E".to_string(), Value::none()).unwrap();
+    assert_eq!(g.diameter(), None);
+
+    // Directed paths cannot be walked backwards
+    let directed = path_graph(GraphType::Directed);
+    assert_eq!(directed.diameter(), None);
+
+    assert_eq!(Graph::new(GraphType::Directed).diameter(), None);
+}
+
+#[test]
+fn test_is_connected() {
+    assert!(path_graph(GraphType::Undirected).is_connected());
+    // Weak connectivity: a directed path counts even though it can't be walked backwards
+    assert!(path_graph(GraphType::Directed).is_connected());
+
+    let mut split = path_graph(GraphType::Directed);
+    split.add_node("E".to_string(), Value::none()).unwrap();
+    assert!(!split.is_connected());
+
+    let mut single = Graph::new(GraphType::Directed);
+    single.add_node("A".to_string(), Value::none()).unwrap();
+    assert!(single.is_connected());
+    assert!(Graph::new(GraphType::Undirected).is_connected());
+}
+
+#[test]
+fn test_degree_and_closeness_centrality() {
+    let g = path_graph(GraphType::Undirected);
+    let degree = g.degree_centrality();
+    assert!((degree["A"] - 1.0 / 3.0).abs() < 1e-9);
+    assert!((degree["B"] - 2.0 / 3.0).abs() < 1e-9);
+
+    let closeness = g.closeness_centrality();
+    assert!((closeness["A"] - 0.5).abs() < 1e-9);
+    assert!((closeness["B"] - 0.75).abs() < 1e-9);
+
+    // The end of a directed path reaches nothing
+    let directed = path_graph(GraphType::Directed).closeness_centrality();
+    assert_eq!(directed["D"], 0.0);
+    assert!((directed["C"] - 1.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_betweenness_centrality() {
+    let g = path_graph(GraphType::Undirected);
+    let betweenness = g.betweenness_centrality();
+    assert_eq!(betweenness["A"], 0.0);
+    assert!((betweenness["B"] - 2.0 / 3.0).abs() < 1e-9);
+    assert!((betweenness["C"] - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(betweenness["D"], 0.0);
+}
+
+#[test]
+fn test_pagerank_sums_to_one() {
+    let g = path_graph(GraphType::Undirected);
+    let ranks = g.pagerank(0.85);
+    let total: f64 = ranks.values().sum();
+    assert!((total - 1.0).abs() < 1e-6);
+    assert!(ranks["B"] > ranks["A"]);
+    assert!((ranks["A"] - ranks["D"]).abs() < 1e-6);
+
+    // D has no outgoing edges, so its rank is spread over every node
+    let directed = path_graph(GraphType::Directed).pagerank(0.85);
+    let total: f64 = directed.values().sum();
+    assert!((total - 1.0).abs() < 1e-6);
+    assert!(directed["D"] > directed["A"]);
+
+    assert!(Graph::new(GraphType::Directed).pagerank(0.85).is_empty());
+}
+
+#[test]
+fn test_connected_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_edge("b", "a")
+joined = g.connected?()
+weak = g.weakly_connected?()
+g.add_node("c", 3)
+split = g.connected?()
+empty = graph{}.connected?()
+"#).unwrap();
+    assert_eq!(executor.get_variable("joined").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("weak").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("split").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("empty").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_count_nodes_and_edges_with_predicates() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 5)
+g.add_node("c", 9)
+g.add_edge("a", "b", "road")
+g.add_edge("b", "c", "rail")
+g.add_edge("a", "c", "road")
+big = g.count_nodes((value) => value > 3)
+roads = g.count_edges((edge) => edge[2]["type"] == "road")
+from_a = g.count_edges((edge) => edge[0] == "a")
+nodes = g.count_nodes()
+edges = g.count_edges()
+"#).unwrap();
+    assert_eq!(executor.get_variable("big").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("roads").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("from_a").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("nodes").unwrap(), Value::number(3.0));
+    assert_eq!(executor.get_variable("edges").unwrap(), Value::number(3.0));
+
+    let err = executor.execute_source(r#"x = g.count_nodes((value) => to_int("x"))"#).unwrap_err();
+    assert!(
+        err.to_string().contains("ValueError: count_nodes() block failed on node 'a': cannot convert"),
+        "got: {}", err
+    );
+}
+
+#[test]
+fn test_find_node_and_select_nodes() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("c", 9)
+g.add_node("a", 1)
+g.add_node("b", 5)
+first_big = g.find_node((value) => value > 3)
+missing = g.find_node((value) => value > 100)
+big = g.select_nodes((value) => value > 3)
+none_selected = g.select_nodes((value) => false)
+not_c = g.select_nodes((node) => node["id"] != "c" and node["properties"].empty?(), :node)
+b_first = g.find_node((node) => node["id"] != "c" and node["value"] > 3, node: true)
+"#).unwrap();
+    assert_eq!(executor.get_variable("first_big").unwrap(), Value::string("c".to_string()));
+    assert_eq!(executor.get_variable("missing").unwrap(), Value::none());
+    assert_eq!(executor.get_variable("big").unwrap().to_string_value(), "[c, b]");
+    assert_eq!(executor.get_variable("none_selected").unwrap().to_string_value(), "[]");
+    assert_eq!(executor.get_variable("not_c").unwrap().to_string_value(), "[a, b]");
+    assert_eq!(executor.get_variable("b_first").unwrap(), Value::string("b".to_string()));
+
+    let err = executor.execute_source(r#"x = g.select_nodes((value) => to_int("x"))"#).unwrap_err();
+    assert!(
+        err.to_string().contains("ValueError: select_nodes() block failed on node 'c': cannot convert"),
+        "got: {}", err
+    );
+    let err = executor.execute_source(r#"x = g.find_node((value) => true, :all)"#).unwrap_err();
+    assert!(err.to_string().contains("find_node() optional argument must be :node"), "got: {}", err);
+}
+
+#[test]
+fn test_map_nodes_and_map_edges() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :undirected }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_edge("a", "b", "road")
+checked = {"checked": true}
+doubled = g.map_nodes((value) => value * 2)
+tagged = g.map_edges((props) => checked)
+values_ok = doubled.get_node("a") == 2 and doubled.get_node("b") == 4
+topology_ok = doubled.to_edge_list() == [["a", "b"]] and doubled.count_edges((edge) => edge[2]["type"] == "road") == 2
+tagged_ok = tagged.count_edges((edge) => edge[2]["checked"] == true) == 2
+source_ok = g.get_node("a") == 1 and g.count_edges((edge) => edge[2].get_or_default("checked", false) == true) == 0
+"#).unwrap();
+
+    for name in ["values_ok", "topology_ok", "tagged_ok", "source_ok"] {
+        assert_eq!(executor.get_variable(name).unwrap(), Value::boolean(true), "{}", name);
+    }
+
+    let err = executor.execute_source(r#"x = g.map_nodes((value) => to_int("x"))"#).unwrap_err();
+    assert!(
+        err.to_string().contains("ValueError: map_nodes() block failed on node 'a': cannot convert"),
+        "got: {}", err
+    );
+    let err = executor.execute_source(r#"x = g.map_edges((props) => 1)"#).unwrap_err();
+    assert!(
+        err.to_string().contains("map_edges() block failed on edge 'a' -> 'b': block must return a map, got num"),
+        "got: {}", err
+    );
+}
+
+// ============================================================================
+// Cycle Detection Tests
+// ============================================================================
+
+#[test]
+fn test_find_cycles_acyclic_graph_is_empty() {
+    assert!(path_graph(GraphType::Directed).find_cycles().is_empty());
+    assert!(path_graph(GraphType::Undirected).find_cycles().is_empty());
+    assert!(!path_graph(GraphType::Directed).has_cycle());
+}
+
+#[test]
+fn test_find_cycles_directed() {
+    // A -> B -> C -> A, plus C -> D -> C
+    let mut g = path_graph(GraphType::Directed);
+    g.add_edge("C", "A", "edge".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("D", "C", "edge".to_string(), None, HashMap::new()).unwrap();
+
+    let mut cycles = g.find_cycles();
+    cycles.sort();
+    assert_eq!(cycles, vec![
+        vec!["A".to_string(), "B".to_string(), "C".to_string()],
+        vec!["C".to_string(), "D".to_string()],
+    ]);
+}
+
+#[test]
+fn test_find_cycles_self_loop() {
+    let mut g = path_graph(GraphType::Directed);
+    g.add_edge("B", "B", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(g.has_cycle());
+    assert_eq!(g.find_cycles(), vec![vec!["B".to_string()]]);
+}
+
+#[test]
+fn test_find_cycles_undirected_ignores_single_edges() {
+    // Square A - B - C - D - A
+    let mut g = path_graph(GraphType::Undirected);
+    g.add_edge("D", "A", "edge".to_string(), None, HashMap::new()).unwrap();
+
+    let cycles = g.find_cycles();
+    assert_eq!(cycles.len(), 1);
+    let mut nodes = cycles[0].clone();
+    nodes.sort();
+    assert_eq!(nodes, vec!["A", "B", "C", "D"]);
+}
+
+#[test]
+fn test_shortest_cycle_directed() {
+    assert_eq!(path_graph(GraphType::Directed).shortest_cycle(), None);
+    assert_eq!(path_graph(GraphType::Undirected).girth(), None);
+
+    // A -> B -> C -> D -> A, with the chord C -> A closing A -> B -> C
+    let mut g = path_graph(GraphType::Directed);
+    g.add_edge("D", "A", "edge".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("C", "A", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert_eq!(g.shortest_cycle(), Some(vec!["A".to_string(), "B".to_string(), "C".to_string()]));
+    assert_eq!(g.girth(), Some(3));
+
+    g.add_edge("D", "C", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert_eq!(g.shortest_cycle(), Some(vec!["C".to_string(), "D".to_string()]));
+
+    g.add_edge("B", "B", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert_eq!(g.shortest_cycle(), Some(vec!["B".to_string()]));
+    assert_eq!(g.girth(), Some(1));
+}
+
+#[test]
+fn test_shortest_cycle_undirected() {
+    let square = undirected(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a")], &["x"]);
+    assert_eq!(square.shortest_cycle(), Some(vec!["a", "b", "c", "d"].into_iter().map(String::from).collect()));
+
+    // The square plus a triangle hanging off "d"
+    let mixed = undirected(
+        &[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a"), ("d", "e"), ("e", "f"), ("f", "d")],
+        &[],
+    );
+    assert_eq!(mixed.shortest_cycle(), Some(vec!["d", "e", "f"].into_iter().map(String::from).collect()));
+    assert_eq!(mixed.girth(), Some(3));
+}
+
+#[test]
+fn test_shortest_cycle_parallel_edges() {
+    let mut g = Graph::new(GraphType::Undirected);
+    g.multigraph = true;
+    for id in ["a", "b", "c"] {
+        g.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    g.add_edge("b", "c", "road".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("c", "b", "rail".to_string(), None, HashMap::new()).unwrap();
+    assert_eq!(g.shortest_cycle(), Some(vec!["b".to_string(), "c".to_string()]));
+    assert_eq!(g.girth(), Some(2));
+
+    // Two edges the same way round are not a cycle in a directed graph
+    let mut directed = Graph::new(GraphType::Directed);
+    directed.multigraph = true;
+    for id in ["a", "b"] {
+        directed.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    directed.add_edge("a", "b", "road".to_string(), None, HashMap::new()).unwrap();
+    directed.add_edge("a", "b", "rail".to_string(), None, HashMap::new()).unwrap();
+    assert_eq!(directed.girth(), None);
+}
+
+#[test]
+fn test_girth_and_shortest_cycle_methods() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :undirected }
+for id in ["a", "b", "c", "d"] {
+    g.add_node(id, none)
+}
+g.add_edge("a", "b")
+g.add_edge("b", "c")
+before = [g.girth(), g.shortest_cycle()]
+g.add_edge("c", "d")
+g.add_edge("d", "a")
+after = [g.girth(), g.shortest_cycle()]
+"#).unwrap();
+    assert_eq!(executor.get_variable("before").unwrap().to_string_value(), "[none, none]");
+    assert_eq!(executor.get_variable("after").unwrap().to_string_value(), "[4, [a, b, c, d]]");
+}
+
+#[test]
+fn test_complement_joins_missing_pairs() {
+    let mut g = undirected(&[("a", "b"), ("b", "c"), ("c", "c")], &["d"]);
+    g.add_node("a".to_string(), Value::number(1.0)).unwrap();
+
+    let complement = g.complement().unwrap();
+    assert_eq!(complement.graph_type, GraphType::Undirected);
+    assert_eq!(complement.node_count(), 4);
+    assert_eq!(complement.get_node("a"), Some(&Value::number(1.0)));
+    for (from, to) in [("a", "c"), ("a", "d"), ("b", "d"), ("c", "d")] {
+        assert!(complement.has_edge(from, to) && complement.has_edge(to, from), "{}-{}", from, to);
+    }
+    for (from, to) in [("a", "b"), ("b", "c"), ("c", "c"), ("a", "a")] {
+        assert!(!complement.has_edge(from, to), "{}-{}", from, to);
+    }
+    assert!(g.has_edge("a", "b"));
+}
+
+#[test]
+fn test_complement_of_complete_graph_is_edgeless() {
+    let complete = undirected(&[("a", "b"), ("a", "c"), ("a", "d"), ("b", "c"), ("b", "d"), ("c", "d")], &[]);
+    let complement = complete.complement().unwrap();
+    assert_eq!(complement.node_count(), 4);
+    assert_eq!(complement.edge_count(), 0);
+
+    // ...and back again
+    assert_eq!(complement.complement().unwrap().edge_count(), complete.edge_count());
+}
+
+#[test]
+fn test_complement_requires_undirected_simple_graph() {
+    let err = directed(&[("a", "b")], &[]).complement().unwrap_err();
+    assert!(err.to_string().contains("ValueError"), "{}", err);
+
+    let mut multi = undirected(&[("a", "b")], &[]);
+    multi.multigraph = true;
+    let err = multi.complement().unwrap_err();
+    assert!(err.to_string().contains("ValueError"), "{}", err);
+}
+
+#[test]
+fn test_complement_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :undirected }
+for id in ["a", "b", "c"] {
+    g.add_node(id, id.upper())
+}
+g.add_edge("a", "b")
+c = g.complement()
+edges = c.to_edge_list()
+result = [edges.length(), edges.contains(["a", "c"]), edges.contains(["b", "c"]), c.get_node("a")]
+"#).unwrap();
+    assert_eq!(executor.get_variable("result").unwrap().to_string_value(), "[2, true, true, A]");
+}
+
+// ============================================================================
+// Reachability Tests
+// ============================================================================
+
+#[test]
+fn test_has_path_honors_direction() {
+    let directed = path_graph(GraphType::Directed);
+    assert!(directed.has_path("A", "D"));
+    assert!(!directed.has_path("D", "A"));
+    assert!(directed.has_path("C", "C"));
+
+    let undirected = path_graph(GraphType::Undirected);
+    assert!(undirected.has_path("D", "A"));
+}
+
+#[test]
+fn test_path_exists_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("A", 1)
+g.add_node("B", 2)
+g.add_node("C", 3)
+g.add_edge("A", "B")
+forward = g.path_exists("A", "B")
+backward = g.path_exists("B", "A")
+isolated = g.path_exists("A", "C")
+itself = g.path_exists("C", "C")
+"#).unwrap();
+    assert_eq!(executor.get_variable("forward").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("backward").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("isolated").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("itself").unwrap(), Value::boolean(true));
+
+    let err = executor.execute_source(r#"missing = g.path_exists("A", "Z")"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: path_exists() node 'Z' not found"), "got: {}", err);
+}
+
+#[test]
+fn test_descendants_and_ancestors_of() {
+    // app -> web -> log, app -> db -> log
+    let mut g = Graph::new(GraphType::Directed);
+    for id in ["app", "web", "db", "log"] {
+        g.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    for (from, to) in [("app", "web"), ("app", "db"), ("web", "log"), ("db", "log")] {
+        g.add_edge(from, to, "edge".to_string(), None, HashMap::new()).unwrap();
+    }
+
+    assert_eq!(g.descendants_of("app"), vec!["web", "db", "log"]);
+    assert_eq!(g.ancestors_of("log"), vec!["web", "db", "app"]);
+    assert!(g.descendants_of("log").is_empty());
+    assert!(g.ancestors_of("missing").is_empty());
+}
+
+#[test]
+fn test_descendants_terminates_on_cycles() {
+    let mut g = path_graph(GraphType::Directed);
+    g.add_edge("D", "A", "edge".to_string(), None, HashMap::new()).unwrap();
+
+    let mut reachable = g.descendants_of("B");
+    reachable.sort();
+    assert_eq!(reachable, vec!["A", "B", "C", "D"]);
+}
+
+#[test]
+fn test_ancestors_descendants_methods() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_edge("a", "b")
+g.add_edge("b", "c")
+g.add_edge("c", "a")
+down = g.descendants("a")
+up = g.ancestors("b", :include_self)
+"#).unwrap();
+    assert_eq!(executor.get_variable("down").unwrap().to_string_value(), "[b, c]");
+    assert_eq!(executor.get_variable("up").unwrap().to_string_value(), "[b, a, c]");
+
+    let err = executor.execute_source(r#"x = g.descendants("zz")"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: descendants() node 'zz' not found"), "got: {}", err);
+}
+
+#[test]
+fn test_reachable_from_marks_every_node() {
+    let g = path_graph(GraphType::Directed);
+
+    assert_eq!(g.reachable_from("B"), vec![
+        ("A".to_string(), false),
+        ("B".to_string(), true),
+        ("C".to_string(), true),
+        ("D".to_string(), true),
+    ]);
+    assert!(g.reachable_from("missing").iter().all(|(_, reached)| !reached));
+}
+
+#[test]
+fn test_reachable_from_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_node("d", 4)
+g.add_edge("a", "b")
+g.add_edge("b", "a")
+g.add_edge("c", "a")
+mask = g.reachable_from("a")
+"#).unwrap();
+    assert_eq!(
+        executor.get_variable("mask").unwrap().to_string_value(),
+        r#"{"a": true, "b": true, "c": false, "d": false}"#
+    );
+
+    let err = executor.execute_source(r#"x = g.reachable_from("zz")"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: reachable_from() node 'zz' not found"), "got: {}", err);
+}
+
+// ============================================================================
+// Traversal Tree Tests
+// ============================================================================
+
+fn diamond_with_island() -> Graph {
+    // A - B, A - C, B - D, C - D, and E on its own
+    let mut g = Graph::new(GraphType::Undirected);
+    for (i, id) in ["A", "B", "C", "D", "E"].iter().enumerate() {
+        g.add_node(id.to_string(), Value::number(i as f64)).unwrap();
+    }
+    for (from, to) in [("A", "B"), ("A", "C"), ("B", "D"), ("C", "D")] {
+        g.add_edge(from, to, "edge".to_string(), None, HashMap::new()).unwrap();
+    }
+    g
+}
+
+#[test]
+fn test_bfs_tree_keeps_first_discovery_edges() {
+    let g = diamond_with_island();
+    let tree = g.traversal_tree("A", false).unwrap();
+
+    assert_eq!(tree.graph_type, GraphType::Directed);
+    assert!(tree.has_ruleset("tree"));
+    assert!(tree.is_tree());
+    assert_eq!(tree.node_count(), 4);
+    assert!(!tree.has_node("E"));
+    assert_eq!(tree.edge_count(), 3);
+    assert!(tree.has_edge("A", "B") && tree.has_edge("A", "C") && tree.has_edge("B", "D"));
+    assert!(!tree.has_edge("B", "A"));
+    assert_eq!(tree.get_node("D"), Some(&Value::number(3.0)));
+}
+
+#[test]
+fn test_dfs_tree_follows_dfs_order() {
+    let g = diamond_with_island();
+    assert_eq!(g.dfs("A"), vec!["A", "C", "D", "B"]);
+
+    let tree = g.traversal_tree("A", true).unwrap();
+    assert!(tree.is_tree());
+    assert_eq!(tree.edge_count(), 3);
+    assert!(tree.has_edge("A", "C") && tree.has_edge("C", "D") && tree.has_edge("D", "B"));
+    assert!(g.traversal_tree("missing", true).unwrap().node_count() == 0);
+}
+
+#[test]
+fn test_bfs_tree_and_dfs_tree_methods() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("root", "r")
+g.add_node("left", "l")
+g.add_node("right", "x")
+g.add_node("orphan", "o")
+g.add_edge("root", "left")
+g.add_edge("root", "right")
+g.add_edge("left", "right")
+g.add_edge("right", "root")
+t = g.bfs_tree("root")
+d = g.dfs_tree("root")
+t_edges = t.edge_count()
+t_is_tree = t.is_tree()
+t_ruleset = t.has_ruleset(:tree)
+t_nodes = t.node_count()
+left_value = t.get_node("left")
+d_has_left_right = d.has_path("left", "right")
+"#).unwrap();
+    assert_eq!(executor.get_variable("t_edges").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("t_is_tree").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("t_ruleset").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("t_nodes").unwrap(), Value::number(3.0));
+    assert_eq!(executor.get_variable("left_value").unwrap(), Value::string("l".to_string()));
+    assert_eq!(executor.get_variable("d_has_left_right").unwrap(), Value::boolean(false));
+
+    // The tree ruleset stays enforced on the result
+    assert!(executor.execute_source(r#"t.add_edge("right", "root")"#).is_err());
+
+    let err = executor.execute_source(r#"x = g.bfs_tree("zz")"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: bfs_tree() node 'zz' not found"), "got: {}", err);
+}
+
+#[test]
+fn test_to_tree_drops_cycle_edges_and_optionally_keeps_a_forest() {
+    let mut g = diamond_with_island();
+    g.add_node("F".to_string(), Value::number(5.0)).unwrap();
+    g.add_edge("E", "F", "edge".to_string(), None, HashMap::new()).unwrap();
+
+    let tree = g.to_tree("A", false).unwrap();
+    assert!(tree.is_tree());
+    assert!(tree.satisfies_ruleset("tree").unwrap());
+    assert_eq!(tree.node_count(), 4);
+    assert_eq!(tree.edge_count(), 3);
+
+    let forest = g.to_tree("A", true).unwrap();
+    assert!(forest.is_tree());
+    assert!(forest.satisfies_ruleset("tree").unwrap());
+    assert_eq!(forest.node_count(), 7);
+    assert_eq!(forest.edge_count(), 6);
+    assert_eq!(forest.get_node(FOREST_ROOT_ID), Some(&Value::none()));
+    assert!(forest.has_edge(FOREST_ROOT_ID, "A") && forest.has_edge(FOREST_ROOT_ID, "E"));
+    assert!(forest.has_edge("E", "F") && !forest.has_edge(FOREST_ROOT_ID, "F"));
+
+    let err = g.to_tree("missing", true).unwrap_err();
+    assert!(err.to_string().contains("ValueError: to_tree() node 'missing' not found"), "got: {}", err);
+}
+
+#[test]
+fn test_to_tree_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_node("lone", 4)
+g.add_edge("a", "b")
+g.add_edge("b", "c")
+g.add_edge("c", "a")
+t = g.to_tree("a")
+f = g.to_tree("a", :forest)
+t_edges = t.edge_count()
+t_ok = t.satisfies_ruleset?(:tree)
+t_has_lone = t.has_node("lone")
+f_ok = f.satisfies_ruleset?(:tree)
+f_nodes = f.node_count()
+f_has_lone = f.has_node("lone")
+missing = try { g.to_tree("zz") } catch ValueError as e { e.message() }
+"#).unwrap();
+    assert_eq!(executor.get_variable("t_edges").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("t_ok").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("t_has_lone").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("f_ok").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("f_nodes").unwrap(), Value::number(5.0));
+    assert_eq!(executor.get_variable("f_has_lone").unwrap(), Value::boolean(true));
+    let missing = executor.get_variable("missing").unwrap();
+    assert!(missing.to_string_value().contains("to_tree() node 'zz' not found"), "got: {:?}", missing);
+}
+
+// ============================================================================
+// Random Walk Tests
+// ============================================================================
+
+#[test]
+fn test_random_walk_follows_edges_and_stops_at_sink() {
+    let g = path_graph(GraphType::Directed);
+
+    assert_eq!(g.random_walk("A", 10, false, || 0.5), vec!["A", "B", "C", "D"]);
+    assert_eq!(g.random_walk("B", 1, false, || 0.5), vec!["B", "C"]);
+    assert_eq!(g.random_walk("C", 0, false, || 0.5), vec!["C"]);
+    assert!(g.random_walk("missing", 3, false, || 0.5).is_empty());
+}
+
+#[test]
+fn test_random_walk_weighted_biases_by_edge_weight() {
+    let mut g = Graph::new(GraphType::Directed);
+    for id in ["hub", "light", "heavy"] {
+        g.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    g.add_edge("hub", "light", "edge".to_string(), Some(1.0), HashMap::new()).unwrap();
+    g.add_edge("hub", "heavy", "edge".to_string(), Some(3.0), HashMap::new()).unwrap();
+
+    // Uniform: a draw of 0.4 lands in the first half (light)
+    assert_eq!(g.random_walk("hub", 1, false, || 0.4), vec!["hub", "light"]);
+    // Weighted: 0.4 * 4 = 1.6 is past light's share of 1
+    assert_eq!(g.random_walk("hub", 1, true, || 0.4), vec!["hub", "heavy"]);
+    assert_eq!(g.random_walk("hub", 1, true, || 0.2), vec!["hub", "light"]);
+}
+
+#[test]
+fn test_walk_method_is_reproducible_under_seed() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+import "random"
+g = graph { type: :undirected }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_edge("a", "b")
+g.add_edge("b", "c")
+g.add_edge("c", "a")
+rand.seed(42)
+first = g.walk("a", 8)
+rand.seed(42)
+second = g.walk("a", 8, weighted: true)
+"#).unwrap();
+    let first = executor.get_variable("first").unwrap();
+    assert_eq!(first, executor.get_variable("second").unwrap());
+    match &first.kind {
+        graphoid::values::ValueKind::List(list) => assert_eq!(list.len(), 9),
+        other => panic!("expected list, got {:?}", other),
+    }
+
+    let err = executor.execute_source(r#"x = g.walk("zz", 3)"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: walk() node 'zz' not found"), "got: {}", err);
+}
+
+// ============================================================================
+// Adjacency List Tests
+// ============================================================================
+
+#[test]
+fn test_adjacency_list_sorts_neighbors_and_keeps_isolated_nodes() {
+    let mut g = Graph::new(GraphType::Directed);
+    for id in ["a", "c", "b", "lonely"] {
+        g.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    g.add_edge("a", "c", "edge".to_string(), Some(2.5), HashMap::new()).unwrap();
+    g.add_edge("a", "b", "edge".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("b", "a", "edge".to_string(), None, HashMap::new()).unwrap();
+
+    let adjacency = g.adjacency_list();
+    let ids: Vec<&str> = adjacency.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "c", "b", "lonely"]);
+    assert_eq!(adjacency[0].1, vec![("b".to_string(), None), ("c".to_string(), Some(2.5))]);
+    assert!(adjacency[1].1.is_empty());
+    assert_eq!(adjacency[2].1, vec![("a".to_string(), None)]);
+    assert!(adjacency[3].1.is_empty());
+}
+
+#[test]
+fn test_adjacency_list_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+d = graph { type: :directed }
+d.add_node("x", 1)
+d.add_node("y", 2)
+d.add_node("z", 3)
+d.add_edge("x", "z")
+d.add_edge("x", "y")
+directed_ok = d.adjacency_list() == {"x": ["y", "z"], "y": [], "z": []}
+
+u = graph { type: :undirected }
+u.add_node("a", 1)
+u.add_node("b", 2)
+u.add_edge("a", "b")
+undirected_ok = u.adjacency_list() == {"a": ["b"], "b": ["a"]}
+weighted_ok = u.adjacency_list(weighted: true) == {"a": [["b", none]], "b": [["a", none]]}
+"#).unwrap();
+
+    for name in ["directed_ok", "undirected_ok", "weighted_ok"] {
+        assert_eq!(executor.get_variable(name).unwrap(), Value::boolean(true), "{}", name);
+    }
+
+    let err = executor.execute_source(r#"x = u.adjacency_list(:all)"#).unwrap_err();
+    assert!(err.to_string().contains("adjacency_list() optional argument must be :weighted"), "got: {}", err);
+}
+
+// ============================================================================
+// Edge List Tests
+// ============================================================================
+
+fn edge(from: &str, to: &str, weight: Option<f64>) -> (String, String, Option<f64>) {
+    (from.to_string(), to.to_string(), weight)
+}
+
+#[test]
+fn test_to_edge_list_lists_undirected_edges_once() {
+    let graph = undirected(&[("a", "b"), ("b", "c"), ("c", "c")], &["lonely"]);
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![edge("a", "b", None), edge("b", "c", None), edge("c", "c", None)]
+    );
+}
+
+#[test]
+fn test_from_edge_list_deduplicates_unless_multigraph() {
+    let edges = vec![edge("a", "b", Some(1.0)), edge("b", "c", None), edge("a", "b", Some(1.0))];
+
+    let simple = Graph::from_edge_list(&edges, GraphType::Directed, false).unwrap();
+    assert_eq!(simple.data_node_ids(), vec!["a", "b", "c"]);
+    assert_eq!(simple.to_edge_list(), vec![edge("a", "b", Some(1.0)), edge("b", "c", None)]);
+
+    let multi = Graph::from_edge_list(&edges, GraphType::Directed, true).unwrap();
+    assert_eq!(multi.to_edge_list().len(), 3);
+
+    // Undirected: b-a is the same edge as a-b
+    let reversed = vec![edge("a", "b", None), edge("b", "a", None)];
+    let undirected = Graph::from_edge_list(&reversed, GraphType::Undirected, false).unwrap();
+    assert_eq!(undirected.to_edge_list(), vec![edge("a", "b", None)]);
+
+    let conflicting = vec![edge("a", "b", Some(1.0)), edge("a", "b", Some(2.0))];
+    let err = Graph::from_edge_list(&conflicting, GraphType::Directed, false).unwrap_err();
+    assert!(err.to_string().contains("ValueError: from_edge_list() entry 1 repeats edge a -> b"), "got: {}", err);
+}
+
+#[test]
+fn test_edge_list_methods_round_trip() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph.from_edge_list([["a", "b", 2], ["b", "c"]])
+nodes_ok = g.nodes() == ["a", "b", "c"]
+weighted_ok = g.to_edge_list() == [["a", "b", 2], ["b", "c", none]]
+
+u = graph.from_edge_list([["x", "y"], ["y", "x"]], directed: false)
+undirected_ok = u.to_edge_list() == [["x", "y"]]
+
+copy = graph.from_edge_list(g.to_edge_list())
+round_trip_ok = copy.to_edge_list() == g.to_edge_list()
+"#).unwrap();
+
+    for name in ["nodes_ok", "weighted_ok", "undirected_ok", "round_trip_ok"] {
+        assert_eq!(executor.get_variable(name).unwrap(), Value::boolean(true), "{}", name);
+    }
+
+    let err = executor.execute_source(r#"bad = graph.from_edge_list([["a", "b"], ["c"]])"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: from_edge_list() entry 1 must be [from, to] or [from, to, weight]"), "got: {}", err);
+}
+
+// ============================================================================
+// Greedy Coloring Tests
+// ============================================================================
+
+fn undirected(edges: &[(&str, &str)], isolated: &[&str]) -> Graph {
+    graph_from_edges(GraphType::Undirected, edges, isolated)
+}
+
+fn graph_from_edges(graph_type: GraphType, edges: &[(&str, &str)], isolated: &[&str]) -> Graph {
+    let mut graph = Graph::new(graph_type);
+    for (from, to) in edges {
+        for id in [from, to] {
+            if !graph.has_node(id) {
+                graph.add_node(id.to_string(), Value::none()).unwrap();
+            }
+        }
+        graph.add_edge(from, to, "edge".to_string(), None, HashMap::new()).unwrap();
+    }
+    for id in isolated {
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    graph
+}
+
+#[test]
+fn test_greedy_coloring_gives_neighbors_different_colors() {
+    let graph = undirected(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("d", "e")], &["lonely"]);
+    let coloring: HashMap<String, usize> = graph.greedy_coloring().into_iter().collect();
+
+    assert_eq!(coloring.len(), 6);
+    for (from, to) in [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("d", "e")] {
+        assert_ne!(coloring[from], coloring[to], "{} and {} share a color", from, to);
+    }
+    // Largest-first: the degree-3 node is colored first
+    assert_eq!(coloring["c"], 0);
+    assert_eq!(coloring["lonely"], 0);
+    assert_eq!(graph.chromatic_number_estimate(), 3);
+}
+
+#[test]
+fn test_greedy_coloring_bipartite_and_empty() {
+    let square = undirected(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a")], &[]);
+    assert_eq!(square.chromatic_number_estimate(), 2);
+    assert_eq!(Graph::new(GraphType::Undirected).chromatic_number_estimate(), 0);
+}
+
+#[test]
+fn test_color_methods_reject_directed_graphs() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :undirected }
+g.add_node("x", 1)
+g.add_node("y", 2)
+g.add_edge("x", "y")
+colors = g.color()
+count = g.chromatic_number_estimate()
+d = graph { type: :directed }
+"#).unwrap();
+    match executor.get_variable("colors").unwrap().kind {
+        graphoid::values::ValueKind::Map(colors) => {
+            assert_eq!(colors.get("x"), Some(&Value::number(0.0)));
+            assert_eq!(colors.get("y"), Some(&Value::number(1.0)));
+        }
+        other => panic!("expected a map, got {:?}", other),
+    }
+    assert_eq!(executor.get_variable("count").unwrap(), Value::number(2.0));
+
+    let err = executor.execute_source("c = d.color()").unwrap_err();
+    assert!(err.to_string().contains("ValueError: color() requires an undirected graph"), "got: {}", err);
+}
+
+// ============================================================================
+// Shape Predicate Tests
+// ============================================================================
+
+#[test]
+fn test_is_tree() {
+    assert!(undirected(&[("a", "b"), ("a", "c"), ("c", "d")], &[]).is_tree());
+    assert!(undirected(&[], &["only"]).is_tree());
+    assert!(!Graph::new(GraphType::Undirected).is_tree());
+    // Right edge count but disconnected (the triangle leaves "x" unreached)
+    assert!(!undirected(&[("a", "b"), ("b", "c"), ("c", "a")], &["x"]).is_tree());
+    // Connected but one edge too many
+    assert!(!undirected(&[("a", "b"), ("b", "c"), ("c", "a")], &[]).is_tree());
+
+    let mut directed = Graph::new(GraphType::Directed);
+    for id in ["a", "b", "c"] {
+        directed.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    directed.add_edge("a", "b", "edge".to_string(), None, HashMap::new()).unwrap();
+    directed.add_edge("b", "a", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(!directed.is_tree());
+}
+
+#[test]
+fn test_is_dag_and_is_bipartite() {
+    let mut graph = Graph::new(GraphType::Directed);
+    for id in ["a", "b", "c"] {
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    graph.add_edge("a", "b", "edge".to_string(), None, HashMap::new()).unwrap();
+    graph.add_edge("a", "c", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(graph.is_dag());
+    assert!(graph.is_bipartite());
+
+    // Odd cycle ignoring direction, but still acyclic
+    graph.add_edge("b", "c", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(graph.is_dag());
+    assert!(!graph.is_bipartite());
+
+    graph.add_edge("c", "a", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(!graph.is_dag());
+
+    let square = undirected(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a")], &["lonely"]);
+    assert!(square.is_bipartite());
+    assert!(!square.is_dag());
+}
+
+#[test]
+fn test_shape_predicate_methods_ignore_rulesets() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("root", 1)
+g.add_node("kid", 2)
+g.add_edge("root", "kid")
+shape = [g.is_tree(), g.is_dag(), g.is_bipartite()]
+"#).unwrap();
+    assert_eq!(executor.get_variable("shape").unwrap().to_string_value(), "[true, true, true]");
+}
+
+#[test]
+fn test_satisfies_ruleset_checks_existing_structure() {
+    let mut graph = Graph::new(GraphType::Directed);
+    for (id, value) in [("root", 5.0), ("lo", 3.0), ("hi", 8.0)] {
+        graph.add_node(id.to_string(), Value::number(value)).unwrap();
+    }
+    graph.add_edge("root", "lo", "left".to_string(), None, HashMap::new()).unwrap();
+    graph.add_edge("root", "hi", "right".to_string(), None, HashMap::new()).unwrap();
+    for ruleset in ["tree", "binary_tree", "bst", "dag"] {
+        assert!(graph.satisfies_ruleset(ruleset).unwrap(), "expected :{}", ruleset);
+    }
+    assert!(!graph.has_ruleset("tree"));
+
+    // A third child breaks the binary shape but not the tree
+    graph.add_node("extra".to_string(), Value::number(9.0)).unwrap();
+    graph.add_edge("root", "extra", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(graph.satisfies_ruleset("tree").unwrap());
+    assert!(!graph.satisfies_ruleset("binary_tree").unwrap());
+
+    // Out-of-order children fail only the BST ordering
+    let mut unordered = Graph::new(GraphType::Directed);
+    for (id, value) in [("root", 5.0), ("lo", 7.0)] {
+        unordered.add_node(id.to_string(), Value::number(value)).unwrap();
+    }
+    unordered.add_edge("root", "lo", "left".to_string(), None, HashMap::new()).unwrap();
+    assert!(unordered.satisfies_ruleset("binary_tree").unwrap());
+    assert!(!unordered.satisfies_ruleset("bst").unwrap());
+
+    // Two roots and a cycle
+    let mut cyclic = Graph::new(GraphType::Directed);
+    for id in ["a", "b", "c"] {
+        cyclic.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    cyclic.add_edge("a", "b", "edge".to_string(), None, HashMap::new()).unwrap();
+    cyclic.add_edge("b", "a", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert!(!cyclic.satisfies_ruleset("dag").unwrap());
+    assert!(!cyclic.satisfies_ruleset("tree").unwrap());
+}
+
+#[test]
+fn test_satisfies_ruleset_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_edge("a", "b")
+checks = [g.satisfies_ruleset?(:tree), g.satisfies_ruleset?(:dag), g.has_ruleset(:tree)]
+g.add_edge("b", "a")
+after = g.satisfies_ruleset?(:dag)
+"#).unwrap();
+    assert_eq!(executor.get_variable("checks").unwrap().to_string_value(), "[true, true, false]");
+    assert_eq!(executor.get_variable("after").unwrap().to_string_value(), "false");
+
+    let err = executor.execute_source("g.satisfies_ruleset?(:forest)").unwrap_err();
+    assert!(err.to_string().contains(
+        "ValueError: unknown ruleset :forest (valid rulesets: :tree, :binary_tree, :bst, :dag)"
+    ));
+}
+
+// ============================================================================
+// Topological Generation Tests
+// ============================================================================
+
+fn directed(edges: &[(&str, &str)], isolated: &[&str]) -> Graph {
+    graph_from_edges(GraphType::Directed, edges, isolated)
+}
+
+#[test]
+fn test_topological_generations_groups_by_depth() {
+    // "d" waits on both "b" (gen 1) and "a" (gen 0), so it lands in gen 2
+    let graph = directed(&[("c", "b"), ("a", "b"), ("b", "d"), ("a", "d")], &["z", "e"]);
+    let generations = graph.topological_generations().unwrap();
+    assert_eq!(generations, vec![
+        vec!["a".to_string(), "c".to_string(), "e".to_string(), "z".to_string()],
+        vec!["b".to_string()],
+        vec!["d".to_string()],
+    ]);
+
+    assert_eq!(Graph::new(GraphType::Directed).topological_generations(), Some(vec![]));
+    assert_eq!(directed(&[("a", "b"), ("b", "c"), ("c", "b")], &[]).topological_generations(), None);
+}
+
+#[test]
+fn test_topological_generations_method() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+for id in ["build", "fetch", "test", "lint", "ship"] {
+    g.add_node(id, none)
+}
+g.add_edge("fetch", "build")
+g.add_edge("build", "test")
+g.add_edge("fetch", "lint")
+g.add_edge("test", "ship")
+g.add_edge("lint", "ship")
+gens = g.topological_generations()
+"#).unwrap();
+    assert_eq!(
+        executor.get_variable("gens").unwrap().to_string_value(),
+        "[[fetch], [build, lint], [test], [ship]]"
+    );
+
+    let err = executor.execute_source("g.add_edge(\"ship\", \"fetch\")\ng.topological_generations()").unwrap_err();
+    assert!(err.to_string().contains("ValueError: topological_generations() requires an acyclic graph"));
+}
@@ -175,3 +175,129 @@ fn test_stats_includes_degree_distribution() {
     // A has the highest degree (2)
     assert_eq!(degree_dist.get("max").unwrap().as_u64().unwrap(), 2);
 }
+
+// ============================================================================
+// Explicit Indexes
+// ============================================================================
+
+fn city_graph() -> Graph {
+    let mut graph = Graph::new(GraphType::Directed);
+    for (id, city) in [("alice", "Boston"), ("bob", "NYC"), ("carol", "Boston")] {
+        let mut props = HashMap::new();
+        props.insert("city".to_string(), Value::string(city.to_string()));
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+        graph.set_node_properties(id, props).unwrap();
+    }
+    graph
+}
+
+fn sorted(mut ids: Vec<String>) -> Vec<String> {
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_create_and_drop_index() {
+    let mut graph = city_graph();
+    assert!(!graph.has_index("city"));
+
+    graph.create_index("city");
+    assert!(graph.has_index("city"));
+    assert_eq!(graph.indexed_properties(), vec!["city".to_string()]);
+    assert_eq!(
+        sorted(graph.nodes_with_property("city", &Value::string("Boston".to_string()))),
+        vec!["alice", "carol"]
+    );
+
+    assert!(graph.drop_index("city"));
+    assert!(!graph.drop_index("city"));
+    assert!(!graph.has_index("city"));
+
+    // Falls back to a scan with the same answer
+    assert_eq!(
+        sorted(graph.nodes_with_property("city", &Value::string("Boston".to_string()))),
+        vec!["alice", "carol"]
+    );
+}
+
+#[test]
+fn test_index_tracks_added_and_removed_nodes() {
+    let mut graph = city_graph();
+    graph.create_index("city");
+
+    let mut props = HashMap::new();
+    props.insert("city".to_string(), Value::string("Boston".to_string()));
+    graph.add_node("dave".to_string(), Value::none()).unwrap();
+    graph.set_node_properties("dave", props).unwrap();
+    graph.remove_node("alice", None).unwrap();
+
+    assert_eq!(
+        sorted(graph.nodes_with_property("city", &Value::string("Boston".to_string()))),
+        vec!["carol", "dave"]
+    );
+}
+
+#[test]
+fn test_index_tracks_changed_properties() {
+    let mut graph = city_graph();
+    graph.create_index("city");
+
+    let mut props = HashMap::new();
+    props.insert("city".to_string(), Value::string("NYC".to_string()));
+    graph.set_node_properties("carol", props).unwrap();
+
+    assert_eq!(graph.nodes_with_property("city", &Value::string("Boston".to_string())), vec!["alice"]);
+    assert_eq!(
+        sorted(graph.nodes_with_property("city", &Value::string("NYC".to_string()))),
+        vec!["bob", "carol"]
+    );
+}
+
+#[test]
+fn test_index_on_map_valued_nodes() {
+    use graphoid::values::Hash;
+
+    let mut graph = Graph::new(GraphType::Directed);
+    let mut index_created_early = graph.clone();
+    index_created_early.create_index("age");
+
+    for (id, age) in [("a", 30.0), ("b", 25.0), ("c", 30.0)] {
+        let mut fields = HashMap::new();
+        fields.insert("age".to_string(), Value::number(age));
+        let value = Value::map(Hash::from_hashmap(fields));
+        graph.add_node(id.to_string(), value.clone()).unwrap();
+        index_created_early.add_node(id.to_string(), value).unwrap();
+    }
+    graph.create_index("age");
+
+    for g in [&graph, &index_created_early] {
+        assert_eq!(sorted(g.nodes_with_property("age", &Value::number(30.0))), vec!["a", "c"]);
+        // Different type with the same display text is not a match
+        assert!(g.nodes_with_property("age", &Value::string("30".to_string())).is_empty());
+    }
+}
+
+#[test]
+fn test_explicit_property_shadows_map_entry() {
+    use graphoid::values::Hash;
+
+    let mut fields = HashMap::new();
+    fields.insert("city".to_string(), Value::string("NYC".to_string()));
+    let mut props = HashMap::new();
+    props.insert("city".to_string(), Value::string("Boston".to_string()));
+
+    let mut graph = Graph::new(GraphType::Directed);
+    graph.add_node("alice".to_string(), Value::map(Hash::from_hashmap(fields.clone()))).unwrap();
+    graph.set_node_properties("alice", props).unwrap();
+    graph.add_node("bob".to_string(), Value::map(Hash::from_hashmap(fields))).unwrap();
+
+    let mut indexed = graph.clone();
+    indexed.create_index("city");
+
+    for g in [&graph, &indexed] {
+        assert_eq!(g.nodes_with_property("city", &Value::string("Boston".to_string())), vec!["alice"]);
+        assert_eq!(g.nodes_with_property("city", &Value::string("NYC".to_string())), vec!["bob"]);
+    }
+    // The auto-indexing lookup follows the same rule
+    assert_eq!(graph.find_nodes_by_property("city", &Value::string("NYC".to_string())), vec!["bob"]);
+}
@@ -1,5 +1,6 @@
 use graphoid::execution::config::{
-    Config, ConfigStack, ErrorMode, BoundsCheckingMode, TypeCoercionMode, NoneHandlingMode, BitWidth, PrecisionMode
+    Config, ConfigStack, ErrorMode, BoundsCheckingMode, TypeCoercionMode, NoneHandlingMode, BitWidth, PrecisionMode,
+    OutputFormat,
 };
 use graphoid::values::Value;
 use std::collections::HashMap;
@@ -12,7 +13,7 @@ fn test_default_config() {
     assert_eq!(config.type_coercion, TypeCoercionMode::Strict);
     assert_eq!(config.none_handling, NoneHandlingMode::Propagate);
     assert_eq!(config.decimal_places, None);
-    assert_eq!(config.strict_types, true);
+    assert_eq!(config.strict_types, false);
     assert_eq!(config.edge_validation, true);
     assert_eq!(config.strict_edge_rules, true);
     assert_eq!(config.none_conversions, true);
@@ -226,12 +227,12 @@ fn test_multiple_config_keys() {
     let mut changes = HashMap::new();
     changes.insert("skip_none".to_string(), Value::boolean(true));
     changes.insert("error_mode".to_string(), Value::symbol("lenient".to_string()));
-    changes.insert("strict_types".to_string(), Value::boolean(false));
+    changes.insert("strict_types".to_string(), Value::boolean(true));
 
     stack.push_with_changes(changes).unwrap();
     assert_eq!(stack.current().skip_none, true);
     assert_eq!(stack.current().error_mode, ErrorMode::Lenient);
-    assert_eq!(stack.current().strict_types, false);
+    assert_eq!(stack.current().strict_types, true);
 }
 
 // ===== Phase 1A: :integer Directive Tests =====
@@ -453,3 +454,37 @@ fn test_nested_32bit_and_64bit_modes() {
     assert_eq!(stack.current().bit_width, BitWidth::Bits64);
     assert_eq!(stack.depth(), 1);
 }
+
+#[test]
+fn test_output_format_config() {
+    let mut stack = ConfigStack::new();
+    assert_eq!(stack.current().output_format, OutputFormat::Text);
+
+    let mut changes = HashMap::new();
+    changes.insert("output".to_string(), Value::symbol("json".to_string()));
+    stack.push_with_changes(changes).unwrap();
+    assert_eq!(stack.current().output_format, OutputFormat::Json);
+
+    let mut invalid = HashMap::new();
+    invalid.insert("output".to_string(), Value::symbol("xml".to_string()));
+    assert!(stack.push_with_changes(invalid).is_err());
+}
+
+#[test]
+fn test_display_precision_config() {
+    let mut stack = ConfigStack::new();
+    assert_eq!(stack.current().display_precision, None);
+
+    let mut changes = HashMap::new();
+    changes.insert("display_precision".to_string(), Value::number(4.0));
+    stack.push_with_changes(changes).unwrap();
+    assert_eq!(stack.current().display_precision, Some(4));
+    stack.pop();
+    assert_eq!(stack.current().display_precision, None);
+
+    for invalid in [Value::number(1.5), Value::string("4".to_string())] {
+        let mut changes = HashMap::new();
+        changes.insert("display_precision".to_string(), invalid);
+        assert!(stack.push_with_changes(changes).is_err());
+    }
+}
@@ -4,6 +4,7 @@
 //! The original bug was that the error type parser was finding the first colon in
 //! "Runtime error: ValueError: message" instead of the user error type.
 
+use graphoid::error::GraphoidError;
 use graphoid::execution::Executor;
 use graphoid::values::Value;
 
@@ -377,3 +378,364 @@ catch RuntimeError as e {
 // ============================================================================
 // Total: 16 exception handling tests specifically for the user error type fix
 // ============================================================================
+
+// ============================================================================
+// ASSERT STATEMENT
+// `assert condition, "message"` raises a catchable AssertionError
+// ============================================================================
+
+#[test]
+fn test_assert_truthy_is_noop() {
+    let source = r#"
+x = 5
+assert x > 0, "x must be positive"
+assert x
+after = true
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("after").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_assert_falsy_raises_catchable_assertion_error() {
+    let source = r#"
+x = 5
+error_type = ""
+message = ""
+line = 0
+try {
+    assert x < 0, "x must be negative"
+}
+catch AssertionError as e {
+    error_type = e.type()
+    message = e.message()
+    line = e.line()
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("error_type").unwrap(), Value::string("AssertionError".to_string()));
+    assert_eq!(executor.get_variable("message").unwrap(), Value::string("x must be negative".to_string()));
+    assert_eq!(executor.get_variable("line").unwrap(), Value::number(7.0));
+}
+
+#[test]
+fn test_assert_without_message_reports_expression() {
+    let source = r#"
+items = [1, 2]
+message = ""
+try {
+    assert items.length() >= 3
+}
+catch as e {
+    message = e.message()
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(
+        executor.get_variable("message").unwrap(),
+        Value::string("Assertion failed: items.length() >= 3".to_string())
+    );
+}
+
+#[test]
+fn test_uncaught_assert_reports_message_and_position() {
+    let source = r#"
+x = 1
+assert x == 2, "x should be 2"
+"#;
+    let mut executor = Executor::new();
+    let err = executor.execute_source(source).unwrap_err().to_string();
+
+    assert!(err.ends_with("AssertionError: x should be 2"), "got: {}", err);
+    assert_eq!(executor.uncaught_error_position().map(|p| p.line), Some(3));
+}
+
+#[test]
+fn test_assert_condition_may_start_with_bang_or_paren() {
+    let source = r#"
+flag = false
+assert !flag
+assert (1 == 1), "parenthesized condition"
+assert (1 == 1)
+caught = none
+try {
+    assert (1 == 2), "one is not two"
+}
+catch AssertionError as e {
+    caught = e.message()
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("caught").unwrap(), Value::string("one is not two".to_string()));
+}
+
+#[test]
+fn test_assertions_disabled_skip_evaluation() {
+    let source = r#"
+configure { assertions: false }
+assert undefined_function(), "never evaluated"
+assert false
+after = true
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("after").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_assertions_disabled_only_inside_configure_block() {
+    let source = r#"
+configure { assertions: false } {
+    assert false
+}
+caught = false
+try {
+    assert false
+}
+catch AssertionError {
+    caught = true
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("caught").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_assert_call_still_reaches_user_function() {
+    // gspec defines `assert(result)`; the parenthesized form stays a call
+    let source = r#"
+seen = none
+fn assert(value) {
+    return value
+}
+seen = assert(42)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("seen").unwrap(), Value::number(42.0));
+}
+
+// ============================================================================
+// ERROR PROPERTY ACCESS TESTS
+// ============================================================================
+
+#[test]
+fn test_caught_error_exposes_fields_as_properties() {
+    let source = r#"
+kind = none
+message = none
+line = none
+cause = 0
+frames = none
+fn fail() {
+    raise ValueError("bad input")
+}
+try {
+    fail()
+}
+catch as e {
+    kind = e.type
+    message = e.message
+    line = e.line
+    cause = e.cause
+    frames = e.stack_trace
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("kind").unwrap(), Value::string("ValueError".to_string()));
+    assert_eq!(executor.get_variable("message").unwrap(), Value::string("bad input".to_string()));
+    assert_eq!(executor.get_variable("line").unwrap(), Value::number(8.0));
+    assert_eq!(executor.get_variable("cause").unwrap(), Value::none());
+    assert_eq!(
+        executor.get_variable("frames").unwrap().to_string_value(),
+        "[fail]"
+    );
+}
+
+#[test]
+fn test_error_stack_trace_property_empty_when_unset() {
+    let source = r#"
+frames = none
+cause_msg = none
+try {
+    raise RuntimeError("top level")
+}
+catch as e {
+    frames = e.stack_trace
+    cause_msg = e.caused_by(ValueError("root")).cause.message
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("frames").unwrap().to_string_value(), "[]");
+    assert_eq!(executor.get_variable("cause_msg").unwrap(), Value::string("root".to_string()));
+}
+
+#[test]
+fn test_unknown_error_property_is_reported() {
+    let source = r#"
+e = ValueError("x")
+v = e.severity
+"#;
+    let mut executor = Executor::new();
+    let err = executor.execute_source(source).unwrap_err().to_string();
+    assert!(err.contains("Error does not have property 'severity'"), "got: {}", err);
+}
+
+// ============================================================================
+// ERROR CHAINING TESTS
+// ============================================================================
+
+#[test]
+fn test_raise_caused_by_chains_original_error() {
+    let source = r#"
+outer_type = none
+cause_type = none
+cause_message = none
+try {
+    try {
+        raise IOError("disk full")
+    }
+    catch as e {
+        raise RuntimeError("could not save") caused_by e
+    }
+}
+catch RuntimeError as err {
+    outer_type = err.type
+    cause_type = err.cause.type
+    cause_message = err.cause.message
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("outer_type").unwrap(), Value::string("RuntimeError".to_string()));
+    assert_eq!(executor.get_variable("cause_type").unwrap(), Value::string("IOError".to_string()));
+    assert_eq!(executor.get_variable("cause_message").unwrap(), Value::string("disk full".to_string()));
+}
+
+#[test]
+fn test_reraised_error_keeps_its_cause() {
+    let source = r#"
+cause_message = none
+try {
+    try {
+        raise ValueError("bad") caused_by TypeError("root")
+    }
+    catch as e {
+        raise e
+    }
+}
+catch as err {
+    cause_message = err.cause.message
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("cause_message").unwrap(), Value::string("root".to_string()));
+}
+
+#[test]
+fn test_uncaught_error_cause_is_available_for_reporting() {
+    let source = r#"
+raise RuntimeError("startup failed") caused_by IOError("missing file")
+"#;
+    let mut executor = Executor::new();
+    assert!(executor.execute_source(source).is_err());
+
+    let cause = executor.uncaught_error_cause().expect("cause should be recorded");
+    assert!(cause.full_chain().starts_with("IOError: missing file"));
+}
+
+// ============================================================================
+// ABORT TESTS
+// ============================================================================
+
+#[test]
+fn test_abort_is_not_caught_but_runs_finally() {
+    let source = r#"
+cleaned_up = false
+caught = false
+fn check(x) {
+    if x < 0 {
+        abort("invariant violated")
+    }
+}
+try {
+    check(-1)
+}
+catch {
+    caught = true
+}
+finally {
+    cleaned_up = true
+}
+"#;
+    let mut executor = Executor::new();
+    let err = executor.execute_source(source).unwrap_err();
+
+    assert!(matches!(err, GraphoidError::Abort { .. }), "got: {:?}", err);
+    assert_eq!(err.to_string(), "Aborted: invariant violated");
+    if let GraphoidError::Abort { stack_trace, .. } = err {
+        assert_eq!(stack_trace, vec!["check".to_string()]);
+    }
+    assert_eq!(executor.get_variable("caught").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("cleaned_up").unwrap(), Value::boolean(true));
+}
+
+#[test]
+fn test_abort_passes_through_typed_catch() {
+    let source = r#"
+try {
+    abort()
+}
+catch RuntimeError {
+    handled = true
+}
+"#;
+    let mut executor = Executor::new();
+    let err = executor.execute_source(source).unwrap_err();
+    assert_eq!(err.to_string(), "Aborted: abort() called");
+}
+
+// ============================================================================
+// JSON ERROR OUTPUT TESTS
+// ============================================================================
+
+#[test]
+fn test_error_to_json_reports_user_error_type() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source("x = to_int(\"abc\")").unwrap_err();
+    let json = err.to_json();
+    assert_eq!(json["error"]["type"], "ValueError");
+    assert_eq!(json["error"]["message"], "cannot convert 'abc' to int");
+    assert!(json["error"]["position"].is_null());
+}
+
+#[test]
+fn test_error_to_json_includes_syntax_position() {
+    let err = GraphoidError::SyntaxError {
+        message: "Unexpected token".to_string(),
+        position: graphoid::error::SourcePosition { line: 2, column: 5, file: None },
+    };
+    let json = err.to_json();
+    assert_eq!(json["error"]["type"], "SyntaxError");
+    assert_eq!(json["error"]["position"]["line"], 2);
+    assert_eq!(json["error"]["position"]["column"], 5);
+}
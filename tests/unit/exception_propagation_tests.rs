@@ -22,6 +22,7 @@ fn make_test_function(name: &str) -> Function {
         is_setter: false,
         is_static: false,
         guard: None,
+        synthetic: None,
     }
 }
 
@@ -1,4 +1,4 @@
-use graphoid::ast::{Argument, AssignmentTarget, BinaryOp, Expr, LiteralValue, Parameter, Stmt, UnaryOp};
+use graphoid::ast::{Argument, AssignmentTarget, BinaryOp, Expr, LiteralValue, MapKey, Parameter, Stmt, UnaryOp};
 use graphoid::error::SourcePosition;
 use graphoid::execution::{Executor, ErrorMode};
 use graphoid::values::{BigNum, Hash, List, Value, ValueKind};
@@ -768,14 +768,14 @@ fn test_eval_map_with_entries() {
     let expr = Expr::Map {
         entries: vec![
             (
-                "name".to_string(),
+                MapKey::Static("name".to_string()),
                 Expr::Literal {
                     value: LiteralValue::String("Alice".to_string()),
                     position: pos(),
                 },
             ),
             (
-                "age".to_string(),
+                MapKey::Static("age".to_string()),
                 Expr::Literal {
                     value: LiteralValue::Number(30.0),
                     position: pos(),
@@ -799,6 +799,31 @@ fn test_eval_map_with_entries() {
     }
 }
 
+#[test]
+fn test_eval_map_computed_keys() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+field = "user"
+m = {[field + "_id"]: 7, name: "static", [:status]: "ok", [2 + 3]: "five", [true]: "yes", "z": 1}
+keys = m.keys()
+five = m[5]
+has_five = m.has_key(5)
+has_string_five = m.has_key("5")
+status = m[:status]
+"#).unwrap();
+    assert_eq!(
+        executor.get_variable("keys").unwrap().to_string_value(),
+        "[user_id, name, :status, 5, true, z]"
+    );
+    assert_eq!(executor.get_variable("five").unwrap(), Value::string("five".to_string()));
+    assert_eq!(executor.get_variable("has_five").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("has_string_five").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("status").unwrap(), Value::string("ok".to_string()));
+
+    let err = executor.execute_source("f = (x) => x\nbad = {[f]: 1}").unwrap_err();
+    assert!(err.to_string().contains("Type error: expected hashable map key, got function"), "got: {}", err);
+}
+
 // ============================================================================
 // STRING OPERATIONS
 // ============================================================================
@@ -957,6 +982,48 @@ fn test_eval_type_error_subtract_strings() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_can_add_and_can_compare_predicates() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+text_num = can_add?("n=", 5)
+num_text = can_add?(5, "x")
+nums = can_add?(1, 2.5)
+list_num = can_add?([1], 2)
+none_num = can_add?(none, 1)
+cmp_nums = can_compare?(1, 2)
+cmp_strings = can_compare?("a", "b")
+cmp_mixed = can_compare?("a", 1)
+cmp_bools = can_compare?(true, false)
+"#).unwrap();
+
+    for (name, expected) in [
+        ("text_num", true), ("num_text", true), ("nums", true), ("list_num", false), ("none_num", false),
+        ("cmp_nums", true), ("cmp_strings", true), ("cmp_mixed", false), ("cmp_bools", false),
+    ] {
+        assert_eq!(executor.get_variable(name).unwrap(), Value::boolean(expected), "{}", name);
+    }
+
+    let err = executor.execute_source("can_add?(1)").unwrap_err();
+    assert!(err.to_string().contains("can_add?() expects 2 arguments, but got 1"), "got: {}", err);
+}
+
+#[test]
+fn test_can_add_and_can_compare_agree_with_operators() {
+    let operands = ["1", "2.5", "\"a\"", "true", "none", "[1]", "{}", ":sym"];
+    for left in operands {
+        for right in operands {
+            for (predicate, op) in [("can_add?", "+"), ("can_compare?", "<"), ("can_compare?", ">=")] {
+                let mut executor = Executor::new();
+                executor.execute_source(&format!("ok = {}({}, {})", predicate, left, right)).unwrap();
+                let predicted = executor.get_variable("ok").unwrap() == Value::boolean(true);
+                let ran = executor.execute_source(&format!("r = {} {} {}", left, op, right)).is_ok();
+                assert_eq!(predicted, ran, "{} {} {}", left, op, right);
+            }
+        }
+    }
+}
+
 #[test]
 fn test_eval_type_error_multiply_string_and_number() {
     let mut executor = Executor::new();
@@ -1534,7 +1601,7 @@ fn test_function_return_none() {
 }
 
 #[test]
-fn test_function_wrong_arg_count() {
+fn test_function_too_few_args_curries() {
     let mut executor = Executor::new();
 
     // Define: func add(a, b) { return a + b }
@@ -1578,7 +1645,7 @@ fn test_function_wrong_arg_count() {
 
     executor.eval_stmt(&func_decl).unwrap();
 
-    // Call with wrong number of arguments: add(2)
+    // Call with too few arguments: add(2) curries instead of running the body
     let call_expr = Expr::Call {
         callee: Box::new(Expr::Variable {
             name: "add".to_string(),
@@ -1591,8 +1658,8 @@ fn test_function_wrong_arg_count() {
         position: pos(),
     };
 
-    let result = executor.eval_expr(&call_expr);
-    assert!(result.is_err());
+    let result = executor.eval_expr(&call_expr).unwrap();
+    assert!(matches!(result.kind, ValueKind::Function(_)));
 }
 
 #[test]
@@ -4059,6 +4126,7 @@ fn test_for_loop_simple() {
 
     let for_stmt = Stmt::For {
         variable: "i".to_string(),
+        unpack: vec![],
         iterable: Expr::List {
             elements: vec![
                 Expr::Literal {
@@ -4123,6 +4191,7 @@ fn test_for_loop_empty_list() {
 
     let for_stmt = Stmt::For {
         variable: "i".to_string(),
+        unpack: vec![],
         iterable: Expr::List {
             elements: vec![],
             position: pos(),
@@ -4174,6 +4243,7 @@ fn test_for_loop_with_strings() {
 
     let for_stmt = Stmt::For {
         variable: "s".to_string(),
+        unpack: vec![],
         iterable: Expr::List {
             elements: vec![
                 Expr::Literal {
@@ -4249,6 +4319,7 @@ fn test_for_loop_in_function() {
             },
             Stmt::For {
                 variable: "n".to_string(),
+                unpack: vec![],
                 iterable: Expr::Variable {
                     name: "numbers".to_string(),
                     position: pos(),
@@ -4344,6 +4415,7 @@ fn test_nested_for_loops() {
 
     let inner_for = Stmt::For {
         variable: "j".to_string(),
+        unpack: vec![],
         iterable: Expr::List {
             elements: vec![
                 Expr::Literal {
@@ -4382,6 +4454,7 @@ fn test_nested_for_loops() {
 
     let outer_for = Stmt::For {
         variable: "i".to_string(),
+        unpack: vec![],
         iterable: Expr::List {
             elements: vec![
                 Expr::Literal {
@@ -4578,8 +4651,8 @@ fn test_map_index_string_key() {
         target: AssignmentTarget::Variable("config".to_string()),
         value: Expr::Map {
             entries: vec![
-                ("name".to_string(), Expr::Literal { value: LiteralValue::String("Alice".to_string()), position: pos() }),
-                ("age".to_string(), Expr::Literal { value: LiteralValue::Number(30.0), position: pos() }),
+                (MapKey::Static("name".to_string()), Expr::Literal { value: LiteralValue::String("Alice".to_string()), position: pos() }),
+                (MapKey::Static("age".to_string()), Expr::Literal { value: LiteralValue::Number(30.0), position: pos() }),
             ],
             position: pos(),
         },
@@ -4615,7 +4688,7 @@ fn test_map_index_missing_key() {
         target: AssignmentTarget::Variable("config".to_string()),
         value: Expr::Map {
             entries: vec![
-                ("name".to_string(), Expr::Literal { value: LiteralValue::String("Alice".to_string()), position: pos() }),
+                (MapKey::Static("name".to_string()), Expr::Literal { value: LiteralValue::String("Alice".to_string()), position: pos() }),
             ],
             position: pos(),
         },
@@ -5719,11 +5792,11 @@ fn test_map_method_keys() {
         value: Expr::Map {
             entries: vec![
                 (
-                    "name".to_string(),
+                    MapKey::Static("name".to_string()),
                     Expr::Literal { value: LiteralValue::String("Alice".to_string()), position: pos() },
                 ),
                 (
-                    "age".to_string(),
+                    MapKey::Static("age".to_string()),
                     Expr::Literal { value: LiteralValue::Number(25.0), position: pos() },
                 ),
             ],
@@ -5770,11 +5843,11 @@ fn test_map_method_values() {
         value: Expr::Map {
             entries: vec![
                 (
-                    "x".to_string(),
+                    MapKey::Static("x".to_string()),
                     Expr::Literal { value: LiteralValue::Number(10.0), position: pos() },
                 ),
                 (
-                    "y".to_string(),
+                    MapKey::Static("y".to_string()),
                     Expr::Literal { value: LiteralValue::Number(20.0), position: pos() },
                 ),
             ],
@@ -5821,11 +5894,11 @@ fn test_map_method_has_key() {
         value: Expr::Map {
             entries: vec![
                 (
-                    "name".to_string(),
+                    MapKey::Static("name".to_string()),
                     Expr::Literal { value: LiteralValue::String("Bob".to_string()), position: pos() },
                 ),
                 (
-                    "age".to_string(),
+                    MapKey::Static("age".to_string()),
                     Expr::Literal { value: LiteralValue::Number(30.0), position: pos() },
                 ),
             ],
@@ -5893,15 +5966,15 @@ fn test_map_method_size() {
         value: Expr::Map {
             entries: vec![
                 (
-                    "a".to_string(),
+                    MapKey::Static("a".to_string()),
                     Expr::Literal { value: LiteralValue::Number(1.0), position: pos() },
                 ),
                 (
-                    "b".to_string(),
+                    MapKey::Static("b".to_string()),
                     Expr::Literal { value: LiteralValue::Number(2.0), position: pos() },
                 ),
                 (
-                    "c".to_string(),
+                    MapKey::Static("c".to_string()),
                     Expr::Literal { value: LiteralValue::Number(3.0), position: pos() },
                 ),
             ],
@@ -5965,7 +6038,7 @@ x = 1
 fn test_execute_nested_configure() {
     let source = r#"
 configure { skip_none: true } {
-    configure { strict_types: false } {
+    configure { strict_types: true } {
         z = 3
     }
 }
@@ -5975,7 +6048,7 @@ configure { skip_none: true } {
 
     // After execution, should be back to defaults
     assert_eq!(executor.config_stack.current().skip_none, false);
-    assert_eq!(executor.config_stack.current().strict_types, true);
+    assert!(!executor.config_stack.current().strict_types);
 }
 
 #[test]
@@ -6055,7 +6128,7 @@ fn test_configure_multiple_settings() {
 configure {
     skip_none: true,
     error_mode: :strict,
-    strict_types: false
+    strict_types: true
 }
 "#;
     let mut executor = Executor::new();
@@ -6063,7 +6136,7 @@ configure {
 
     assert_eq!(executor.config_stack.current().skip_none, true);
     assert_eq!(executor.config_stack.current().error_mode, ErrorMode::Strict);
-    assert_eq!(executor.config_stack.current().strict_types, false);
+    assert!(executor.config_stack.current().strict_types);
 }
 
 #[test]
@@ -6128,6 +6201,80 @@ fn test_configure_decimal_places() {
     assert_eq!(executor.config_stack.current().decimal_places, Some(3));
 }
 
+#[test]
+fn test_configure_display_precision_formats_floats_only() {
+    let source = r#"
+x = 2 / 3
+configure { display_precision: 3 } {
+    shown = x.to_string()
+    whole = (10 / 2).to_string()
+    listed = [1.23456, 2, 0.5].to_string()
+    tiny = (0 - 0.00001).to_string()
+    unchanged = x == 2 / 3
+}
+after = x.to_string()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("shown").unwrap(), Value::string("0.667".to_string()));
+    assert_eq!(executor.get_variable("whole").unwrap(), Value::string("5".to_string()));
+    assert_eq!(executor.get_variable("listed").unwrap(), Value::string("[1.235, 2, 0.5]".to_string()));
+    assert_eq!(executor.get_variable("tiny").unwrap(), Value::string("0".to_string()));
+    assert_eq!(executor.get_variable("unchanged").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("after").unwrap(), Value::string((2.0_f64 / 3.0).to_string()));
+    assert_eq!(executor.config_stack.current().display_precision, None);
+}
+
+#[test]
+fn test_display_precision_nests_with_precision_blocks() {
+    let source = r#"
+configure { display_precision: 4 }
+precision 2 {
+    configure { display_precision: 1 } {
+        inner = (1 / 3).to_string()
+    }
+    outer = (1 / 3).to_string()
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("inner").unwrap(), Value::string("0.3".to_string()));
+    // `precision 2` rounds 1 / 3 to 0.33 before display_precision formats it
+    assert_eq!(executor.get_variable("outer").unwrap(), Value::string("0.33".to_string()));
+    assert_eq!(Value::number(1.0 / 3.0).to_string_value(), "0.3333");
+    assert!(executor.precision_stack.is_empty());
+
+    let err = executor.execute_source("configure { display_precision: -1 }").unwrap_err();
+    assert!(err.to_string().contains("display_precision must be a non-negative whole number"), "got: {}", err);
+}
+
+#[test]
+fn test_display_precision_does_not_merge_number_keys() {
+    let source = r#"
+fn half(x) {
+    return x / 2
+}
+m = memoize(half)
+configure { display_precision: 2 } {
+    k = {}
+    k[0.001] = "a"
+    k[0.002] = "b"
+    count = k.keys().length()
+    first = k[0.001]
+    halves = [m(0.001), m(0.002)]
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("count").unwrap(), Value::number(2.0));
+    assert_eq!(executor.get_variable("first").unwrap(), Value::string("a".to_string()));
+    let halves = executor.get_variable("halves").unwrap();
+    assert_eq!(halves, Value::list(List::from_vec(vec![Value::number(0.0005), Value::number(0.001)])));
+}
+
 #[test]
 fn test_configure_edge_validation() {
     let source = "configure { edge_validation: false }";
@@ -6160,7 +6307,7 @@ fn test_deeply_nested_configure() {
     let source = r#"
 configure { skip_none: true } {
     configure { error_mode: :lenient } {
-        configure { strict_types: false } {
+        configure { strict_types: true } {
             x = 1
         }
     }
@@ -6172,7 +6319,7 @@ configure { skip_none: true } {
     // All configs should be popped, back to defaults
     assert_eq!(executor.config_stack.current().skip_none, false);
     assert_eq!(executor.config_stack.current().error_mode, ErrorMode::Strict);
-    assert_eq!(executor.config_stack.current().strict_types, true);
+    assert!(!executor.config_stack.current().strict_types);
 }
 
 #[test]
@@ -6180,7 +6327,7 @@ fn test_precision_and_configure_complex_nesting() {
     let source = r#"
 configure { skip_none: true } {
     precision 2 {
-        configure { strict_types: false } {
+        configure { strict_types: true } {
             precision 0 {
                 y = 5
             }
@@ -6193,7 +6340,7 @@ configure { skip_none: true } {
 
     // Everything should be restored
     assert_eq!(executor.config_stack.current().skip_none, false);
-    assert_eq!(executor.config_stack.current().strict_types, true);
+    assert!(!executor.config_stack.current().strict_types);
     assert!(executor.precision_stack.is_empty());
 }
 
@@ -6215,6 +6362,79 @@ configure { skip_none: true } {
     assert_eq!(x, Value::number(10.0));
 }
 
+#[test]
+fn test_type_annotations_not_enforced_by_default() {
+    let source = r#"
+num x = 10
+x = "ten"
+list<num> scores = [1, 2]
+scores.append!("A")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+    assert_eq!(executor.get_variable("x").unwrap(), Value::string("ten".to_string()));
+}
+
+#[test]
+fn test_strict_types_rejects_reassignment_with_other_type() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+configure { strict_types: true }
+num x = 10
+x = 11
+string name = "a"
+name = "b"
+untyped = 1
+untyped = "anything"
+"#).unwrap();
+    assert_eq!(executor.get_variable("x").unwrap(), Value::number(11.0));
+
+    let err = executor.execute_source(r#"x = "ten""#).unwrap_err();
+    assert!(err.to_string().contains("TypeError: cannot assign string to 'x' declared as num"), "got: {}", err);
+    assert_eq!(executor.get_variable("x").unwrap(), Value::number(11.0));
+
+    let err = executor.execute_source(r#"bool flag = 1"#).unwrap_err();
+    assert!(err.to_string().contains("TypeError: cannot assign num to 'flag' declared as bool"), "got: {}", err);
+}
+
+#[test]
+fn test_strict_types_checks_collection_element_constraints() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+configure { strict_types: true }
+list<num> scores = [95, 87]
+scores.append!(92)
+scores[0] = 96
+map<string> names = {"a": "Ada"}
+names["b"] = "Bob"
+"#).unwrap();
+
+    for source in [
+        r#"scores.append!("A")"#,
+        r#"scores[1] = "B""#,
+        r#"names["c"] = 3"#,
+        r#"list<num> mixed = [1, "two"]"#,
+    ] {
+        let err = executor.execute_source(source).unwrap_err();
+        assert!(err.to_string().contains("TypeError: cannot store"), "{}: got {}", source, err);
+    }
+}
+
+#[test]
+fn test_strict_types_scoped_to_configure_block() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"
+num x = 1
+configure { strict_types: true } {
+    x = "one"
+}
+"#).unwrap_err();
+    assert!(err.to_string().contains("TypeError: cannot assign string to 'x' declared as num"), "got: {}", err);
+
+    // Outside the block the annotation is documentation again
+    executor.execute_source(r#"x = "one""#).unwrap();
+}
+
 #[test]
 fn test_precision_with_arithmetic() {
     let source = r#"
@@ -6230,10 +6450,73 @@ precision 1 {
         name: "result".to_string(),
         position: pos(),
     }).unwrap();
-    // Result should be calculated (precision will be applied in future milestones)
+    // Result is rounded to 1 decimal place
     assert_eq!(result, Value::number(6.2));
 }
 
+#[test]
+fn test_precision_rounds_division_and_restores() {
+    let source = r#"
+before = 1 / 3
+precision 2 {
+    third = 1 / 3
+    product = 0.125 * 3
+    precision 4 {
+        inner = 2 / 3
+    }
+    after_inner = 2 / 3
+}
+after = 2 / 3
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("before").unwrap(), Value::number(1.0 / 3.0));
+    assert_eq!(executor.get_variable("third").unwrap(), Value::number(0.33));
+    assert_eq!(executor.get_variable("product").unwrap(), Value::number(0.38));
+    assert_eq!(executor.get_variable("inner").unwrap(), Value::number(0.6667));
+    assert_eq!(executor.get_variable("after_inner").unwrap(), Value::number(0.67));
+    assert_eq!(executor.get_variable("after").unwrap(), Value::number(2.0 / 3.0));
+}
+
+#[test]
+fn test_precision_int_truncates_division() {
+    let source = r#"
+precision :int {
+    a = 7 / 2
+    b = (0 - 7) / 2
+    c = 2.5 * 3
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("a").unwrap(), Value::number(3.0));
+    assert_eq!(executor.get_variable("b").unwrap(), Value::number(-3.0));
+    assert_eq!(executor.get_variable("c").unwrap(), Value::number(7.0));
+}
+
+#[test]
+fn test_current_precision() {
+    let source = r#"
+outside = current_precision()
+precision 3 {
+    three = current_precision()
+    precision :int {
+        int_mode = current_precision()
+    }
+    restored = current_precision()
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("outside").unwrap(), Value::none());
+    assert_eq!(executor.get_variable("three").unwrap(), Value::number(3.0));
+    assert_eq!(executor.get_variable("int_mode").unwrap(), Value::number(0.0));
+    assert_eq!(executor.get_variable("restored").unwrap(), Value::number(3.0));
+}
+
 // ============================================================================
 // Total: 23 configuration and precision execution tests
 // ============================================================================
@@ -9076,3 +9359,193 @@ configure { precision: :high } {
 // ============================================================================
 // Total: 4 BigInt conversion tests
 // ============================================================================
+
+// ============================================================================
+// STRING FORMAT OPERATOR (%) TESTS
+// ============================================================================
+
+#[test]
+fn test_string_percent_format_conversions() {
+    let source = r#"
+summary = "%s scored %.2f (%d%%)" % ["Ann", 91.456, 91.7]
+padded = "[%5s|%-5s|%05d]" % ["ab", "cd", -42]
+single = "hello %s" % "world"
+remainder = 10 % 3
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("summary").unwrap(), Value::string("Ann scored 91.46 (91%)".to_string()));
+    assert_eq!(executor.get_variable("padded").unwrap(), Value::string("[   ab|cd   |-0042]".to_string()));
+    assert_eq!(executor.get_variable("single").unwrap(), Value::string("hello world".to_string()));
+    assert_eq!(executor.get_variable("remainder").unwrap(), Value::number(1.0));
+}
+
+#[test]
+fn test_string_percent_format_argument_count_mismatch() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"x = "%s and %s" % ["one"]"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: not enough arguments for format string"), "got: {}", err);
+
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"x = "%d" % [1, 2]"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError: too many arguments for format string"), "got: {}", err);
+
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"x = "%d" % ["seven"]"#).unwrap_err();
+    assert!(err.to_string().contains("TypeError: %d requires a number, got string"), "got: {}", err);
+}
+
+// ============================================================================
+// ITERATION HELPER (range / enumerate / zip / line_stream) TESTS
+// ============================================================================
+
+#[test]
+fn test_iteration_helpers_as_lists() {
+    let source = r#"
+up = range(4)
+down = range(10, 0, -3)
+pairs = enumerate(["a", "b"])
+zipped = zip([1, 2, 3], "xy")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("up").unwrap().to_string_value(), "[0, 1, 2, 3]");
+    assert_eq!(executor.get_variable("down").unwrap().to_string_value(), "[10, 7, 4, 1]");
+    assert_eq!(executor.get_variable("pairs").unwrap().to_string_value(), "[[0, a], [1, b]]");
+    assert_eq!(executor.get_variable("zipped").unwrap().to_string_value(), "[[1, x], [2, y]]");
+}
+
+#[test]
+fn test_for_loop_unpacks_iteration_helpers() {
+    let source = r#"
+labels = []
+for i, name in enumerate(["ann", "bob"]) {
+    labels = labels.append(name + i.to_string())
+}
+total = 0
+for a, b in zip([1, 2, 3], [10, 20, 30]) {
+    total = total + a * b
+}
+seen = []
+for n in range(0, 1000000) {
+    if n == 3 { break }
+    seen = seen.append(n)
+}
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("labels").unwrap().to_string_value(), "[ann0, bob1]");
+    assert_eq!(executor.get_variable("total").unwrap(), Value::number(140.0));
+    assert_eq!(executor.get_variable("seen").unwrap().to_string_value(), "[0, 1, 2]");
+}
+
+#[test]
+fn test_range_zero_step_and_unpack_mismatch_errors() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source("for n in range(1, 5, 0) { print(n) }").unwrap_err();
+    assert!(err.to_string().contains("ValueError: range() step cannot be zero"), "got: {}", err);
+
+    let mut executor = Executor::new();
+    let err = executor.execute_source("for a, b in [[1, 2, 3]] { print(a) }").unwrap_err();
+    assert!(err.to_string().contains("ValueError: for loop expected 2 values to unpack, got 3"), "got: {}", err);
+}
+
+#[test]
+fn test_user_function_shadows_range() {
+    let source = r#"
+fn range(a, b) { return "mine" }
+result = range(1, 2)
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+    assert_eq!(executor.get_variable("result").unwrap(), Value::string("mine".to_string()));
+}
+
+#[test]
+fn test_line_stream_and_read_lines_block() {
+    let path = std::env::temp_dir().join("graphoid_test_line_stream.txt");
+    std::fs::write(&path, "alpha\r\nbeta\ngamma\ndelta").unwrap();
+    let source = format!(r#"
+import "io"
+path = "{}"
+misuse = try {{ line_stream(path) }} catch as e {{ e.message() }}
+firsts = []
+for line in line_stream(path) {{
+    if line == "gamma" {{ break }}
+    firsts = firsts.append(line)
+}}
+result = io.read_lines(path, line => {{
+    if line == "delta" {{ break }}
+    print(line.upper())
+}})
+"#, path.display());
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(&source).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    let misuse = executor.get_variable("misuse").unwrap().to_string_value();
+    assert!(misuse.contains("line_stream() can only be used as a for loop iterable"), "got: {}", misuse);
+    assert_eq!(executor.get_variable("firsts").unwrap().to_string_value(), "[alpha, beta]");
+    assert_eq!(executor.get_captured_output(), "ALPHA\nBETA\nGAMMA\n");
+    assert_eq!(executor.get_variable("result").unwrap(), Value::none());
+}
+
+#[test]
+fn test_line_stream_read_error_is_catchable_io_error() {
+    let path = std::env::temp_dir().join("graphoid_test_line_stream_bad.txt");
+    std::fs::write(&path, b"one\ntwo\n\xff\xfe\nfour\n").unwrap();
+    let source = format!(r#"
+count = 0
+message = try {{
+    for line in line_stream("{}") {{
+        count = count + 1
+    }}
+    "no error"
+}} catch IOError as e {{
+    e.message()
+}}
+missing = try {{
+    for line in line_stream("/no/such/graphoid/file.txt") {{ }}
+}} catch IOError {{ "caught" }}
+"#, path.display());
+    let mut executor = Executor::new();
+    executor.execute_source(&source).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(executor.get_variable("count").unwrap(), Value::number(2.0));
+    let message = executor.get_variable("message").unwrap().to_string_value();
+    assert!(message.contains("failed after line 2"), "got: {}", message);
+    assert_eq!(executor.get_variable("missing").unwrap(), Value::string("caught".to_string()));
+}
+
+#[test]
+fn test_const_declaration_is_frozen_and_not_reassignable() {
+    let source = r#"
+const LIMIT = 10
+const NAMES = ["a", "b"]
+fn shadow() {
+    num LIMIT = 3
+    return LIMIT
+}
+shadowed = shadow()
+frozen = NAMES.is_frozen()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+    assert_eq!(executor.get_variable("shadowed").unwrap(), Value::number(3.0));
+    assert_eq!(executor.get_variable("LIMIT").unwrap(), Value::number(10.0));
+    assert_eq!(executor.get_variable("frozen").unwrap(), Value::boolean(true));
+
+    for statement in ["LIMIT = 11", "const LIMIT = 12", "for LIMIT in [1] { }"] {
+        let err = executor.execute_source(statement).unwrap_err();
+        assert!(err.to_string().contains("cannot reassign constant LIMIT"), "got: {}", err);
+    }
+
+    let err = executor.execute_source("fn bump() { LIMIT = LIMIT + 1 }\nbump()").unwrap_err();
+    assert!(err.to_string().contains("cannot reassign constant LIMIT"), "got: {}", err);
+    assert_eq!(executor.get_variable("LIMIT").unwrap(), Value::number(10.0));
+}
@@ -4,7 +4,7 @@ use graphoid::lexer::Lexer;
 use graphoid::parser::Parser;
 use graphoid::ast::*;
 use graphoid::execution::Executor;
-use graphoid::values::ValueKind;
+use graphoid::values::{Value, ValueKind};
 
 // ============================================================================
 // PARSER TESTS - Verify AST structure
@@ -219,6 +219,16 @@ graph TaskGraph(:dag) {
     }
 }
 
+#[test]
+fn test_parse_graph_with_unknown_type_lists_valid_types() {
+    let source = "graph Roads(:multi) {}";
+    let tokens = Lexer::new(source).tokenize().unwrap();
+    let err = Parser::new(tokens).parse().unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("Unknown graph type ':multi'"), "{}", message);
+    assert!(message.contains(":multigraph"), "{}", message);
+}
+
 #[test]
 fn test_parse_graph_with_setter() {
     let source = r#"
@@ -468,6 +478,42 @@ graph TaskGraph(:dag) {
     }
 }
 
+#[test]
+fn test_exec_graph_multigraph_type() {
+    let source = r#"
+graph Roads(:multigraph) {}
+Roads.add_node("a", 1)
+Roads.add_node("b", 2)
+Roads.add_edge("a", "b")
+Roads.add_edge("a", "b")
+count = Roads.edge_count()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("count").unwrap(), Value::number(2.0));
+}
+
+#[test]
+fn test_exec_graph_expr_unknown_type_is_value_error() {
+    let source = r#"
+fn build() {
+    try {
+        g = graph { type: :weird }
+    } catch ValueError as e {
+        return e.message()
+    }
+    return "no error"
+}
+message = build()
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    let message = executor.get_variable("message").unwrap().to_string_value();
+    assert!(message.starts_with("Unknown graph type ':weird'"), "{}", message);
+}
+
 #[test]
 fn test_exec_graph_private_method_renamed() {
     let source = r#"
@@ -1700,3 +1746,208 @@ internal = c.get_internal()
         other => panic!("Expected number 0, got {:?}", other),
     }
 }
+
+// ============================================================================
+// OPERATOR OVERLOADING TESTS - Special methods (__add__, __eq__, ...)
+// ============================================================================
+
+#[test]
+fn test_operator_overload_add() {
+    let source = r#"
+graph Vec2 {
+    x: 0
+    y: 0
+
+    fn __add__(other) {
+        return Vec2 { x: x + other.x, y: y + other.y }
+    }
+}
+
+a = Vec2 { x: 1, y: 2 }
+b = Vec2 { x: 10, y: 20 }
+c = a + b
+cx = c.x
+cy = c.y
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    match &executor.get_variable("cx").unwrap().kind {
+        ValueKind::Number(n) => assert_eq!(*n, 11.0),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    match &executor.get_variable("cy").unwrap().kind {
+        ValueKind::Number(n) => assert_eq!(*n, 22.0),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_operator_overload_eq_and_not_eq() {
+    let source = r#"
+graph Money {
+    cents: 0
+
+    fn __eq__(other) {
+        return cents == other.cents
+    }
+}
+
+a = Money { cents: 100 }
+b = Money { cents: 100 }
+c = Money { cents: 5 }
+same = a == b
+different = a != c
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert!(matches!(executor.get_variable("same").unwrap().kind, ValueKind::Boolean(true)));
+    assert!(matches!(executor.get_variable("different").unwrap().kind, ValueKind::Boolean(true)));
+}
+
+#[test]
+fn test_operator_overload_index() {
+    let source = r#"
+graph Row {
+    values: [5, 6, 7]
+
+    fn __index__(i) {
+        return values[i]
+    }
+}
+
+r = Row {}
+result = r[1]
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    match &executor.get_variable("result").unwrap().kind {
+        ValueKind::Number(n) => assert_eq!(*n, 6.0),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_operator_overload_to_string() {
+    let source = r#"
+graph Point {
+    x: 0
+    y: 0
+
+    fn __to_string__() {
+        return "(" + x.to_string() + ", " + y.to_string() + ")"
+    }
+}
+
+p = Point { x: 3, y: 4 }
+text = p.to_string()
+label = "at " + p
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    match &executor.get_variable("text").unwrap().kind {
+        ValueKind::String(s) => assert_eq!(s, "(3, 4)"),
+        other => panic!("Expected string, got {:?}", other),
+    }
+    match &executor.get_variable("label").unwrap().kind {
+        ValueKind::String(s) => assert_eq!(s, "at (3, 4)"),
+        other => panic!("Expected string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_operator_overload_to_string_inside_collections() {
+    let source = r#"
+graph Point {
+    x: 0
+
+    fn __to_string__() {
+        return "P" + x.to_string()
+    }
+}
+
+p = Point { x: 1 }
+print([p, 2], {"at": p})
+listed = [p, "s"].to_string()
+mapped = {"at": p}.to_string()
+joined = [p, p].join(", ")
+label = "pts: " + [p, [p]]
+"#;
+    let mut executor = Executor::new();
+    executor.enable_output_capture();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_captured_output(), "[P1, 2] {\"at\": P1}\n");
+    for (name, expected) in [
+        ("listed", "[P1, \"s\"]"),
+        ("mapped", "{\"at\": P1}"),
+        ("joined", "P1, P1"),
+        ("label", "pts: [P1, [P1]]"),
+    ] {
+        match &executor.get_variable(name).unwrap().kind {
+            ValueKind::String(s) => assert_eq!(s, expected, "{}", name),
+            other => panic!("Expected string for {}, got {:?}", name, other),
+        }
+    }
+}
+
+#[test]
+fn test_operator_overload_error_propagates() {
+    let source = r#"
+graph Strict {
+    fn __add__(other) {
+        raise "cannot add Strict"
+    }
+}
+
+a = Strict {}
+b = a + 1
+"#;
+    let mut executor = Executor::new();
+    let result = executor.execute_source(source);
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("cannot add Strict"));
+}
+
+#[test]
+fn test_operator_without_special_method_uses_default() {
+    let source = r#"
+graph Plain {
+    value: 1
+}
+
+a = Plain {}
+b = Plain {}
+same = a == b
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert!(matches!(executor.get_variable("same").unwrap().kind, ValueKind::Boolean(true)));
+}
+
+#[test]
+fn test_exec_method_default_parameters() {
+    let source = r#"
+graph Greeter {
+    fn greet(name, greeting = "Hello") {
+        return greeting + ", " + name
+    }
+}
+
+g = Greeter {}
+short = g.greet("Ann")
+full = g.greet("Bob", "Hi")
+"#;
+    let mut executor = Executor::new();
+    executor.execute_source(source).unwrap();
+
+    assert_eq!(executor.get_variable("short").unwrap().to_string_value(), "Hello, Ann");
+    assert_eq!(executor.get_variable("full").unwrap().to_string_value(), "Hi, Bob");
+
+    let err = executor.execute_source("x = g.greet()").unwrap_err();
+    assert!(err.to_string().contains("Method 'greet' expects 2 arguments, but got 0"), "got: {}", err);
+}
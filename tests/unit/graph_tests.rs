@@ -127,6 +127,55 @@ fn test_graph_keys_values() {
     assert_eq!(values.len(), 2);
 }
 
+#[test]
+fn test_multigraph_keeps_parallel_edges() {
+    let mut g = Graph::new(GraphType::Directed);
+    g.multigraph = true;
+    g.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    g.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    g.add_edge("A", "B", "road".to_string(), Some(5.0), HashMap::new()).unwrap();
+    g.add_edge("A", "B", "rail".to_string(), Some(3.0), HashMap::new()).unwrap();
+
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.edge_multiplicity("A", "B"), 2);
+    let types: Vec<String> = g.data_edge_list().into_iter().map(|(_, _, t)| t).collect();
+    assert_eq!(types, vec!["road", "rail"]);
+
+    // Removing drops one edge at a time, most recent first
+    assert!(g.remove_edge("A", "B").unwrap());
+    assert_eq!(g.edge_multiplicity("A", "B"), 1);
+    assert_eq!(g.data_edge_list()[0].2, "road");
+    assert!(g.remove_edge("A", "B").unwrap());
+    assert_eq!(g.edge_count(), 0);
+}
+
+#[test]
+fn test_multigraph_parallel_edges_removed_with_node() {
+    let mut g = Graph::new(GraphType::Undirected);
+    g.multigraph = true;
+    g.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    g.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    g.add_edge("A", "B", "edge".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("A", "B", "edge".to_string(), None, HashMap::new()).unwrap();
+    assert_eq!(g.edge_multiplicity("B", "A"), 2);
+
+    g.remove_node("B", None).unwrap();
+    assert!(g.parallel_edges.is_empty());
+    assert_eq!(g.edge_count(), 0);
+}
+
+#[test]
+fn test_plain_graph_replaces_repeated_edge() {
+    let mut g = Graph::new(GraphType::Directed);
+    g.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    g.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    g.add_edge("A", "B", "first".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("A", "B", "second".to_string(), None, HashMap::new()).unwrap();
+
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g.edge_multiplicity("A", "B"), 1);
+}
+
 // ============================================================================
 // BASIC TREE TESTS (using Graph with :tree ruleset)
 // ============================================================================
@@ -517,6 +566,7 @@ fn make_test_function(name: &str) -> Function {
         is_setter: false,
         is_static: false,
         guard: None,
+        synthetic: None,
     }
 }
 
@@ -638,6 +688,114 @@ fn test_clone_preserves_methods() {
     assert!(g2.has_node("value"));
 }
 
+#[test]
+fn test_clone_structure_resets_values_and_edge_properties() {
+    let mut g = Graph::new(GraphType::Undirected);
+    g.add_node("a".to_string(), Value::number(1.0)).unwrap();
+    g.add_node("b".to_string(), Value::string("two".to_string())).unwrap();
+    let mut props = HashMap::new();
+    props.insert("since".to_string(), Value::number(2020.0));
+    g.add_edge("a", "b", "friend".to_string(), Some(3.0), props).unwrap();
+    g.attach_method("get_value".to_string(), make_test_function("get_value"));
+
+    let shape = g.clone_structure(&Value::number(0.0));
+
+    assert_eq!(shape.graph_type, GraphType::Undirected);
+    assert_eq!(shape.node_ids(), g.node_ids());
+    assert_eq!(shape.get_node("a"), Some(&Value::number(0.0)));
+    assert_eq!(shape.get_node("b"), Some(&Value::number(0.0)));
+    assert!(shape.has_edge("a", "b") && shape.has_edge("b", "a"));
+    assert_eq!(shape.get_edge_weight("a", "b"), Some(3.0));
+    assert!(shape.has_method("get_value"));
+    for (_, _, edge) in shape.data_edge_infos() {
+        assert_eq!(edge.edge_type, "friend");
+        assert!(edge.properties.is_empty());
+    }
+
+    // The source keeps its data
+    assert_eq!(g.get_node("a"), Some(&Value::number(1.0)));
+    assert!(g.data_edge_infos().iter().all(|(_, _, edge)| edge.properties.contains_key("since")));
+}
+
+#[test]
+fn test_clone_structure_does_not_alias_source_or_default() {
+    let mut g = Graph::new(GraphType::Directed);
+    g.add_node("a".to_string(), Value::none()).unwrap();
+    g.add_node("b".to_string(), Value::none()).unwrap();
+    g.add_edge("a", "b", "edge".to_string(), None, HashMap::new()).unwrap();
+
+    let default = Value::graph(Graph::new(GraphType::Directed));
+    let mut shape = g.clone_structure(&default);
+    shape.add_node("c".to_string(), Value::none()).unwrap();
+    assert!(!g.has_node("c"));
+
+    // Each node gets its own copy of a graph default
+    let (Some(a), Some(b)) = (shape.get_node("a"), shape.get_node("b")) else { panic!("missing nodes") };
+    if let (graphoid::values::ValueKind::Graph(a), graphoid::values::ValueKind::Graph(b)) = (&a.kind, &b.kind) {
+        a.borrow_mut().add_node("x".to_string(), Value::none()).unwrap();
+        assert_eq!(b.borrow().node_count(), 0);
+    } else {
+        panic!("expected graph values");
+    }
+}
+
+#[test]
+fn test_map_node_values_keeps_topology_and_source() {
+    let mut g = Graph::new(GraphType::Directed);
+    g.add_node("a".to_string(), Value::number(1.0)).unwrap();
+    g.add_node("b".to_string(), Value::number(2.0)).unwrap();
+    g.add_edge("a", "b", "edge".to_string(), Some(4.0), HashMap::new()).unwrap();
+    g.attach_method("get_value".to_string(), make_test_function("get_value"));
+
+    let mut visited = Vec::new();
+    let doubled = g.map_node_values(|id, value| {
+        visited.push(id.to_string());
+        Ok(Value::number(value.to_number().unwrap() * 2.0))
+    }).unwrap();
+
+    assert_eq!(visited, vec!["a", "b"]);
+    assert_eq!(doubled.get_node("a"), Some(&Value::number(2.0)));
+    assert_eq!(doubled.get_node("b"), Some(&Value::number(4.0)));
+    assert_eq!(doubled.get_edge_weight("a", "b"), Some(4.0));
+    assert!(doubled.has_method("get_value"));
+    assert_eq!(g.get_node("a"), Some(&Value::number(1.0)));
+
+    let err = g.map_node_values(|id, _| Err(graphoid::error::GraphoidError::runtime(format!("bad {}", id))))
+        .unwrap_err();
+    assert!(err.to_string().contains("bad a"), "got: {}", err);
+}
+
+#[test]
+fn test_map_edge_properties_updates_both_directions_and_parallel_edges() {
+    let mut g = Graph::new(GraphType::Undirected);
+    g.multigraph = true;
+    g.add_node("a".to_string(), Value::none()).unwrap();
+    g.add_node("b".to_string(), Value::none()).unwrap();
+    g.add_edge("a", "b", "road".to_string(), None, HashMap::new()).unwrap();
+    g.add_edge("a", "b", "rail".to_string(), None, HashMap::new()).unwrap();
+
+    let mut calls = 0;
+    let tagged = g.map_edge_properties(|_, _, properties| {
+        calls += 1;
+        let mut properties = properties.clone();
+        properties.insert("seen".to_string(), Value::number(calls as f64));
+        Ok(properties)
+    }).unwrap();
+
+    // One call per edge, not per stored direction
+    assert_eq!(calls, 2);
+    let seen: Vec<(String, String, Option<Value>)> = tagged.data_edge_infos().into_iter()
+        .map(|(from, to, edge)| (from.to_string(), to.to_string(), edge.properties.get("seen").cloned()))
+        .collect();
+    assert_eq!(seen, vec![
+        ("a".to_string(), "b".to_string(), Some(Value::number(1.0))),
+        ("a".to_string(), "b".to_string(), Some(Value::number(2.0))),
+        ("b".to_string(), "a".to_string(), Some(Value::number(1.0))),
+        ("b".to_string(), "a".to_string(), Some(Value::number(2.0))),
+    ]);
+    assert!(g.data_edge_infos().iter().all(|(_, _, edge)| edge.properties.is_empty()));
+}
+
 #[test]
 fn test_method_node_has_correct_type() {
     let mut g = Graph::new(GraphType::Directed);
@@ -1,6 +1,7 @@
 //! Lexer unit tests
 
 use graphoid::lexer::{Lexer, token::TokenType};
+use graphoid::error::GraphoidError;
 
 // ============================================================================
 // PHASE 1: Token Creation Tests
@@ -264,6 +265,25 @@ fn test_logical_operators() {
     assert_eq!(tokens[3].token_type, TokenType::PipePipe);
 }
 
+#[test]
+fn test_pipe_greater_operator() {
+    let mut lexer = Lexer::new("xs |> sort() | 1");
+    let tokens = lexer.tokenize().unwrap();
+
+    assert_eq!(tokens[1].token_type, TokenType::PipeGreater);
+    assert_eq!(tokens[5].token_type, TokenType::Pipe);
+}
+
+#[test]
+fn test_multi_char_operator_lexemes() {
+    let mut lexer = Lexer::new("a >= b != c |> d");
+    let tokens = lexer.tokenize().unwrap();
+
+    assert_eq!(tokens[1].lexeme, ">=");
+    assert_eq!(tokens[3].lexeme, "!=");
+    assert_eq!(tokens[5].lexeme, "|>");
+}
+
 // ============================================================================
 // PHASE 7: Comments
 // ============================================================================
@@ -663,6 +683,78 @@ y = 43"#;
     }
 }
 
+#[test]
+fn test_hash_block_comment() {
+    let mut lexer = Lexer::new("42 #[ block comment ]# 43");
+    let tokens = lexer.tokenize().unwrap();
+
+    assert_eq!(tokens[0].token_type, TokenType::Number(42.0));
+    assert_eq!(tokens[1].token_type, TokenType::Number(43.0));
+    assert_eq!(tokens[2].token_type, TokenType::Eof);
+}
+
+#[test]
+fn test_hash_block_comment_nests() {
+    let mut lexer = Lexer::new("1 #[ outer #[ inner ]# still outer ]# 2");
+    let tokens = lexer.tokenize().unwrap();
+
+    assert_eq!(tokens[0].token_type, TokenType::Number(1.0));
+    assert_eq!(tokens[1].token_type, TokenType::Number(2.0));
+    assert_eq!(tokens[2].token_type, TokenType::Eof);
+}
+
+#[test]
+fn test_hash_block_comment_positions() {
+    let source = "x #[ one\ntwo\n  three ]# y\nz";
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize().unwrap();
+
+    assert_eq!(tokens[0].token_type, TokenType::Identifier("x".to_string()));
+    assert_eq!((tokens[0].line, tokens[0].column), (1, 1));
+
+    // Multi-line comment produces no newline tokens but advances the line
+    assert_eq!(tokens[1].token_type, TokenType::Identifier("y".to_string()));
+    assert_eq!((tokens[1].line, tokens[1].column), (3, 12));
+
+    assert_eq!(tokens[2].token_type, TokenType::Newline);
+    assert_eq!(tokens[3].token_type, TokenType::Identifier("z".to_string()));
+    assert_eq!((tokens[3].line, tokens[3].column), (4, 1));
+}
+
+#[test]
+fn test_hash_block_comment_inside_brackets() {
+    let mut lexer = Lexer::new("[1, #[ skip ]# 2 # trailing\n, 3]");
+    let tokens = lexer.tokenize().unwrap();
+    let types: Vec<TokenType> = tokens.into_iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::LeftBracket,
+            TokenType::Number(1.0),
+            TokenType::Comma,
+            TokenType::Number(2.0),
+            TokenType::Newline,
+            TokenType::Comma,
+            TokenType::Number(3.0),
+            TokenType::RightBracket,
+            TokenType::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_unterminated_hash_block_comment() {
+    let mut lexer = Lexer::new("x = 1\n  #[ outer #[ inner ]#\n");
+    match lexer.tokenize() {
+        Err(graphoid::error::GraphoidError::SyntaxError { message, position }) => {
+            assert!(message.contains("Unterminated block comment"));
+            assert_eq!((position.line, position.column), (2, 3));
+        }
+        other => panic!("Expected SyntaxError, got {:?}", other),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // EDGE CASES
 // ---------------------------------------------------------------------------
@@ -1166,6 +1258,35 @@ fn test_invalid_hex_literal() {
     assert!(result.is_err(), "Should error on hex literal with invalid chars");
 }
 
+#[test]
+fn test_exponent_literals() {
+    let mut lexer = Lexer::new("1e9 2.5e-3 6E+2 1_000e3 1_000_000 0xFe3");
+    let tokens = lexer.tokenize().unwrap();
+
+    let expected = [1e9, 2.5e-3, 600.0, 1_000_000.0, 1_000_000.0, 4067.0];
+    for (token, value) in tokens.iter().zip(expected) {
+        match &token.token_type {
+            TokenType::Number(n) => assert_eq!(*n, value, "lexeme {}", token.lexeme),
+            other => panic!("Expected number, got {:?}", other),
+        }
+    }
+    assert_eq!(tokens[1].lexeme, "2.5e-3");
+    assert_eq!(tokens[3].lexeme, "1_000e3");
+}
+
+#[test]
+fn test_exponent_without_digits_is_syntax_error() {
+    for source in ["1e", "1e+", "2.5E-", "3ex"] {
+        let mut lexer = Lexer::new(source);
+        match lexer.tokenize() {
+            Err(GraphoidError::SyntaxError { message, .. }) => {
+                assert!(message.contains("must have at least one digit"), "{}: {}", source, message)
+            }
+            other => panic!("Expected syntax error for {}, got {:?}", source, other),
+        }
+    }
+}
+
 #[test]
 fn test_bitwise_expression() {
     let source = "mask = 0xFF & 0x0F | (0b1010 ^ 0b0101)";
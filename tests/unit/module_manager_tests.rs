@@ -87,6 +87,43 @@ fn test_module_not_found_error() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_resolve_searches_current_dir_then_extra_paths() {
+    let manager = ModuleManager::new();
+
+    std::fs::create_dir_all("/tmp/graphoid_test_paths/app").unwrap();
+    std::fs::create_dir_all("/tmp/graphoid_test_paths/vendor").unwrap();
+    std::fs::write("/tmp/graphoid_test_paths/app/shared.gr", "# app copy").unwrap();
+    std::fs::write("/tmp/graphoid_test_paths/vendor/shared.gr", "# vendor copy").unwrap();
+    std::fs::write("/tmp/graphoid_test_paths/vendor/extra.gr", "# vendor only").unwrap();
+
+    let current = PathBuf::from("/tmp/graphoid_test_paths/app/main.gr");
+    let extra = vec![PathBuf::from("/tmp/graphoid_test_paths/vendor")];
+
+    // The current file's directory wins over the extra search paths
+    let shared = manager.resolve_module_path_in("shared", Some(&current), &extra).unwrap();
+    assert!(shared.ends_with("app/shared.gr"));
+
+    let vendored = manager.resolve_module_path_in("extra", Some(&current), &extra).unwrap();
+    assert!(vendored.ends_with("vendor/extra.gr"));
+    assert!(manager.resolve_module_path("extra", Some(&current)).is_err());
+}
+
+#[test]
+fn test_module_not_found_lists_tried_paths() {
+    let manager = ModuleManager::new();
+    let current = PathBuf::from("/tmp/graphoid_test_paths/app/main.gr");
+    let extra = vec![PathBuf::from("/tmp/graphoid_test_paths/vendor")];
+
+    let err = manager.resolve_module_path_in("missing", Some(&current), &extra).unwrap_err();
+    assert_eq!(err.error_type(), "ModuleNotFound");
+    let message = err.to_string();
+    assert!(message.contains("Module not found: 'missing'"));
+    assert!(message.contains("/tmp/graphoid_test_paths/app/missing.gr"));
+    assert!(message.contains("/tmp/graphoid_test_paths/vendor/missing.gr"));
+    assert!(message.contains("stdlib"));
+}
+
 #[test]
 fn test_register_and_get_module() {
     let mut manager = ModuleManager::new();
@@ -296,3 +296,31 @@ fn test_default_creates_empty_global() {
     let ns = NamespaceGraph::default();
     assert_eq!(ns.current_scope_type(), ScopeType::Global);
 }
+
+#[test]
+fn test_const_cannot_be_set() {
+    let mut ns = NamespaceGraph::new();
+    ns.define_const("LIMIT".to_string(), Value::number(10.0));
+    assert!(ns.is_const("LIMIT"));
+
+    let err = ns.set("LIMIT", Value::number(11.0)).unwrap_err();
+    assert!(err.to_string().contains("cannot reassign constant LIMIT"));
+    assert_eq!(ns.get("LIMIT").unwrap(), Value::number(10.0));
+}
+
+#[test]
+fn test_const_can_be_shadowed_in_child_scope() {
+    let mut ns = NamespaceGraph::new();
+    ns.define_const("LIMIT".to_string(), Value::number(10.0));
+
+    let mut child = NamespaceGraph::with_parent(ns);
+    assert!(child.is_const("LIMIT"));
+    assert!(!child.is_const_in_current_scope("LIMIT"));
+    child.define("LIMIT".to_string(), Value::number(3.0));
+    assert!(!child.is_const("LIMIT"));
+    child.set("LIMIT", Value::number(4.0)).unwrap();
+
+    let parent = child.take_parent().unwrap();
+    assert_eq!(parent.get("LIMIT").unwrap(), Value::number(10.0));
+    assert!(parent.is_const("LIMIT"));
+}
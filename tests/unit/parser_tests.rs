@@ -380,6 +380,25 @@ fn test_parse_variable_declaration_with_type() {
     }
 }
 
+#[test]
+fn test_parse_const_declaration() {
+    let mut lexer = Lexer::new("const LIMIT = 10\nconst.pi");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::ConstDecl { name, value, is_private, .. } => {
+            assert_eq!(name, "LIMIT");
+            assert!(!is_private);
+            assert!(matches!(value, Expr::Literal { value: LiteralValue::Number(n), .. } if *n == 10.0));
+        }
+        _ => panic!("Expected const declaration"),
+    }
+    // `const` is still an ordinary name elsewhere (the constants module alias)
+    assert!(matches!(&program.statements[1], Stmt::Expression { .. }));
+}
+
 // ============================================================================
 // PHASE 5: Collection Literals
 // ============================================================================
@@ -436,7 +455,7 @@ fn test_parse_map() {
             match expr {
                 Expr::Map { entries, .. } => {
                     assert_eq!(entries.len(), 1);
-                    assert_eq!(entries[0].0, "key");
+                    assert_eq!(entries[0].0, MapKey::Static("key".to_string()));
                 }
                 _ => panic!("Expected map"),
             }
@@ -445,6 +464,53 @@ fn test_parse_map() {
     }
 }
 
+#[test]
+fn test_parse_map_computed_key() {
+    let mut lexer = Lexer::new("m = {name: 1, [prefix + \"_id\"]: 2}");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::Assignment { value: Expr::Map { entries, .. }, .. } => {
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].0, MapKey::Static("name".to_string()));
+            assert!(matches!(entries[1].0, MapKey::Computed(Expr::Binary { .. })));
+        }
+        other => panic!("Expected map assignment, got {:?}", other),
+    }
+
+    let tokens = Lexer::new("m = {[1: 2}").tokenize().unwrap();
+    assert!(Parser::new(tokens).parse().is_err());
+}
+
+#[test]
+fn test_parse_spread_in_literals() {
+    let tokens = Lexer::new("xs = [...a, 1, ...b]").tokenize().unwrap();
+    let program = Parser::new(tokens).parse().unwrap();
+    match &program.statements[0] {
+        Stmt::Assignment { value: Expr::List { elements, .. }, .. } => {
+            assert_eq!(elements.len(), 3);
+            assert!(matches!(&elements[0], Expr::Spread { expr, .. } if matches!(**expr, Expr::Variable { .. })));
+            assert!(matches!(elements[1], Expr::Literal { .. }));
+            assert!(matches!(elements[2], Expr::Spread { .. }));
+        }
+        other => panic!("Expected list assignment, got {:?}", other),
+    }
+
+    let tokens = Lexer::new("m = {...base, key: 1}").tokenize().unwrap();
+    let program = Parser::new(tokens).parse().unwrap();
+    match &program.statements[0] {
+        Stmt::Assignment { value: Expr::Map { entries, .. }, .. } => {
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].0, MapKey::Spread);
+            assert!(matches!(entries[0].1, Expr::Variable { .. }));
+            assert_eq!(entries[1].0, MapKey::Static("key".to_string()));
+        }
+        other => panic!("Expected map assignment, got {:?}", other),
+    }
+}
+
 // ============================================================================
 // PHASE 6: Control Flow Tests
 // ============================================================================
@@ -545,6 +611,21 @@ fn test_parse_for_loop() {
     }
 }
 
+#[test]
+fn test_parse_for_loop_with_several_variables() {
+    let source = "for i, x in enumerate(items) { print(i) }";
+    let tokens = Lexer::new(source).tokenize().unwrap();
+    let program = Parser::new(tokens).parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::For { variable, unpack, .. } => {
+            assert_eq!(variable, "i");
+            assert_eq!(unpack, &vec!["x".to_string()]);
+        }
+        _ => panic!("Expected for statement"),
+    }
+}
+
 // ============================================================================
 // PHASE 7: Function Tests
 // ============================================================================
@@ -1329,3 +1410,121 @@ try {
 // ============================================================================
 // Total: 60 comprehensive parser tests (48 previous + 12 try/catch)
 // ============================================================================
+
+// ============================================================================
+// Pipe operator (|>)
+// ============================================================================
+
+#[test]
+fn test_parse_pipe_desugars_to_call() {
+    let mut lexer = Lexer::new("xs |> keep(f) |> sort()");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::Expression { expr: Expr::Call { callee, args, .. }, .. } => {
+            assert!(matches!(**callee, Expr::Variable { ref name, .. } if name == "sort"));
+            assert_eq!(args.len(), 1);
+            match &args[0] {
+                Argument::Positional { expr: Expr::Call { callee, args, .. }, .. } => {
+                    assert!(matches!(**callee, Expr::Variable { ref name, .. } if name == "keep"));
+                    assert_eq!(args.len(), 2);
+                    assert!(matches!(&args[0], Argument::Positional { expr: Expr::Variable { name, .. }, .. } if name == "xs"));
+                }
+                other => panic!("Expected piped call as argument, got {:?}", other),
+            }
+        }
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_pipe_into_bare_name_and_method_call() {
+    let mut lexer = Lexer::new("x |> double |> util.scale(2)");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::Expression { expr: Expr::MethodCall { method, args, .. }, .. } => {
+            assert_eq!(method, "scale");
+            assert_eq!(args.len(), 2);
+            assert!(matches!(&args[0], Argument::Positional { expr: Expr::Call { .. }, .. }));
+        }
+        other => panic!("Expected method call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_pipe_has_lower_precedence_than_or() {
+    let mut lexer = Lexer::new("a + 1 |> f");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::Expression { expr: Expr::Call { args, .. }, .. } => {
+            assert!(matches!(&args[0], Argument::Positional { expr: Expr::Binary { op: BinaryOp::Add, .. }, .. }));
+        }
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_pipe_into_literal_is_error() {
+    let mut lexer = Lexer::new("x |> 42");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let result = parser.parse();
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("|>"));
+}
+
+// ============================================================================
+// Assert statement
+// ============================================================================
+
+#[test]
+fn test_parse_assert_with_message() {
+    let mut lexer = Lexer::new("assert x.length() >= 1, \"empty\"");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::Assert { condition, message, source, .. } => {
+            assert!(matches!(condition, Expr::Binary { op: BinaryOp::GreaterEqual, .. }));
+            assert!(matches!(message, Some(Expr::Literal { .. })));
+            assert_eq!(source, "x.length() >= 1");
+        }
+        other => panic!("Expected assert statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_assert_without_message() {
+    let mut lexer = Lexer::new("assert not done");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    match &program.statements[0] {
+        Stmt::Assert { message, source, .. } => {
+            assert!(message.is_none());
+            assert_eq!(source, "not done");
+        }
+        other => panic!("Expected assert statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_assert_call_is_expression() {
+    let mut lexer = Lexer::new("assert(result)");
+    let tokens = lexer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().unwrap();
+
+    assert!(matches!(&program.statements[0], Stmt::Expression { expr: Expr::Call { .. }, .. }));
+}
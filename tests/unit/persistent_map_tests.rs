@@ -0,0 +1,265 @@
+//! Persistent map and structural sharing tests
+//!
+//! A List's backing graph stores its nodes in a PersistentMap, so copies of a
+//! list share storage. These tests make sure updates to one copy never leak
+//! into another, for lists and for the owned storage of other graphs.
+
+use graphoid::values::{Graph, GraphType, Hash, List, PersistentMap, Value};
+use std::collections::HashMap;
+
+// ============================================================================
+// PersistentMap Basics
+// ============================================================================
+
+#[test]
+fn test_persistent_map_insert_get_remove() {
+    let mut map = PersistentMap::new();
+    for i in 0..2000 {
+        assert_eq!(map.insert(format!("k{}", i), i), None);
+    }
+    assert_eq!(map.len(), 2000);
+    assert_eq!(map.get("k1234"), Some(&1234));
+    assert_eq!(map.insert("k1234".to_string(), -1), Some(1234));
+    assert_eq!(map.len(), 2000);
+
+    for i in (0..2000).step_by(2) {
+        assert_eq!(map.remove(format!("k{}", i).as_str()), Some(if i == 1234 { -1 } else { i }));
+    }
+    assert_eq!(map.len(), 1000);
+    assert!(!map.contains_key("k0"));
+    assert!(map.contains_key("k1"));
+    assert_eq!(map.remove("k0"), None);
+    assert_eq!(map.iter().count(), 1000);
+}
+
+#[test]
+fn test_persistent_map_clone_is_independent() {
+    let mut original: PersistentMap<String, i32> = (0..500).map(|i| (i.to_string(), i)).collect();
+    let mut copy = original.clone();
+
+    copy.insert("new".to_string(), 1);
+    *copy.get_mut("7").unwrap() = 700;
+    copy.remove("8");
+    original.insert("other".to_string(), 2);
+
+    assert_eq!(original.get("7"), Some(&7));
+    assert_eq!(original.get("8"), Some(&8));
+    assert!(!original.contains_key("new"));
+    assert_eq!(copy.get("7"), Some(&700));
+    assert!(!copy.contains_key("8"));
+    assert!(!copy.contains_key("other"));
+    assert_eq!(original.len(), 501);
+    assert_eq!(copy.len(), 500);
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct SameHash(u32);
+
+impl std::hash::Hash for SameHash {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        0u8.hash(state);
+    }
+}
+
+#[test]
+fn test_persistent_map_full_hash_collisions() {
+    let mut map = PersistentMap::new();
+    for i in 0..5 {
+        map.insert(SameHash(i), i);
+    }
+    let snapshot = map.clone();
+    assert_eq!(map.len(), 5);
+    assert_eq!(map.get(&SameHash(3)), Some(&3));
+
+    assert_eq!(map.remove(&SameHash(3)), Some(3));
+    assert_eq!(map.get(&SameHash(3)), None);
+    assert_eq!(snapshot.get(&SameHash(3)), Some(&3));
+    assert_eq!(map.values().sum::<u32>(), 7);
+}
+
+#[test]
+fn test_persistent_map_retain_and_equality() {
+    let mut a: PersistentMap<String, i32> = (0..100).map(|i| (i.to_string(), i)).collect();
+    let b = a.clone();
+    assert_eq!(a, b);
+
+    a.retain(|_, v| *v % 3 == 0);
+    assert_eq!(a.len(), 34);
+    assert_ne!(a, b);
+    assert_eq!(b.len(), 100);
+}
+
+#[test]
+fn test_persistent_map_iterates_in_insertion_order() {
+    let mut map = PersistentMap::new();
+    for key in ["zeta", "alpha", "mid", "beta"] {
+        map.insert(key.to_string(), key.len());
+    }
+    map.insert("alpha".to_string(), 99);
+    map.remove("mid");
+    map.insert("mid".to_string(), 3);
+
+    let keys: Vec<&str> = map.keys().map(|k| k.as_str()).collect();
+    assert_eq!(keys, vec!["zeta", "alpha", "beta", "mid"]);
+    assert_eq!(map.get("alpha"), Some(&99));
+
+    // iter_mut walks the trie directly, so only the contents are fixed
+    let mut values: Vec<usize> = map.iter_mut().map(|(_, v)| *v).collect();
+    values.sort_unstable();
+    assert_eq!(values, vec![3, 4, 4, 99]);
+}
+
+#[test]
+fn test_persistent_map_retain_keeping_everything_stays_shared() {
+    let mut map: PersistentMap<u32, u32> = (0..500).map(|i| (i, i)).collect();
+    let copy = map.clone();
+    map.retain(|_, v| *v < 1000);
+    assert!(map.ptr_eq(&copy));
+
+    map.retain(|k, _| *k != 42);
+    assert!(!map.ptr_eq(&copy));
+    assert_eq!(map.len(), 499);
+    assert_eq!(copy.get(&42), Some(&42));
+}
+
+#[test]
+fn test_persistent_map_order_survives_heavy_removal() {
+    let mut map: PersistentMap<u32, u32> = (0..500).map(|i| (i, i)).collect();
+    let copy = map.clone();
+    map.retain(|k, _| k % 7 == 0);
+    for i in 1000..1010 {
+        map.insert(i, i);
+    }
+
+    let expected: Vec<u32> = (0..500).filter(|k| k % 7 == 0).chain(1000..1010).collect();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), expected);
+    assert_eq!(copy.keys().copied().collect::<Vec<_>>(), (0..500).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_persistent_map_equality_ignores_order() {
+    let a: PersistentMap<&str, i32> = [("x", 1), ("y", 2)].into_iter().collect();
+    let b: PersistentMap<&str, i32> = [("y", 2), ("x", 1)].into_iter().collect();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_graph_nodes_and_neighbors_keep_insertion_order() {
+    let mut graph = Graph::new(GraphType::Directed);
+    for id in ["root", "zebra", "apple", "mango"] {
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    for to in ["mango", "zebra", "apple"] {
+        graph.add_edge("root", to, "edge".to_string(), None, HashMap::new()).unwrap();
+    }
+
+    assert_eq!(graph.node_ids(), vec!["root", "zebra", "apple", "mango"]);
+    assert_eq!(graph.neighbors("root"), vec!["mango", "zebra", "apple"]);
+    assert_eq!(graph.bfs("root"), vec!["root", "mango", "zebra", "apple"]);
+}
+
+// ============================================================================
+// Copy Mutation Tests
+// ============================================================================
+
+fn numbers(list: &List) -> Vec<f64> {
+    list.to_vec().iter().map(|v| v.to_number().unwrap()).collect()
+}
+
+#[test]
+fn test_only_list_graphs_share_node_storage() {
+    let list = List::from_vec(vec![Value::number(1.0), Value::number(2.0)]);
+    let copy = list.clone();
+    assert!(list.graph.nodes.is_shared());
+    assert!(list.graph.nodes.ptr_eq(&copy.graph.nodes));
+
+    let mut graph = Graph::new(GraphType::Directed);
+    graph.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    assert!(!graph.nodes.is_shared());
+    assert!(!graph.nodes.ptr_eq(&graph.clone().nodes));
+    assert!(!Hash::new().graph.nodes.is_shared());
+}
+
+#[test]
+fn test_list_append_on_copy_leaves_original() {
+    let original = List::from_vec((0..100).map(|i| Value::number(i as f64)).collect());
+    let mut copy = original.clone();
+    copy.append(Value::number(100.0)).unwrap();
+
+    assert_eq!(original.len(), 100);
+    assert_eq!(copy.len(), 101);
+    assert_eq!(copy.get(100), Some(&Value::number(100.0)));
+    assert_eq!(original.get(100), None);
+    // The original's last node has no successor edge to the copy's new node
+    assert!(original.graph.neighbors("node_99").is_empty());
+}
+
+#[test]
+fn test_list_set_on_copy_and_original() {
+    let mut original = List::from_vec(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)]);
+    let mut copy = original.clone();
+
+    copy.set(1, Value::number(20.0)).unwrap();
+    original.set(2, Value::number(30.0)).unwrap();
+
+    assert_eq!(numbers(&original), vec![1.0, 2.0, 30.0]);
+    assert_eq!(numbers(&copy), vec![1.0, 20.0, 3.0]);
+}
+
+#[test]
+fn test_list_removal_on_copy_leaves_original() {
+    let original = List::from_vec(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)]);
+    let mut popped = original.clone();
+    let mut removed = original.clone();
+
+    assert_eq!(popped.pop().unwrap(), Value::number(3.0));
+    removed.remove_at_index(0).unwrap();
+
+    assert_eq!(numbers(&original), vec![1.0, 2.0, 3.0]);
+    assert_eq!(numbers(&popped), vec![1.0, 2.0]);
+    assert_eq!(numbers(&removed), vec![2.0, 3.0]);
+}
+
+#[test]
+fn test_freezing_copy_does_not_freeze_original() {
+    let original = Value::list(List::from_vec(vec![
+        Value::list(List::from_vec(vec![Value::number(1.0)])),
+    ]));
+    let mut frozen = original.clone();
+    frozen.freeze();
+
+    assert!(frozen.is_frozen());
+    assert!(!original.is_frozen());
+    if let graphoid::values::ValueKind::List(list) = &original.kind {
+        assert!(!list.get(0).unwrap().is_frozen());
+    }
+}
+
+#[test]
+fn test_hash_insert_on_copy_leaves_original() {
+    let mut original = Hash::new();
+    original.insert("a".to_string(), Value::number(1.0)).unwrap();
+    let mut copy = original.clone();
+
+    copy.insert("a".to_string(), Value::number(10.0)).unwrap();
+    copy.insert("b".to_string(), Value::number(2.0)).unwrap();
+
+    assert_eq!(original.get("a"), Some(&Value::number(1.0)));
+    assert_eq!(original.get("b"), None);
+    assert_eq!(copy.get("a"), Some(&Value::number(10.0)));
+}
+
+#[test]
+fn test_graph_edge_on_copy_leaves_original() {
+    let mut original = Graph::new(GraphType::Directed);
+    original.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    original.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    let mut copy = original.clone();
+
+    copy.add_edge("A", "B", "edge".to_string(), None, HashMap::new()).unwrap();
+    copy.remove_node("B", None).unwrap();
+
+    assert!(original.has_node("B"));
+    assert!(original.neighbors("A").is_empty());
+    assert_eq!(copy.node_count(), 1);
+}
@@ -0,0 +1,58 @@
+//! Parsed program cache tests
+//!
+//! `load` and `import` parse each file once and reuse the cached Program until
+//! the file changes on disk.
+
+use graphoid::execution::{program_cache, Executor};
+use std::rc::Rc;
+
+#[test]
+fn test_parse_file_reuses_cached_program() {
+    let temp_dir = std::env::temp_dir().join("graphoid_program_cache_test");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let path = temp_dir.join("helpers.gr");
+    std::fs::write(&path, "x = 1\n").unwrap();
+
+    let first = program_cache::parse_file(&path).unwrap();
+    let second = program_cache::parse_file(&path).unwrap();
+    assert!(Rc::ptr_eq(&first, &second), "Unchanged file should not be re-parsed");
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_parse_file_reparses_changed_file() {
+    let temp_dir = std::env::temp_dir().join("graphoid_program_cache_test2");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let path = temp_dir.join("helpers.gr");
+    std::fs::write(&path, "x = 1\n").unwrap();
+    let first = program_cache::parse_file(&path).unwrap();
+
+    std::fs::write(&path, "x = 1\ny = 2\n").unwrap();
+    let second = program_cache::parse_file(&path).unwrap();
+    assert!(!Rc::ptr_eq(&first, &second), "Changed file should be re-parsed");
+    assert_eq!(second.statements.len(), 2);
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_repeated_load_uses_cache_without_changing_results() {
+    let temp_dir = std::env::temp_dir().join("graphoid_program_cache_test3");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let path = temp_dir.join("counter.gr");
+    std::fs::write(&path, "count = count + 1\n").unwrap();
+
+    program_cache::clear();
+    let code = format!(
+        "count = 0\nload \"{0}\"\nload \"{0}\"\nload \"{0}\"\n",
+        path.to_str().unwrap()
+    );
+    let mut executor = Executor::new();
+    executor.execute_source(&code).unwrap();
+
+    assert_eq!(executor.env().get("count").unwrap().to_number(), Some(3.0));
+    assert_eq!(program_cache::cached_count(), 1);
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
@@ -276,3 +276,20 @@ fn test_token_urlsafe_returns_safe_string() {
         _ => panic!("token_urlsafe() should return a string"),
     }
 }
+
+#[test]
+fn test_weighted_choice_validates_weights() {
+    let module = RandomModule::new();
+    let functions = module.functions();
+    let weighted = functions.get("weighted_choice").expect("Should have weighted_choice function");
+    let items = Value::list(graphoid::values::List::from_vec(vec![Value::string("a".to_string()), Value::string("b".to_string())]));
+    let weights = |w: Vec<f64>| Value::list(graphoid::values::List::from_vec(w.into_iter().map(Value::number).collect()));
+
+    let result = weighted(&[items.clone(), weights(vec![0.0, 3.0])]).expect("Should return value");
+    assert_eq!(result, Value::string("b".to_string()));
+
+    for bad in [vec![1.0], vec![1.0, -2.0], vec![0.0, 0.0]] {
+        let err = weighted(&[items.clone(), weights(bad)]).unwrap_err();
+        assert!(err.to_string().contains("ValueError"), "got: {}", err);
+    }
+}
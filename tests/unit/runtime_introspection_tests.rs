@@ -336,3 +336,44 @@ fn test_module_var_is_main_in_top_level() {
     let result = executor.get_variable("m").unwrap();
     assert_eq!(result, Value::string("__main__".to_string()));
 }
+
+// ============================================================================
+// respond_to?() / methods()
+// ============================================================================
+
+#[test]
+fn test_respond_to_includes_inherited_and_static_methods() {
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+graph Base {
+    fn hello() { return 1 }
+}
+graph Derived from Base {
+    static fn make() { return 2 }
+    fn _hidden() { return 3 }
+}
+inherited = Derived.respond_to?("hello")
+static_method = Derived.respond_to?(:make)
+hidden = Derived.respond_to?("_hidden")
+builtin = Derived.respond_to?("shortest_path")
+names = Derived.methods()
+"#).unwrap();
+    assert_eq!(executor.get_variable("inherited").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("static_method").unwrap(), Value::boolean(true));
+    assert_eq!(executor.get_variable("hidden").unwrap(), Value::boolean(false));
+    assert_eq!(executor.get_variable("builtin").unwrap(), Value::boolean(true));
+
+    let names = executor.get_variable("names").unwrap();
+    let ValueKind::List(names) = &names.kind else { panic!("methods() should return a list") };
+    let names = names.to_vec();
+    assert!(names.contains(&Value::symbol("hello")));
+    assert!(names.contains(&Value::symbol("make")));
+    assert!(!names.contains(&Value::symbol("_hidden")));
+}
+
+#[test]
+fn test_respond_to_rejects_non_name_argument() {
+    let mut executor = Executor::new();
+    let err = executor.execute_source(r#"x = [1].respond_to?(5)"#).unwrap_err();
+    assert!(err.to_string().contains("Type error: expected method name string or symbol, got num"), "got: {}", err);
+}
@@ -246,6 +246,106 @@ fn test_insert_subgraph_preserves_edges() {
     assert!(main_graph.has_edge("B", "D"));
 }
 
+// =========================================================================
+// merge_with Tests
+// =========================================================================
+
+#[test]
+fn test_merge_with_combines_node_properties() {
+    let mut left = Graph::new(GraphType::Directed);
+    left.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    left.nodes.get_mut("A").unwrap().properties.insert("color".to_string(), Value::string("red".to_string()));
+    left.nodes.get_mut("A").unwrap().properties.insert("size".to_string(), Value::number(1.0));
+
+    let mut right = Graph::new(GraphType::Directed);
+    right.add_node("A".to_string(), Value::number(2.0)).unwrap();
+    right.nodes.get_mut("A").unwrap().properties.insert("color".to_string(), Value::string("blue".to_string()));
+
+    let merged = left.merge_with(&right, |_, mine, _| Ok(mine.clone())).unwrap();
+    let node = merged.nodes.get("A").unwrap();
+    assert_eq!(node.value, Value::number(1.0));
+    assert_eq!(node.properties.get("color"), Some(&Value::string("blue".to_string())));
+    assert_eq!(node.properties.get("size"), Some(&Value::number(1.0)));
+}
+
+#[test]
+fn test_merge_with_keeps_receiver_rules_and_leaves_inputs() {
+    let mut left = Graph::new(GraphType::Directed);
+    left.rulesets.push("dag".to_string());
+    left.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    left.freeze();
+
+    let mut right = Graph::new(GraphType::Directed);
+    right.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    right.add_edge("B", "B", "self".to_string(), None, HashMap::new()).unwrap();
+
+    let err = left.merge_with(&right, |_, _, theirs| Ok(theirs.clone())).unwrap_err();
+    assert!(err.to_string().contains("ValueError"), "{}", err);
+    assert_eq!(left.node_count(), 1);
+    assert!(left.is_frozen());
+}
+
+// =========================================================================
+// relabel_nodes_with Tests
+// =========================================================================
+
+#[test]
+fn test_relabel_nodes_keeps_properties_and_parallel_edges() {
+    let mut graph = Graph::new(GraphType::Undirected);
+    graph.multigraph = true;
+    graph.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    graph.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    graph.nodes.get_mut("A").unwrap().properties.insert("color".to_string(), Value::string("red".to_string()));
+    graph.add_edge("A", "B", "road".to_string(), Some(1.0), HashMap::new()).unwrap();
+    graph.add_edge("A", "B", "rail".to_string(), Some(2.0), HashMap::new()).unwrap();
+
+    let mapping = HashMap::from([("A".to_string(), "X".to_string()), ("Missing".to_string(), "Y".to_string())]);
+    let relabeled = graph.relabel_nodes_with(&mapping, |_, _, _| unreachable!()).unwrap();
+
+    assert_eq!(relabeled.data_node_ids().len(), 2);
+    assert!(relabeled.has_node("X") && relabeled.has_node("B") && !relabeled.has_node("Y"));
+    assert_eq!(relabeled.nodes.get("X").unwrap().properties.get("color"), Some(&Value::string("red".to_string())));
+    assert!(relabeled.has_edge("X", "B") && relabeled.has_edge("B", "X"));
+    assert_eq!(relabeled.edge_count(), graph.edge_count());
+    assert!(graph.has_node("A"));
+}
+
+#[test]
+fn test_relabel_nodes_collision_resolution() {
+    let mut graph = Graph::new(GraphType::Directed);
+    graph.add_node("A".to_string(), Value::number(1.0)).unwrap();
+    graph.add_node("B".to_string(), Value::number(2.0)).unwrap();
+    graph.add_node("C".to_string(), Value::number(3.0)).unwrap();
+    graph.add_edge("A", "C", "link".to_string(), None, HashMap::new()).unwrap();
+    graph.add_edge("B", "C", "link".to_string(), None, HashMap::new()).unwrap();
+
+    let mapping = HashMap::from([("A".to_string(), "AB".to_string()), ("B".to_string(), "AB".to_string())]);
+    let merged = graph.relabel_nodes_with(&mapping, |_, kept, incoming| {
+        match (&kept.kind, &incoming.kind) {
+            (graphoid::values::ValueKind::Number(a), graphoid::values::ValueKind::Number(b)) => Ok(Value::number(a + b)),
+            _ => unreachable!(),
+        }
+    }).unwrap();
+    assert_eq!(merged.nodes.get("AB").unwrap().value, Value::number(3.0));
+    assert!(merged.has_edge("AB", "C"));
+    assert_eq!(merged.data_node_ids().len(), 2);
+
+    let err = graph.relabel_nodes_with(&mapping, |id, _, _| {
+        Err(graphoid::error::GraphoidError::runtime(format!("ValueError: collision at {}", id)))
+    }).unwrap_err();
+    assert!(err.to_string().contains("collision at AB"), "{}", err);
+}
+
+#[test]
+fn test_relabel_nodes_rejects_reserved_ids() {
+    let mut graph = Graph::new(GraphType::Directed);
+    graph.add_node("A".to_string(), Value::number(1.0)).unwrap();
+
+    let mapping = HashMap::from([("A".to_string(), "__self__".to_string())]);
+    let err = graph.relabel_nodes_with(&mapping, |_, kept, _| Ok(kept.clone())).unwrap_err();
+    assert!(err.to_string().contains("ValueError"), "{}", err);
+}
+
 // =========================================================================
 // Integration Tests
 // =========================================================================
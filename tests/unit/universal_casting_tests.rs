@@ -361,3 +361,60 @@ fn test_number_to_num() {
     let result = executor.env().get("result").unwrap();
     assert!(matches!(&result.kind, ValueKind::Number(n) if *n == 42.5));
 }
+
+// ============================================================================
+// Symbol Conversion Tests
+// ============================================================================
+
+#[test]
+fn test_string_to_symbol() {
+    let mut executor = Executor::new();
+    let code = r#"
+        result = "status".to_symbol()
+        same = result == :status
+    "#;
+
+    executor.execute_source(code).unwrap();
+    let result = executor.env().get("result").unwrap();
+    assert!(matches!(&result.kind, ValueKind::Symbol(s) if s.as_str() == "status"));
+    let same = executor.env().get("same").unwrap();
+    assert!(matches!(&same.kind, ValueKind::Boolean(b) if *b));
+}
+
+#[test]
+fn test_symbol_to_string_strips_colon() {
+    let mut executor = Executor::new();
+    let code = r#"
+        result = :ok.to_string()
+        round_trip = result.to_symbol() == :ok
+    "#;
+
+    executor.execute_source(code).unwrap();
+    let result = executor.env().get("result").unwrap();
+    assert!(matches!(&result.kind, ValueKind::String(s) if s == "ok"));
+    let round_trip = executor.env().get("round_trip").unwrap();
+    assert!(matches!(&round_trip.kind, ValueKind::Boolean(b) if *b));
+}
+
+#[test]
+fn test_string_to_symbol_rejects_invalid_names() {
+    for source in [r#""bad name".to_symbol()"#, r#""".to_symbol()"#, r#""a-b".to_symbol()"#] {
+        let mut executor = Executor::new();
+        let err = executor.execute_source(source).unwrap_err();
+        assert!(err.to_string().contains("ValueError"), "{}: {}", source, err);
+    }
+}
+
+#[test]
+fn test_string_to_symbol_as_computed_map_key() {
+    let mut executor = Executor::new();
+    let code = r#"
+        field = "name"
+        record = {[field.to_symbol()]: "Alice"}
+        result = record[:name]
+    "#;
+
+    executor.execute_source(code).unwrap();
+    let result = executor.env().get("result").unwrap();
+    assert!(matches!(&result.kind, ValueKind::String(s) if s == "Alice"));
+}
@@ -223,6 +223,42 @@ fn test_to_string_value_map() {
     assert!(s.contains("\"a\": 1")); // Keys are quoted in map string representation
 }
 
+#[test]
+fn test_inspect_quotes_strings_and_keeps_symbols() {
+    let mut hash = Hash::new();
+    let _ = hash.insert("k".to_string(), Value::string("line\n".to_string()));
+    let val = Value::list(List::from_vec(vec![
+        Value::string("1".to_string()),
+        Value::number(1.0),
+        Value::symbol("ok".to_string()),
+        Value::none(),
+        Value::map(hash),
+    ]));
+    assert_eq!(val.inspect(), r#"["1", 1, :ok, none, {"k": "line\n"}]"#);
+    assert_eq!(Value::string("1".to_string()).to_string_value(), "1");
+}
+
+#[test]
+fn test_inspect_graph_shows_nodes_and_edges() {
+    let mut graph = Graph::new(GraphType::Undirected);
+    graph.add_node("a".to_string(), Value::number(1.0)).unwrap();
+    graph.add_node("b".to_string(), Value::string("x".to_string())).unwrap();
+    graph.add_edge("a", "b", "link".to_string(), None, HashMap::new()).unwrap();
+    let val = Value::graph(graph);
+    assert_eq!(val.inspect(), r#"<graph nodes: {"a": 1, "b": "x"}, edges: ["a" -- "b" :link]>"#);
+}
+
+#[test]
+fn test_inspect_stops_at_cycles() {
+    let val = Value::graph(Graph::new(GraphType::Directed));
+    if let ValueKind::Graph(rc) = &val.kind {
+        let mut hash = Hash::new();
+        let _ = hash.insert("back".to_string(), val.clone());
+        rc.borrow_mut().add_node("self".to_string(), Value::map(hash)).unwrap();
+    }
+    assert_eq!(val.inspect(), r#"<graph nodes: {"self": {"back": ...}}, edges: []>"#);
+}
+
 // ============================================================================
 // Freezing Tests
 // ============================================================================
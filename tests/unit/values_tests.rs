@@ -1,4 +1,4 @@
-use graphoid::values::{Value, List, Hash, ValueKind};
+use graphoid::values::{Value, List, Hash, ValueKind, Symbol, ValueSet};
 use std::collections::HashMap;
 
 #[test]
@@ -95,3 +95,170 @@ fn test_map_creation() {
         panic!("Expected Map variant");
     }
 }
+
+#[test]
+fn test_symbols_are_interned() {
+    let a = Value::symbol("interned".to_string());
+    let b = Value::symbol("interned");
+
+    match (&a.kind, &b.kind) {
+        (ValueKind::Symbol(x), ValueKind::Symbol(y)) => {
+            assert!(std::ptr::eq(x.as_str(), y.as_str()));
+            assert_eq!(x, "interned");
+        }
+        _ => panic!("Expected Symbol variants"),
+    }
+    assert_ne!(a, Value::symbol("other"));
+}
+
+#[test]
+fn test_symbol_equal_across_threads() {
+    // The intern pool is shared by all threads, so a symbol made on another
+    // thread is the same allocation as one made here
+    let remote = std::thread::spawn(|| Symbol::intern("shared")).join().unwrap();
+    let local = Symbol::intern("shared");
+    assert_eq!(remote, local);
+    assert!(std::ptr::eq(remote.as_str(), local.as_str()));
+}
+
+#[test]
+fn test_symbol_pool_releases_unused_names() {
+    let kept = Symbol::intern("kept_through_sweeps");
+    for i in 0..10_000 {
+        let temporary = Symbol::intern(&format!("temporary_symbol_{}", i));
+        assert_eq!(temporary, Symbol::intern(&format!("temporary_symbol_{}", i)));
+    }
+    assert!(Symbol::pool_size() < 5_000, "pool grew to {}", Symbol::pool_size());
+    assert!(std::ptr::eq(kept.as_str(), Symbol::intern("kept_through_sweeps").as_str()));
+}
+
+#[test]
+fn test_equal_values_hash_alike() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash as _, Hasher};
+
+    fn hash_of(value: &Value) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut ab = HashMap::new();
+    ab.insert("a".to_string(), Value::number(1.0));
+    ab.insert("b".to_string(), Value::number(2.0));
+    let first = Value::map(Hash::from_hashmap(ab.clone()));
+    let second = Value::map(Hash::from_hashmap(ab));
+    assert_eq!(first, second);
+    assert_eq!(hash_of(&first), hash_of(&second));
+
+    assert_eq!(hash_of(&Value::number(0.0)), hash_of(&Value::number(-0.0)));
+    let list = Value::list(List::from_vec(vec![Value::number(1.0), Value::string("x".to_string())]));
+    assert_eq!(hash_of(&list), hash_of(&list.clone()));
+    assert_ne!(hash_of(&Value::string("1".to_string())), hash_of(&Value::number(1.0)));
+}
+
+#[test]
+fn test_hash_accepts_value_keys() {
+    let pair = Value::list(List::from_vec(vec![Value::number(1.0), Value::number(2.0)]));
+    let mut hash = Hash::new();
+    hash.insert("1".to_string(), Value::string("text".to_string())).unwrap();
+    hash.insert_key(Value::number(1.0), Value::string("number".to_string())).unwrap();
+    hash.insert_key(pair.clone(), Value::string("pair".to_string())).unwrap();
+
+    assert_eq!(hash.len(), 3);
+    assert_eq!(hash.get("1"), Some(&Value::string("text".to_string())));
+    assert_eq!(hash.get_key(&Value::number(1.0)), Some(&Value::string("number".to_string())));
+    assert_eq!(hash.get_key(&pair), Some(&Value::string("pair".to_string())));
+    assert!(hash.key_values().contains(&pair));
+
+    assert_eq!(hash.remove_key(&pair).unwrap(), Some(Value::string("pair".to_string())));
+    assert!(!hash.contains(&pair));
+    let channel_key = Value::channel(graphoid::values::Channel::new(None));
+    assert!(hash.insert_key(channel_key, Value::none()).is_err());
+}
+
+#[test]
+fn test_hash_displays_in_insertion_order() {
+    let mut hash = Hash::new();
+    for (key, n) in [("zebra", 1.0), ("apple", 2.0), ("mango", 3.0)] {
+        hash.insert(key.to_string(), Value::number(n)).unwrap();
+    }
+    // Updating a key keeps its position
+    hash.insert("zebra".to_string(), Value::number(9.0)).unwrap();
+    let map = Value::map(hash.clone());
+    assert_eq!(map.to_string_value(), r#"{"zebra": 9, "apple": 2, "mango": 3}"#);
+    assert_eq!(hash.keys(), vec!["zebra", "apple", "mango"]);
+
+    let mut reordered = Hash::new();
+    for (key, n) in [("mango", 3.0), ("apple", 2.0), ("zebra", 9.0)] {
+        reordered.insert(key.to_string(), Value::number(n)).unwrap();
+    }
+    assert_eq!(Value::map(reordered), map);
+}
+
+#[test]
+fn test_hash_to_string_sorted() {
+    let mut inner = Hash::new();
+    inner.insert("y".to_string(), Value::number(2.0)).unwrap();
+    inner.insert("x".to_string(), Value::number(1.0)).unwrap();
+    let mut hash = Hash::new();
+    hash.insert("b".to_string(), Value::map(inner)).unwrap();
+    hash.insert("a".to_string(), Value::boolean(true)).unwrap();
+    hash.insert_key(Value::number(10.0), Value::none()).unwrap();
+    hash.insert_key(Value::number(2.0), Value::none()).unwrap();
+
+    assert_eq!(
+        hash.to_string_sorted(),
+        r#"{2: none, 10: none, "a": true, "b": {"x": 1, "y": 2}}"#
+    );
+}
+
+#[test]
+fn test_hash_value_keys_do_not_collide_with_string_keys() {
+    let mut hash = Hash::new();
+    hash.insert_key(Value::number(1.0), Value::string("number".to_string())).unwrap();
+    // Non-string keys are kept beside the graph, not as node properties
+    assert!(hash.graph.nodes.values().all(|node| node.properties.is_empty()));
+
+    let disguised = Value::string(format!("\u{0}{}", Value::number(1.0).content_key()));
+    let err = hash.insert_key(disguised, Value::none()).unwrap_err();
+    assert!(err.to_string().contains("ValueError: map keys cannot start with a NUL character"), "got: {}", err);
+    assert!(hash.insert("\u{0}raw".to_string(), Value::none()).is_err());
+    assert_eq!(hash.key_values(), vec![Value::number(1.0)]);
+}
+
+#[test]
+fn test_hash_code_is_stable_and_matches_equality() {
+    let list = Value::list(List::from_vec(vec![Value::number(1.0), Value::string("x".to_string())]));
+    assert_eq!(list.hash_code(), list.clone().hash_code());
+    assert_eq!(Value::number(0.0).hash_code(), Value::number(-0.0).hash_code());
+    assert_ne!(Value::string("1".to_string()).hash_code(), Value::number(1.0).hash_code());
+    assert!(list.hash_code() < 2f64.powi(53));
+}
+
+#[test]
+fn test_value_to_json() {
+    let mut hash = Hash::new();
+    hash.insert("ratio".to_string(), Value::number(2.5)).unwrap();
+    let list = Value::list(List::from_vec(vec![
+        Value::number(3.0),
+        Value::string("a".to_string()),
+        Value::none(),
+        Value::boolean(true),
+        Value::map(hash),
+    ]));
+    assert_eq!(list.to_json().to_string(), r#"[3,"a",null,true,{"ratio":2.5}]"#);
+    assert_eq!(Value::number(f64::NAN).to_json(), serde_json::Value::Null);
+}
+
+#[test]
+fn test_value_set_dedupes_by_value() {
+    let mut set = ValueSet::new();
+    assert!(set.insert(Value::number(1.0)));
+    assert!(!set.insert(Value::number(1.0)));
+    assert!(set.insert(Value::string("1".to_string())));
+    let pair = Value::list(List::from_vec(vec![Value::number(1.0), Value::number(2.0)]));
+    assert!(set.insert(pair.clone()));
+    assert!(set.contains(&pair));
+    assert_eq!(set.len(), 3);
+}
@@ -532,6 +532,150 @@ fn test_dijkstra_mixed_weighted_unweighted_graph() {
     assert_eq!(path, vec!["A".to_string(), "B".to_string(), "C".to_string()]);
 }
 
+// ============================================================================
+// astar_path() Tests
+// ============================================================================
+
+fn astar_diamond() -> Graph {
+    // A -1-> B -1-> D, A -1-> C -5-> D
+    let mut graph = Graph::new(GraphType::Directed);
+    for id in ["A", "B", "C", "D"] {
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    graph.add_edge("A", "B", "road".to_string(), Some(1.0), HashMap::new()).unwrap();
+    graph.add_edge("B", "D", "road".to_string(), Some(1.0), HashMap::new()).unwrap();
+    graph.add_edge("A", "C", "road".to_string(), Some(1.0), HashMap::new()).unwrap();
+    graph.add_edge("C", "D", "road".to_string(), Some(5.0), HashMap::new()).unwrap();
+    graph
+}
+
+#[test]
+fn test_astar_finds_cheapest_path_with_admissible_heuristic() {
+    let graph = astar_diamond();
+    let mut calls = Vec::new();
+    let path = graph.astar_path("A", "D", |id| {
+        calls.push(id.to_string());
+        Ok(0.0)
+    }).unwrap();
+    assert_eq!(path, Some(vec!["A".to_string(), "B".to_string(), "D".to_string()]));
+
+    // Each node is estimated at most once
+    calls.sort();
+    calls.dedup();
+    assert_eq!(calls.len(), 4);
+}
+
+#[test]
+fn test_astar_inadmissible_heuristic_may_be_non_optimal() {
+    let graph = astar_diamond();
+    let path = graph.astar_path("A", "D", |id| Ok(if id == "B" { 100.0 } else { 0.0 })).unwrap();
+    assert_eq!(path, Some(vec!["A".to_string(), "C".to_string(), "D".to_string()]));
+}
+
+#[test]
+fn test_astar_no_path_and_negative_heuristic() {
+    let graph = astar_diamond();
+    assert_eq!(graph.astar_path("D", "A", |_| Ok(0.0)).unwrap(), None);
+    assert_eq!(graph.astar_path("A", "Z", |_| Ok(0.0)).unwrap(), None);
+
+    let err = graph.astar_path("A", "D", |_| Ok(-1.0)).unwrap_err();
+    assert!(err.to_string().contains("ValueError: astar_path() heuristic must return a non-negative number"), "got: {}", err);
+}
+
+#[test]
+fn test_astar_path_method_calls_heuristic_block() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+remaining = {"a": 2, "b": 1, "c": 0, "d": 9}
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_node("d", 4)
+g.add_edge("a", "b")
+g.add_edge("b", "c")
+g.add_edge("a", "d")
+route = g.astar_path("a", "c", (id) => remaining[id])
+stuck = g.astar_path("c", "a", (id) => 0)
+"#).unwrap();
+    assert_eq!(executor.get_variable("route").unwrap().to_string_value(), "[a, b, c]");
+    assert_eq!(executor.get_variable("stuck").unwrap(), Value::none());
+
+    let err = executor.execute_source(r#"x = g.astar_path("a", "c", (id) => -1)"#).unwrap_err();
+    assert!(err.to_string().contains("ValueError"), "got: {}", err);
+}
+
+// ============================================================================
+// weight_of_path() / total_weight() Tests
+// ============================================================================
+
+#[test]
+fn test_weight_of_path_sums_weights_and_defaults_to_one() {
+    // A -5-> B -> C (unweighted) -2.5-> D
+    let mut graph = Graph::new(GraphType::Directed);
+    for id in ["A", "B", "C", "D"] {
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    graph.add_edge("A", "B", "road".to_string(), Some(5.0), HashMap::new()).unwrap();
+    graph.add_edge("B", "C", "road".to_string(), None, HashMap::new()).unwrap();
+    graph.add_edge("C", "D", "road".to_string(), Some(2.5), HashMap::new()).unwrap();
+
+    let path = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    assert_eq!(graph.weight_of_path(&path(&["A", "B", "C", "D"])).unwrap(), 8.5);
+    assert_eq!(graph.weight_of_path(&path(&["B"])).unwrap(), 0.0);
+    assert_eq!(graph.weight_of_path(&[]).unwrap(), 0.0);
+    assert_eq!(graph.total_weight(), 8.5);
+
+    let err = graph.weight_of_path(&path(&["A", "C"])).unwrap_err();
+    assert!(err.to_string().contains("ValueError: weight_of_path() has no edge from 'A' to 'C'"), "got: {}", err);
+    let err = graph.weight_of_path(&path(&["A", "Z"])).unwrap_err();
+    assert!(err.to_string().contains("node 'Z' not found"), "got: {}", err);
+}
+
+#[test]
+fn test_total_weight_counts_undirected_edges_once() {
+    let mut graph = Graph::new(GraphType::Undirected);
+    for id in ["A", "B", "C"] {
+        graph.add_node(id.to_string(), Value::none()).unwrap();
+    }
+    graph.add_edge("A", "B", "road".to_string(), Some(4.0), HashMap::new()).unwrap();
+    graph.add_edge("C", "B", "road".to_string(), Some(1.5), HashMap::new()).unwrap();
+
+    assert_eq!(graph.total_weight(), 5.5);
+    let path = vec!["C".to_string(), "B".to_string(), "A".to_string()];
+    assert_eq!(graph.weight_of_path(&path).unwrap(), 5.5);
+    assert_eq!(Graph::new(GraphType::Directed).total_weight(), 0.0);
+}
+
+#[test]
+fn test_weight_of_path_method_on_shortest_path() {
+    use graphoid::execution::Executor;
+
+    let mut executor = Executor::new();
+    executor.execute_source(r#"
+g = graph { type: :directed }
+g.add_node("a", 1)
+g.add_node("b", 2)
+g.add_node("c", 3)
+g.add_edge("a", "b", "road", 2)
+g.add_edge("b", "c", "road", 3)
+g.add_edge("a", "c", "road", 10)
+route = g.shortest_path("a", "c", :weighted)
+cost = g.weight_of_path(route)
+total = g.total_weight()
+broken = try { g.weight_of_path(["c", "a"]) } catch ValueError as e { e.message() }
+"#).unwrap();
+    assert_eq!(executor.get_variable("cost").unwrap(), Value::number(5.0));
+    assert_eq!(executor.get_variable("total").unwrap(), Value::number(15.0));
+    let broken = executor.get_variable("broken").unwrap();
+    assert!(broken.to_string_value().contains("no edge from 'c' to 'a'"), "got: {:?}", broken);
+
+    let err = executor.execute_source(r#"x = g.weight_of_path(["a", 2])"#).unwrap_err();
+    assert!(err.to_string().contains("TypeError"), "got: {}", err);
+}
+
 // ============================================================================
 // nodes_within() Tests (10 tests) - TDD: Write tests first!
 // ============================================================================
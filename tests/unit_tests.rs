@@ -38,6 +38,7 @@ mod unit {
     pub mod os_module_tests;
     pub mod bst_ordering_tests;
     pub mod bitwise_tests;
+    pub mod program_cache_tests;
     pub mod bitwise_unsigned_debug;
     pub mod string_methods_tests;
     pub mod number_methods_tests;
@@ -67,4 +68,5 @@ mod unit {
     pub mod ffi_struct_tests;
     pub mod ffi_callback_tests;
     pub mod ffi_safety_tests;
+    pub mod persistent_map_tests;
 }